@@ -23,6 +23,7 @@ use beads_rust::model::{Issue, IssueType, Priority, Status};
 use beads_rust::storage::{IssueUpdate, ListFilters, ReadyFilters, ReadySortPolicy, SqliteStorage};
 use chrono::Utc;
 use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use std::collections::BTreeMap;
 use std::hint::black_box;
 use std::io::Cursor;
 use std::sync::Once;
@@ -60,6 +61,7 @@ fn create_test_issue(i: usize) -> Issue {
         updated_at: Utc::now(),
         closed_at: None,
         close_reason: None,
+        close_outcome: None,
         closed_by_session: None,
         due_at: None,
         defer_until: None,
@@ -81,6 +83,7 @@ fn create_test_issue(i: usize) -> Issue {
         labels: vec![format!("label-{}", i % 5)],
         dependencies: vec![],
         comments: vec![],
+        fields: BTreeMap::new(),
     }
 }
 
@@ -285,6 +288,7 @@ fn bench_update_issue(c: &mut Criterion) {
                 external_ref: None,
                 closed_at: None,
                 close_reason: None,
+                close_outcome: None,
                 closed_by_session: None,
                 deleted_at: None,
                 deleted_by: None,
@@ -329,6 +333,7 @@ fn bench_close_issue_with_reason(c: &mut Criterion) {
                 status: Some(Status::Closed),
                 closed_at: Some(Some(Utc::now())),
                 close_reason: Some(Some("benchmark close".to_string())),
+                close_outcome: None,
                 closed_by_session: Some(Some("bench-session".to_string())),
                 ..IssueUpdate::default()
             };
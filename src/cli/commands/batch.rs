@@ -0,0 +1,565 @@
+//! Batch command implementation.
+//!
+//! Reads one operation per JSONL line, resolves every ID up front (including
+//! `$name` placeholders for issues created earlier in the same file), then
+//! applies the whole batch in a single storage transaction.
+
+use super::create::{CreateConfig, build_issue};
+use crate::cli::{BatchArgs, CreateArgs, UpdateArgs};
+use crate::config;
+use crate::error::{BeadsError, Result, ResultExt};
+use crate::model::{CloseOutcome, DependencyType};
+use crate::output::OutputContext;
+use crate::storage::{BatchOp, SqliteStorage};
+use crate::util::id::{IdGenerator, IdResolver, ResolverConfig, child_id, find_matching_ids};
+use crate::validation::LabelValidator;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+/// One line of the operations file, tagged by `op`.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum OpSpec {
+    Create(CreateSpec),
+    Update(UpdateSpec),
+    Close(CloseSpec),
+    DepAdd(DepAddSpec),
+    LabelAdd(LabelAddSpec),
+    Comment(CommentSpec),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CreateSpec {
+    /// Placeholder name; later operations refer to the new issue as `$name`
+    #[serde(rename = "ref")]
+    name: Option<String>,
+    title: String,
+    #[serde(rename = "type")]
+    issue_type: Option<String>,
+    priority: Option<PriorityValue>,
+    status: Option<String>,
+    description: Option<String>,
+    assignee: Option<String>,
+    owner: Option<String>,
+    #[serde(default)]
+    labels: Vec<String>,
+    parent: Option<String>,
+    /// `type:id` or `id` (blocks), as for `br create --deps`
+    #[serde(default)]
+    deps: Vec<String>,
+    estimate: Option<i32>,
+    due: Option<String>,
+    defer: Option<String>,
+    external_ref: Option<String>,
+    /// `name=value`, as for `br create --field`
+    #[serde(default)]
+    fields: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct UpdateSpec {
+    id: String,
+    title: Option<String>,
+    description: Option<String>,
+    design: Option<String>,
+    acceptance_criteria: Option<String>,
+    notes: Option<String>,
+    status: Option<String>,
+    priority: Option<PriorityValue>,
+    #[serde(rename = "type")]
+    issue_type: Option<String>,
+    assignee: Option<String>,
+    owner: Option<String>,
+    estimate: Option<i32>,
+    due: Option<String>,
+    defer: Option<String>,
+    external_ref: Option<String>,
+    reason: Option<String>,
+    /// `name=value`, as for `br update --field`; an empty value clears it
+    #[serde(default)]
+    fields: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CloseSpec {
+    id: String,
+    reason: Option<String>,
+    /// `success`, `failure` or `wontfix`, as for `br close --outcome`
+    outcome: Option<CloseOutcome>,
+    /// Close even if open blockers remain
+    #[serde(default)]
+    force: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DepAddSpec {
+    id: String,
+    depends_on: String,
+    #[serde(rename = "type")]
+    dep_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LabelAddSpec {
+    id: String,
+    label: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CommentSpec {
+    id: String,
+    text: String,
+}
+
+/// Priority as a number (`1`) or a string (`"P1"`).
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum PriorityValue {
+    Number(i64),
+    Text(String),
+}
+
+impl PriorityValue {
+    fn into_string(self) -> String {
+        match self {
+            Self::Number(value) => value.to_string(),
+            Self::Text(value) => value,
+        }
+    }
+}
+
+/// Outcome of one operation.
+#[derive(Debug, Serialize)]
+pub struct OpResult {
+    pub op: &'static str,
+    /// The issue the operation applied to
+    pub id: String,
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// False when there was nothing to do (label or dependency already
+    /// present, issue already closed)
+    pub changed: bool,
+}
+
+/// JSON output for the batch command.
+#[derive(Debug, Serialize)]
+pub struct BatchResult {
+    /// Placeholder name to the ID it was given
+    pub ids: BTreeMap<String, String>,
+    pub results: Vec<OpResult>,
+}
+
+/// Execute the batch command.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or parsed, an ID or
+/// placeholder cannot be resolved, or any operation fails; in that case
+/// nothing is written.
+pub fn execute(args: &BatchArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let specs = parse_ops(&read_ops(args)?)?;
+
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let config = CreateConfig {
+        id_config: config::id_config_from_layer(&layer),
+        default_priority: config::default_priority_from_layer(&layer)?,
+        default_issue_type: config::default_issue_type_from_layer(&layer)?,
+        actor: config::resolve_actor(&layer),
+    };
+
+    let mut planner = Planner::new(&storage_ctx.storage, &config, &beads_dir)?;
+    let mut ops = Vec::with_capacity(specs.len());
+    let mut results = Vec::with_capacity(specs.len());
+    for (line, spec) in specs {
+        let (op, result) = planner.plan(spec).with_context(|| format!("line {line}"))?;
+        ops.push(op);
+        results.push(result);
+    }
+    let ids = planner.refs;
+
+    let changed = storage_ctx.storage.apply_batch(&ops, &config.actor)?;
+    for (result, changed) in results.iter_mut().zip(changed) {
+        result.changed = changed;
+    }
+    if let Some(last) = results.last() {
+        crate::util::set_last_touched_id(&beads_dir, &last.id);
+    }
+
+    let result = BatchResult { ids, results };
+    if ctx.is_json() {
+        ctx.json_pretty(&result);
+    } else if !ctx.is_quiet() {
+        let created = result.results.iter().filter(|r| r.op == "create").count();
+        ctx.success(&format!(
+            "Applied {} operation(s), {created} issue(s) created",
+            result.results.len()
+        ));
+        for op in &result.results {
+            let name = op
+                .name
+                .as_deref()
+                .map(|name| format!(" (${name})"))
+                .unwrap_or_default();
+            let unchanged = if op.changed { "" } else { " (no change)" };
+            println!("  {:<9} {}{name}{unchanged}", op.op, op.id);
+        }
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+    Ok(())
+}
+
+fn read_ops(args: &BatchArgs) -> Result<String> {
+    if args.file.as_os_str() == "-" {
+        let mut buffer = String::new();
+        std::io::stdin().read_to_string(&mut buffer)?;
+        return Ok(buffer);
+    }
+    Ok(fs::read_to_string(&args.file)?)
+}
+
+/// Parse the operations, keeping each one's line number for error messages.
+fn parse_ops(input: &str) -> Result<Vec<(usize, OpSpec)>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str(line)
+                .map(|spec| (index + 1, spec))
+                .map_err(|err| BeadsError::JsonlParse {
+                    line: index + 1,
+                    reason: err.to_string(),
+                })
+        })
+        .collect()
+}
+
+/// Turns operation specs into storage operations with concrete IDs.
+struct Planner<'a> {
+    storage: &'a SqliteStorage,
+    config: &'a CreateConfig,
+    resolver: IdResolver,
+    all_ids: Vec<String>,
+    now: DateTime<Utc>,
+    /// Placeholder name to the ID of the issue created for it
+    refs: BTreeMap<String, String>,
+    /// IDs of issues created earlier in the batch
+    created: HashSet<String>,
+    /// Next free child number per parent, once a child was planned
+    next_child: HashMap<String, u32>,
+    /// Workspaces that routed `dep_add` targets live in
+    routed: config::RoutedIssueLookup,
+}
+
+impl<'a> Planner<'a> {
+    fn new(storage: &'a SqliteStorage, config: &'a CreateConfig, beads_dir: &Path) -> Result<Self> {
+        Ok(Self {
+            storage,
+            config,
+            resolver: IdResolver::new(ResolverConfig::with_prefix(config.id_config.prefix.clone())),
+            all_ids: storage.get_all_ids()?,
+            now: Utc::now(),
+            refs: BTreeMap::new(),
+            created: HashSet::new(),
+            next_child: HashMap::new(),
+            routed: config::RoutedIssueLookup::new(beads_dir),
+        })
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn plan(&mut self, spec: OpSpec) -> Result<(BatchOp, OpResult)> {
+        let result = |op: &'static str, id: &str| OpResult {
+            op,
+            id: id.to_string(),
+            name: None,
+            changed: false,
+        };
+        match spec {
+            OpSpec::Create(spec) => self.plan_create(spec),
+            OpSpec::Update(spec) => {
+                let id = self.resolve(&spec.id)?;
+                let args = UpdateArgs {
+                    title: spec.title,
+                    description: spec.description,
+                    design: spec.design,
+                    acceptance_criteria: spec.acceptance_criteria,
+                    notes: spec.notes,
+                    status: spec.status,
+                    priority: spec.priority.map(PriorityValue::into_string),
+                    type_: spec.issue_type,
+                    assignee: spec.assignee,
+                    owner: spec.owner,
+                    estimate: spec.estimate,
+                    due: spec.due,
+                    defer: spec.defer,
+                    external_ref: spec.external_ref,
+                    reason: spec.reason,
+                    fields: spec.fields,
+                    ..UpdateArgs::default()
+                };
+                let update = super::update::build_update(
+                    &args,
+                    &self.config.actor,
+                    self.storage.workflow(),
+                )?;
+                let fields =
+                    super::update::build_field_changes(&args, self.storage.field_schema())?;
+                Ok((
+                    BatchOp::Update {
+                        id: id.clone(),
+                        update,
+                        fields,
+                    },
+                    result("update", &id),
+                ))
+            }
+            OpSpec::Close(spec) => {
+                let id = self.resolve(&spec.id)?;
+                Ok((
+                    BatchOp::Close {
+                        id: id.clone(),
+                        reason: spec.reason.unwrap_or_else(|| "done".to_string()),
+                        outcome: spec.outcome,
+                        force: spec.force,
+                    },
+                    result("close", &id),
+                ))
+            }
+            OpSpec::DepAdd(spec) => {
+                let issue_id = self.resolve(&spec.id)?;
+                let depends_on_id = self.resolve_dep_add_target(&spec.depends_on)?;
+                let type_str = spec.dep_type.as_deref().unwrap_or("blocks");
+                let dep_type: DependencyType =
+                    type_str.parse().map_err(|_| invalid_dep_type(type_str))?;
+                if let DependencyType::Custom(_) = dep_type {
+                    return Err(invalid_dep_type(type_str));
+                }
+                if issue_id == depends_on_id {
+                    return Err(BeadsError::SelfDependency { id: issue_id });
+                }
+                Ok((
+                    BatchOp::AddDependency {
+                        issue_id: issue_id.clone(),
+                        depends_on_id,
+                        dep_type: dep_type.as_str().to_string(),
+                    },
+                    result("dep_add", &issue_id),
+                ))
+            }
+            OpSpec::LabelAdd(spec) => {
+                let issue_id = self.resolve(&spec.id)?;
+                let label = spec.label.trim().to_string();
+                LabelValidator::validate(&label)
+                    .map_err(|e| BeadsError::validation("label", e.message))?;
+                Ok((
+                    BatchOp::AddLabel {
+                        issue_id: issue_id.clone(),
+                        label,
+                    },
+                    result("label_add", &issue_id),
+                ))
+            }
+            OpSpec::Comment(spec) => {
+                let issue_id = self.resolve(&spec.id)?;
+                if spec.text.trim().is_empty() {
+                    return Err(BeadsError::validation("text", "comment text required"));
+                }
+                Ok((
+                    BatchOp::AddComment {
+                        issue_id: issue_id.clone(),
+                        text: spec.text,
+                    },
+                    result("comment", &issue_id),
+                ))
+            }
+        }
+    }
+
+    fn plan_create(&mut self, spec: CreateSpec) -> Result<(BatchOp, OpResult)> {
+        if let Some(name) = &spec.name
+            && (name.is_empty() || self.refs.contains_key(name))
+        {
+            return Err(BeadsError::validation(
+                "ref",
+                format!("placeholder '{name}' is empty or already defined"),
+            ));
+        }
+
+        let parent = spec
+            .parent
+            .as_deref()
+            .map(|parent| self.resolve(parent))
+            .transpose()?;
+        let deps = spec
+            .deps
+            .iter()
+            .map(|dep| self.resolve_dep_target(dep))
+            .collect::<Result<Vec<_>>>()?;
+        let args = CreateArgs {
+            title: Some(spec.title),
+            type_: spec.issue_type,
+            priority: spec.priority.map(PriorityValue::into_string),
+            description: spec.description,
+            assignee: spec.assignee,
+            owner: spec.owner,
+            labels: spec.labels,
+            parent,
+            deps,
+            estimate: spec.estimate,
+            due: spec.due,
+            defer: spec.defer,
+            external_ref: spec.external_ref,
+            status: spec.status,
+            fields: spec.fields,
+            ..CreateArgs::default()
+        };
+
+        let title = args.title.as_deref().unwrap_or_default();
+        let id = self.allocate_id(title, args.parent.as_deref())?;
+        let issue = build_issue(self.storage, &args, self.config, &id, self.now)?;
+
+        self.created.insert(id.clone());
+        if let Some(name) = &spec.name {
+            self.refs.insert(name.clone(), id.clone());
+        }
+        let result = OpResult {
+            op: "create",
+            id,
+            name: spec.name,
+            changed: false,
+        };
+        Ok((BatchOp::Create(Box::new(issue)), result))
+    }
+
+    /// A fresh ID that collides neither with the database nor with IDs
+    /// planned earlier in the batch.
+    fn allocate_id(&mut self, title: &str, parent: Option<&str>) -> Result<String> {
+        if let Some(parent) = parent {
+            let number = match self.next_child.get(parent) {
+                Some(number) => *number,
+                None => self.storage.next_child_number(parent)?,
+            };
+            self.next_child.insert(parent.to_string(), number + 1);
+            return Ok(child_id(parent, number));
+        }
+
+        let count = self.storage.count_issues()? + self.created.len();
+        Ok(IdGenerator::new(self.config.id_config.clone()).generate(
+            title,
+            None,
+            None,
+            self.now,
+            count,
+            |id| self.created.contains(id) || self.storage.id_exists(id).unwrap_or(false),
+        ))
+    }
+
+    /// Resolve `$name` placeholders and (partial) IDs of existing issues.
+    fn resolve(&self, input: &str) -> Result<String> {
+        if let Some(name) = input.strip_prefix('$') {
+            return self.refs.get(name).cloned().ok_or_else(|| {
+                BeadsError::validation(
+                    "ref",
+                    format!(
+                        "unknown placeholder ${name}; define it with \"ref\" on an earlier create"
+                    ),
+                )
+            });
+        }
+        if self.created.contains(input) {
+            return Ok(input.to_string());
+        }
+        self.resolver
+            .resolve(
+                input,
+                |id| {
+                    self.all_ids
+                        .binary_search_by(|p| p.as_str().cmp(id))
+                        .is_ok()
+                },
+                |hash| find_matching_ids(&self.all_ids, hash),
+            )
+            .map(|resolved| resolved.id)
+    }
+
+    /// Resolve a `dep_add` target as `br dep add` does: `external:` refs and
+    /// full IDs of issues in another routed workspace are kept verbatim.
+    fn resolve_dep_add_target(&mut self, input: &str) -> Result<String> {
+        if input.starts_with("external:") {
+            return Ok(input.to_string());
+        }
+        let local = input.starts_with('$')
+            || self.created.contains(input)
+            || self
+                .all_ids
+                .binary_search_by(|p| p.as_str().cmp(input))
+                .is_ok();
+        if !local && let Some(routed_storage) = self.routed.storage_for(input) {
+            if routed_storage.id_exists(input)? {
+                return Ok(input.to_string());
+            }
+            return Err(BeadsError::IssueNotFound {
+                id: input.to_string(),
+            });
+        }
+        self.resolve(input)
+    }
+
+    /// Substitute a placeholder in a `type:id` dependency; other targets are
+    /// kept as written, like `br create --deps`.
+    fn resolve_dep_target(&self, dep: &str) -> Result<String> {
+        match dep.split_once(':') {
+            Some((dep_type, target)) if target.starts_with('$') => {
+                Ok(format!("{dep_type}:{}", self.resolve(target)?))
+            }
+            None if dep.starts_with('$') => self.resolve(dep),
+            _ => Ok(dep.to_string()),
+        }
+    }
+}
+
+fn invalid_dep_type(dep_type: &str) -> BeadsError {
+    BeadsError::validation(
+        "type",
+        format!(
+            "Unknown dependency type: '{dep_type}'. \
+             Allowed types: blocks, parent-child, conditional-blocks, waits-for, \
+             related, discovered-from, replies-to, relates-to, duplicates, \
+             supersedes, caused-by"
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ops_reports_line_and_skips_blank_lines() {
+        let input = "{\"op\":\"create\",\"ref\":\"a\",\"title\":\"A\",\"priority\":1}\n\n\
+                     {\"op\":\"dep_add\",\"id\":\"$a\",\"depends_on\":\"bd-1\"}\n";
+        let ops = parse_ops(input).unwrap();
+        assert_eq!(ops.len(), 2);
+        assert_eq!(ops[1].0, 3);
+        assert!(matches!(&ops[0].1, OpSpec::Create(spec) if spec.name.as_deref() == Some("a")));
+
+        let err =
+            parse_ops("{\"op\":\"create\",\"title\":\"A\"}\n{\"op\":\"explode\"}").unwrap_err();
+        assert!(matches!(err, BeadsError::JsonlParse { line: 2, .. }));
+        let err = parse_ops("{\"op\":\"close\",\"id\":\"x\",\"resaon\":\"typo\"}").unwrap_err();
+        assert!(matches!(err, BeadsError::JsonlParse { line: 1, .. }));
+    }
+}
@@ -4,13 +4,17 @@
 
 use crate::cli::{BlockedArgs, OutputFormat, resolve_output_format_basic};
 use crate::config::{
-    CliOverrides, discover_beads_dir, external_project_db_paths, load_config,
-    open_storage_with_cli, should_use_color,
+    CliOverrides, discover_beads_dir, external_project_db_paths, load_config, open_routed_storages,
+    open_storage_with_cli, routed_dependency_statuses, should_use_color,
 };
 use crate::error::Result;
-use crate::format::{BlockedIssue, BlockedIssueOutput};
+use crate::format::{BlockedIssue, BlockedIssueOutput, RoutedRecord};
 use crate::model::{IssueType, Priority};
 use crate::output::{OutputContext, OutputMode};
+use crate::storage::SqliteStorage;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 /// Execute the blocked command.
@@ -41,6 +45,67 @@ pub fn execute(
     let quiet = overrides.quiet.unwrap_or(false);
     let ctx = OutputContext::from_output_format(output_format, quiet, !use_color);
 
+    if args.all_routes {
+        return execute_all_routes(args, &beads_dir, storage, output_format, &ctx);
+    }
+
+    let mut blocked_issues = collect_blocked(storage, &beads_dir, &external_db_paths, args)?;
+
+    // Sort by priority (ascending), then by blocker count (descending)
+    sort_blocked_issues(&mut blocked_issues);
+
+    // Apply limit
+    if args.limit > 0 && blocked_issues.len() > args.limit {
+        blocked_issues.truncate(args.limit);
+    }
+
+    for bi in &blocked_issues {
+        tracing::trace!(
+            id = %bi.issue.id,
+            blockers = ?bi.blocked_by,
+            "Blocked issue: {} blocked by {:?}",
+            bi.issue.id,
+            bi.blocked_by
+        );
+    }
+
+    // Output
+    if matches!(ctx.mode(), OutputMode::Quiet) {
+        return Ok(());
+    }
+
+    match output_format {
+        OutputFormat::Json => {
+            let output: Vec<BlockedIssueOutput> =
+                blocked_issues.iter().map(blocked_output).collect();
+            ctx.json_pretty(&output);
+        }
+        OutputFormat::Toon => {
+            let output: Vec<BlockedIssueOutput> =
+                blocked_issues.iter().map(blocked_output).collect();
+            ctx.toon_with_stats(&output, args.stats);
+        }
+        OutputFormat::Text | OutputFormat::Csv => {
+            let max_width = if args.wrap { ctx.width() } else { 0 };
+            if matches!(ctx.mode(), OutputMode::Rich) {
+                render_blocked_rich(&blocked_issues, args.detailed, storage, max_width);
+            } else {
+                print_text_output(&blocked_issues, args.detailed, storage, max_width);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Blocked issues of one database, including external blockers, with the
+/// type/priority/label filters applied.
+fn collect_blocked(
+    storage: &SqliteStorage,
+    beads_dir: &Path,
+    external_db_paths: &HashMap<String, PathBuf>,
+    args: &BlockedArgs,
+) -> Result<Vec<BlockedIssue>> {
     // Get blocked issues from cache
     let blocked_raw = storage.get_blocked_issues()?;
 
@@ -60,12 +125,13 @@ pub fn execute(
         })
         .collect();
 
-    let external_statuses =
-        storage.resolve_external_dependency_statuses(&external_db_paths, true)?;
+    let mut external_statuses =
+        storage.resolve_external_dependency_statuses(external_db_paths, true)?;
+    external_statuses.extend(routed_dependency_statuses(storage, beads_dir)?);
     let external_blockers = storage.external_blockers(&external_statuses)?;
 
     if !external_blockers.is_empty() {
-        let mut by_id: std::collections::HashMap<String, usize> = blocked_issues
+        let mut by_id: HashMap<String, usize> = blocked_issues
             .iter()
             .enumerate()
             .map(|(idx, bi)| (bi.issue.id.clone(), idx))
@@ -105,97 +171,113 @@ pub fn execute(
         filter_by_labels(&mut blocked_issues, storage, &args.label)?;
     }
 
-    // Sort by priority (ascending), then by blocker count (descending)
-    sort_blocked_issues(&mut blocked_issues);
+    Ok(blocked_issues)
+}
 
-    // Apply limit
-    if args.limit > 0 && blocked_issues.len() > args.limit {
-        blocked_issues.truncate(args.limit);
+/// Merge blocked issues from every routed workspace (opened read-only).
+fn execute_all_routes(
+    args: &BlockedArgs,
+    beads_dir: &Path,
+    local_storage: &SqliteStorage,
+    output_format: OutputFormat,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let routed = open_routed_storages(beads_dir)?;
+    let mut merged: Vec<(usize, BlockedIssue)> = Vec::new();
+    for (idx, routed_storage) in routed.iter().enumerate() {
+        let blocked = collect_blocked(
+            &routed_storage.storage,
+            &routed_storage.beads_dir,
+            &routed_storage.external_db_paths,
+            args,
+        )?;
+        merged.extend(blocked.into_iter().map(|bi| (idx, bi)));
     }
-
-    for bi in &blocked_issues {
-        tracing::trace!(
-            id = %bi.issue.id,
-            blockers = ?bi.blocked_by,
-            "Blocked issue: {} blocked by {:?}",
-            bi.issue.id,
-            bi.blocked_by
-        );
+    merged.sort_by(|(_, a), (_, b)| compare_blocked(a, b));
+    if args.limit > 0 && merged.len() > args.limit {
+        merged.truncate(args.limit);
     }
 
-    // Output
     if matches!(ctx.mode(), OutputMode::Quiet) {
         return Ok(());
     }
-
+    let records = || -> Vec<RoutedRecord<BlockedIssueOutput>> {
+        merged
+            .iter()
+            .map(|(idx, bi)| RoutedRecord {
+                project: routed[*idx].project.clone(),
+                item: blocked_output(bi),
+            })
+            .collect()
+    };
     match output_format {
-        OutputFormat::Json => {
-            let output: Vec<BlockedIssueOutput> = blocked_issues
-                .iter()
-                .map(|bi| BlockedIssueOutput {
-                    blocked_by: bi
-                        .blocked_by
-                        .iter()
-                        .map(|blocker_ref| blocker_id_from_ref(blocker_ref).to_string())
-                        .collect(),
-                    blocked_by_count: bi.blocked_by_count,
-                    created_at: bi.issue.created_at,
-                    created_by: bi.issue.created_by.clone(),
-                    description: bi.issue.description.clone(),
-                    id: bi.issue.id.clone(),
-                    issue_type: bi.issue.issue_type.clone(),
-                    priority: bi.issue.priority,
-                    status: bi.issue.status.clone(),
-                    title: bi.issue.title.clone(),
-                    updated_at: bi.issue.updated_at,
-                })
-                .collect();
-            ctx.json_pretty(&output);
-        }
-        OutputFormat::Toon => {
-            let output: Vec<BlockedIssueOutput> = blocked_issues
-                .iter()
-                .map(|bi| BlockedIssueOutput {
-                    blocked_by: bi
-                        .blocked_by
-                        .iter()
-                        .map(|blocker_ref| blocker_id_from_ref(blocker_ref).to_string())
-                        .collect(),
-                    blocked_by_count: bi.blocked_by_count,
-                    created_at: bi.issue.created_at,
-                    created_by: bi.issue.created_by.clone(),
-                    description: bi.issue.description.clone(),
-                    id: bi.issue.id.clone(),
-                    issue_type: bi.issue.issue_type.clone(),
-                    priority: bi.issue.priority,
-                    status: bi.issue.status.clone(),
-                    title: bi.issue.title.clone(),
-                    updated_at: bi.issue.updated_at,
-                })
-                .collect();
-            ctx.toon_with_stats(&output, args.stats);
-        }
+        OutputFormat::Json => ctx.json_pretty(&records()),
+        OutputFormat::Toon => ctx.toon_with_stats(&records(), args.stats),
         OutputFormat::Text | OutputFormat::Csv => {
             let max_width = if args.wrap { ctx.width() } else { 0 };
-            if matches!(ctx.mode(), OutputMode::Rich) {
-                render_blocked_rich(&blocked_issues, args.detailed, storage, max_width);
-            } else {
-                print_text_output(&blocked_issues, args.detailed, storage, max_width);
+            let rich = matches!(ctx.mode(), OutputMode::Rich);
+            if merged.is_empty() {
+                if rich {
+                    render_blocked_rich(&[], args.detailed, local_storage, max_width);
+                } else {
+                    println!("✨ No blocked issues");
+                }
+                return Ok(());
+            }
+            // One section per project, in route order; details need that project's DB.
+            for (idx, routed_storage) in routed.iter().enumerate() {
+                let issues: Vec<BlockedIssue> = merged
+                    .iter()
+                    .filter(|(i, _)| *i == idx)
+                    .map(|(_, bi)| bi.clone())
+                    .collect();
+                if issues.is_empty() {
+                    continue;
+                }
+                println!("\n── {} ──", routed_storage.project);
+                if rich {
+                    render_blocked_rich(&issues, args.detailed, &routed_storage.storage, max_width);
+                } else {
+                    print_text_output(&issues, args.detailed, &routed_storage.storage, max_width);
+                }
             }
         }
     }
-
     Ok(())
 }
 
+fn blocked_output(bi: &BlockedIssue) -> BlockedIssueOutput {
+    BlockedIssueOutput {
+        blocked_by: bi
+            .blocked_by
+            .iter()
+            .map(|blocker_ref| blocker_id_from_ref(blocker_ref).to_string())
+            .collect(),
+        blocked_by_count: bi.blocked_by_count,
+        conditions: blocker_conditions(&bi.blocked_by),
+        created_at: bi.issue.created_at,
+        created_by: bi.issue.created_by.clone(),
+        description: bi.issue.description.clone(),
+        id: bi.issue.id.clone(),
+        issue_type: bi.issue.issue_type.clone(),
+        priority: bi.issue.priority,
+        status: bi.issue.status.clone(),
+        title: bi.issue.title.clone(),
+        updated_at: bi.issue.updated_at,
+    }
+}
+
 /// Sort blocked issues by priority (ascending), then by blocker count (descending).
 fn sort_blocked_issues(issues: &mut [BlockedIssue]) {
-    issues.sort_by(|a, b| {
-        let pa = a.issue.priority.0;
-        let pb = b.issue.priority.0;
-        pa.cmp(&pb)
-            .then_with(|| b.blocked_by_count.cmp(&a.blocked_by_count))
-    });
+    issues.sort_by(compare_blocked);
+}
+
+fn compare_blocked(a: &BlockedIssue, b: &BlockedIssue) -> Ordering {
+    a.issue
+        .priority
+        .0
+        .cmp(&b.issue.priority.0)
+        .then_with(|| b.blocked_by_count.cmp(&a.blocked_by_count))
 }
 
 /// Filter blocked issues by issue type (case-insensitive).
@@ -230,7 +312,7 @@ fn filter_by_priority(issues: &mut Vec<BlockedIssue>, priorities: &[String]) ->
 
 fn filter_by_labels(
     issues: &mut Vec<BlockedIssue>,
-    storage: &crate::storage::SqliteStorage,
+    storage: &SqliteStorage,
     labels: &[String],
 ) -> Result<()> {
     let mut filtered = Vec::with_capacity(issues.len());
@@ -252,7 +334,7 @@ fn filter_by_labels(
 fn print_text_output(
     blocked_issues: &[BlockedIssue],
     verbose: bool,
-    storage: &crate::storage::SqliteStorage,
+    storage: &SqliteStorage,
     max_width: usize,
 ) {
     use crate::format::truncate_title;
@@ -316,6 +398,9 @@ fn print_text_output(
                 ids.join(", ")
             );
         }
+        for condition in blocker_conditions(&bi.blocked_by) {
+            println!("  ↳ {condition}");
+        }
     }
 }
 
@@ -326,10 +411,28 @@ fn blocker_id_from_ref(blocker_ref: &str) -> &str {
         .map_or(blocker_ref, |(prefix, _)| prefix)
 }
 
+/// Explain the `conditional-blocks` refs in a blocked-by list.
+///
+/// `:if-failure` means the blocker is still open and the issue runs only if it
+/// fails; `:moot` means the blocker closed without failing.
+fn blocker_conditions(blocked_by: &[String]) -> Vec<String> {
+    blocked_by
+        .iter()
+        .filter_map(|blocker_ref| {
+            let (id, state) = blocker_ref.rsplit_once(':')?;
+            match state {
+                "if-failure" => Some(format!("runs only if {id} fails")),
+                "moot" => Some(format!("moot: {id} closed without failing")),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
 fn render_blocked_rich(
     blocked_issues: &[BlockedIssue],
     verbose: bool,
-    storage: &crate::storage::SqliteStorage,
+    storage: &SqliteStorage,
     max_width: usize,
 ) {
     use crate::format::truncate_title;
@@ -435,6 +538,11 @@ fn render_blocked_rich(
             );
             console.print_renderable(&detail);
         }
+        for condition in blocker_conditions(&bi.blocked_by) {
+            let mut note = Text::new("");
+            note.append_styled(&format!("  \u{21b3} {condition}"), Style::new().dim());
+            console.print_renderable(&note);
+        }
     }
 }
 
@@ -445,6 +553,7 @@ mod tests {
     use crate::logging::init_test_logging;
     use crate::model::{Issue, IssueType, Priority, Status};
     use chrono::{TimeZone, Utc};
+    use std::collections::BTreeMap;
     use tracing::info;
 
     fn make_issue(id: &str, title: &str, priority: i32, issue_type: IssueType) -> Issue {
@@ -467,6 +576,7 @@ mod tests {
             updated_at: Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             due_at: None,
             defer_until: None,
@@ -488,6 +598,7 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -694,4 +805,23 @@ mod tests {
         assert!(ids.contains(&"c"));
         info!("test_filter_by_priority_multiple: assertions passed");
     }
+
+    #[test]
+    fn test_blocker_conditions_explain_conditional_refs() {
+        init_test_logging();
+        info!("test_blocker_conditions_explain_conditional_refs: starting");
+        let refs = vec![
+            "bd-a:open".to_string(),
+            "bd-b:if-failure".to_string(),
+            "bd-c:moot".to_string(),
+        ];
+        assert_eq!(
+            blocker_conditions(&refs),
+            vec![
+                "runs only if bd-b fails".to_string(),
+                "moot: bd-c closed without failing".to_string(),
+            ]
+        );
+        info!("test_blocker_conditions_explain_conditional_refs: assertions passed");
+    }
 }
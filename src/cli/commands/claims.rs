@@ -0,0 +1,132 @@
+//! Claims command implementation.
+//!
+//! Shows who holds which issue, for which session, and until when.
+
+use crate::cli::ClaimsCommands;
+use crate::config;
+use crate::error::Result;
+use crate::output::OutputContext;
+use chrono::{DateTime, Utc};
+use rich_rust::prelude::*;
+use serde::Serialize;
+
+/// A claim joined with its issue title, for `claims list`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ClaimEntry {
+    pub issue_id: String,
+    pub title: String,
+    pub claimed_by: String,
+    pub claimed_by_session: Option<String>,
+    pub claimed_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    /// The lease lapsed, so `br ready` offers the issue again.
+    pub expired: bool,
+}
+
+/// Execute a claims subcommand.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be opened or queried.
+pub fn execute(
+    command: &ClaimsCommands,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    match command {
+        ClaimsCommands::List => list(cli, ctx),
+    }
+}
+
+fn list(cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let storage = &storage_ctx.storage;
+
+    let now = Utc::now();
+    let mut entries = Vec::new();
+    for claim in storage.get_claims()? {
+        let title = storage
+            .get_issue(&claim.issue_id)?
+            .map(|issue| issue.title)
+            .unwrap_or_default();
+        entries.push(ClaimEntry {
+            expired: claim.is_expired(now),
+            issue_id: claim.issue_id,
+            title,
+            claimed_by: claim.claimed_by,
+            claimed_by_session: claim.claimed_by_session,
+            claimed_at: claim.claimed_at,
+            expires_at: claim.expires_at,
+        });
+    }
+
+    if ctx.is_json() {
+        ctx.json_pretty(&entries);
+        return Ok(());
+    }
+    if ctx.is_quiet() {
+        return Ok(());
+    }
+    if entries.is_empty() {
+        println!("No claims.");
+        return Ok(());
+    }
+    if ctx.is_rich() {
+        render_table(&entries, ctx);
+    } else {
+        for entry in &entries {
+            println!(
+                "{}  {}{}  until {}{}  {}",
+                entry.issue_id,
+                entry.claimed_by,
+                entry
+                    .claimed_by_session
+                    .as_deref()
+                    .map(|session| format!(" ({session})"))
+                    .unwrap_or_default(),
+                entry.expires_at.to_rfc3339(),
+                if entry.expired { " [expired]" } else { "" },
+                entry.title
+            );
+        }
+    }
+    Ok(())
+}
+
+fn render_table(entries: &[ClaimEntry], ctx: &OutputContext) {
+    let theme = ctx.theme();
+    let mut table = Table::new()
+        .title(Text::styled("Claims", theme.panel_title.clone()))
+        .box_style(theme.box_style)
+        .border_style(theme.panel_border.clone())
+        .with_column(Column::new("ID").min_width(10).max_width(20))
+        .with_column(Column::new("Holder").min_width(8).max_width(20))
+        .with_column(Column::new("Session").min_width(8).max_width(20))
+        .with_column(Column::new("Expires").min_width(16).max_width(25))
+        .with_column(Column::new("Title").min_width(15));
+
+    for entry in entries {
+        let expires_style = if entry.expired {
+            theme.warning.clone()
+        } else {
+            theme.timestamp.clone()
+        };
+        let expires = if entry.expired {
+            format!("{} (expired)", entry.expires_at.format("%Y-%m-%d %H:%M"))
+        } else {
+            entry.expires_at.format("%Y-%m-%d %H:%M").to_string()
+        };
+        table.add_row(Row::new(vec![
+            Cell::new(Text::styled(entry.issue_id.clone(), theme.issue_id.clone())),
+            Cell::new(Text::new(entry.claimed_by.clone())),
+            Cell::new(Text::styled(
+                entry.claimed_by_session.clone().unwrap_or_default(),
+                theme.dimmed.clone(),
+            )),
+            Cell::new(Text::styled(expires, expires_style)),
+            Cell::new(Text::new(entry.title.clone())),
+        ]));
+    }
+    ctx.render(&table);
+}
@@ -3,9 +3,9 @@
 use crate::cli::CloseArgs as CliCloseArgs;
 use crate::config;
 use crate::error::{BeadsError, Result};
-use crate::model::Status;
+use crate::model::{CloseOutcome, Issue, Status};
 use crate::output::OutputContext;
-use crate::storage::IssueUpdate;
+use crate::storage::{IssueUpdate, SqliteStorage};
 use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
@@ -17,6 +17,8 @@ pub struct CloseArgs {
     pub ids: Vec<String>,
     /// Close reason
     pub reason: Option<String>,
+    /// How the work ended (drives `conditional-blocks` dependents)
+    pub outcome: Option<CloseOutcome>,
     /// Force close even if blocked
     pub force: bool,
     /// Session ID for `closed_by_session` field
@@ -30,6 +32,7 @@ impl From<&CliCloseArgs> for CloseArgs {
         Self {
             ids: cli.ids.clone(),
             reason: cli.reason.clone(),
+            outcome: cli.outcome,
             force: cli.force,
             session: cli.session.clone(),
             suggest_next: cli.suggest_next,
@@ -48,8 +51,7 @@ pub fn execute_cli(
     cli: &config::CliOverrides,
     ctx: &OutputContext,
 ) -> Result<()> {
-    let args = CloseArgs::from(cli_args);
-    execute_with_args(&args, json, cli, ctx)
+    execute_with_args(&CloseArgs::from(cli_args), json, cli, ctx)
 }
 
 /// Result of a close operation for JSON output.
@@ -85,6 +87,23 @@ pub struct ClosedIssue {
     pub closed_at: String,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub close_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub close_outcome: Option<String>,
+}
+
+impl ClosedIssue {
+    fn from_issue(issue: &Issue) -> Self {
+        Self {
+            id: issue.id.clone(),
+            title: issue.title.clone(),
+            status: issue.status.as_str().to_string(),
+            closed_at: issue.closed_at.unwrap_or_else(Utc::now).to_rfc3339(),
+            close_reason: issue.close_reason.clone(),
+            close_outcome: issue
+                .close_outcome
+                .map(|outcome| outcome.as_str().to_string()),
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -107,6 +126,7 @@ pub fn execute(
     let args = CloseArgs {
         ids,
         reason: None,
+        outcome: None,
         force: false,
         session: None,
         suggest_next: false,
@@ -178,80 +198,22 @@ pub fn execute_with_args(
         Vec::new()
     };
 
-    let mut closed_issues: Vec<ClosedIssue> = Vec::new();
-    let mut skipped_issues: Vec<SkippedIssue> = Vec::new();
-
-    for resolved in &resolved_ids {
-        let id = &resolved.id;
-        tracing::info!(id = %id, "Closing issue");
-
-        // Get current issue
-        let Some(issue) = storage.get_issue(id)? else {
-            skipped_issues.push(SkippedIssue {
-                id: id.clone(),
-                reason: "issue not found".to_string(),
-            });
-            continue;
-        };
-
-        // Check if already closed
-        if issue.status.is_terminal() {
-            skipped_issues.push(SkippedIssue {
-                id: id.clone(),
-                reason: format!("already {}", issue.status.as_str()),
-            });
-            continue;
-        }
-
-        // Check if blocked (unless --force)
-        if !args.force && storage.is_blocked(id)? {
-            let mut blocker_ids = storage
-                .get_blocked_issues()?
-                .into_iter()
-                .find(|(issue, _)| issue.id == *id)
-                .map(|(_, blockers)| blockers)
-                .unwrap_or_default();
-            if blocker_ids.is_empty() {
-                blocker_ids = storage.get_dependencies(id)?;
-            }
-            tracing::debug!(blocked_by = ?blocker_ids, "Issue is blocked");
-            let reason = if blocker_ids.is_empty() {
-                "blocked by dependencies".to_string()
-            } else {
-                format!("blocked by: {}", blocker_ids.join(", "))
-            };
-            skipped_issues.push(SkippedIssue {
-                id: id.clone(),
-                reason,
-            });
-            continue;
-        }
-
-        // Build update
-        let now = Utc::now();
-        let close_reason = args.reason.clone().unwrap_or_else(|| "done".to_string());
-        let update = IssueUpdate {
-            status: Some(Status::Closed),
-            closed_at: Some(Some(now)),
-            close_reason: Some(Some(close_reason.clone())),
-            closed_by_session: args.session.clone().map(Some),
-            ..Default::default()
-        };
-
-        // Apply update
-        storage.update_issue(id, &update, &actor)?;
-        tracing::info!(id = %id, reason = ?args.reason, "Issue closed");
-
-        // Update last touched
-        crate::util::set_last_touched_id(&beads_dir, id);
-
-        closed_issues.push(ClosedIssue {
-            id: id.clone(),
-            title: issue.title.clone(),
-            status: "closed".to_string(),
-            closed_at: now.to_rfc3339(),
-            close_reason: Some(close_reason),
-        });
+    let ids: Vec<String> = resolved_ids
+        .into_iter()
+        .map(|resolved| resolved.id)
+        .collect();
+    let CloseResult {
+        closed: closed_issues,
+        skipped: skipped_issues,
+    } = close_issues(storage, &ids, args, &actor)?;
+
+    // Moot dependents closed alongside do not count as touched
+    if let Some(last) = closed_issues
+        .iter()
+        .rev()
+        .find(|closed| ids.contains(&closed.id))
+    {
+        crate::util::set_last_touched_id(&beads_dir, &last.id);
     }
 
     // Handle suggest-next: find issues that became unblocked
@@ -309,8 +271,13 @@ pub fn execute_with_args(
         } else {
             for closed in &closed_issues {
                 let mut msg = format!("Closed {}: {}", closed.id, closed.title);
-                if let Some(reason) = &closed.close_reason {
-                    msg.push_str(&format!(" ({reason})"));
+                match (&closed.close_outcome, &closed.close_reason) {
+                    (Some(outcome), Some(reason)) => {
+                        msg.push_str(&format!(" ({outcome}: {reason})"))
+                    }
+                    (Some(outcome), None) => msg.push_str(&format!(" ({outcome})")),
+                    (None, Some(reason)) => msg.push_str(&format!(" ({reason})")),
+                    (None, None) => {}
                 }
                 ctx.success(&msg);
             }
@@ -331,6 +298,125 @@ pub fn execute_with_args(
     Ok(())
 }
 
+/// Close the already-resolved `ids` with `args`' reason, outcome and session.
+///
+/// Missing and already closed issues are skipped, as are blocked issues
+/// unless `args.force` is set. Conditional dependents that storage closes as
+/// moot are reported after the issue that made them moot. Shared by
+/// `br close`, `git-sync` and [`crate::Workspace`]; `args.ids` and
+/// `args.suggest_next` are left to the caller.
+///
+/// # Errors
+///
+/// Returns an error if a database operation fails.
+pub fn close_issues(
+    storage: &mut SqliteStorage,
+    ids: &[String],
+    args: &CloseArgs,
+    actor: &str,
+) -> Result<CloseResult> {
+    let mut closed_issues: Vec<ClosedIssue> = Vec::new();
+    let mut skipped_issues: Vec<SkippedIssue> = Vec::new();
+
+    for id in ids {
+        tracing::info!(id = %id, "Closing issue");
+
+        // Get current issue
+        let Some(issue) = storage.get_issue(id)? else {
+            skipped_issues.push(SkippedIssue {
+                id: id.clone(),
+                reason: "issue not found".to_string(),
+            });
+            continue;
+        };
+
+        // Check if already closed
+        if issue.status.is_terminal() {
+            skipped_issues.push(SkippedIssue {
+                id: id.clone(),
+                reason: format!("already {}", issue.status.as_str()),
+            });
+            continue;
+        }
+
+        // Check if blocked (unless --force)
+        if !args.force
+            && let Some(reason) = blocked_reason(storage, id)?
+        {
+            skipped_issues.push(SkippedIssue {
+                id: id.clone(),
+                reason,
+            });
+            continue;
+        }
+
+        // Build update
+        let now = Utc::now();
+        let close_reason = args.reason.clone().unwrap_or_else(|| "done".to_string());
+        let update = IssueUpdate::close(&close_reason, args.outcome, args.session.clone(), now);
+
+        // Storage closes these as moot if this close succeeds
+        let conditional_dependents: Vec<String> = storage
+            .get_dependents_with_metadata(id)?
+            .into_iter()
+            .filter(|dep| dep.dep_type == "conditional-blocks" && !dep.status.is_terminal())
+            .map(|dep| dep.id)
+            .collect();
+
+        // Apply update
+        storage.update_issue(id, &update, actor)?;
+        tracing::info!(id = %id, reason = ?args.reason, "Issue closed");
+
+        closed_issues.push(ClosedIssue {
+            id: id.clone(),
+            title: issue.title.clone(),
+            status: "closed".to_string(),
+            closed_at: now.to_rfc3339(),
+            close_reason: Some(close_reason),
+            close_outcome: args.outcome.map(|outcome| outcome.as_str().to_string()),
+        });
+
+        for dependent_id in conditional_dependents {
+            if let Some(dependent) = storage.get_issue(&dependent_id)?
+                && dependent.status == Status::Closed
+            {
+                closed_issues.push(ClosedIssue::from_issue(&dependent));
+            }
+        }
+    }
+
+    Ok(CloseResult {
+        closed: closed_issues,
+        skipped: skipped_issues,
+    })
+}
+
+/// Why `id` cannot be closed without `--force`, naming its blockers;
+/// `None` if it is not blocked.
+///
+/// # Errors
+///
+/// Returns an error if the blocked cache cannot be read.
+pub fn blocked_reason(storage: &SqliteStorage, id: &str) -> Result<Option<String>> {
+    if !storage.is_blocked(id)? {
+        return Ok(None);
+    }
+    // The cache lists each blocker as `id:state`: unfinished or missing
+    // blockers, plus conditional blockers that closed without failing (`:moot`)
+    let blocker_ids = storage
+        .get_blocked_issues()?
+        .into_iter()
+        .find(|(issue, _)| issue.id == id)
+        .map(|(_, blockers)| blockers)
+        .unwrap_or_default();
+    tracing::debug!(blocked_by = ?blocker_ids, "Issue is blocked");
+    Ok(Some(if blocker_ids.is_empty() {
+        "blocked by dependencies".to_string()
+    } else {
+        format!("blocked by: {}", blocker_ids.join(", "))
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -344,6 +430,7 @@ mod tests {
         let args = CloseArgs::default();
         assert!(args.ids.is_empty());
         assert!(args.reason.is_none());
+        assert!(args.outcome.is_none());
         assert!(!args.force);
         assert!(args.session.is_none());
         assert!(!args.suggest_next);
@@ -354,6 +441,7 @@ mod tests {
         let args = CloseArgs {
             ids: vec!["bd-abc".to_string(), "bd-xyz".to_string()],
             reason: Some("Fixed in PR #123".to_string()),
+            outcome: Some(CloseOutcome::Failure),
             force: true,
             session: Some("session-456".to_string()),
             suggest_next: true,
@@ -379,6 +467,7 @@ mod tests {
                 status: "closed".to_string(),
                 closed_at: "2026-01-01T00:00:00Z".to_string(),
                 close_reason: None,
+                close_outcome: None,
             }],
             skipped: vec![],
         };
@@ -412,6 +501,7 @@ mod tests {
                     status: "closed".to_string(),
                     closed_at: "2026-01-01T00:00:00Z".to_string(),
                     close_reason: Some("Done".to_string()),
+                    close_outcome: None,
                 },
                 ClosedIssue {
                     id: "bd-b".to_string(),
@@ -419,6 +509,7 @@ mod tests {
                     status: "closed".to_string(),
                     closed_at: "2026-01-02T00:00:00Z".to_string(),
                     close_reason: None,
+                    close_outcome: None,
                 },
             ],
             skipped: vec![SkippedIssue {
@@ -448,6 +539,7 @@ mod tests {
                 status: "closed".to_string(),
                 closed_at: "2026-01-15T10:00:00Z".to_string(),
                 close_reason: Some("Completed".to_string()),
+                close_outcome: None,
             }],
             skipped: vec![],
             unblocked: vec![
@@ -501,6 +593,7 @@ mod tests {
             status: "closed".to_string(),
             closed_at: "2026-01-17T08:00:00Z".to_string(),
             close_reason: Some("Fixed in commit abc123".to_string()),
+            close_outcome: None,
         };
         let json = serde_json::to_string(&issue).unwrap();
         assert!(json.contains("\"close_reason\":\"Fixed in commit abc123\""));
@@ -514,6 +607,7 @@ mod tests {
             status: "closed".to_string(),
             closed_at: "2026-01-17T08:00:00Z".to_string(),
             close_reason: None,
+            close_outcome: None,
         };
         let json = serde_json::to_string(&issue).unwrap();
         // close_reason should be omitted due to skip_serializing_if
@@ -528,6 +622,7 @@ mod tests {
             status: "closed".to_string(),
             closed_at: "2026-12-31T23:59:59Z".to_string(),
             close_reason: Some("End of year cleanup".to_string()),
+            close_outcome: None,
         };
         let json = serde_json::to_string(&issue).unwrap();
         let parsed: ClosedIssue = serde_json::from_str(&json).unwrap();
@@ -610,6 +705,7 @@ mod tests {
                     status: "closed".to_string(),
                     closed_at: "2026-01-01T00:00:00Z".to_string(),
                     close_reason: None,
+                    close_outcome: None,
                 },
                 ClosedIssue {
                     id: "bd-2".to_string(),
@@ -617,6 +713,7 @@ mod tests {
                     status: "closed".to_string(),
                     closed_at: "2026-01-01T00:00:01Z".to_string(),
                     close_reason: Some("Batch close".to_string()),
+                    close_outcome: None,
                 },
             ],
             skipped: vec![
@@ -636,11 +733,27 @@ mod tests {
         assert_eq!(parsed.skipped.len(), 2);
     }
 
+    #[test]
+    fn test_close_args_parses_outcome() {
+        use crate::cli::{Cli, Commands};
+        use clap::Parser;
+
+        let cli = Cli::parse_from(["br", "close", "bd-a", "--outcome", "failed"]);
+        let Commands::Close(close) = cli.command else {
+            panic!("expected close command");
+        };
+        let args = CloseArgs::from(&close);
+        assert_eq!(args.outcome, Some(CloseOutcome::Failure));
+
+        assert!(Cli::try_parse_from(["br", "close", "bd-a", "--outcome", "maybe"]).is_err());
+    }
+
     #[test]
     fn test_close_args_clone() {
         let args = CloseArgs {
             ids: vec!["bd-clone".to_string()],
             reason: Some("Clone test".to_string()),
+            outcome: None,
             force: true,
             session: Some("sess".to_string()),
             suggest_next: true,
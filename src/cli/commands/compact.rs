@@ -0,0 +1,433 @@
+//! Compact command implementation.
+//!
+//! Shrinks the text of old closed issues so `issues.jsonl` stays small in
+//! long-lived repositories. Each level replaces the long text fields with an
+//! extractive summary (or a summary supplied by the user); the full original
+//! is kept in the local `compaction_archive` table and can be brought back
+//! with `--restore`.
+
+use crate::cli::CompactArgs;
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::model::{Issue, Status};
+use crate::output::OutputContext;
+use crate::storage::SqliteStorage;
+use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::fs;
+use std::io::{self, Read};
+use tracing::info;
+
+/// Highest supported compaction level.
+pub const MAX_LEVEL: i32 = 2;
+
+/// Marker appended when a summary cuts a sentence short.
+const ELLIPSIS: char = '…';
+
+/// A single issue compacted (or that would be, with `--dry-run`).
+#[derive(Debug, Clone, Serialize)]
+pub struct CompactedIssue {
+    pub id: String,
+    pub title: String,
+    pub level: i32,
+    pub original_size: i32,
+    pub compacted_size: usize,
+}
+
+/// Result of `br compact`.
+#[derive(Debug, Clone, Serialize)]
+pub struct CompactOutput {
+    pub dry_run: bool,
+    pub level: i32,
+    pub compacted: Vec<CompactedIssue>,
+    pub bytes_saved: usize,
+}
+
+/// Result of `br compact --restore`.
+#[derive(Debug, Clone, Serialize)]
+pub struct RestoreOutput {
+    pub id: String,
+    pub restored: bool,
+}
+
+/// Execute the compact command.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be opened, an ID cannot be
+/// resolved, a named issue is not eligible, or the summary cannot be read.
+pub fn execute(args: &CompactArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    if !(1..=MAX_LEVEL).contains(&args.level) {
+        return Err(BeadsError::validation(
+            "level",
+            format!("must be between 1 and {MAX_LEVEL}"),
+        ));
+    }
+    if args.older_than < 0 {
+        return Err(BeadsError::validation("older-than", "must be >= 0"));
+    }
+    if args.summary.is_some() && args.ids.len() != 1 {
+        return Err(BeadsError::validation(
+            "summary",
+            "--summary requires exactly one issue ID",
+        ));
+    }
+
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let actor = config::resolve_actor(&layer);
+    let id_config = config::id_config_from_layer(&layer);
+    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let storage = &mut storage_ctx.storage;
+
+    if let Some(input) = &args.restore {
+        let id = resolve_id(storage, &resolver, input)?;
+        let restored = storage.restore_compacted(&id, &actor)?;
+        if ctx.is_json() {
+            ctx.json_pretty(&RestoreOutput { id, restored });
+        } else if restored {
+            ctx.success(&format!("Restored {id} from the compaction archive"));
+        } else {
+            ctx.warning(&format!(
+                "No archived text for {id} (it may have been compacted in another clone)"
+            ));
+        }
+        return Ok(());
+    }
+
+    let candidates = if args.ids.is_empty() {
+        let cutoff = Utc::now() - Duration::days(args.older_than);
+        storage.compaction_candidates(args.level, cutoff)?
+    } else {
+        explicit_candidates(storage, &resolver, &args.ids, args.level)?
+    };
+
+    let summary = args.summary.as_deref().map(read_summary).transpose()?;
+    let now = Utc::now();
+    let commit = if args.dry_run {
+        None
+    } else {
+        crate::util::git::short_head_sha()
+    };
+
+    let mut output = CompactOutput {
+        dry_run: args.dry_run,
+        level: args.level,
+        compacted: Vec::new(),
+        bytes_saved: 0,
+    };
+    for issue in &candidates {
+        let Some(compacted) =
+            compact_issue_text(issue, args.level, summary.as_deref(), now, commit.clone())
+        else {
+            continue;
+        };
+
+        let before = text_size(issue);
+        let after = text_size(&compacted);
+        output.bytes_saved += before.saturating_sub(after);
+        output.compacted.push(CompactedIssue {
+            id: issue.id.clone(),
+            title: issue.title.clone(),
+            level: args.level,
+            original_size: compacted.original_size.unwrap_or(0),
+            compacted_size: after,
+        });
+
+        if !args.dry_run {
+            storage.compact_issue(issue, &compacted, &actor)?;
+            info!(id = %issue.id, level = args.level, before, after, "Compacted issue");
+        }
+    }
+
+    if ctx.is_json() {
+        ctx.json_pretty(&output);
+        return Ok(());
+    }
+
+    if output.compacted.is_empty() {
+        ctx.info("No issues to compact.");
+        return Ok(());
+    }
+    let verb = if args.dry_run {
+        "Would compact"
+    } else {
+        "Compacted"
+    };
+    for item in &output.compacted {
+        ctx.print(&format!(
+            "{verb} {} to level {}: {} ({} → {} bytes)",
+            item.id, item.level, item.title, item.original_size, item.compacted_size
+        ));
+    }
+    ctx.success(&format!(
+        "{verb} {} issue(s), saving {} bytes",
+        output.compacted.len(),
+        output.bytes_saved
+    ));
+    Ok(())
+}
+
+fn resolve_id(storage: &SqliteStorage, resolver: &IdResolver, input: &str) -> Result<String> {
+    let all_ids = storage.get_all_ids()?;
+    Ok(resolver
+        .resolve(
+            input,
+            |id| storage.id_exists(id).unwrap_or(false),
+            |hash| find_matching_ids(&all_ids, hash),
+        )?
+        .id)
+}
+
+/// Issues named on the command line skip the age threshold, but must still be
+/// closed and not already compacted to `level`.
+fn explicit_candidates(
+    storage: &SqliteStorage,
+    resolver: &IdResolver,
+    inputs: &[String],
+    level: i32,
+) -> Result<Vec<Issue>> {
+    let mut issues = Vec::with_capacity(inputs.len());
+    for input in inputs {
+        let id = resolve_id(storage, resolver, input)?;
+        let issue = storage
+            .get_issue(&id)?
+            .ok_or_else(|| BeadsError::IssueNotFound { id: id.clone() })?;
+        if issue.status != Status::Closed {
+            return Err(BeadsError::validation(
+                "id",
+                format!(
+                    "{id} is {}; only closed issues can be compacted",
+                    issue.status
+                ),
+            ));
+        }
+        let current = issue.compaction_level.unwrap_or(0);
+        if current >= level {
+            return Err(BeadsError::validation(
+                "level",
+                format!("{id} is already compacted to level {current}"),
+            ));
+        }
+        issues.push(issue);
+    }
+    Ok(issues)
+}
+
+/// Read a user-supplied summary from a file, or stdin when `source` is `-`.
+fn read_summary(source: &str) -> Result<String> {
+    let mut text = String::new();
+    if source == "-" {
+        io::stdin().read_to_string(&mut text)?;
+    } else {
+        text = fs::read_to_string(source)?;
+    }
+    let text = text.trim().to_string();
+    if text.is_empty() {
+        return Err(BeadsError::validation("summary", "summary is empty"));
+    }
+    Ok(text)
+}
+
+/// Total bytes of the text fields compaction touches.
+#[must_use]
+pub fn text_size(issue: &Issue) -> usize {
+    [
+        &issue.description,
+        &issue.design,
+        &issue.acceptance_criteria,
+        &issue.notes,
+    ]
+    .iter()
+    .map(|field| field.as_deref().map_or(0, str::len))
+    .sum()
+}
+
+/// Build the compacted form of `issue` at `level`.
+///
+/// Level 1 keeps the leading sentences of each text field; level 2 keeps only
+/// the first sentence of the description and drops design, acceptance
+/// criteria and notes. A `summary` replaces the description outright.
+/// Returns `None` if compaction would not make the issue any smaller.
+#[must_use]
+pub fn compact_issue_text(
+    issue: &Issue,
+    level: i32,
+    summary: Option<&str>,
+    now: DateTime<Utc>,
+    commit: Option<String>,
+) -> Option<Issue> {
+    let (description_limit, description_sentences, other_limit) = if level >= 2 {
+        (160, 1, None)
+    } else {
+        (500, usize::MAX, Some(200))
+    };
+
+    let mut compacted = issue.clone();
+    compacted.description = summary.map_or_else(
+        || {
+            issue
+                .description
+                .as_deref()
+                .map(|text| summarize(text, description_limit, description_sentences))
+        },
+        |text| Some(text.to_string()),
+    );
+    for field in [
+        &mut compacted.design,
+        &mut compacted.acceptance_criteria,
+        &mut compacted.notes,
+    ] {
+        *field = match other_limit {
+            Some(limit) => field
+                .as_deref()
+                .map(|text| summarize(text, limit, usize::MAX)),
+            None => None,
+        };
+    }
+
+    let before = text_size(issue);
+    if summary.is_none() && text_size(&compacted) >= before {
+        return None;
+    }
+
+    // Keep the size from before the first compaction across levels
+    let original_size = match issue.original_size {
+        Some(size) if size > 0 && issue.compaction_level.unwrap_or(0) > 0 => size,
+        _ => i32::try_from(before).unwrap_or(i32::MAX),
+    };
+    compacted.compaction_level = Some(level);
+    compacted.compacted_at = Some(now);
+    compacted.compacted_at_commit = commit;
+    compacted.original_size = Some(original_size);
+    Some(compacted)
+}
+
+/// Deterministic extractive summary: up to `max_sentences` whole leading
+/// sentences that fit in `max_chars`, or the first sentence cut at a word
+/// boundary.
+#[must_use]
+pub fn summarize(text: &str, max_chars: usize, max_sentences: usize) -> String {
+    let normalized = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let all = sentences(&normalized);
+    if all.len() <= max_sentences && normalized.chars().count() <= max_chars {
+        return normalized;
+    }
+
+    let mut summary = String::new();
+    for sentence in all.into_iter().take(max_sentences) {
+        let separator = usize::from(!summary.is_empty());
+        if summary.chars().count() + separator + sentence.chars().count() > max_chars {
+            break;
+        }
+        if separator == 1 {
+            summary.push(' ');
+        }
+        summary.push_str(sentence);
+    }
+    if !summary.is_empty() {
+        return summary;
+    }
+
+    // First sentence alone is too long: cut at the last word that fits
+    let budget = max_chars.saturating_sub(1);
+    let mut cut = String::new();
+    for word in normalized.split(' ') {
+        let needed = cut.chars().count() + usize::from(!cut.is_empty()) + word.chars().count();
+        if needed > budget {
+            break;
+        }
+        if !cut.is_empty() {
+            cut.push(' ');
+        }
+        cut.push_str(word);
+    }
+    if cut.is_empty() {
+        cut = normalized.chars().take(budget).collect();
+    }
+    cut.push(ELLIPSIS);
+    cut
+}
+
+/// Split whitespace-normalized text after `.`, `!` or `?` followed by a space.
+fn sentences(text: &str) -> Vec<&str> {
+    let mut result = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((idx, ch)) = chars.next() {
+        if matches!(ch, '.' | '!' | '?') && matches!(chars.peek(), Some((_, ' '))) {
+            result.push(&text[start..=idx]);
+            start = idx + 2;
+        }
+    }
+    if start < text.len() {
+        result.push(&text[start..]);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn closed_issue(description: &str) -> Issue {
+        Issue {
+            id: "bd-1".to_string(),
+            title: "Old".to_string(),
+            status: Status::Closed,
+            description: Some(description.to_string()),
+            notes: Some("n".repeat(400)),
+            ..Issue::default()
+        }
+    }
+
+    #[test]
+    fn test_summarize_keeps_whole_sentences() {
+        let text = "First point.  Second\npoint! Third point that is much longer?";
+        assert_eq!(
+            summarize(text, 30, usize::MAX),
+            "First point. Second point!"
+        );
+        assert_eq!(summarize(text, 30, 1), "First point.");
+        assert_eq!(summarize("Short.", 30, usize::MAX), "Short.");
+    }
+
+    #[test]
+    fn test_summarize_cuts_long_sentence_at_word() {
+        let summary = summarize("alpha beta gamma delta epsilon", 17, usize::MAX);
+        assert_eq!(summary, "alpha beta gamma…");
+        assert!(summary.chars().count() <= 17);
+    }
+
+    #[test]
+    fn test_compact_level_two_drops_secondary_fields() {
+        let issue = closed_issue(&"Lead sentence. ".repeat(40));
+        let now = Utc::now();
+
+        let level1 = compact_issue_text(&issue, 1, None, now, None).unwrap();
+        assert!(level1.notes.as_deref().unwrap().chars().count() <= 200);
+        assert_eq!(level1.compaction_level, Some(1));
+        assert_eq!(
+            level1.original_size,
+            Some(i32::try_from(text_size(&issue)).unwrap())
+        );
+
+        let level2 = compact_issue_text(&level1, 2, None, now, None).unwrap();
+        assert_eq!(level2.description.as_deref(), Some("Lead sentence."));
+        assert_eq!(level2.notes, None);
+        assert_eq!(level2.original_size, level1.original_size);
+    }
+
+    #[test]
+    fn test_compact_skips_when_nothing_shrinks() {
+        let mut issue = closed_issue("Tiny.");
+        issue.notes = None;
+        assert!(compact_issue_text(&issue, 1, None, Utc::now(), None).is_none());
+
+        let summarized =
+            compact_issue_text(&issue, 1, Some("Custom summary"), Utc::now(), None).unwrap();
+        assert_eq!(summarized.description.as_deref(), Some("Custom summary"));
+    }
+}
@@ -1,9 +1,10 @@
 use crate::cli::{CountArgs, CountBy};
 use crate::config;
+use crate::config::workflow::Workflow;
 use crate::error::Result;
 use crate::model::{IssueType, Priority, Status};
 use crate::output::{OutputContext, OutputMode};
-use crate::storage::{ListFilters, SqliteStorage};
+use crate::storage::{FilterExpr, ListFilters, SqliteStorage};
 use rich_rust::prelude::*;
 use serde::Serialize;
 use std::collections::BTreeMap;
@@ -41,7 +42,7 @@ pub fn execute(
     let storage = &storage_ctx.storage;
 
     let mut filters = ListFilters::default();
-    let statuses = parse_statuses(&args.status)?;
+    let statuses = parse_statuses(&args.status, storage.workflow())?;
     let types = parse_types(&args.types)?;
     let priorities = parse_priorities(&args.priority)?;
 
@@ -63,6 +64,13 @@ pub fn execute(
     filters.include_closed = filters.include_closed || args.include_closed;
     filters.include_templates = args.include_templates;
     filters.title_contains.clone_from(&args.title_contains);
+    if let Some(ref input) = args.where_ {
+        let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
+        filters.set_where(FilterExpr::parse(
+            input,
+            &config::resolve_actor(&config_layer),
+        )?);
+    }
 
     let issues = storage.list_issues(&filters)?;
     let total = issues.len();
@@ -173,10 +181,10 @@ fn render_count_grouped_rich(
     console.print_renderable(&panel);
 }
 
-fn parse_statuses(values: &[String]) -> Result<Vec<Status>> {
+fn parse_statuses(values: &[String], workflow: &Workflow) -> Result<Vec<Status>> {
     values
         .iter()
-        .map(|value| value.parse())
+        .map(|value| workflow.parse_status(value))
         .collect::<Result<Vec<Status>>>()
 }
 
@@ -282,6 +290,7 @@ mod tests {
             updated_at: Utc::now(),
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             due_at: None,
             defer_until: None,
@@ -304,6 +313,7 @@ mod tests {
             dependencies: vec![],
             comments: vec![],
             content_hash: None,
+            fields: BTreeMap::new(),
         }
     }
 
@@ -1,14 +1,16 @@
 use crate::cli::CreateArgs;
+use crate::cli::commands::template;
 use crate::config;
 use crate::error::{BeadsError, Result};
 use crate::model::{Dependency, DependencyType, Issue, IssueType, Priority, Status};
 use crate::output::OutputContext;
 use crate::storage::SqliteStorage;
-use crate::util::id::{IdGenerator, child_id};
+use crate::util::id::{IdGenerator, IdResolver, ResolverConfig, child_id};
 use crate::util::markdown_import::{parse_dependency, parse_markdown_file};
 use crate::util::time::parse_flexible_timestamp;
 use crate::validation::{IssueValidator, LabelValidator};
 use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
 use std::path::Path;
 use std::str::FromStr;
 
@@ -42,6 +44,9 @@ pub fn execute(args: &CreateArgs, cli: &config::CliOverrides, ctx: &OutputContex
         }
         return execute_import(file_path, args, cli, ctx);
     }
+    if args.from_template.is_some() {
+        return execute_from_template(args, cli, ctx);
+    }
 
     // 1. Open storage (unless dry run without DB)
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
@@ -108,13 +113,40 @@ pub fn execute(args: &CreateArgs, cli: &config::CliOverrides, ctx: &OutputContex
 /// - ID generation fails
 /// - Validation fails
 /// - Storage write fails
-#[allow(clippy::too_many_lines)]
 pub fn create_issue_impl(
     storage: &mut SqliteStorage,
     args: &CreateArgs,
     config: &CreateConfig,
 ) -> Result<Issue> {
     // 1. Resolve title
+    let title = resolve_title(args)?;
+
+    // 2. Generate ID
+    let now = Utc::now();
+
+    let id = generate_issue_id(
+        storage,
+        title,
+        args.parent.as_deref(),
+        &config.id_config,
+        now,
+    )?;
+
+    // 3-6. Build and validate
+    let issue = build_issue(storage, args, config, &id, now)?;
+
+    // 7. Dry Run check - return early
+    if args.dry_run {
+        return Ok(issue);
+    }
+
+    // 8. Create (atomic)
+    storage.create_issue(&issue, &config.actor)?;
+
+    Ok(issue)
+}
+
+fn resolve_title(args: &CreateArgs) -> Result<&String> {
     let title = args
         .title
         .as_ref()
@@ -124,57 +156,25 @@ pub fn create_issue_impl(
     if title.is_empty() {
         return Err(BeadsError::validation("title", "cannot be empty"));
     }
+    Ok(title)
+}
 
-    // 2. Generate ID
-    let now = Utc::now();
-
-    // When a parent is specified, generate a child ID (parent.1, parent.2, etc.)
-    // instead of a random hash-based ID
-    let id = if let Some(parent_id) = &args.parent {
-        // Verify parent exists
-        if !storage.id_exists(parent_id).unwrap_or(false) {
-            return Err(BeadsError::IssueNotFound {
-                id: parent_id.clone(),
-            });
-        }
-
-        // Find next available child number
-        let next_num = storage.next_child_number(parent_id)?;
-        let candidate = child_id(parent_id, next_num);
-
-        // Double-check the ID doesn't exist (race condition safety)
-        if storage.id_exists(&candidate).unwrap_or(false) {
-            // Extremely unlikely, but handle by incrementing
-            let mut num = next_num + 1;
-            loop {
-                let alt = child_id(parent_id, num);
-                if !storage.id_exists(&alt).unwrap_or(false) {
-                    break alt;
-                }
-                num += 1;
-                if num > next_num + 100 {
-                    return Err(BeadsError::validation(
-                        "parent",
-                        "could not find available child ID",
-                    ));
-                }
-            }
-        } else {
-            candidate
-        }
-    } else {
-        // Standard ID generation for non-child issues
-        let id_gen = IdGenerator::new(config.id_config.clone());
-        let count = storage.count_issues()?;
-        id_gen.generate(
-            title,
-            None, // description
-            None, // creator
-            now,
-            count,
-            |id| storage.id_exists(id).unwrap_or(false),
-        )
-    };
+/// Build the issue `args` describe under an already chosen ID, with labels
+/// and dependencies attached and everything validated, without writing it.
+///
+/// # Errors
+///
+/// Returns an error if the title is empty, a value fails to parse, or
+/// validation fails.
+#[allow(clippy::too_many_lines)]
+pub fn build_issue(
+    storage: &SqliteStorage,
+    args: &CreateArgs,
+    config: &CreateConfig,
+    id: &str,
+    now: DateTime<Utc>,
+) -> Result<Issue> {
+    let title = resolve_title(args)?;
 
     // 3. Parse fields
     let priority = if let Some(p) = &args.priority {
@@ -194,7 +194,7 @@ pub fn create_issue_impl(
 
     // Parse status (default to Open if not provided)
     let status = if let Some(s) = &args.status {
-        Status::from_str(s)?
+        storage.workflow().parse_status(s)?
     } else {
         Status::Open
     };
@@ -206,9 +206,16 @@ pub fn create_issue_impl(
         None
     };
 
+    let fields: BTreeMap<String, serde_json::Value> = storage
+        .field_schema()
+        .parse_assignments(&args.fields)?
+        .into_iter()
+        .filter_map(|(name, value)| Some((name, value?)))
+        .collect();
+
     // 4. Construct Issue
     let mut issue = Issue {
-        id: id.clone(),
+        id: id.to_string(),
         title: title.clone(),
         description: args.description.clone(),
         status,
@@ -231,6 +238,7 @@ pub fn create_issue_impl(
         created_by: Some(config.actor.clone()),
         closed_at,
         close_reason: None,
+        close_outcome: None,
         closed_by_session: None,
         source_system: None,
         source_repo: None,
@@ -248,6 +256,7 @@ pub fn create_issue_impl(
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        fields,
     };
 
     // Compute content hash
@@ -255,22 +264,344 @@ pub fn create_issue_impl(
 
     // 5. Validate Issue
     IssueValidator::validate(&issue).map_err(BeadsError::from_validation_errors)?;
+    IssueValidator::validate_fields(&issue, storage.field_schema())
+        .map_err(BeadsError::from_validation_errors)?;
 
     // 5b. Validate Relations (fail fast before DB writes)
-    validate_relations(args, &id)?;
+    validate_relations(args, id)?;
 
     // 6. Populate Relations (labels & dependencies)
     populate_relations(&mut issue, args, &config.actor, now);
 
-    // 7. Dry Run check - return early
-    if args.dry_run {
-        return Ok(issue);
+    Ok(issue)
+}
+
+/// Generate an ID for a new issue.
+///
+/// With a parent, this is the next free child ID (`parent.1`, `parent.2`, ...);
+/// otherwise a hash-based ID from the configured prefix.
+///
+/// # Errors
+///
+/// Returns an error if the parent does not exist or no free child ID is found.
+pub fn generate_issue_id(
+    storage: &SqliteStorage,
+    title: &str,
+    parent: Option<&str>,
+    id_config: &crate::util::id::IdConfig,
+    now: DateTime<Utc>,
+) -> Result<String> {
+    // When a parent is specified, generate a child ID (parent.1, parent.2, etc.)
+    // instead of a random hash-based ID
+    if let Some(parent_id) = parent {
+        // Verify parent exists
+        if !storage.id_exists(parent_id).unwrap_or(false) {
+            return Err(BeadsError::IssueNotFound {
+                id: parent_id.to_string(),
+            });
+        }
+
+        // Find next available child number
+        let next_num = storage.next_child_number(parent_id)?;
+        let candidate = child_id(parent_id, next_num);
+
+        // Double-check the ID doesn't exist (race condition safety)
+        if storage.id_exists(&candidate).unwrap_or(false) {
+            // Extremely unlikely, but handle by incrementing
+            let mut num = next_num + 1;
+            loop {
+                let alt = child_id(parent_id, num);
+                if !storage.id_exists(&alt).unwrap_or(false) {
+                    return Ok(alt);
+                }
+                num += 1;
+                if num > next_num + 100 {
+                    return Err(BeadsError::validation(
+                        "parent",
+                        "could not find available child ID",
+                    ));
+                }
+            }
+        } else {
+            Ok(candidate)
+        }
+    } else {
+        // Standard ID generation for non-child issues
+        let id_gen = IdGenerator::new(id_config.clone());
+        let count = storage.count_issues()?;
+        Ok(id_gen.generate(
+            title,
+            None, // description
+            None, // creator
+            now,
+            count,
+            |id| storage.id_exists(id).unwrap_or(false),
+        ))
     }
+}
 
-    // 8. Create (atomic)
-    storage.create_issue(&issue, &config.actor)?;
+/// Execute `create --from-template`.
+fn execute_from_template(
+    args: &CreateArgs,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
 
-    Ok(issue)
+    let config = CreateConfig {
+        id_config: config::id_config_from_layer(&layer),
+        default_priority: config::default_priority_from_layer(&layer)?,
+        default_issue_type: config::default_issue_type_from_layer(&layer)?,
+        actor: config::resolve_actor(&layer),
+    };
+    let resolver = IdResolver::new(ResolverConfig::with_prefix(config.id_config.prefix.clone()));
+    let input = args.from_template.as_deref().unwrap_or_default();
+    let template = template::resolve_template(&storage_ctx.storage, &resolver, input)?;
+
+    let issues = create_from_template_impl(&mut storage_ctx.storage, &template, args, &config)?;
+    let Some(root) = issues.first() else {
+        return Ok(());
+    };
+
+    if args.silent {
+        println!("{}", root.id);
+    } else if ctx.is_json() {
+        let mut output = Vec::with_capacity(issues.len());
+        for issue in &issues {
+            let full = if args.dry_run {
+                None
+            } else {
+                storage_ctx.storage.get_issue_for_export(&issue.id)?
+            };
+            output.push(full.unwrap_or_else(|| issue.clone()));
+        }
+        // A single instance prints like a plain create; a molecule prints all members
+        if args.molecule {
+            ctx.json_pretty(&output);
+        } else {
+            ctx.json_pretty(&output[0]);
+        }
+    } else {
+        let verb = if args.dry_run {
+            "Dry run: would create"
+        } else {
+            "Created"
+        };
+        ctx.success(&format!(
+            "{verb} {}: {} (from template {})",
+            root.id, root.title, template.id
+        ));
+        for issue in &issues[1..] {
+            ctx.print(&format!("  {}: {}", issue.id, issue.title));
+        }
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+    Ok(())
+}
+
+/// Instantiate a template as real issues.
+///
+/// The template's title, description, design, acceptance criteria, labels,
+/// type and priority are copied with `{{variable}}` placeholders filled from
+/// `--var`; explicit create flags override the copied values on the root.
+/// With `--molecule`, child templates are instantiated under the new root with
+/// fresh child IDs and the dependencies between members are recreated.
+/// Returns the created issues, root first.
+///
+/// # Errors
+///
+/// Returns an error if a placeholder has no value, validation fails, or the
+/// storage write fails.
+#[allow(clippy::too_many_lines)]
+pub fn create_from_template_impl(
+    storage: &mut SqliteStorage,
+    template: &Issue,
+    args: &CreateArgs,
+    config: &CreateConfig,
+) -> Result<Vec<Issue>> {
+    let vars = template::parse_vars(&args.vars)?;
+    let title_override = args.title.as_ref().or(args.title_flag.as_ref());
+    let members = if args.molecule {
+        template::molecule_members(storage, template.clone())?
+    } else {
+        vec![template.clone()]
+    };
+
+    // Gather per-member labels and check every placeholder has a value up front
+    let mut member_labels = Vec::with_capacity(members.len());
+    let mut missing = std::collections::BTreeSet::new();
+    for (index, member) in members.iter().enumerate() {
+        let labels = storage.get_labels(&member.id)?;
+        let mut needed = template::template_issue_variables(member, &labels);
+        if index == 0 && title_override.is_some() {
+            needed = template::template_issue_variables(
+                &Issue {
+                    title: String::new(),
+                    ..member.clone()
+                },
+                &labels,
+            );
+        }
+        missing.extend(needed.into_iter().filter(|name| !vars.contains_key(name)));
+        member_labels.push(labels);
+    }
+    if !missing.is_empty() {
+        return Err(BeadsError::validation(
+            "var",
+            format!(
+                "missing value for template variable(s): {}",
+                missing.into_iter().collect::<Vec<_>>().join(", ")
+            ),
+        ));
+    }
+
+    // Assign fresh IDs: the root like a normal create, members as children of
+    // their (new) parent
+    let now = Utc::now();
+    let root_title = title_override.map_or_else(
+        || template::render_template(&template.title, &vars),
+        Clone::clone,
+    );
+    let mut id_map: std::collections::HashMap<String, String> = std::collections::HashMap::new();
+    let mut template_parents: std::collections::HashMap<String, String> =
+        std::collections::HashMap::new();
+    let mut child_counts: std::collections::HashMap<String, u32> = std::collections::HashMap::new();
+    for (index, member) in members.iter().enumerate() {
+        let new_id = if index == 0 {
+            generate_issue_id(
+                storage,
+                &root_title,
+                args.parent.as_deref(),
+                &config.id_config,
+                now,
+            )?
+        } else {
+            let parent = storage.get_parent_id(&member.id)?.unwrap_or_default();
+            let new_parent = id_map.get(&parent).cloned().unwrap_or_default();
+            template_parents.insert(member.id.clone(), new_parent.clone());
+            // Start after any `<parent>.N` left behind, e.g. by a tombstone
+            let next = match child_counts.get(&new_parent) {
+                Some(next) => *next,
+                None => storage.next_child_number(&new_parent)?,
+            };
+            child_counts.insert(new_parent.clone(), next + 1);
+            child_id(&new_parent, next)
+        };
+        id_map.insert(member.id.clone(), new_id);
+    }
+
+    let mut issues = Vec::with_capacity(members.len());
+    for (index, (member, labels)) in members.iter().zip(&member_labels).enumerate() {
+        let is_root = index == 0;
+        let id = id_map[&member.id].clone();
+        let render = |text: &Option<String>| {
+            text.as_deref()
+                .map(|text| template::render_template(text, &vars))
+        };
+
+        let mut issue = Issue {
+            id: id.clone(),
+            title: if is_root {
+                root_title.clone()
+            } else {
+                template::render_template(&member.title, &vars)
+            },
+            description: if is_root && args.description.is_some() {
+                args.description.clone()
+            } else {
+                render(&member.description)
+            },
+            design: render(&member.design),
+            acceptance_criteria: render(&member.acceptance_criteria),
+            priority: match (&args.priority, is_root) {
+                (Some(priority), true) => Priority::from_str(priority)?,
+                _ => member.priority,
+            },
+            issue_type: match (&args.type_, is_root) {
+                (Some(issue_type), true) => IssueType::from_str(issue_type)?,
+                _ => member.issue_type.clone(),
+            },
+            status: Status::Open,
+            created_at: now,
+            updated_at: now,
+            created_by: Some(config.actor.clone()),
+            ephemeral: args.ephemeral,
+            ..Issue::default()
+        };
+
+        if is_root {
+            if let Some(status) = &args.status {
+                issue.status = storage.workflow().parse_status(status)?;
+                if matches!(issue.status, Status::Closed | Status::Tombstone) {
+                    issue.closed_at = Some(now);
+                }
+            }
+            issue.assignee.clone_from(&args.assignee);
+            issue.owner.clone_from(&args.owner);
+            issue.estimated_minutes = args.estimate;
+            issue.due_at = parse_optional_date(args.due.as_deref())?;
+            issue.defer_until = parse_optional_date(args.defer.as_deref())?;
+            issue.external_ref.clone_from(&args.external_ref);
+        }
+
+        for label in labels {
+            let label = template::render_template(label, &vars);
+            LabelValidator::validate(&label)
+                .map_err(|e| BeadsError::validation("label", e.message))?;
+            if !issue.labels.contains(&label) {
+                issue.labels.push(label);
+            }
+        }
+
+        if is_root {
+            validate_relations(args, &id)?;
+            populate_relations(&mut issue, args, &config.actor, now);
+            let mut seen = std::collections::HashSet::new();
+            issue.labels.retain(|label| seen.insert(label.clone()));
+        } else if let Some(new_parent) = template_parents.get(&member.id) {
+            issue.dependencies.push(Dependency {
+                issue_id: id.clone(),
+                depends_on_id: new_parent.clone(),
+                dep_type: DependencyType::ParentChild,
+                created_at: now,
+                created_by: Some(config.actor.clone()),
+                metadata: None,
+                thread_id: None,
+            });
+        }
+
+        // Recreate dependencies between molecule members
+        for dep in storage.get_dependencies_full(&member.id)? {
+            if dep.dep_type == DependencyType::ParentChild {
+                continue;
+            }
+            if let Some(target) = id_map.get(&dep.depends_on_id) {
+                issue.dependencies.push(Dependency {
+                    issue_id: id.clone(),
+                    depends_on_id: target.clone(),
+                    dep_type: dep.dep_type,
+                    created_at: now,
+                    created_by: Some(config.actor.clone()),
+                    metadata: None,
+                    thread_id: None,
+                });
+            }
+        }
+
+        issue.content_hash = Some(issue.compute_content_hash());
+        IssueValidator::validate(&issue).map_err(BeadsError::from_validation_errors)?;
+        issues.push(issue);
+    }
+
+    // All members or none, so a failure never leaves a half-built molecule
+    if !args.dry_run {
+        storage.create_issues(&issues, &config.actor)?;
+    }
+
+    Ok(issues)
 }
 
 fn validate_relations(args: &CreateArgs, id: &str) -> Result<()> {
@@ -414,7 +745,7 @@ fn execute_import(
 
     // Parse status (default to Open if not provided)
     let import_status = if let Some(s) = &args.status {
-        Status::from_str(s)?
+        storage_ctx.storage.workflow().parse_status(s)?
     } else {
         Status::Open
     };
@@ -497,6 +828,7 @@ fn execute_import(
             created_by: None,
             closed_at: import_closed_at,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             source_system: None,
             source_repo: None,
@@ -514,6 +846,7 @@ fn execute_import(
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            fields: BTreeMap::new(),
         };
 
         issue.content_hash = Some(issue.compute_content_hash());
@@ -639,11 +972,15 @@ mod tests {
             due: None,
             defer: None,
             external_ref: None,
+            fields: Vec::new(),
             status: None,
             ephemeral: false,
             dry_run: false,
             silent: false,
             file: None,
+            from_template: None,
+            vars: Vec::new(),
+            molecule: false,
         }
     }
 
@@ -770,6 +1107,34 @@ mod tests {
         info!("test_create_issue_with_labels_and_deps: assertions passed");
     }
 
+    #[test]
+    fn test_create_issue_with_custom_fields() {
+        init_test_logging();
+        info!("test_create_issue_with_custom_fields: starting");
+        let mut storage = setup_memory_storage();
+        let mut layer = config::ConfigLayer::default();
+        layer
+            .startup
+            .insert("fields.story_points".to_string(), "int".to_string());
+        storage.set_field_schema(config::fields::FieldSchema::from_layer(&layer).unwrap());
+        let config = default_config();
+
+        let mut args = default_args();
+        args.fields = vec!["story_points=5".to_string()];
+        let issue = create_issue_impl(&mut storage, &args, &config).expect("create failed");
+        let fields = storage.get_fields(&issue.id).expect("get fields");
+        assert_eq!(
+            fields.get("story_points"),
+            Some(&serde_json::Value::from(5))
+        );
+
+        args.fields = vec!["story_points=many".to_string()];
+        assert!(create_issue_impl(&mut storage, &args, &config).is_err());
+        args.fields = vec!["mood=happy".to_string()];
+        assert!(create_issue_impl(&mut storage, &args, &config).is_err());
+        info!("test_create_issue_with_custom_fields: assertions passed");
+    }
+
     #[test]
     fn test_create_parent_dependency() {
         init_test_logging();
@@ -1046,4 +1411,103 @@ mod tests {
         assert_eq!(labels, vec!["trimmed"]);
         info!("test_create_issue_trims_labels: assertions passed");
     }
+
+    // =========================================================================
+    // Template instantiation tests
+    // =========================================================================
+
+    fn create_template(storage: &mut SqliteStorage, title: &str, parent: Option<&str>) -> Issue {
+        let args = crate::cli::TemplateCreateArgs {
+            title: title.to_string(),
+            description: Some("Ship {{version}}".to_string()),
+            labels: vec!["release-{{version}}".to_string()],
+            parent: parent.map(str::to_string),
+            ..Default::default()
+        };
+        template::create_template_impl(storage, &args, &default_config()).expect("template")
+    }
+
+    #[test]
+    fn test_create_from_template_substitutes_variables() {
+        init_test_logging();
+        info!("test_create_from_template_substitutes_variables: starting");
+        let mut storage = setup_memory_storage();
+        let config = default_config();
+        let tmpl = create_template(&mut storage, "Release {{version}}", None);
+
+        let mut args = default_args();
+        args.title = None;
+        args.from_template = Some(tmpl.id.clone());
+        args.vars = vec!["version=2.0".to_string()];
+        args.priority = Some("1".to_string());
+
+        let issues =
+            create_from_template_impl(&mut storage, &tmpl, &args, &config).expect("instantiate");
+        assert_eq!(issues.len(), 1);
+        let created = storage.get_issue(&issues[0].id).unwrap().unwrap();
+        assert_ne!(created.id, tmpl.id);
+        assert_eq!(created.title, "Release 2.0");
+        assert_eq!(created.description.as_deref(), Some("Ship 2.0"));
+        assert_eq!(created.priority, Priority::HIGH);
+        assert!(!created.is_template);
+        assert_eq!(
+            storage.get_labels(&created.id).unwrap(),
+            vec!["release-2.0"]
+        );
+
+        args.vars.clear();
+        let err = create_from_template_impl(&mut storage, &tmpl, &args, &config).unwrap_err();
+        assert!(err.to_string().contains("version"));
+        info!("test_create_from_template_substitutes_variables: assertions passed");
+    }
+
+    #[test]
+    fn test_create_from_template_molecule() {
+        init_test_logging();
+        info!("test_create_from_template_molecule: starting");
+        let mut storage = setup_memory_storage();
+        let config = default_config();
+        let epic = create_template(&mut storage, "Release {{version}}", None);
+        let build = create_template(&mut storage, "Build {{version}}", Some(&epic.id));
+        let publish = create_template(&mut storage, "Publish {{version}}", Some(&epic.id));
+        storage
+            .add_dependency(&publish.id, &build.id, "blocks", "tester")
+            .unwrap();
+
+        let mut args = default_args();
+        args.title = None;
+        args.from_template = Some(epic.id.clone());
+        args.vars = vec!["version=3.1".to_string()];
+        args.molecule = true;
+
+        let issues =
+            create_from_template_impl(&mut storage, &epic, &args, &config).expect("instantiate");
+        assert_eq!(issues.len(), 3);
+        let root = &issues[0];
+        let titles: Vec<_> = issues.iter().map(|issue| issue.title.as_str()).collect();
+        assert_eq!(titles, vec!["Release 3.1", "Build 3.1", "Publish 3.1"]);
+        for child in &issues[1..] {
+            assert!(child.id.starts_with(&format!("{}.", root.id)));
+            assert_eq!(
+                storage.get_parent_id(&child.id).unwrap().as_deref(),
+                Some(root.id.as_str())
+            );
+        }
+
+        // The internal blocks edge points at the new build issue, not the template
+        assert_eq!(
+            storage.get_dependencies(&issues[2].id).unwrap().len(),
+            2,
+            "parent-child plus blocks"
+        );
+        assert!(
+            storage
+                .get_dependencies(&issues[2].id)
+                .unwrap()
+                .contains(&issues[1].id)
+        );
+        assert!(storage.is_blocked(&issues[2].id).unwrap());
+        assert!(!storage.is_blocked(&issues[1].id).unwrap());
+        info!("test_create_from_template_molecule: assertions passed");
+    }
 }
@@ -411,6 +411,7 @@ mod tests {
     use crate::model::{Issue, IssueType, Priority, Status};
     use crate::storage::SqliteStorage;
     use chrono::{Datelike, Duration, Local, Utc};
+    use std::collections::BTreeMap;
     use std::env;
     use std::path::PathBuf;
     use std::sync::Mutex;
@@ -457,6 +458,7 @@ mod tests {
             created_by: None,
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             due_at: None,
             defer_until: None,
@@ -478,6 +480,7 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
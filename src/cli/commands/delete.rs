@@ -468,6 +468,7 @@ mod tests {
     use super::*;
     use crate::model::{Issue, IssueType, Priority, Status};
     use chrono::Utc;
+    use std::collections::BTreeMap;
     use std::io::Write;
     use tempfile::NamedTempFile;
     use tracing::info;
@@ -496,6 +497,7 @@ mod tests {
             created_by: None,
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             due_at: None,
             defer_until: None,
@@ -517,6 +519,7 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -41,17 +41,35 @@ pub fn execute(
     let actor = config::resolve_actor(&config_layer);
 
     let external_db_paths = config::external_project_db_paths(&config_layer, &beads_dir);
+    let mut routed = config::RoutedIssueLookup::new(&beads_dir);
 
     match command {
-        DepCommands::Add(args) => dep_add(args, storage, &resolver, &all_ids, &actor, json, ctx),
-        DepCommands::Remove(args) => {
-            dep_remove(args, storage, &resolver, &all_ids, &actor, json, ctx)
-        }
+        DepCommands::Add(args) => dep_add(
+            args,
+            storage,
+            &resolver,
+            &all_ids,
+            &mut routed,
+            &actor,
+            json,
+            ctx,
+        ),
+        DepCommands::Remove(args) => dep_remove(
+            args,
+            storage,
+            &resolver,
+            &all_ids,
+            &mut routed,
+            &actor,
+            json,
+            ctx,
+        ),
         DepCommands::List(args) => dep_list(
             args,
             storage,
             &resolver,
             &all_ids,
+            &mut routed,
             &external_db_paths,
             json,
             quiet,
@@ -62,6 +80,7 @@ pub fn execute(
             storage,
             &resolver,
             &all_ids,
+            &mut routed,
             &external_db_paths,
             json,
             ctx,
@@ -115,23 +134,21 @@ struct CyclesResult {
     count: usize,
 }
 
+#[allow(clippy::too_many_arguments)]
 fn dep_add(
     args: &DepAddArgs,
     storage: &mut SqliteStorage,
     resolver: &IdResolver,
     all_ids: &[String],
+    routed: &mut config::RoutedIssueLookup,
     actor: &str,
     _json: bool,
     ctx: &OutputContext,
 ) -> Result<()> {
     let issue_id = resolve_issue_id(storage, resolver, all_ids, &args.issue)?;
 
-    // External dependencies don't need resolution
-    let depends_on_id = if args.depends_on.starts_with("external:") {
-        args.depends_on.clone()
-    } else {
-        resolve_issue_id(storage, resolver, all_ids, &args.depends_on)?
-    };
+    let depends_on_id =
+        resolve_dependency_target(storage, resolver, all_ids, routed, &args.depends_on)?;
 
     // Parse and validate dependency type
     let dep_type_str = &args.dep_type;
@@ -161,9 +178,10 @@ fn dep_add(
         return Err(BeadsError::SelfDependency { id: issue_id });
     }
 
-    // Cycle check for blocking types only
+    // Cycle check for blocking types only; external and routed targets have
+    // no edges in this database.
     if dep_type.is_blocking()
-        && !depends_on_id.starts_with("external:")
+        && storage.id_exists(&depends_on_id)?
         && storage.would_create_cycle(&issue_id, &depends_on_id, true)?
     {
         return Err(BeadsError::DependencyCycle {
@@ -221,23 +239,25 @@ fn dep_add(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn dep_remove(
     args: &DepRemoveArgs,
     storage: &mut SqliteStorage,
     resolver: &IdResolver,
     all_ids: &[String],
+    routed: &mut config::RoutedIssueLookup,
     actor: &str,
     _json: bool,
     ctx: &OutputContext,
 ) -> Result<()> {
     let issue_id = resolve_issue_id(storage, resolver, all_ids, &args.issue)?;
 
-    // External dependencies don't need resolution
-    let depends_on_id = if args.depends_on.starts_with("external:") {
-        args.depends_on.clone()
-    } else {
-        resolve_issue_id(storage, resolver, all_ids, &args.depends_on)?
-    };
+    // A dangling edge (its target deleted) must still be removable by its raw ID
+    let depends_on_id =
+        match resolve_dependency_target(storage, resolver, all_ids, routed, &args.depends_on) {
+            Err(BeadsError::IssueNotFound { .. }) => args.depends_on.clone(),
+            resolved => resolved?,
+        };
 
     let removed = storage.remove_dependency(&issue_id, &depends_on_id, actor)?;
 
@@ -284,6 +304,7 @@ fn dep_list(
     storage: &SqliteStorage,
     resolver: &IdResolver,
     all_ids: &[String],
+    routed: &mut config::RoutedIssueLookup,
     external_db_paths: &HashMap<String, PathBuf>,
     json: bool,
     quiet: bool,
@@ -304,14 +325,24 @@ fn dep_list(
                     continue;
                 }
             }
-            items.push(DepListItem {
+            let mut item = DepListItem {
                 issue_id: issue_id.clone(),
                 depends_on_id: dep.id.clone(),
                 dep_type: dep.dep_type.clone(),
                 title: dep.title.clone(),
                 status: dep.status.as_str().to_string(),
                 priority: dep.priority.0,
-            });
+            };
+            // Targets in another routed workspace have no local row
+            if item.title.is_empty()
+                && !item.depends_on_id.starts_with("external:")
+                && let Some(target) = routed.get_issue(&item.depends_on_id)
+            {
+                item.title = target.title;
+                item.status = target.status.as_str().to_string();
+                item.priority = target.priority.0;
+            }
+            items.push(item);
         }
     }
 
@@ -518,6 +549,7 @@ fn dep_tree(
     storage: &SqliteStorage,
     resolver: &IdResolver,
     all_ids: &[String],
+    routed: &mut config::RoutedIssueLookup,
     external_db_paths: &HashMap<String, PathBuf>,
     _json: bool,
     ctx: &OutputContext,
@@ -556,12 +588,18 @@ fn dep_tree(
             continue;
         }
 
+        let mut is_routed = false;
         let issue = if item.id == root_id {
             Some(root_issue.clone())
         } else if item.id.starts_with("external:") {
             None
+        } else if let Some(issue) = storage.get_issue(&item.id)? {
+            Some(issue)
         } else {
-            storage.get_issue(&item.id)?
+            // Missing locally; the issue may live in another routed workspace
+            let routed_issue = routed.get_issue(&item.id);
+            is_routed = routed_issue.is_some();
+            routed_issue
         };
 
         let (title, priority, status) = if let Some(ref issue) = issue {
@@ -602,8 +640,16 @@ fn dep_tree(
             let mut new_path = item.path.clone();
             new_path.push(item.id.clone());
 
-            // Get dependencies (issues that this one depends on)
-            let mut dependencies = storage.get_dependencies(&item.id)?;
+            // Get dependencies (issues that this one depends on), reading
+            // routed issues' edges from their own workspace
+            let mut dependencies = if is_routed {
+                match routed.storage_for(&item.id) {
+                    Some(routed_storage) => routed_storage.get_dependencies(&item.id)?,
+                    None => Vec::new(),
+                }
+            } else {
+                storage.get_dependencies(&item.id)?
+            };
 
             // Get full issue details for sorting
             // This is slightly inefficient (N queries), but necessary for sorting by priority.
@@ -847,6 +893,32 @@ fn render_cycles_rich(ctx: &OutputContext, cycles: &[Vec<String>], count: usize)
     ctx.render(&panel);
 }
 
+/// Resolve the target of `dep add`/`dep remove`.
+///
+/// `external:` refs pass through unchanged. Full IDs whose prefix routes to
+/// another workspace are checked there and kept verbatim; everything else
+/// resolves locally.
+fn resolve_dependency_target(
+    storage: &SqliteStorage,
+    resolver: &IdResolver,
+    all_ids: &[String],
+    routed: &mut config::RoutedIssueLookup,
+    input: &str,
+) -> Result<String> {
+    if input.starts_with("external:") || storage.id_exists(input)? {
+        return Ok(input.to_string());
+    }
+    if let Some(routed_storage) = routed.storage_for(input) {
+        if routed_storage.id_exists(input)? {
+            return Ok(input.to_string());
+        }
+        return Err(BeadsError::IssueNotFound {
+            id: input.to_string(),
+        });
+    }
+    resolve_issue_id(storage, resolver, all_ids, input)
+}
+
 fn resolve_issue_id(
     storage: &SqliteStorage,
     resolver: &IdResolver,
@@ -868,7 +940,7 @@ mod tests {
     use crate::logging::init_test_logging;
     use crate::model::{Issue, IssueType, Priority, Status};
     use chrono::{TimeZone, Utc};
-    use std::collections::HashMap;
+    use std::collections::{BTreeMap, HashMap};
     use tracing::info;
 
     fn make_test_issue(id: &str, title: &str) -> Issue {
@@ -891,6 +963,7 @@ mod tests {
             updated_at: Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             due_at: None,
             defer_until: None,
@@ -912,6 +985,7 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
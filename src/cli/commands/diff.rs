@@ -0,0 +1,245 @@
+//! `br diff` command implementation.
+//!
+//! Compares two sets of issues at the issue level instead of line by line.
+//! Each side is a JSONL path, a history backup name or `db`.
+
+use crate::cli::DiffArgs;
+use crate::config;
+use crate::error::Result;
+use crate::output::OutputContext;
+use crate::sync::diff::{DiffSource, FieldChange, IssueDiff, IssueRef, SetChange};
+use rich_rust::prelude::*;
+use serde_json::Value;
+use std::fmt::Write as FmtWrite;
+
+/// Execute the diff command.
+///
+/// # Errors
+///
+/// Returns an error if a side cannot be resolved or loaded.
+pub fn execute(args: &DiffArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let old = DiffSource::resolve(&args.old, &beads_dir)?;
+    let new = DiffSource::resolve(&args.new, &beads_dir)?;
+
+    // Only touch the database when one of the sides asks for it
+    let storage_ctx = if old == DiffSource::Database || new == DiffSource::Database {
+        Some(config::open_storage_with_cli(&beads_dir, cli)?)
+    } else {
+        None
+    };
+    let storage = storage_ctx.as_ref().map(|ctx| &ctx.storage);
+
+    let diff = IssueDiff::between(
+        &old.label(),
+        &old.load(storage)?,
+        &new.label(),
+        &new.load(storage)?,
+    );
+
+    if ctx.is_json() {
+        ctx.json_pretty(&diff);
+        return Ok(());
+    }
+    if ctx.is_quiet() {
+        return Ok(());
+    }
+    if ctx.is_rich() {
+        render_rich(&diff, ctx);
+    } else {
+        print!("{}", render_unified(&diff));
+    }
+    Ok(())
+}
+
+/// Render the diff as unified-style text, one `@@` hunk per issue.
+#[must_use]
+pub fn render_unified(diff: &IssueDiff) -> String {
+    let mut out = format!("--- {}\n+++ {}\n", diff.old, diff.new);
+    let mut hunks: Vec<(&str, String)> = Vec::new();
+
+    for issue in &diff.added {
+        hunks.push((&issue.id, whole_issue_hunk(issue, "added", '+')));
+    }
+    for issue in &diff.removed {
+        hunks.push((&issue.id, whole_issue_hunk(issue, "removed", '-')));
+    }
+    for issue in &diff.tombstoned {
+        hunks.push((&issue.id, whole_issue_hunk(issue, "tombstoned", '-')));
+    }
+    for change in &diff.changed {
+        let mut hunk = format!("@@ {} {} @@\n", change.id, change.title);
+        for field in &change.fields {
+            push_value_lines(&mut hunk, '-', &field.field, &field.old);
+            push_value_lines(&mut hunk, '+', &field.field, &field.new);
+        }
+        push_set_lines(&mut hunk, "label", &change.labels);
+        push_set_lines(&mut hunk, "dependency", &change.dependencies);
+        push_set_lines(&mut hunk, "comment", &change.comments);
+        hunks.push((&change.id, hunk));
+    }
+
+    hunks.sort_by_key(|(id, _)| *id);
+    for (_, hunk) in hunks {
+        out.push_str(&hunk);
+    }
+    out
+}
+
+fn whole_issue_hunk(issue: &IssueRef, what: &str, sign: char) -> String {
+    format!("@@ {} {what} @@\n{sign}title: {}\n", issue.id, issue.title)
+}
+
+fn push_value_lines(out: &mut String, sign: char, field: &str, value: &Value) {
+    if value.is_null() {
+        return;
+    }
+    let text = value_text(value);
+    let mut lines = text.lines();
+    let _ = writeln!(out, "{sign}{field}: {}", lines.next().unwrap_or(""));
+    for line in lines {
+        let _ = writeln!(out, "{sign}  {line}");
+    }
+}
+
+fn push_set_lines(out: &mut String, kind: &str, change: &SetChange) {
+    for member in &change.removed {
+        let _ = writeln!(out, "-{kind}: {member}");
+    }
+    for member in &change.added {
+        let _ = writeln!(out, "+{kind}: {member}");
+    }
+}
+
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::Null => "(unset)".to_string(),
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn field_detail(field: &FieldChange) -> String {
+    let one_line = |value: &Value| value_text(value).replace('\n', " ");
+    format!(
+        "{}: {} → {}",
+        field.field,
+        one_line(&field.old),
+        one_line(&field.new)
+    )
+}
+
+fn set_details(kind: &str, change: &SetChange) -> Vec<String> {
+    change
+        .added
+        .iter()
+        .map(|member| format!("+{kind} {member}"))
+        .chain(
+            change
+                .removed
+                .iter()
+                .map(|member| format!("-{kind} {member}")),
+        )
+        .collect()
+}
+
+fn render_rich(diff: &IssueDiff, ctx: &OutputContext) {
+    let theme = ctx.theme();
+    let summary = format!(
+        "{} → {}\n{} added · {} removed · {} tombstoned · {} changed · {} unchanged",
+        diff.old,
+        diff.new,
+        diff.added.len(),
+        diff.removed.len(),
+        diff.tombstoned.len(),
+        diff.changed.len(),
+        diff.unchanged
+    );
+    let panel = Panel::from_text(&summary)
+        .title(Text::styled("Issue Diff", theme.panel_title.clone()))
+        .box_style(theme.box_style)
+        .border_style(theme.panel_border.clone());
+    ctx.render(&panel);
+    if diff.is_empty() {
+        return;
+    }
+
+    let mut table = Table::new()
+        .box_style(theme.box_style)
+        .border_style(theme.panel_border.clone())
+        .with_column(Column::new("Issue").min_width(10).max_width(20))
+        .with_column(Column::new("Change").min_width(10).max_width(12))
+        .with_column(Column::new("Details").min_width(20));
+
+    let whole = [
+        (&diff.added, "added", theme.success.clone()),
+        (&diff.removed, "removed", theme.error.clone()),
+        (&diff.tombstoned, "tombstoned", theme.warning.clone()),
+    ];
+    for (issues, what, style) in whole {
+        for issue in issues {
+            table.add_row(Row::new(vec![
+                Cell::new(Text::styled(issue.id.clone(), theme.issue_id.clone())),
+                Cell::new(Text::styled(what, style.clone())),
+                Cell::new(Text::new(issue.title.clone())),
+            ]));
+        }
+    }
+    for change in &diff.changed {
+        let details: Vec<String> = change
+            .fields
+            .iter()
+            .map(field_detail)
+            .chain(set_details("label", &change.labels))
+            .chain(set_details("dep", &change.dependencies))
+            .chain(set_details("comment", &change.comments))
+            .collect();
+        table.add_row(Row::new(vec![
+            Cell::new(Text::styled(change.id.clone(), theme.issue_id.clone())),
+            Cell::new(Text::styled("changed", theme.info.clone())),
+            Cell::new(Text::new(details.join("\n"))),
+        ]));
+    }
+    ctx.render(&table);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sync::diff::IssueChange;
+
+    #[test]
+    fn test_render_unified_orders_hunks_by_id() {
+        let diff = IssueDiff {
+            old: "a.jsonl".to_string(),
+            new: "db".to_string(),
+            added: vec![IssueRef {
+                id: "bd-2".to_string(),
+                title: "New".to_string(),
+            }],
+            changed: vec![IssueChange {
+                id: "bd-1".to_string(),
+                title: "Edited".to_string(),
+                fields: vec![FieldChange {
+                    field: "notes".to_string(),
+                    old: Value::Null,
+                    new: Value::String("one\ntwo".to_string()),
+                }],
+                labels: SetChange {
+                    added: vec!["urgent".to_string()],
+                    removed: Vec::new(),
+                },
+                dependencies: SetChange::default(),
+                comments: SetChange::default(),
+            }],
+            ..IssueDiff::default()
+        };
+
+        assert_eq!(
+            render_unified(&diff),
+            "--- a.jsonl\n+++ db\n\
+             @@ bd-1 Edited @@\n+notes: one\n+  two\n+label: urgent\n\
+             @@ bd-2 added @@\n+title: New\n"
+        );
+    }
+}
@@ -436,6 +436,7 @@ mod tests {
     use super::*;
     use crate::model::{Issue, Priority};
     use chrono::TimeZone;
+    use std::collections::BTreeMap;
 
     fn base_issue(id: &str, title: &str, issue_type: IssueType, status: Status) -> Issue {
         Issue {
@@ -457,6 +458,7 @@ mod tests {
             updated_at: Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             due_at: None,
             defer_until: None,
@@ -478,6 +480,7 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -0,0 +1,120 @@
+//! Gc command implementation.
+//!
+//! Purges tombstones past retention from the database, then rewrites the
+//! JSONL so the purged issues disappear from it as well.
+
+use super::tombstones::{self, TombstoneEntry};
+use crate::cli::GcArgs;
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::output::OutputContext;
+use crate::sync::{ExportConfig, export_to_jsonl_with_policy, finalize_export};
+use chrono::Utc;
+use serde::Serialize;
+
+/// JSON output for the gc command.
+#[derive(Debug, Serialize)]
+pub struct GcResult {
+    pub dry_run: bool,
+    pub retention_days: u64,
+    pub purged: Vec<TombstoneEntry>,
+    pub purged_count: usize,
+}
+
+/// Execute the gc command.
+///
+/// # Errors
+///
+/// Returns an error if no retention is configured or given, or if the purge
+/// or the JSONL rewrite fails.
+pub fn execute(args: &GcArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let actor = config::resolve_actor(&config_layer);
+
+    let Some(retention_days) = args
+        .older_than
+        .or(storage_ctx.paths.metadata.deletions_retention_days)
+    else {
+        return Err(BeadsError::validation(
+            "older_than",
+            "no retention period: pass --older-than DAYS or set deletions_retention_days in metadata.json",
+        ));
+    };
+    if retention_days == 0 {
+        return Err(BeadsError::validation(
+            "older_than",
+            "retention of 0 days keeps tombstones forever; nothing to purge",
+        ));
+    }
+
+    let now = Utc::now();
+    let expired: Vec<TombstoneEntry> = storage_ctx
+        .storage
+        .get_tombstones()?
+        .iter()
+        .filter(|issue| issue.is_expired_tombstone(Some(retention_days)))
+        .map(|issue| TombstoneEntry::new(issue, Some(retention_days), now))
+        .collect();
+
+    let mut purged_count = 0;
+    if !args.dry_run && !expired.is_empty() {
+        let ids: Vec<String> = expired.iter().map(|entry| entry.id.clone()).collect();
+        purged_count = storage_ctx.storage.purge_tombstones(&ids, &actor)?;
+
+        // The purged IDs are still in the JSONL, hence `force`
+        let export_config = ExportConfig {
+            force: true,
+            is_default_path: true,
+            retention_days: Some(retention_days),
+            beads_dir: Some(beads_dir.clone()),
+            export_events: config::sync_events_enabled(&beads_dir),
+            ..Default::default()
+        };
+        let (export_result, _report) = export_to_jsonl_with_policy(
+            &storage_ctx.storage,
+            &storage_ctx.paths.jsonl_path,
+            &export_config,
+        )?;
+        finalize_export(
+            &mut storage_ctx.storage,
+            &export_result,
+            Some(&export_result.issue_hashes),
+        )?;
+    }
+
+    let result = GcResult {
+        dry_run: args.dry_run,
+        retention_days,
+        purged_count,
+        purged: expired,
+    };
+    if ctx.is_json() {
+        ctx.json_pretty(&result);
+        return Ok(());
+    }
+    if ctx.is_quiet() {
+        return Ok(());
+    }
+
+    if result.purged.is_empty() {
+        println!("No tombstones older than {retention_days} days.");
+        return Ok(());
+    }
+    let heading = if args.dry_run {
+        format!(
+            "Would purge {} tombstone(s) older than {retention_days} days",
+            result.purged.len()
+        )
+    } else {
+        format!("Purged {purged_count} tombstone(s) older than {retention_days} days")
+    };
+    if ctx.is_rich() {
+        tombstones::render_table(&result.purged, &heading, ctx);
+    } else {
+        println!("{heading}:");
+        tombstones::print_plain(&result.purged);
+    }
+    Ok(())
+}
@@ -0,0 +1,457 @@
+//! `br git-sync` command implementation.
+//!
+//! Reads commit trailers such as `Closes: bd-abc`, `Fixes: bd-abc` or
+//! `Refs: bd-abc` from `git log` and closes or annotates the referenced
+//! issues, leaving the commit SHA and subject as a comment. Git is only ever
+//! read; the last processed commit is kept in the beads metadata table so
+//! reruns only look at new commits. The cursor stops short of the first
+//! commit whose close was blocked, so that close is retried next time.
+
+use crate::cli::GitSyncArgs;
+use crate::cli::commands::close::{self, CloseArgs};
+use crate::cli::commands::orphans::{is_git_repo, issue_id_regex};
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::model::CloseOutcome;
+use crate::output::OutputContext;
+use crate::storage::SqliteStorage;
+use crate::util::id::normalize_id;
+use regex::Regex;
+use serde::Serialize;
+use std::process::Command;
+use tracing::{debug, info, warn};
+
+/// Metadata key holding the last commit `git-sync` processed.
+pub const METADATA_GIT_SYNC_LAST_COMMIT: &str = "git_sync_last_commit";
+
+const FIELD_SEP: char = '\x1f';
+const RECORD_SEP: char = '\x1e';
+
+/// What a commit trailer says about an issue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TrailerIntent {
+    /// `Closes:`, `Fixes:`, `Resolves:` and their tense variants.
+    Closes,
+    /// `Refs:`, `References:`, `Related:`, `See-also:`.
+    Refs,
+}
+
+/// A commit carrying at least one issue trailer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrailerCommit {
+    pub sha: String,
+    pub subject: String,
+    pub closes: Vec<String>,
+    pub refs: Vec<String>,
+    /// Value of a `Beads-Session:` (or `Session:`) trailer.
+    pub session: Option<String>,
+}
+
+impl TrailerCommit {
+    #[must_use]
+    pub fn short_sha(&self) -> &str {
+        &self.sha[..self.sha.len().min(7)]
+    }
+
+    /// How this commit refers to `issue_id`, if at all.
+    #[must_use]
+    pub fn intent_for(&self, issue_id: &str) -> Option<TrailerIntent> {
+        if self.closes.iter().any(|id| id == issue_id) {
+            Some(TrailerIntent::Closes)
+        } else if self.refs.iter().any(|id| id == issue_id) {
+            Some(TrailerIntent::Refs)
+        } else {
+            None
+        }
+    }
+
+    fn targets(&self) -> impl Iterator<Item = (&str, TrailerIntent)> {
+        self.closes
+            .iter()
+            .map(|id| (id.as_str(), TrailerIntent::Closes))
+            .chain(
+                self.refs
+                    .iter()
+                    .map(|id| (id.as_str(), TrailerIntent::Refs)),
+            )
+    }
+}
+
+/// One issue touched (or, with `--dry-run`, that would be touched).
+#[derive(Debug, Clone, Serialize)]
+pub struct GitSyncAction {
+    pub commit: String,
+    pub subject: String,
+    pub issue_id: String,
+    pub intent: TrailerIntent,
+    /// `closed`, `commented`, `blocked`, `already_recorded` or `not_found`.
+    pub action: &'static str,
+    /// Why a `blocked` issue was left open.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// Result of `br git-sync`.
+#[derive(Debug, Clone, Serialize)]
+pub struct GitSyncOutput {
+    pub dry_run: bool,
+    /// Commit the scan resumed after; `None` for a full scan.
+    pub since: Option<String>,
+    pub head: Option<String>,
+    /// Commit the next run resumes after; short of `head` when a close was
+    /// blocked, so it is retried.
+    pub next_since: Option<String>,
+    pub commits_scanned: usize,
+    pub actions: Vec<GitSyncAction>,
+}
+
+/// Execute the git-sync command.
+///
+/// # Errors
+///
+/// Returns an error if the current directory is not a git repository, git
+/// cannot be read, or database operations fail.
+pub fn execute(args: &GitSyncArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let actor = config::resolve_actor(&layer);
+    let prefix = config::id_config_from_layer(&layer).prefix;
+    let storage = &mut storage_ctx.storage;
+
+    if !is_git_repo() {
+        return Err(BeadsError::validation(
+            "git",
+            "git-sync must run inside a git repository",
+        ));
+    }
+
+    let head = crate::util::git::head_sha();
+    let mut since = if args.full {
+        None
+    } else {
+        storage.get_metadata(METADATA_GIT_SYNC_LAST_COMMIT)?
+    };
+
+    let mut output = GitSyncOutput {
+        dry_run: args.dry_run,
+        since: since.clone(),
+        head: head.clone(),
+        next_since: since.clone(),
+        commits_scanned: 0,
+        actions: Vec::new(),
+    };
+
+    if let Some(head_sha) = &head
+        && since.as_ref() != Some(head_sha)
+    {
+        let re = issue_id_regex(&prefix)?;
+        let log = match read_trailer_log(since.as_deref()) {
+            Ok(log) => log,
+            Err(err) if since.is_some() => {
+                // History was rewritten or the commit is gone; start over.
+                warn!(error = %err, "Last processed commit not found; rescanning all history");
+                since = None;
+                output.since = None;
+                read_trailer_log(None)?
+            }
+            Err(err) => return Err(err),
+        };
+        output.commits_scanned = log.matches(RECORD_SEP).count();
+        let commits = parse_trailer_log(&log, &re);
+        debug!(
+            commits = commits.len(),
+            since = ?since,
+            "Parsed commit trailers"
+        );
+
+        // Resume after the last commit before the first blocked close
+        let mut cursor = since.clone();
+        let mut held = false;
+        for commit in &commits {
+            for (issue_id, intent) in commit.targets() {
+                let action =
+                    apply_trailer(storage, commit, issue_id, intent, &actor, args.dry_run)?;
+                held |= action.action == "blocked";
+                output.actions.push(action);
+            }
+            if !held {
+                cursor = Some(commit.sha.clone());
+            }
+        }
+        if !held {
+            cursor = Some(head_sha.clone());
+        }
+        output.next_since.clone_from(&cursor);
+
+        if !args.dry_run
+            && let Some(cursor) = &cursor
+        {
+            storage.set_metadata(METADATA_GIT_SYNC_LAST_COMMIT, cursor)?;
+        }
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+    render(&output, ctx);
+    Ok(())
+}
+
+fn render(output: &GitSyncOutput, ctx: &OutputContext) {
+    if ctx.is_json() {
+        ctx.json_pretty(output);
+        return;
+    }
+    if ctx.is_quiet() {
+        return;
+    }
+
+    for action in &output.actions {
+        let short = &action.commit[..action.commit.len().min(7)];
+        let what = match (action.action, output.dry_run) {
+            ("closed", false) => format!("Closed {}", action.issue_id),
+            ("closed", true) => format!("Would close {}", action.issue_id),
+            ("commented", false) => format!("Commented on {}", action.issue_id),
+            ("commented", true) => format!("Would comment on {}", action.issue_id),
+            ("not_found", _) => format!("Skipped {} (not found)", action.issue_id),
+            ("blocked", _) => format!(
+                "Skipped {} ({})",
+                action.issue_id,
+                action.reason.as_deref().unwrap_or("blocked")
+            ),
+            _ => format!("Skipped {} (already recorded)", action.issue_id),
+        };
+        ctx.print(&format!("{what} ({short}: {})", action.subject));
+    }
+
+    let touched = output
+        .actions
+        .iter()
+        .filter(|action| matches!(action.action, "closed" | "commented"))
+        .count();
+    ctx.info(&format!(
+        "Scanned {} commit(s); {} issue update(s){}",
+        output.commits_scanned,
+        touched,
+        if output.dry_run { " (dry run)" } else { "" }
+    ));
+
+    let blocked: Vec<&str> = output
+        .actions
+        .iter()
+        .filter(|action| action.action == "blocked")
+        .map(|action| action.issue_id.as_str())
+        .collect();
+    if !blocked.is_empty() {
+        ctx.info(&format!(
+            "Left open while blocked: {}; the next git-sync retries them",
+            blocked.join(", ")
+        ));
+    }
+}
+
+/// Close or annotate `issue_id` according to one commit trailer.
+///
+/// Closing goes through the same path as `br close --outcome success`, so an
+/// issue still blocked by open dependencies is skipped rather than closed.
+/// A comment naming the commit is left otherwise; if one already exists the
+/// trailer is treated as processed, so rescans and reopened issues are left
+/// alone. Blocked issues get no comment, so a later run (which resumes
+/// before the blocked commit) closes them once they are unblocked.
+///
+/// # Errors
+///
+/// Returns an error if reading or updating the issue fails.
+pub fn apply_trailer(
+    storage: &mut SqliteStorage,
+    commit: &TrailerCommit,
+    issue_id: &str,
+    intent: TrailerIntent,
+    actor: &str,
+    dry_run: bool,
+) -> Result<GitSyncAction> {
+    let mut action = GitSyncAction {
+        commit: commit.sha.clone(),
+        subject: commit.subject.clone(),
+        issue_id: issue_id.to_string(),
+        intent,
+        action: "commented",
+        reason: None,
+    };
+    let Some(issue) = storage.get_issue(issue_id)? else {
+        action.action = "not_found";
+        return Ok(action);
+    };
+    if storage
+        .get_comments(issue_id)?
+        .iter()
+        .any(|comment| comment.body.contains(&commit.sha))
+    {
+        action.action = "already_recorded";
+        return Ok(action);
+    }
+
+    if intent == TrailerIntent::Closes && !issue.status.is_terminal() {
+        if let Some(reason) = close::blocked_reason(storage, issue_id)? {
+            action.action = "blocked";
+            action.reason = Some(reason);
+            return Ok(action);
+        }
+        action.action = "closed";
+    }
+    if dry_run {
+        return Ok(action);
+    }
+
+    if action.action == "closed" {
+        let args = CloseArgs {
+            reason: Some(format!("Closed by commit {}", commit.short_sha())),
+            outcome: Some(CloseOutcome::Success),
+            session: commit.session.clone(),
+            ..CloseArgs::default()
+        };
+        close::close_issues(storage, &[issue_id.to_string()], &args, actor)?;
+        info!(id = %issue_id, commit = %commit.sha, "Closed issue from commit trailer");
+    }
+
+    let verb = match intent {
+        TrailerIntent::Closes => "Closed in",
+        TrailerIntent::Refs => "Referenced in",
+    };
+    storage.add_comment(
+        issue_id,
+        actor,
+        &format!("{verb} commit {}: {}", commit.sha, commit.subject),
+    )?;
+    Ok(action)
+}
+
+/// Read commits oldest-first with their trailers, after `since` if given.
+///
+/// `--no-optional-locks` keeps git from refreshing anything under `.git/`.
+///
+/// # Errors
+///
+/// Returns an error if git cannot be run or the range is invalid.
+pub fn read_trailer_log(since: Option<&str>) -> Result<String> {
+    let range = since.map_or_else(|| "HEAD".to_string(), |sha| format!("{sha}..HEAD"));
+    let format = format!("--format=%H{FIELD_SEP}%s{FIELD_SEP}%(trailers:only,unfold){RECORD_SEP}");
+    let output = Command::new("git")
+        .args(["--no-optional-locks", "log", "--reverse", &format, &range])
+        .output()?;
+    if !output.status.success() {
+        return Err(BeadsError::Config(format!(
+            "git log {range} failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Parse `read_trailer_log` output, keeping commits with issue trailers.
+///
+/// IDs are matched with `id_re` (see [`issue_id_regex`]); an issue named by
+/// both a closing and a referencing trailer counts as closed.
+#[must_use]
+pub fn parse_trailer_log(log: &str, id_re: &Regex) -> Vec<TrailerCommit> {
+    let mut commits = Vec::new();
+    for record in log.split(RECORD_SEP) {
+        let mut fields = record.trim_start_matches('\n').splitn(3, FIELD_SEP);
+        let (Some(sha), Some(subject)) = (fields.next(), fields.next()) else {
+            continue;
+        };
+        if sha.is_empty() {
+            continue;
+        }
+
+        let mut commit = TrailerCommit {
+            sha: sha.to_string(),
+            subject: subject.to_string(),
+            closes: Vec::new(),
+            refs: Vec::new(),
+            session: None,
+        };
+        for line in fields.next().unwrap_or_default().lines() {
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let ids = || {
+                id_re
+                    .captures_iter(value)
+                    .filter_map(|cap| cap.get(1))
+                    .map(|m| normalize_id(m.as_str()))
+            };
+            match key.trim().to_ascii_lowercase().as_str() {
+                "close" | "closes" | "closed" | "fix" | "fixes" | "fixed" | "resolve"
+                | "resolves" | "resolved" => push_unique(&mut commit.closes, ids()),
+                "ref" | "refs" | "references" | "related" | "see-also" => {
+                    push_unique(&mut commit.refs, ids());
+                }
+                "beads-session" | "session" => {
+                    let value = value.trim();
+                    if !value.is_empty() {
+                        commit.session = Some(value.to_string());
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        commit.refs.retain(|id| !commit.closes.contains(id));
+        if !commit.closes.is_empty() || !commit.refs.is_empty() {
+            commits.push(commit);
+        }
+    }
+    commits
+}
+
+fn push_unique(ids: &mut Vec<String>, found: impl Iterator<Item = String>) {
+    for id in found {
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(sha: &str, subject: &str, trailers: &str) -> String {
+        format!("{sha}{FIELD_SEP}{subject}{FIELD_SEP}{trailers}{RECORD_SEP}\n")
+    }
+
+    #[test]
+    fn parse_trailer_log_reads_intents_and_session() {
+        let re = issue_id_regex("bd").unwrap();
+        let log = [
+            record(
+                "aaaa1111",
+                "Fix login",
+                "Closes: bd-abc, BD-def\nRefs: bd-abc bd-xyz\nBeads-Session: s-42\n",
+            ),
+            record("bbbb2222", "Plain commit", ""),
+            record(
+                "cccc3333",
+                "Docs",
+                "Signed-off-by: A <a@b>\nSee-also: bd-q1.2\n",
+            ),
+            record("dddd4444", "Other project", "Fixes: fe-123\n"),
+        ]
+        .concat();
+
+        let commits = parse_trailer_log(&log, &re);
+        assert_eq!(commits.len(), 2);
+
+        assert_eq!(commits[0].sha, "aaaa1111");
+        assert_eq!(commits[0].closes, vec!["bd-abc", "bd-def"]);
+        assert_eq!(commits[0].refs, vec!["bd-xyz"]);
+        assert_eq!(commits[0].session.as_deref(), Some("s-42"));
+        assert_eq!(commits[0].intent_for("bd-abc"), Some(TrailerIntent::Closes));
+        assert_eq!(commits[0].intent_for("bd-xyz"), Some(TrailerIntent::Refs));
+
+        assert_eq!(commits[1].subject, "Docs");
+        assert_eq!(commits[1].refs, vec!["bd-q1.2"]);
+        assert!(commits[1].closes.is_empty());
+    }
+}
@@ -4,10 +4,13 @@
 //!
 //! - `br graph <issue-id>`: Show all dependents of an issue (what depends on it)
 //! - `br graph --all`: Show connected components for `open`/`in_progress`/`blocked` issues
+//! - `br graph --format dot|mermaid|graphml|json`: Export either mode with typed edges,
+//!   status/priority styling and parent-child clusters
 
-use crate::cli::GraphArgs;
+use crate::cli::{GraphArgs, GraphFormat};
 use crate::config;
 use crate::error::{BeadsError, Result};
+use crate::format::graph::{self, GraphCluster, GraphExport, GraphExportEdge, GraphExportNode};
 use crate::model::{DependencyType, Issue, Status};
 use crate::output::{OutputContext, OutputMode};
 use crate::storage::{ListFilters, SqliteStorage};
@@ -66,15 +69,203 @@ pub fn execute(args: &GraphArgs, cli: &config::CliOverrides, ctx: &OutputContext
     let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
     let all_ids = storage_ctx.storage.get_all_ids()?;
 
-    if args.all {
-        graph_all(&storage_ctx.storage, args.compact, ctx)
+    let root_id = if args.all {
+        None
     } else {
         let issue_id = args.issue.as_ref().ok_or_else(|| {
             BeadsError::validation("issue", "Issue ID required unless --all is specified")
         })?;
+        Some(resolve_issue_id(
+            &storage_ctx.storage,
+            &resolver,
+            &all_ids,
+            issue_id,
+        )?)
+    };
+
+    let format = args.format.unwrap_or_default();
+    if format != GraphFormat::Text || args.include_closed || args.max_depth.is_some() {
+        let export = build_export(
+            &storage_ctx.storage,
+            root_id.as_deref(),
+            args.include_closed,
+            args.max_depth,
+        )?;
+        return print_export(&export, format, ctx);
+    }
+
+    match root_id {
+        None => graph_all(&storage_ctx.storage, args.compact, ctx),
+        Some(root_id) => graph_single(&storage_ctx.storage, &root_id, args.compact, ctx),
+    }
+}
+
+/// Print an export in the requested format.
+///
+/// Without `--format` (only `--include-closed` or `--max-depth`), the filtered
+/// graph is shown as JSON under `--json` and as a node list plus typed edges
+/// otherwise.
+fn print_export(export: &GraphExport, format: GraphFormat, ctx: &OutputContext) -> Result<()> {
+    match format {
+        GraphFormat::Dot => print!("{}", graph::render_dot(export)),
+        GraphFormat::Mermaid => print!("{}", graph::render_mermaid(export)),
+        GraphFormat::Graphml => print!("{}", graph::render_graphml(export)),
+        GraphFormat::Json => println!("{}", serde_json::to_string_pretty(export)?),
+        GraphFormat::Text if ctx.is_json() => ctx.json_pretty(export),
+        GraphFormat::Text => {
+            for node in &export.nodes {
+                let indent = "  ".repeat(node.depth + 1);
+                println!(
+                    "{}{}: {} [P{}] [{}]",
+                    indent, node.id, node.title, node.priority, node.status
+                );
+            }
+            for edge in &export.edges {
+                println!("  {} -> {} ({})", edge.from, edge.to, edge.dep_type);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Collect nodes, typed edges and parent-child clusters for export.
+///
+/// Single-issue mode walks dependents of `root_id` along edges that affect
+/// ready work; `--all` takes every active issue (plus closed ones when
+/// `include_closed` is set). Edges of every type between collected nodes are
+/// included.
+fn build_export(
+    storage: &SqliteStorage,
+    root_id: Option<&str>,
+    include_closed: bool,
+    max_depth: Option<usize>,
+) -> Result<GraphExport> {
+    let within_depth = |depth: usize| max_depth.is_none_or(|max| depth <= max);
+    let mut nodes: Vec<GraphExportNode> = Vec::new();
+
+    if let Some(root_id) = root_id {
+        let root_issue = storage
+            .get_issue(root_id)?
+            .ok_or_else(|| BeadsError::IssueNotFound {
+                id: root_id.to_string(),
+            })?;
+        nodes.push(export_node(&root_issue, 0));
+
+        let mut visited: HashSet<String> = HashSet::from([root_id.to_string()]);
+        let mut queue: VecDeque<(String, usize)> = VecDeque::from([(root_id.to_string(), 0)]);
+        while let Some((current_id, depth)) = queue.pop_front() {
+            if !within_depth(depth + 1) {
+                continue;
+            }
+            let mut dependents = storage.get_dependents_with_metadata(&current_id)?;
+            dependents.retain(|dep| {
+                dep.dep_type
+                    .parse::<DependencyType>()
+                    .unwrap_or(DependencyType::Blocks)
+                    .affects_ready_work()
+                    && (include_closed || !dep.status.is_terminal())
+            });
+            dependents.sort_by(|a, b| a.priority.0.cmp(&b.priority.0).then(a.id.cmp(&b.id)));
+            for dep in dependents {
+                if visited.insert(dep.id.clone())
+                    && let Some(issue) = storage.get_issue(&dep.id)?
+                {
+                    nodes.push(export_node(&issue, depth + 1));
+                    queue.push_back((dep.id, depth + 1));
+                }
+            }
+        }
+    } else {
+        let filters = if include_closed {
+            ListFilters {
+                include_closed: true,
+                include_templates: false,
+                ..Default::default()
+            }
+        } else {
+            ListFilters {
+                statuses: Some(vec![Status::Open, Status::InProgress, Status::Blocked]),
+                include_closed: false,
+                include_templates: false,
+                ..Default::default()
+            }
+        };
+        let issues = storage.list_issues(&filters)?;
+        let ids: Vec<String> = issues.iter().map(|i| i.id.clone()).collect();
+        let id_set: HashSet<&String> = ids.iter().collect();
+        let all_dependencies = storage.get_all_dependency_records()?;
+        let depths = calculate_depths(&all_dependencies, &ids, &id_set);
+
+        for issue in &issues {
+            let depth = depths.get(&issue.id).copied().unwrap_or(0);
+            if within_depth(depth) {
+                nodes.push(export_node(issue, depth));
+            }
+        }
+        nodes.sort_by(|a, b| {
+            a.depth
+                .cmp(&b.depth)
+                .then(a.priority.cmp(&b.priority))
+                .then(a.id.cmp(&b.id))
+        });
+    }
+
+    let titles: HashMap<&str, &str> = nodes
+        .iter()
+        .map(|n| (n.id.as_str(), n.title.as_str()))
+        .collect();
+    let all_dependencies = storage.get_all_dependency_records()?;
+    let mut edges: Vec<GraphExportEdge> = Vec::new();
+    let mut clusters: Vec<GraphCluster> = Vec::new();
+    let mut clustered: HashSet<String> = HashSet::new();
+
+    for node in &nodes {
+        let Some(deps) = all_dependencies.get(&node.id) else {
+            continue;
+        };
+        for dep in deps {
+            let Some(target_title) = titles.get(dep.depends_on_id.as_str()) else {
+                continue;
+            };
+            edges.push(GraphExportEdge {
+                from: node.id.clone(),
+                to: dep.depends_on_id.clone(),
+                dep_type: dep.dep_type.as_str().to_string(),
+            });
+
+            // A node belongs to at most one cluster: its first parent.
+            if dep.dep_type == DependencyType::ParentChild && clustered.insert(node.id.clone()) {
+                if let Some(cluster) = clusters.iter_mut().find(|c| c.id == dep.depends_on_id) {
+                    cluster.members.push(node.id.clone());
+                } else {
+                    clusters.push(GraphCluster {
+                        id: dep.depends_on_id.clone(),
+                        title: (*target_title).to_string(),
+                        members: vec![node.id.clone()],
+                    });
+                }
+            }
+        }
+    }
+    edges.sort_by(|a, b| (&a.from, &a.to).cmp(&(&b.from, &b.to)));
+    clusters.sort_by(|a, b| a.id.cmp(&b.id));
+
+    Ok(GraphExport {
+        root: root_id.map(str::to_string),
+        nodes,
+        edges,
+        clusters,
+    })
+}
 
-        let resolved_id = resolve_issue_id(&storage_ctx.storage, &resolver, &all_ids, issue_id)?;
-        graph_single(&storage_ctx.storage, &resolved_id, args.compact, ctx)
+fn export_node(issue: &Issue, depth: usize) -> GraphExportNode {
+    GraphExportNode {
+        id: issue.id.clone(),
+        title: issue.title.clone(),
+        status: issue.status.as_str().to_string(),
+        priority: issue.priority.0,
+        issue_type: issue.issue_type.as_str().to_string(),
+        depth,
     }
 }
 
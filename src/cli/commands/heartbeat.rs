@@ -0,0 +1,64 @@
+//! Heartbeat command implementation.
+//!
+//! Renews claim leases taken with `br update --claim` or `br ready --claim`
+//! so long-running work is not handed to another agent.
+
+use crate::cli::HeartbeatArgs;
+use crate::config;
+use crate::error::Result;
+use crate::model::Claim;
+use crate::output::OutputContext;
+use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+
+/// Execute the heartbeat command.
+///
+/// # Errors
+///
+/// Returns an error if an ID cannot be resolved, the lease is invalid, or an
+/// issue is not claimed by the current actor.
+pub fn execute(
+    args: &HeartbeatArgs,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+
+    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let actor = config::resolve_actor(&config_layer);
+    let lease = super::update::claim_lease(args.lease.as_deref(), &config_layer)?;
+    let id_config = config::id_config_from_layer(&config_layer);
+    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let all_ids = storage_ctx.storage.get_all_ids()?;
+
+    let resolved_ids = resolver.resolve_all(
+        &args.ids,
+        |id| all_ids.binary_search_by(|p| p.as_str().cmp(id)).is_ok(),
+        |hash| find_matching_ids(&all_ids, hash),
+    )?;
+
+    let mut renewed: Vec<Claim> = Vec::with_capacity(resolved_ids.len());
+    for resolved in &resolved_ids {
+        renewed.push(
+            storage_ctx
+                .storage
+                .heartbeat_claim(&resolved.id, &actor, lease)?,
+        );
+    }
+
+    if ctx.is_json() {
+        ctx.json_pretty(&renewed);
+        return Ok(());
+    }
+    if ctx.is_quiet() {
+        return Ok(());
+    }
+    for claim in &renewed {
+        ctx.success(&format!(
+            "Renewed {} until {}",
+            claim.issue_id,
+            claim.expires_at.to_rfc3339()
+        ));
+    }
+    Ok(())
+}
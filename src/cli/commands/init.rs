@@ -1,3 +1,4 @@
+use crate::config;
 use crate::error::{BeadsError, Result};
 use crate::output::{OutputContext, OutputMode};
 use crate::storage::SqliteStorage;
@@ -63,6 +64,7 @@ pub fn execute(
 # issue_prefix: bd
 # default_priority: 2
 # default_type: task
+# sync.events: true  # keep the audit log in events.jsonl so history survives clones
 ";
         fs::write(config_path, config)?;
     }
@@ -86,6 +88,19 @@ last-touched
         fs::write(gitignore_path, gitignore)?;
     }
 
+    // With the event log enabled, merge it as a union of lines
+    let gitattributes = if config::sync_events_enabled(&beads_dir) {
+        Some(
+            match crate::sync::events::ensure_union_merge_attribute(&beads_dir)? {
+                None => InitStepStatus::Existing,
+                Some(true) => InitStepStatus::Updated,
+                Some(false) => InitStepStatus::Created,
+            },
+        )
+    } else {
+        None
+    };
+
     // Write empty issues.jsonl for compatibility with bv (beads_viewer)
     // bv expects this file to exist even if there are no issues yet
     let jsonl_path = beads_dir.join("issues.jsonl");
@@ -106,6 +121,7 @@ last-touched
             force,
             config_existed,
             gitignore_existed,
+            gitattributes,
             jsonl_existed,
             prefix_set.as_deref(),
         );
@@ -140,6 +156,7 @@ fn build_init_steps(
     force: bool,
     config_existed: bool,
     gitignore_existed: bool,
+    gitattributes: Option<InitStepStatus>,
     jsonl_existed: bool,
     prefix: Option<&str>,
 ) -> Vec<InitStep> {
@@ -193,6 +210,13 @@ fn build_init_steps(
         },
     });
 
+    if let Some(status) = gitattributes {
+        steps.push(InitStep {
+            label: ".gitattributes".to_string(),
+            status,
+        });
+    }
+
     steps.push(InitStep {
         label: "issues.jsonl (for bv compatibility)".to_string(),
         status: if jsonl_existed {
@@ -3,16 +3,21 @@
 //! Primary discovery interface with classic filter semantics and
 //! `IssueWithCounts` JSON output. Supports text, JSON, and CSV formats.
 
-use crate::cli::{ListArgs, OutputFormat, resolve_output_format};
+use crate::cli::{ListArgs, OutputFormat, print_as_of_notes, resolve_output_format};
 use crate::config;
+use crate::config::fields::FieldSchema;
+use crate::config::workflow::Workflow;
 use crate::error::{BeadsError, Result};
 use crate::format::csv;
-use crate::format::{IssueWithCounts, TextFormatOptions, format_issue_line_with, terminal_width};
-use crate::model::{IssueType, Priority, Status};
+use crate::format::{
+    IssueWithCounts, RoutedRecord, TextFormatOptions, format_issue_line_with, terminal_width,
+};
+use crate::model::{Issue, IssueType, Priority, Status};
 use crate::output::{IssueTable, IssueTableColumns, OutputContext, OutputMode};
-use crate::storage::{ListFilters, SqliteStorage};
-use chrono::Utc;
-use std::collections::HashSet;
+use crate::storage::{FIELD_SORT_PREFIX, FilterExpr, ListFilters, SqliteStorage};
+use crate::sync::as_of;
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, HashSet};
 use std::io::IsTerminal;
 
 /// Execute the list command.
@@ -32,6 +37,17 @@ pub fn execute(
     let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
     let storage = &storage_ctx.storage;
     let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
+    let snapshot = args
+        .as_of
+        .as_deref()
+        .map(|input| as_of::reconstruct_for_arg(input, &storage_ctx))
+        .transpose()?;
+    let storage = snapshot
+        .as_ref()
+        .map_or(storage, |snapshot| &snapshot.storage);
+    let now = snapshot
+        .as_ref()
+        .map_or_else(Utc::now, |snapshot| snapshot.as_of);
     let use_color = config::should_use_color(&config_layer);
     let max_width = if std::io::stdout().is_terminal() {
         Some(terminal_width())
@@ -45,7 +61,17 @@ pub fn execute(
     };
 
     // Build filter from args
-    let mut filters = build_filters(args)?;
+    let mut filters = build_filters(
+        args,
+        storage_ctx.storage.workflow(),
+        storage_ctx.storage.field_schema(),
+    )?;
+    if let Some(ref input) = args.where_ {
+        filters.set_where(FilterExpr::parse(
+            input,
+            &config::resolve_actor(&config_layer),
+        )?);
+    }
     let client_filters = needs_client_filters(args);
     let limit = if client_filters {
         filters.limit.take()
@@ -54,26 +80,45 @@ pub fn execute(
     };
 
     // Validate sort key before query
-    validate_sort_key(args.sort.as_deref())?;
-
-    // Query issues
-    let issues = storage.list_issues(&filters)?;
-    let mut issues = if client_filters {
-        apply_client_filters(storage, issues, args)?
-    } else {
-        issues
-    };
-
-    if let Some(limit) = limit {
-        if limit > 0 && issues.len() > limit {
-            issues.truncate(limit);
-        }
-    }
+    validate_sort_key(args.sort.as_deref(), storage_ctx.storage.field_schema())?;
 
     // Determine output format: --json flag overrides --format
     let output_format = resolve_output_format(args.format, outer_ctx.is_json(), false);
     let quiet = cli.quiet.unwrap_or(false);
     let ctx = OutputContext::from_output_format(output_format, quiet, !use_color);
+
+    if args.all_routes {
+        let mut routed = Vec::new();
+        for routed_storage in config::open_routed_storages(&beads_dir)? {
+            let issues = query_issues(
+                &routed_storage.storage,
+                &filters,
+                args,
+                now,
+                client_filters,
+                limit,
+            )?;
+            routed.extend(
+                with_counts(&routed_storage.storage, issues)?
+                    .into_iter()
+                    .map(|item| RoutedRecord {
+                        project: routed_storage.project.clone(),
+                        item,
+                    }),
+            );
+        }
+        if let Some(limit) = limit.or(filters.limit)
+            && limit > 0
+        {
+            routed.truncate(limit);
+        }
+        render_routed(&routed, args, output_format, &ctx, format_options);
+        return Ok(());
+    }
+
+    // Query issues
+    let issues = query_issues(storage, &filters, args, now, client_filters, limit)?;
+
     if matches!(ctx.mode(), OutputMode::Quiet) {
         return Ok(());
     }
@@ -81,41 +126,26 @@ pub fn execute(
     // Output
     match output_format {
         OutputFormat::Json | OutputFormat::Toon => {
-            // Fetch relations for all issues
-            let issue_ids: Vec<String> = issues.iter().map(|i| i.id.clone()).collect();
-            let mut labels_map = storage.get_labels_for_issues(&issue_ids)?;
-
-            // Use batch counting
-            let dependency_counts = storage.count_dependencies_for_issues(&issue_ids)?;
-            let dependent_counts = storage.count_dependents_for_issues(&issue_ids)?;
-
-            // Convert to IssueWithCounts
-            let issues_with_counts: Vec<IssueWithCounts> = issues
-                .into_iter()
-                .map(|mut issue| {
-                    if let Some(labels) = labels_map.remove(&issue.id) {
-                        issue.labels = labels;
-                    }
-
-                    let dependency_count = *dependency_counts.get(&issue.id).unwrap_or(&0);
-                    let dependent_count = *dependent_counts.get(&issue.id).unwrap_or(&0);
-
-                    IssueWithCounts {
-                        issue,
-                        dependency_count,
-                        dependent_count,
-                    }
-                })
-                .collect();
-
-            if matches!(output_format, OutputFormat::Toon) {
-                ctx.toon_with_stats(&issues_with_counts, args.stats);
-            } else {
-                ctx.json_pretty(&issues_with_counts);
+            let issues_with_counts = with_counts(storage, issues)?;
+
+            match (&snapshot, output_format) {
+                (Some(snapshot), OutputFormat::Toon) => ctx.toon_with_stats(
+                    &snapshot.records(issues_with_counts, |row| row.issue.id.as_str()),
+                    args.stats,
+                ),
+                (Some(snapshot), _) => ctx.json_pretty(
+                    &snapshot.records(issues_with_counts, |row| row.issue.id.as_str()),
+                ),
+                (None, OutputFormat::Toon) => {
+                    ctx.toon_with_stats(&issues_with_counts, args.stats);
+                }
+                (None, _) => ctx.json_pretty(&issues_with_counts),
             }
         }
         OutputFormat::Csv => {
             let fields = csv::parse_fields(args.fields.as_deref());
+            let mut issues = issues;
+            attach_custom_fields(storage, &mut issues, &fields)?;
             let csv_output = csv::format_csv(&issues, &fields);
             print!("{csv_output}");
         }
@@ -159,14 +189,169 @@ pub fn execute(
                     println!("{line}");
                 }
             }
+            if let Some(snapshot) = &snapshot {
+                print_as_of_notes(&ctx, snapshot, issues.iter().map(|issue| issue.id.as_str()));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Run the list query plus client-side filters against one database.
+fn query_issues(
+    storage: &SqliteStorage,
+    filters: &ListFilters,
+    args: &ListArgs,
+    now: DateTime<Utc>,
+    client_filters: bool,
+    limit: Option<usize>,
+) -> Result<Vec<Issue>> {
+    let issues = storage.list_issues(filters)?;
+    let mut issues = if client_filters {
+        apply_client_filters(storage, issues, args, now)?
+    } else {
+        issues
+    };
+
+    if let Some(limit) = limit {
+        if limit > 0 && issues.len() > limit {
+            issues.truncate(limit);
         }
     }
+    Ok(issues)
+}
+
+/// Attach labels, custom fields and dependency counts for JSON/TOON output.
+fn with_counts(storage: &SqliteStorage, issues: Vec<Issue>) -> Result<Vec<IssueWithCounts>> {
+    // Fetch relations for all issues
+    let issue_ids: Vec<String> = issues.iter().map(|i| i.id.clone()).collect();
+    let mut labels_map = storage.get_labels_for_issues(&issue_ids)?;
+    let mut fields_map = storage.get_fields_for_issues(&issue_ids)?;
+
+    // Use batch counting
+    let dependency_counts = storage.count_dependencies_for_issues(&issue_ids)?;
+    let dependent_counts = storage.count_dependents_for_issues(&issue_ids)?;
+
+    Ok(issues
+        .into_iter()
+        .map(|mut issue| {
+            if let Some(labels) = labels_map.remove(&issue.id) {
+                issue.labels = labels;
+            }
+            if let Some(fields) = fields_map.remove(&issue.id) {
+                issue.fields = fields;
+            }
+
+            let dependency_count = *dependency_counts.get(&issue.id).unwrap_or(&0);
+            let dependent_count = *dependent_counts.get(&issue.id).unwrap_or(&0);
 
+            IssueWithCounts {
+                issue,
+                dependency_count,
+                dependent_count,
+            }
+        })
+        .collect())
+}
+
+/// Load custom field values when CSV output asks for `fields.<name>` columns.
+pub(crate) fn attach_custom_fields(
+    storage: &SqliteStorage,
+    issues: &mut [Issue],
+    columns: &[&str],
+) -> Result<()> {
+    if !columns
+        .iter()
+        .any(|column| column.starts_with(csv::CUSTOM_FIELD_PREFIX))
+    {
+        return Ok(());
+    }
+    let issue_ids: Vec<String> = issues.iter().map(|i| i.id.clone()).collect();
+    let mut fields_map = storage.get_fields_for_issues(&issue_ids)?;
+    for issue in issues {
+        if let Some(fields) = fields_map.remove(&issue.id) {
+            issue.fields = fields;
+        }
+    }
     Ok(())
 }
 
+/// Render `--all-routes` results. Rows stay grouped in route order, each
+/// project sorted by the usual list order.
+fn render_routed(
+    routed: &[RoutedRecord<IssueWithCounts>],
+    args: &ListArgs,
+    output_format: OutputFormat,
+    ctx: &OutputContext,
+    format_options: TextFormatOptions,
+) {
+    if matches!(ctx.mode(), OutputMode::Quiet) {
+        return;
+    }
+    match output_format {
+        OutputFormat::Json => ctx.json_pretty(&routed),
+        OutputFormat::Toon => ctx.toon_with_stats(&routed, args.stats),
+        OutputFormat::Csv => {
+            let fields = csv::parse_fields(args.fields.as_deref());
+            println!("project,{}", fields.join(","));
+            for record in routed {
+                println!(
+                    "{},{}",
+                    csv::escape_field(&record.project),
+                    csv::format_issue_row(&record.item.issue, &fields)
+                );
+            }
+        }
+        OutputFormat::Text => {
+            if matches!(ctx.mode(), OutputMode::Rich) {
+                let issues: Vec<Issue> = routed.iter().map(|r| r.item.issue.clone()).collect();
+                let projects: HashMap<String, String> = routed
+                    .iter()
+                    .map(|r| (r.item.issue.id.clone(), r.project.clone()))
+                    .collect();
+                let columns = IssueTableColumns {
+                    project: true,
+                    id: true,
+                    priority: true,
+                    status: true,
+                    issue_type: true,
+                    title: true,
+                    assignee: args.long,
+                    created: args.long,
+                    updated: args.long,
+                    ..Default::default()
+                };
+                let mut table = IssueTable::new(&issues, ctx.theme())
+                    .columns(columns)
+                    .projects(projects)
+                    .title(format!("Issues across routes ({})", issues.len()))
+                    .wrap(args.wrap);
+                if args.wrap {
+                    table = table.width(Some(ctx.width()));
+                }
+                ctx.render(&table.build());
+            } else {
+                let project_width = routed
+                    .iter()
+                    .map(|r| r.project.chars().count())
+                    .max()
+                    .unwrap_or(0);
+                for record in routed {
+                    let line = format_issue_line_with(&record.item.issue, format_options);
+                    println!("{:<project_width$} {line}", record.project);
+                }
+            }
+        }
+    }
+}
+
 /// Convert CLI args to storage filter.
-fn build_filters(args: &ListArgs) -> Result<ListFilters> {
+fn build_filters(
+    args: &ListArgs,
+    workflow: &Workflow,
+    field_schema: &FieldSchema,
+) -> Result<ListFilters> {
     // Parse status strings to Status enums
     let statuses = if args.status.is_empty() {
         None
@@ -174,7 +359,7 @@ fn build_filters(args: &ListArgs) -> Result<ListFilters> {
         Some(
             args.status
                 .iter()
-                .map(|s| s.parse())
+                .map(|s| workflow.parse_status(s))
                 .collect::<Result<Vec<Status>>>()?,
         )
     };
@@ -242,6 +427,8 @@ fn build_filters(args: &ListArgs) -> Result<ListFilters> {
         },
         updated_before: None,
         updated_after: None,
+        where_expr: None,
+        fields: field_schema.parse_filters(&args.field)?,
     })
 }
 
@@ -259,9 +446,10 @@ fn needs_client_filters(args: &ListArgs) -> bool {
 
 fn apply_client_filters(
     storage: &SqliteStorage,
-    issues: Vec<crate::model::Issue>,
+    issues: Vec<Issue>,
     args: &ListArgs,
-) -> Result<Vec<crate::model::Issue>> {
+    now: DateTime<Utc>,
+) -> Result<Vec<Issue>> {
     let id_filter: Option<HashSet<&str>> = if args.id.is_empty() {
         None
     } else {
@@ -275,11 +463,10 @@ fn apply_client_filters(
         let issue_ids: Vec<String> = issues.iter().map(|i| i.id.clone()).collect();
         storage.get_labels_for_issues(&issue_ids)?
     } else {
-        std::collections::HashMap::new()
+        HashMap::new()
     };
 
     let mut filtered = Vec::new();
-    let now = Utc::now();
     let min_priority = args.priority_min.map(i32::from);
     let max_priority = args.priority_max.map(i32::from);
     let desc_needle = args.desc_contains.as_deref().map(str::to_lowercase);
@@ -365,10 +552,20 @@ fn apply_client_filters(
     Ok(filtered)
 }
 
-fn validate_sort_key(sort: Option<&str>) -> Result<()> {
+fn validate_sort_key(sort: Option<&str>, field_schema: &FieldSchema) -> Result<()> {
     let Some(sort_key) = sort else {
         return Ok(());
     };
+    if let Some(name) = sort_key.strip_prefix(FIELD_SORT_PREFIX) {
+        return if field_schema.get(name).is_some() {
+            Ok(())
+        } else {
+            Err(BeadsError::validation(
+                "sort",
+                format!("custom field '{name}' is not declared in config.yaml"),
+            ))
+        };
+    }
 
     match sort_key {
         "priority" | "created_at" | "updated_at" | "title" | "created" | "updated" => Ok(()),
@@ -398,7 +595,8 @@ mod tests {
             ..Default::default()
         };
 
-        let filters = build_filters(&args).expect("build filters");
+        let filters = build_filters(&args, &Workflow::default(), &FieldSchema::default())
+            .expect("build filters");
         assert!(filters.include_closed);
         assert!(
             filters
@@ -419,7 +617,8 @@ mod tests {
             ..Default::default()
         };
 
-        let filters = build_filters(&args).expect("build filters");
+        let filters = build_filters(&args, &Workflow::default(), &FieldSchema::default())
+            .expect("build filters");
         let priorities = filters.priorities.expect("priorities");
         let values: Vec<i32> = priorities.iter().map(|p| p.0).collect();
         assert_eq!(values, vec![0, 2]);
@@ -0,0 +1,537 @@
+//! Mail command implementation.
+//!
+//! Messages between agents are ephemeral issues of type `message`: the
+//! sender is recorded in `sender`, the recipient in `assignee`, and replies
+//! link to their parent with a `replies-to` dependency carrying the thread
+//! ID. Being ephemeral, messages never reach `issues.jsonl`. Read receipts
+//! are local; acknowledging a message closes it.
+
+use crate::cli::commands::create::generate_issue_id;
+use crate::cli::{
+    MailAckArgs, MailCommands, MailInboxArgs, MailReadArgs, MailReplyArgs, MailSendArgs,
+    MailThreadArgs,
+};
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::model::{Dependency, DependencyType, Issue, IssueType, Priority, Status};
+use crate::output::OutputContext;
+use crate::storage::{IssueUpdate, ListFilters, SqliteStorage};
+use crate::util::id::{IdConfig, IdResolver, ResolverConfig, find_matching_ids};
+use crate::validation::IssueValidator;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+
+/// Issue type used for messages.
+pub const MESSAGE_TYPE: &str = "message";
+
+/// A message as shown by the mail subcommands.
+#[derive(Debug, Clone, Serialize)]
+pub struct MailMessage {
+    pub id: String,
+    pub thread_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to: Option<String>,
+    pub from: String,
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+    pub read: bool,
+    pub acknowledged: bool,
+}
+
+/// A rendered conversation for `br mail thread`.
+#[derive(Debug, Clone, Serialize)]
+pub struct MailThread {
+    pub thread_id: String,
+    pub messages: Vec<MailMessage>,
+}
+
+struct MailContext {
+    storage_ctx: config::OpenStorageResult,
+    actor: String,
+    id_config: IdConfig,
+    resolver: IdResolver,
+}
+
+/// Execute a mail subcommand.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be opened, an ID does not resolve
+/// to a message, or validation fails.
+pub fn execute(
+    command: &MailCommands,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let id_config = config::id_config_from_layer(&layer);
+    let mut mail = MailContext {
+        storage_ctx,
+        actor: config::resolve_actor(&layer),
+        resolver: IdResolver::new(ResolverConfig::with_prefix(id_config.prefix.clone())),
+        id_config,
+    };
+
+    match command {
+        MailCommands::Send(args) => mail_send(&mut mail, args, ctx),
+        MailCommands::Inbox(args) => mail_inbox(&mail, args, ctx),
+        MailCommands::Reply(args) => mail_reply(&mut mail, args, ctx),
+        MailCommands::Thread(args) => mail_thread(&mut mail, args, ctx),
+        MailCommands::Read(args) => mail_read(&mut mail, args, ctx),
+        MailCommands::Ack(args) => mail_ack(&mut mail, args, ctx),
+    }
+}
+
+fn mail_send(mail: &mut MailContext, args: &MailSendArgs, ctx: &OutputContext) -> Result<()> {
+    let message = send_message_impl(
+        &mut mail.storage_ctx.storage,
+        &mail.id_config,
+        &mail.actor,
+        &args.to,
+        &args.subject,
+        args.body.as_deref(),
+        None,
+    )?;
+    print_sent(mail, &message, ctx)
+}
+
+fn mail_reply(mail: &mut MailContext, args: &MailReplyArgs, ctx: &OutputContext) -> Result<()> {
+    let storage = &mail.storage_ctx.storage;
+    let original = resolve_message(storage, &mail.resolver, &args.id)?;
+
+    // Reply to whoever sent the original, unless that was us
+    let to = args
+        .to
+        .clone()
+        .unwrap_or_else(|| match original.sender.as_deref() {
+            Some(sender) if sender != mail.actor => sender.to_string(),
+            _ => original.assignee.clone().unwrap_or_default(),
+        });
+    let subject = args.subject.clone().unwrap_or_else(|| {
+        if original.title.starts_with("Re: ") {
+            original.title.clone()
+        } else {
+            format!("Re: {}", original.title)
+        }
+    });
+
+    let message = send_message_impl(
+        &mut mail.storage_ctx.storage,
+        &mail.id_config,
+        &mail.actor,
+        &to,
+        &subject,
+        Some(&args.body),
+        Some(&original),
+    )?;
+    print_sent(mail, &message, ctx)
+}
+
+fn print_sent(mail: &MailContext, message: &Issue, ctx: &OutputContext) -> Result<()> {
+    let storage = &mail.storage_ctx.storage;
+    if ctx.is_json() {
+        let read = storage.read_message_ids(&mail.actor)?;
+        ctx.json_pretty(&to_mail_message(storage, message, &read)?);
+    } else {
+        ctx.success(&format!(
+            "Sent {} to {}: {}",
+            message.id,
+            message.assignee.as_deref().unwrap_or(""),
+            message.title
+        ));
+    }
+    Ok(())
+}
+
+/// Create a message issue from `sender` to `to`, optionally as a reply.
+///
+/// # Errors
+///
+/// Returns an error if the recipient or subject is empty, or the issue
+/// cannot be created.
+pub fn send_message_impl(
+    storage: &mut SqliteStorage,
+    id_config: &IdConfig,
+    sender: &str,
+    to: &str,
+    subject: &str,
+    body: Option<&str>,
+    reply_to: Option<&Issue>,
+) -> Result<Issue> {
+    let to = to.trim();
+    if to.is_empty() {
+        return Err(BeadsError::validation("to", "recipient cannot be empty"));
+    }
+
+    let now = Utc::now();
+    let id = generate_issue_id(storage, subject, None, id_config, now)?;
+    let dependencies = match reply_to {
+        Some(parent) => vec![Dependency {
+            issue_id: id.clone(),
+            depends_on_id: parent.id.clone(),
+            dep_type: DependencyType::RepliesTo,
+            created_at: now,
+            created_by: Some(sender.to_string()),
+            metadata: None,
+            thread_id: Some(storage.message_thread_id(&parent.id)?),
+        }],
+        None => Vec::new(),
+    };
+
+    let message = Issue {
+        id,
+        title: subject.trim().to_string(),
+        description: body
+            .map(str::trim)
+            .filter(|b| !b.is_empty())
+            .map(str::to_string),
+        status: Status::Open,
+        priority: Priority::MEDIUM,
+        issue_type: IssueType::Custom(MESSAGE_TYPE.to_string()),
+        assignee: Some(to.to_string()),
+        created_at: now,
+        created_by: Some(sender.to_string()),
+        updated_at: now,
+        sender: Some(sender.to_string()),
+        ephemeral: true,
+        dependencies,
+        ..Issue::default()
+    };
+    IssueValidator::validate(&message).map_err(BeadsError::from_validation_errors)?;
+    storage.create_issue(&message, sender)?;
+    Ok(message)
+}
+
+fn mail_inbox(mail: &MailContext, args: &MailInboxArgs, ctx: &OutputContext) -> Result<()> {
+    let storage = &mail.storage_ctx.storage;
+    let messages = inbox_impl(storage, &mail.actor, args.all)?;
+
+    if ctx.is_json() {
+        ctx.json_pretty(&messages);
+        return Ok(());
+    }
+    if messages.is_empty() {
+        ctx.info(&format!("No unread messages for {}.", mail.actor));
+        return Ok(());
+    }
+
+    ctx.print(&format!("Inbox for {} ({}):", mail.actor, messages.len()));
+    for message in &messages {
+        let marker = if message.acknowledged {
+            "✓"
+        } else if message.read {
+            " "
+        } else {
+            "●"
+        };
+        ctx.print(&format!(
+            "{marker} {}  {}  from {}  {}",
+            message.id,
+            message.created_at.format("%Y-%m-%d %H:%M"),
+            message.from,
+            message.subject
+        ));
+    }
+    Ok(())
+}
+
+/// Messages addressed to `recipient`, oldest first.
+///
+/// Without `all`, only unread, unacknowledged messages are returned.
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn inbox_impl(storage: &SqliteStorage, recipient: &str, all: bool) -> Result<Vec<MailMessage>> {
+    let filters = ListFilters {
+        types: Some(vec![IssueType::Custom(MESSAGE_TYPE.to_string())]),
+        assignee: Some(recipient.to_string()),
+        include_closed: all,
+        include_deferred: true,
+        sort: Some("created_at".to_string()),
+        reverse: true,
+        ..ListFilters::default()
+    };
+    let read = storage.read_message_ids(recipient)?;
+
+    storage
+        .list_issues(&filters)?
+        .iter()
+        .filter(|issue| all || !read.contains(&issue.id))
+        .map(|issue| to_mail_message(storage, issue, &read))
+        .collect()
+}
+
+fn mail_read(mail: &mut MailContext, args: &MailReadArgs, ctx: &OutputContext) -> Result<()> {
+    let storage = &mut mail.storage_ctx.storage;
+    let message = resolve_message(storage, &mail.resolver, &args.id)?;
+    storage.mark_messages_read(std::slice::from_ref(&message.id), &mail.actor)?;
+
+    let read = storage.read_message_ids(&mail.actor)?;
+    let view = to_mail_message(storage, &message, &read)?;
+    if ctx.is_json() {
+        ctx.json_pretty(&view);
+    } else {
+        ctx.print(&render_message(&view, 0));
+    }
+    Ok(())
+}
+
+fn mail_ack(mail: &mut MailContext, args: &MailAckArgs, ctx: &OutputContext) -> Result<()> {
+    let storage = &mut mail.storage_ctx.storage;
+    let mut acked = Vec::with_capacity(args.ids.len());
+    for input in &args.ids {
+        let message = resolve_message(storage, &mail.resolver, input)?;
+        if message.status != Status::Closed {
+            let update = IssueUpdate {
+                status: Some(Status::Closed),
+                closed_at: Some(Some(Utc::now())),
+                close_reason: Some(Some(format!("acknowledged by {}", mail.actor))),
+                ..IssueUpdate::default()
+            };
+            storage.update_issue(&message.id, &update, &mail.actor)?;
+        }
+        acked.push(message.id);
+    }
+    storage.mark_messages_read(&acked, &mail.actor)?;
+
+    if ctx.is_json() {
+        ctx.json_pretty(&serde_json::json!({ "acknowledged": acked }));
+    } else {
+        for id in &acked {
+            ctx.success(&format!("Acknowledged {id}"));
+        }
+    }
+    Ok(())
+}
+
+fn mail_thread(mail: &mut MailContext, args: &MailThreadArgs, ctx: &OutputContext) -> Result<()> {
+    let storage = &mut mail.storage_ctx.storage;
+    let message = resolve_message(storage, &mail.resolver, &args.id)?;
+    let thread = thread_impl(storage, &message.id, &mail.actor)?;
+
+    // Viewing a thread reads every message in it that was sent to us
+    let mine: Vec<String> = thread
+        .messages
+        .iter()
+        .filter(|m| m.to == mail.actor && !m.read)
+        .map(|m| m.id.clone())
+        .collect();
+    storage.mark_messages_read(&mine, &mail.actor)?;
+
+    if ctx.is_json() {
+        ctx.json_pretty(&thread);
+        return Ok(());
+    }
+
+    ctx.print(&format!(
+        "Thread {} ({} messages)",
+        thread.thread_id,
+        thread.messages.len()
+    ));
+    let depths = thread_depths(&thread.messages);
+    for message in &thread.messages {
+        ctx.newline();
+        let depth = depths.get(message.id.as_str()).copied().unwrap_or(0);
+        ctx.print(&render_message(message, depth));
+    }
+    Ok(())
+}
+
+/// Load the whole conversation containing message `id`.
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn thread_impl(storage: &SqliteStorage, id: &str, reader: &str) -> Result<MailThread> {
+    let thread_id = storage.message_thread_id(id)?;
+    let read = storage.read_message_ids(reader)?;
+    let mut messages = Vec::new();
+    for message_id in storage.thread_message_ids(&thread_id)? {
+        if let Some(issue) = storage.get_issue(&message_id)? {
+            messages.push(to_mail_message(storage, &issue, &read)?);
+        }
+    }
+    Ok(MailThread {
+        thread_id,
+        messages,
+    })
+}
+
+/// Reply nesting depth of each message, for indentation.
+fn thread_depths(messages: &[MailMessage]) -> HashMap<&str, usize> {
+    let parents: HashMap<&str, &str> = messages
+        .iter()
+        .filter_map(|m| m.reply_to.as_deref().map(|parent| (m.id.as_str(), parent)))
+        .collect();
+
+    messages
+        .iter()
+        .map(|m| {
+            let mut depth = 0;
+            let mut seen = HashSet::new();
+            let mut current = m.id.as_str();
+            while let Some(parent) = parents.get(current) {
+                if !seen.insert(current) {
+                    break;
+                }
+                depth += 1;
+                current = parent;
+            }
+            (m.id.as_str(), depth)
+        })
+        .collect()
+}
+
+fn render_message(message: &MailMessage, depth: usize) -> String {
+    let indent = "  ".repeat(depth);
+    let mut out = format!(
+        "{indent}{}  {} → {}  {}",
+        message.id,
+        message.from,
+        message.to,
+        message.created_at.format("%Y-%m-%d %H:%M")
+    );
+    let _ = write!(out, "\n{indent}Subject: {}", message.subject);
+    if message.acknowledged {
+        let _ = write!(out, " (acknowledged)");
+    }
+    for line in message.body.lines() {
+        let _ = write!(out, "\n{indent}  {line}");
+    }
+    out
+}
+
+fn to_mail_message(
+    storage: &SqliteStorage,
+    issue: &Issue,
+    read: &HashSet<String>,
+) -> Result<MailMessage> {
+    let reply_to = storage
+        .get_dependencies_full(&issue.id)?
+        .into_iter()
+        .find(|dep| dep.dep_type == DependencyType::RepliesTo)
+        .map(|dep| dep.depends_on_id);
+
+    Ok(MailMessage {
+        id: issue.id.clone(),
+        thread_id: storage.message_thread_id(&issue.id)?,
+        reply_to,
+        from: issue.sender.clone().unwrap_or_default(),
+        to: issue.assignee.clone().unwrap_or_default(),
+        subject: issue.title.clone(),
+        body: issue.description.clone().unwrap_or_default(),
+        created_at: issue.created_at,
+        read: read.contains(&issue.id),
+        acknowledged: issue.status == Status::Closed,
+    })
+}
+
+fn resolve_message(storage: &SqliteStorage, resolver: &IdResolver, input: &str) -> Result<Issue> {
+    let all_ids = storage.get_all_ids()?;
+    let id = resolver
+        .resolve(
+            input,
+            |id| storage.id_exists(id).unwrap_or(false),
+            |hash| find_matching_ids(&all_ids, hash),
+        )?
+        .id;
+    let issue = storage
+        .get_issue(&id)?
+        .ok_or_else(|| BeadsError::IssueNotFound { id: id.clone() })?;
+    if issue.issue_type.as_str() != MESSAGE_TYPE {
+        return Err(BeadsError::validation(
+            "id",
+            format!("{id} is not a message"),
+        ));
+    }
+    Ok(issue)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_send_reply_and_thread() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let config = IdConfig::with_prefix("bd");
+
+        let first = send_message_impl(
+            &mut storage,
+            &config,
+            "alice",
+            "bob",
+            "Need review",
+            Some("Please look at the parser."),
+            None,
+        )
+        .unwrap();
+        assert!(first.ephemeral);
+
+        let reply = send_message_impl(
+            &mut storage,
+            &config,
+            "bob",
+            "alice",
+            "Re: Need review",
+            Some("Looks good."),
+            Some(&first),
+        )
+        .unwrap();
+        let follow_up = send_message_impl(
+            &mut storage,
+            &config,
+            "alice",
+            "bob",
+            "Re: Need review",
+            Some("Thanks!"),
+            Some(&reply),
+        )
+        .unwrap();
+
+        let thread = thread_impl(&storage, &follow_up.id, "bob").unwrap();
+        assert_eq!(thread.thread_id, first.id);
+        let ids: Vec<&str> = thread.messages.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(
+            ids,
+            vec![first.id.as_str(), reply.id.as_str(), follow_up.id.as_str()]
+        );
+        assert_eq!(
+            thread.messages[2].reply_to.as_deref(),
+            Some(reply.id.as_str())
+        );
+        assert_eq!(thread_depths(&thread.messages)[follow_up.id.as_str()], 2);
+
+        // Messages never reach the JSONL export
+        assert!(storage.get_all_issues_for_export().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_inbox_tracks_read_messages() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let config = IdConfig::with_prefix("bd");
+        let message =
+            send_message_impl(&mut storage, &config, "alice", "bob", "Ping", None, None).unwrap();
+
+        let unread = inbox_impl(&storage, "bob", false).unwrap();
+        assert_eq!(unread.len(), 1);
+        assert_eq!(unread[0].from, "alice");
+        assert!(inbox_impl(&storage, "alice", false).unwrap().is_empty());
+
+        storage
+            .mark_messages_read(std::slice::from_ref(&message.id), "bob")
+            .unwrap();
+        assert!(inbox_impl(&storage, "bob", false).unwrap().is_empty());
+
+        let all = inbox_impl(&storage, "bob", true).unwrap();
+        assert_eq!(all.len(), 1);
+        assert!(all[0].read);
+    }
+}
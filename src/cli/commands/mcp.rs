@@ -0,0 +1,537 @@
+//! MCP command implementation.
+//!
+//! `br mcp` runs a Model Context Protocol server over stdio. Tools are thin
+//! wrappers around the `br serve` handlers, so they get the same staleness
+//! checks before each call and the same debounced flush afterwards. Input
+//! schemas are generated from the request parameters and output schemas are
+//! the `br schema` types. Issues are also readable as `beads://<id>`
+//! resources.
+
+use super::serve::{
+    CloseParams, CommentParams, CreateParams, DepParams, INTERNAL_ERROR, INVALID_PARAMS,
+    INVALID_REQUEST, IdParams, ListParams, METHOD_NOT_FOUND, PARSE_ERROR, ReadyParams, RpcError,
+    RpcResult, Server, UpdateParams, serve_stdio,
+};
+use crate::cli::McpArgs;
+use crate::config;
+use crate::error::Result;
+use crate::format::{IssueDetails, IssueWithCounts, ReadyIssue};
+use crate::model::{Comment, Issue};
+use crate::storage::ListFilters;
+use crate::workspace::Workspace;
+use schemars::{JsonSchema, schema_for};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::time::Duration;
+
+/// Newest protocol revision this server implements.
+const PROTOCOL_VERSION: &str = "2025-06-18";
+/// Older revisions we still answer in their own version.
+const SUPPORTED_VERSIONS: [&str; 3] = [PROTOCOL_VERSION, "2025-03-26", "2024-11-05"];
+const RESOURCE_SCHEME: &str = "beads://";
+const RESOURCE_MIME: &str = "application/json";
+
+/// Execute the mcp command.
+///
+/// # Errors
+///
+/// Returns an error if the workspace cannot be opened or the final flush
+/// fails.
+pub fn execute(args: &McpArgs, cli: &config::CliOverrides) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut workspace = Workspace::open_with(&beads_dir, cli)?;
+    workspace.set_auto_flush(false);
+    let mut server = Server::new(workspace);
+
+    tracing::info!(path = %beads_dir.display(), "Serving MCP on stdio");
+    serve_stdio(
+        &mut server,
+        Duration::from_millis(args.flush_debounce_ms),
+        handle_message,
+    )
+}
+
+/// Tool results must be objects, so lists are wrapped.
+#[derive(Serialize, JsonSchema)]
+struct IssueList<T> {
+    issues: Vec<T>,
+}
+
+struct Tool {
+    name: &'static str,
+    /// `br serve` method that implements the tool.
+    method: &'static str,
+    description: &'static str,
+    input_schema: Value,
+    output_schema: Value,
+    /// Whether the result is an array to wrap in [`IssueList`].
+    returns_list: bool,
+}
+
+fn schema<T: JsonSchema>() -> Value {
+    serde_json::to_value(schema_for!(T)).unwrap_or(Value::Null)
+}
+
+fn flag_schema(field: &str) -> Value {
+    json!({
+        "type": "object",
+        "properties": { field: { "type": "boolean" } },
+        "required": [field],
+    })
+}
+
+fn tools() -> Vec<Tool> {
+    let tool = |name, method, description, input_schema, output_schema, returns_list| Tool {
+        name,
+        method,
+        description,
+        input_schema,
+        output_schema,
+        returns_list,
+    };
+    vec![
+        tool(
+            "ready",
+            "ready",
+            "Open issues with no open blockers, in work order",
+            schema::<ReadyParams>(),
+            schema::<IssueList<ReadyIssue>>(),
+            true,
+        ),
+        tool(
+            "list",
+            "list",
+            "List issues with filters; closed issues only with `all` or a closed status",
+            schema::<ListParams>(),
+            schema::<IssueList<IssueWithCounts>>(),
+            true,
+        ),
+        tool(
+            "show",
+            "show",
+            "Issue with labels, dependencies, dependents, comments and recent events",
+            schema::<IdParams>(),
+            schema::<IssueDetails>(),
+            false,
+        ),
+        tool(
+            "create",
+            "create",
+            "Create an issue; unset fields use the project defaults",
+            schema::<CreateParams>(),
+            schema::<Issue>(),
+            false,
+        ),
+        tool(
+            "update",
+            "update",
+            "Change issue fields; null clears an optional field",
+            schema::<UpdateParams>(),
+            schema::<Issue>(),
+            false,
+        ),
+        tool(
+            "close",
+            "close",
+            "Close an issue; fails if it is still blocked",
+            schema::<CloseParams>(),
+            schema::<Issue>(),
+            false,
+        ),
+        tool(
+            "dep_add",
+            "dep.add",
+            "Make an issue depend on another",
+            schema::<DepParams>(),
+            flag_schema("added"),
+            false,
+        ),
+        tool(
+            "dep_remove",
+            "dep.remove",
+            "Remove a dependency",
+            schema::<DepParams>(),
+            flag_schema("removed"),
+            false,
+        ),
+        tool(
+            "comment_add",
+            "comments.add",
+            "Add a comment to an issue",
+            schema::<CommentParams>(),
+            schema::<Comment>(),
+            false,
+        ),
+    ]
+}
+
+#[derive(Debug, Deserialize)]
+struct Message {
+    #[serde(default)]
+    id: Option<Value>,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+/// Handle one JSON-RPC message, returning the response line if one is due.
+fn handle_message(server: &mut Server, line: &str) -> Option<String> {
+    let line = line.trim();
+    if line.is_empty() {
+        return None;
+    }
+
+    let (id, outcome) = match serde_json::from_str::<Value>(line) {
+        Err(e) => (Value::Null, Err(RpcError::new(PARSE_ERROR, e.to_string()))),
+        Ok(value) => match serde_json::from_value::<Message>(value) {
+            Err(e) => (
+                Value::Null,
+                Err(RpcError::new(INVALID_REQUEST, e.to_string())),
+            ),
+            Ok(message) => {
+                // Notifications (initialized, cancelled, ...) need no action
+                let id = message.id?;
+                (id, dispatch(server, &message.method, message.params))
+            }
+        },
+    };
+
+    let response = match outcome {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(error) => json!({ "jsonrpc": "2.0", "id": id, "error": error }),
+    };
+    Some(response.to_string())
+}
+
+fn dispatch(server: &mut Server, method: &str, params: Value) -> RpcResult {
+    match method {
+        "initialize" => Ok(initialize(&params)),
+        "ping" => Ok(json!({})),
+        "tools/list" => {
+            let tools: Vec<Value> = tools()
+                .into_iter()
+                .map(|tool| {
+                    json!({
+                        "name": tool.name,
+                        "description": tool.description,
+                        "inputSchema": tool.input_schema,
+                        "outputSchema": tool.output_schema,
+                    })
+                })
+                .collect();
+            Ok(json!({ "tools": tools }))
+        }
+        "tools/call" => call_tool(server, params),
+        "resources/list" => list_resources(server),
+        "resources/templates/list" => Ok(json!({
+            "resourceTemplates": [{
+                "uriTemplate": format!("{RESOURCE_SCHEME}{{id}}"),
+                "name": "issue",
+                "description": "An issue with its labels, dependencies and comments",
+                "mimeType": RESOURCE_MIME,
+            }],
+        })),
+        "resources/read" => read_resource(server, params),
+        other => Err(RpcError::new(
+            METHOD_NOT_FOUND,
+            format!("unknown method '{other}'"),
+        )),
+    }
+}
+
+fn initialize(params: &Value) -> Value {
+    let requested = params["protocolVersion"].as_str().unwrap_or_default();
+    let version = SUPPORTED_VERSIONS
+        .into_iter()
+        .find(|supported| *supported == requested)
+        .unwrap_or(PROTOCOL_VERSION);
+    json!({
+        "protocolVersion": version,
+        "capabilities": {
+            "tools": { "listChanged": false },
+            "resources": { "subscribe": false, "listChanged": false },
+        },
+        "serverInfo": { "name": "br", "version": env!("CARGO_PKG_VERSION") },
+        "instructions": "Issue tracker for this repository. Call `ready` to find work, \
+                         `update` with status in_progress to claim it, and `close` when done.",
+    })
+}
+
+#[derive(Debug, Deserialize)]
+struct ToolCall {
+    name: String,
+    #[serde(default)]
+    arguments: Value,
+}
+
+/// Run a tool. Command failures are tool results with `isError`, so the
+/// model sees the message and hint rather than a protocol error.
+fn call_tool(server: &mut Server, params: Value) -> RpcResult {
+    let call: ToolCall = serde_json::from_value(params)
+        .map_err(|e| RpcError::new(INVALID_PARAMS, format!("invalid params: {e}")))?;
+    let Some(tool) = tools().into_iter().find(|tool| tool.name == call.name) else {
+        return Err(RpcError::new(
+            INVALID_PARAMS,
+            format!("unknown tool '{}'", call.name),
+        ));
+    };
+
+    let outcome = server
+        .refresh()
+        .map_err(RpcError::from)
+        .and_then(|()| server.call(tool.method, call.arguments));
+
+    Ok(match outcome {
+        Ok(value) => {
+            let structured = match value {
+                Value::Array(issues) if tool.returns_list => {
+                    serde_json::to_value(IssueList { issues }).unwrap_or(Value::Null)
+                }
+                other => other,
+            };
+            json!({
+                "content": [{ "type": "text", "text": structured.to_string() }],
+                "structuredContent": structured,
+                "isError": false,
+            })
+        }
+        Err(error) => {
+            let mut content = vec![json!({ "type": "text", "text": error_text(&error) })];
+            if let Some(data) = &error.data {
+                content.push(json!({ "type": "text", "text": data.to_string() }));
+            }
+            json!({ "content": content, "isError": true })
+        }
+    })
+}
+
+/// Message with the error code and recovery hint, when there is one.
+fn error_text(error: &RpcError) -> String {
+    let Some(data) = &error.data else {
+        return error.message.clone();
+    };
+    let code = data["code"].as_str().unwrap_or("ERROR");
+    match data["hint"].as_str() {
+        Some(hint) => format!("{code}: {}\nHint: {hint}", error.message),
+        None => format!("{code}: {}", error.message),
+    }
+}
+
+/// Open issues as resources; closed ones stay readable through the template.
+fn list_resources(server: &mut Server) -> RpcResult {
+    server.refresh()?;
+    let issues = server.workspace().list(&ListFilters::default())?;
+    let resources: Vec<Value> = issues
+        .into_iter()
+        .map(|row| {
+            json!({
+                "uri": format!("{RESOURCE_SCHEME}{}", row.issue.id),
+                "name": row.issue.id,
+                "title": row.issue.title,
+                "description": format!("{} · {}", row.issue.status, row.issue.priority),
+                "mimeType": RESOURCE_MIME,
+            })
+        })
+        .collect();
+    Ok(json!({ "resources": resources }))
+}
+
+#[derive(Debug, Deserialize)]
+struct ReadParams {
+    uri: String,
+}
+
+fn read_resource(server: &mut Server, params: Value) -> RpcResult {
+    let params: ReadParams = serde_json::from_value(params)
+        .map_err(|e| RpcError::new(INVALID_PARAMS, format!("invalid params: {e}")))?;
+    let Some(id) = params.uri.strip_prefix(RESOURCE_SCHEME) else {
+        return Err(RpcError::new(
+            INVALID_PARAMS,
+            format!("unsupported resource URI '{}'", params.uri),
+        ));
+    };
+
+    server.refresh()?;
+    let details = server.workspace().show(id)?;
+    let text = serde_json::to_string_pretty(&details)
+        .map_err(|e| RpcError::new(INTERNAL_ERROR, e.to_string()))?;
+    Ok(json!({
+        "contents": [{
+            "uri": format!("{RESOURCE_SCHEME}{}", details.issue.id),
+            "mimeType": RESOURCE_MIME,
+            "text": text,
+        }],
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::SqliteStorage;
+    use tempfile::TempDir;
+
+    fn server() -> (TempDir, Server) {
+        let temp = TempDir::new().unwrap();
+        let beads_dir = temp.path().join(".beads");
+        std::fs::create_dir(&beads_dir).unwrap();
+        let mut storage = SqliteStorage::open(&beads_dir.join("beads.db")).unwrap();
+        storage.set_config("issue_prefix", "mcp").unwrap();
+        drop(storage);
+        let mut workspace = Workspace::open(&beads_dir).unwrap();
+        workspace.set_auto_flush(false);
+        (temp, Server::new(workspace))
+    }
+
+    fn request(server: &mut Server, id: i64, method: &str, params: &Value) -> Value {
+        let line = json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params });
+        serde_json::from_str(&handle_message(server, &line.to_string()).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_tools_have_object_schemas() {
+        let (_temp, mut server) = server();
+        let listed = request(&mut server, 1, "tools/list", &json!({}));
+        let tools = listed["result"]["tools"].as_array().unwrap();
+        assert_eq!(tools.len(), 9);
+        for tool in tools {
+            assert_eq!(tool["inputSchema"]["type"], "object", "{}", tool["name"]);
+            assert_eq!(tool["outputSchema"]["type"], "object", "{}", tool["name"]);
+        }
+    }
+
+    #[test]
+    fn test_tool_calls_and_resources() {
+        let (_temp, mut server) = server();
+        let created = request(
+            &mut server,
+            1,
+            "tools/call",
+            &json!({ "name": "create", "arguments": { "title": "Via MCP" } }),
+        );
+        assert_eq!(created["result"]["isError"], false);
+        let id = created["result"]["structuredContent"]["id"]
+            .as_str()
+            .unwrap()
+            .to_string();
+
+        let ready = request(
+            &mut server,
+            2,
+            "tools/call",
+            &json!({ "name": "ready", "arguments": {} }),
+        );
+        assert_eq!(ready["result"]["structuredContent"]["issues"][0]["id"], id);
+
+        let read = request(
+            &mut server,
+            3,
+            "resources/read",
+            &json!({ "uri": format!("beads://{id}") }),
+        );
+        let text = read["result"]["contents"][0]["text"].as_str().unwrap();
+        assert!(text.contains("Via MCP"));
+
+        let missing = request(
+            &mut server,
+            4,
+            "tools/call",
+            &json!({ "name": "show", "arguments": { "id": "mcp-zzz" } }),
+        );
+        assert_eq!(missing["result"]["isError"], true);
+        let message = missing["result"]["content"][0]["text"].as_str().unwrap();
+        assert!(message.starts_with("ISSUE_NOT_FOUND"));
+    }
+
+    #[test]
+    fn test_protocol_errors() {
+        let (_temp, mut server) = server();
+        let unknown = request(&mut server, 1, "tools/frobnicate", &json!({}));
+        assert_eq!(unknown["error"]["code"], METHOD_NOT_FOUND);
+
+        for (id, method, params) in [
+            (2, "tools/call", json!({ "arguments": {} })),
+            (
+                3,
+                "tools/call",
+                json!({ "name": "explode", "arguments": {} }),
+            ),
+            (4, "resources/read", json!({ "uri": "file:///etc/passwd" })),
+            (5, "resources/read", json!({})),
+        ] {
+            let response = request(&mut server, id, method, &params);
+            assert_eq!(response["error"]["code"], INVALID_PARAMS, "{response}");
+        }
+
+        // Bad tool arguments come back as a tool error the model can read
+        let bad_arguments = request(
+            &mut server,
+            6,
+            "tools/call",
+            &json!({ "name": "create", "arguments": { "title": 7 } }),
+        );
+        assert_eq!(bad_arguments["result"]["isError"], true);
+        let message = bad_arguments["result"]["content"][0]["text"]
+            .as_str()
+            .unwrap();
+        assert!(message.starts_with("invalid params"), "{message}");
+    }
+
+    #[test]
+    fn test_tool_errors_include_structured_payload() {
+        let (_temp, mut server) = server();
+        let missing = request(
+            &mut server,
+            1,
+            "tools/call",
+            &json!({ "name": "close", "arguments": { "id": "mcp-zzz" } }),
+        );
+        assert_eq!(missing["result"]["isError"], true);
+        let content = missing["result"]["content"].as_array().unwrap();
+        assert_eq!(content.len(), 2);
+        let data: Value = serde_json::from_str(content[1]["text"].as_str().unwrap()).unwrap();
+        assert_eq!(data["code"], "ISSUE_NOT_FOUND");
+        assert!(data["retryable"].is_boolean());
+        if let Some(hint) = data["hint"].as_str() {
+            let message = content[0]["text"].as_str().unwrap();
+            assert!(message.ends_with(&format!("Hint: {hint}")), "{message}");
+        }
+    }
+
+    #[test]
+    fn test_tool_calls_see_external_jsonl_changes() {
+        let (_temp, mut server) = server();
+        let created = request(
+            &mut server,
+            1,
+            "tools/call",
+            &json!({ "name": "create", "arguments": { "title": "Before" } }),
+        );
+        let id = created["result"]["structuredContent"]["id"]
+            .as_str()
+            .unwrap()
+            .to_string();
+        server.call("sync.flush", json!({})).unwrap();
+
+        // Another process retitles the issue in the JSONL
+        let jsonl_path = server.workspace().paths().jsonl_path.clone();
+        let mut issue: Value =
+            serde_json::from_str(std::fs::read_to_string(&jsonl_path).unwrap().trim()).unwrap();
+        issue["title"] = json!("After");
+        issue["updated_at"] = json!("2099-01-01T00:00:00Z");
+        std::fs::write(&jsonl_path, format!("{issue}\n")).unwrap();
+        std::fs::File::options()
+            .write(true)
+            .open(&jsonl_path)
+            .unwrap()
+            .set_modified(std::time::SystemTime::now() + Duration::from_secs(5))
+            .unwrap();
+
+        let shown = request(
+            &mut server,
+            2,
+            "tools/call",
+            &json!({ "name": "show", "arguments": { "id": id } }),
+        );
+        assert_eq!(shown["result"]["structuredContent"]["title"], "After");
+    }
+}
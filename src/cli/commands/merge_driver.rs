@@ -0,0 +1,66 @@
+//! `br merge-driver` command implementation.
+//!
+//! Entry point git calls to merge `issues.jsonl`; the merge itself lives in
+//! [`crate::sync::merge_driver`].
+
+use crate::cli::MergeDriverArgs;
+use crate::error::{BeadsError, Result};
+use crate::output::OutputContext;
+use crate::sync::merge_driver::{self, MERGE_DRIVER_NAME};
+use serde_json::json;
+
+/// Execute the merge-driver command.
+///
+/// True conflicts come back as [`BeadsError::MergeConflicts`], whose non-zero
+/// exit code tells git the merge is unresolved.
+///
+/// # Errors
+///
+/// Returns an error if a side cannot be read, the result cannot be written,
+/// or conflicts were written.
+pub fn execute(args: &MergeDriverArgs, ctx: &OutputContext) -> Result<()> {
+    let outcome = merge_driver::merge_files(&args.base, &args.ours, &args.theirs)?;
+
+    if ctx.is_json() {
+        ctx.json_pretty(&json!({
+            "merged": outcome.merged,
+            "conflicts": outcome.conflicts,
+        }));
+    }
+
+    if !outcome.conflicts.is_empty() {
+        return Err(BeadsError::MergeConflicts {
+            ids: outcome.conflicts,
+        });
+    }
+    Ok(())
+}
+
+/// Print what to add to `.gitattributes` and git config (`br init --merge-driver`).
+pub fn print_setup(ctx: &OutputContext) {
+    let (attributes, config) = merge_driver::setup_snippet();
+    let command =
+        format!("git config merge.{MERGE_DRIVER_NAME}.driver \"br merge-driver %O %A %B\"");
+
+    if ctx.is_json() {
+        ctx.json_pretty(&json!({
+            "gitattributes_path": ".beads/.gitattributes",
+            "gitattributes": attributes,
+            "git_config": config,
+            "command": command,
+        }));
+        return;
+    }
+    if ctx.is_quiet() {
+        return;
+    }
+
+    println!("# Add to .beads/.gitattributes (committed):");
+    println!("{attributes}");
+    println!();
+    println!("# Add to .git/config (per clone):");
+    println!("{config}");
+    println!();
+    println!("# or run:");
+    println!("{command}");
+}
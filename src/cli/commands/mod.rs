@@ -1,9 +1,12 @@
 pub mod agents;
 pub mod audit;
+pub mod batch;
 pub mod blocked;
 pub mod changelog;
+pub mod claims;
 pub mod close;
 pub mod comments;
+pub mod compact;
 pub mod completions;
 pub mod config;
 pub mod count;
@@ -11,26 +14,38 @@ pub mod create;
 pub mod defer;
 pub mod delete;
 pub mod dep;
+pub mod diff;
 pub mod doctor;
 pub mod epic;
+pub mod gc;
+pub mod git_sync;
 pub mod graph;
+pub mod heartbeat;
 pub mod history;
 pub mod info;
 pub mod init;
 pub mod label;
 pub mod lint;
 pub mod list;
+pub mod mail;
+pub mod mcp;
+pub mod merge_driver;
 pub mod orphans;
+pub mod plan;
 pub mod q;
 pub mod query;
 pub mod ready;
 pub mod reopen;
 pub mod schema;
 pub mod search;
+pub mod serve;
 pub mod show;
 pub mod stale;
 pub mod stats;
 pub mod sync;
+pub mod template;
+pub mod tombstones;
+pub mod undelete;
 pub mod update;
 pub mod version;
 pub mod r#where;
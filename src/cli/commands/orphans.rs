@@ -1,10 +1,13 @@
 //! orphans command implementation.
 //!
 //! Scans git commits for issue ID references and identifies issues
-//! that are still `open/in_progress` but referenced in commits.
+//! that are still `open/in_progress` but referenced in commits. Commits
+//! with `Closes:`/`Refs:` trailers carry an explicit intent, which `--fix`
+//! applies the same way `br git-sync` does.
 
 use crate::cli::OrphansArgs;
 use crate::cli::commands::close::{self, CloseArgs};
+use crate::cli::commands::git_sync::{self, TrailerCommit, TrailerIntent};
 use crate::config;
 use crate::error::Result;
 use crate::model::{Issue, Status};
@@ -27,6 +30,9 @@ pub struct OrphanIssue {
     pub status: String,
     pub latest_commit: String,
     pub latest_commit_message: String,
+    /// Intent from the latest commit trailer naming this issue, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub intent: Option<TrailerIntent>,
 }
 
 /// Execute the orphans command.
@@ -52,7 +58,7 @@ pub fn execute(
     };
 
     // Try to open storage - return empty if not found
-    let Ok(storage_ctx) = config::open_storage_with_cli(&beads_dir, cli) else {
+    let Ok(mut storage_ctx) = config::open_storage_with_cli(&beads_dir, cli) else {
         output_empty(ctx.is_json() || args.robot, ctx);
         return Ok(());
     };
@@ -79,7 +85,10 @@ pub fn execute(
         "Retrieved commit references"
     );
 
-    if commit_refs.is_empty() {
+    // Trailers live in the commit body, which `--oneline` does not show
+    let trailer_commits = latest_trailer_commits(&prefix);
+
+    if commit_refs.is_empty() && trailer_commits.is_empty() {
         output_empty(ctx.is_json() || args.robot, ctx);
         return Ok(());
     }
@@ -100,6 +109,11 @@ pub fn execute(
             .entry(issue_id.clone())
             .or_insert_with(|| (commit_hash.clone(), commit_msg.clone()));
     }
+    for (issue_id, commit) in &trailer_commits {
+        issue_commits
+            .entry(issue_id.clone())
+            .or_insert_with(|| (commit.short_sha().to_string(), commit.subject.clone()));
+    }
 
     // Find orphans: issues that are referenced in commits but still open
     let mut orphans: Vec<OrphanIssue> = Vec::new();
@@ -116,12 +130,16 @@ pub fn execute(
                 context_snippets.insert(issue_id.clone(), format!("{commit_hash} {commit_msg}"));
             }
 
+            let intent = trailer_commits
+                .get(&issue_id)
+                .and_then(|commit| commit.intent_for(&issue_id));
             orphans.push(OrphanIssue {
                 issue_id,
                 title,
                 status,
                 latest_commit: commit_hash.clone(),
                 latest_commit_message: commit_msg.clone(),
+                intent,
             });
             orphan_issues.push(issue);
         }
@@ -180,12 +198,18 @@ pub fn execute(
         println!();
 
         for (idx, orphan) in orphans.iter().enumerate() {
+            let intent = match orphan.intent {
+                Some(TrailerIntent::Closes) => " (commit trailer: closes)",
+                Some(TrailerIntent::Refs) => " (commit trailer: refs)",
+                None => "",
+            };
             println!(
-                "{}. [{}] {} {}",
+                "{}. [{}] {} {}{}",
                 idx + 1,
                 orphan.status,
                 orphan.issue_id,
-                orphan.title
+                orphan.title,
+                intent
             );
             if args.details {
                 println!(
@@ -197,31 +221,61 @@ pub fn execute(
     }
 
     if args.fix {
+        let actor = config::resolve_actor(&config_layer);
         println!();
         println!("Interactive close mode:");
         for orphan in &orphans {
-            print!("Close {} ({})? [y/N] ", orphan.issue_id, orphan.title);
-            io::stdout().flush()?;
-
-            let mut input = String::new();
-            if io::stdin().read_line(&mut input).is_ok() {
-                let input = input.trim().to_lowercase();
-                if input == "y" || input == "yes" {
-                    // Close the issue directly using internal API
-                    let close_args = CloseArgs {
-                        ids: vec![orphan.issue_id.clone()],
-                        reason: Some("Implemented (detected by orphans scan)".to_string()),
-                        force: false,
-                        session: None,
-                        suggest_next: false,
-                    };
-
-                    if let Err(e) = close::execute_with_args(&close_args, false, cli, ctx) {
-                        eprintln!("  Failed to close {}: {}", orphan.issue_id, e);
+            // Trailers state the intent, so apply them as git-sync would
+            if let (Some(intent), Some(commit)) =
+                (orphan.intent, trailer_commits.get(&orphan.issue_id))
+            {
+                let verb = match intent {
+                    TrailerIntent::Closes => "Close",
+                    TrailerIntent::Refs => "Annotate",
+                };
+                print!(
+                    "{verb} {} from commit {}? [y/N] ",
+                    orphan.issue_id,
+                    commit.short_sha()
+                );
+                io::stdout().flush()?;
+                if confirmed() {
+                    let applied = git_sync::apply_trailer(
+                        &mut storage_ctx.storage,
+                        commit,
+                        &orphan.issue_id,
+                        intent,
+                        &actor,
+                        false,
+                    )?;
+                    if let Some(reason) = applied.reason {
+                        println!("  Skipped {}: {reason}", orphan.issue_id);
                     }
                 } else {
                     println!("  Skipped {}", orphan.issue_id);
                 }
+                continue;
+            }
+
+            print!("Close {} ({})? [y/N] ", orphan.issue_id, orphan.title);
+            io::stdout().flush()?;
+
+            if confirmed() {
+                // Close the issue directly using internal API
+                let close_args = CloseArgs {
+                    ids: vec![orphan.issue_id.clone()],
+                    reason: Some("Implemented (detected by orphans scan)".to_string()),
+                    outcome: args.outcome,
+                    force: false,
+                    session: None,
+                    suggest_next: false,
+                };
+
+                if let Err(e) = close::execute_with_args(&close_args, false, cli, ctx) {
+                    eprintln!("  Failed to close {}: {}", orphan.issue_id, e);
+                }
+            } else {
+                println!("  Skipped {}", orphan.issue_id);
             }
         }
     }
@@ -229,8 +283,35 @@ pub fn execute(
     Ok(())
 }
 
+/// Read a y/N answer from stdin; anything but "y"/"yes" declines.
+fn confirmed() -> bool {
+    let mut input = String::new();
+    if io::stdin().read_line(&mut input).is_err() {
+        return false;
+    }
+    let input = input.trim().to_lowercase();
+    input == "y" || input == "yes"
+}
+
+/// Latest commit whose trailers name each issue.
+///
+/// Trailers are optional context here, so git failures yield an empty map.
+fn latest_trailer_commits(prefix: &str) -> HashMap<String, TrailerCommit> {
+    let (Ok(re), Ok(log)) = (issue_id_regex(prefix), git_sync::read_trailer_log(None)) else {
+        return HashMap::new();
+    };
+    let mut latest = HashMap::new();
+    // The log is oldest-first, so later commits overwrite earlier ones
+    for commit in git_sync::parse_trailer_log(&log, &re) {
+        for id in commit.closes.iter().chain(&commit.refs) {
+            latest.insert(id.clone(), commit.clone());
+        }
+    }
+    latest
+}
+
 /// Check if the current directory is inside a git repository.
-fn is_git_repo() -> bool {
+pub(crate) fn is_git_repo() -> bool {
     Command::new("git")
         .args(["rev-parse", "--git-dir"])
         .output()
@@ -266,17 +347,7 @@ fn get_git_commit_refs(prefix: &str) -> Result<Vec<(String, String, String)>> {
 ///
 /// Looks for patterns like `(bd-abc123)` or `bd-abc123` in commit messages.
 fn parse_git_log<R: BufRead>(reader: R, prefix: &str) -> Result<Vec<(String, String, String)>> {
-    // Pattern matches prefix-id including hierarchical IDs like bd-abc.1
-    // We use word boundaries \b to avoid matching suffix/prefix (e.g. abd-123 or bd-123a)
-    // although matching bd-123a is technically valid if 123a is the hash.
-    // The previous regex forced parens: r"\(({}-[a-zA-Z0-9]+(?:\.[0-9]+)?)\)"
-    // Use (?i) for case-insensitive matching (user input in commits varies)
-    let pattern = format!(
-        r"(?i)\b({}-[a-z0-9]+(?:\.[0-9]+)?)\b",
-        regex::escape(prefix)
-    );
-    let re = Regex::new(&pattern)
-        .map_err(|e| crate::error::BeadsError::Config(format!("Invalid regex pattern: {e}")))?;
+    let re = issue_id_regex(prefix)?;
 
     let mut results = Vec::new();
 
@@ -307,6 +378,25 @@ fn parse_git_log<R: BufRead>(reader: R, prefix: &str) -> Result<Vec<(String, Str
     Ok(results)
 }
 
+/// Regex matching issue IDs with `prefix` in commit text; group 1 is the ID.
+///
+/// # Errors
+///
+/// Returns an error if the pattern cannot be compiled.
+pub fn issue_id_regex(prefix: &str) -> Result<Regex> {
+    // Pattern matches prefix-id including hierarchical IDs like bd-abc.1
+    // We use word boundaries \b to avoid matching suffix/prefix (e.g. abd-123 or bd-123a)
+    // although matching bd-123a is technically valid if 123a is the hash.
+    // The previous regex forced parens: r"\(({}-[a-zA-Z0-9]+(?:\.[0-9]+)?)\)"
+    // Use (?i) for case-insensitive matching (user input in commits varies)
+    let pattern = format!(
+        r"(?i)\b({}-[a-z0-9]+(?:\.[0-9]+)?)\b",
+        regex::escape(prefix)
+    );
+    Regex::new(&pattern)
+        .map_err(|e| crate::error::BeadsError::Config(format!("Invalid regex pattern: {e}")))
+}
+
 /// Output empty result in appropriate format.
 fn output_empty(json: bool, ctx: &OutputContext) {
     if json || ctx.is_json() {
@@ -0,0 +1,760 @@
+//! Plan command implementation.
+//!
+//! Turns `estimated_minutes` and the blocking dependency DAG into a forecast:
+//! - Critical path (longest remaining chain of work) and slack per issue
+//! - A greedy list schedule for N parallel workers
+//! - Projected start/finish dates given working hours per day
+//!
+//! `br plan <epic>` limits the plan to the epic, its children (recursively)
+//! and everything they transitively depend on. Parent issues finish after
+//! their children; closed issues count as done.
+
+use crate::cli::{PlanArgs, PlanFormat};
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::model::{Dependency, DependencyType, Issue};
+use crate::output::{OutputContext, OutputMode};
+use crate::storage::{ListFilters, SqliteStorage};
+use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use crate::util::time::parse_flexible_timestamp;
+use chrono::{DateTime, Duration, Utc};
+use rich_rust::prelude::*;
+use serde::Serialize;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::fmt::Write as _;
+
+/// One unit of work fed to the scheduler.
+#[derive(Debug, Clone)]
+struct PlanTask {
+    id: String,
+    title: String,
+    status: String,
+    priority: i32,
+    minutes: u64,
+    estimated: bool,
+    /// Issues that must finish before this one starts.
+    predecessors: Vec<String>,
+}
+
+/// Per-issue plan output.
+#[derive(Debug, Clone, Serialize)]
+pub struct PlannedIssue {
+    pub id: String,
+    pub title: String,
+    pub status: String,
+    pub priority: i32,
+    /// Remaining work in minutes (the default estimate when unestimated).
+    pub estimate_minutes: u64,
+    pub estimated: bool,
+    pub predecessors: Vec<String>,
+    /// Earliest start/finish with unlimited workers, in work minutes.
+    pub earliest_start: u64,
+    pub earliest_finish: u64,
+    /// Latest start that does not delay the critical path.
+    pub latest_start: u64,
+    pub slack_minutes: u64,
+    pub critical: bool,
+    /// 1-based worker from the parallel schedule.
+    pub worker: usize,
+    pub scheduled_start: u64,
+    pub scheduled_finish: u64,
+    pub projected_start: DateTime<Utc>,
+    pub projected_finish: DateTime<Utc>,
+}
+
+/// JSON output for `br plan`.
+#[derive(Debug, Clone, Serialize)]
+pub struct PlanOutput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    pub workers: usize,
+    pub start: DateTime<Utc>,
+    pub hours_per_day: u32,
+    /// Sum of all remaining estimates.
+    pub total_minutes: u64,
+    /// Length of the critical path (lower bound on completion).
+    pub critical_path_minutes: u64,
+    /// Length of the N-worker schedule.
+    pub schedule_minutes: u64,
+    pub projected_completion: DateTime<Utc>,
+    pub critical_path: Vec<String>,
+    pub unestimated: Vec<String>,
+    pub issues: Vec<PlannedIssue>,
+}
+
+/// Execute the plan command.
+///
+/// # Errors
+///
+/// Returns an error if the target cannot be resolved, the start date is
+/// invalid, the dependency graph has a cycle, or database access fails.
+pub fn execute(
+    args: &PlanArgs,
+    json: bool,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let start = match args.start.as_deref() {
+        Some(value) => parse_flexible_timestamp(value, "start")?,
+        None => Utc::now(),
+    };
+
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let storage = &storage_ctx.storage;
+
+    let target = match args.target.as_deref() {
+        Some(input) => {
+            let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
+            let id_config = config::id_config_from_layer(&config_layer);
+            let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+            let all_ids = storage.get_all_ids()?;
+            let resolved = resolver.resolve(
+                input,
+                |id| storage.id_exists(id).unwrap_or(false),
+                |hash| find_matching_ids(&all_ids, hash),
+            )?;
+            Some(resolved.id)
+        }
+        None => None,
+    };
+
+    let tasks = collect_tasks(storage, target.as_deref(), u64::from(args.default_estimate))?;
+    let plan = build_plan(
+        &tasks,
+        target,
+        args.workers as usize,
+        start,
+        args.hours_per_day,
+    )?;
+
+    match args.format.unwrap_or_default() {
+        PlanFormat::Mermaid => print!("{}", render_gantt(&plan)),
+        PlanFormat::Json => println!("{}", serde_json::to_string_pretty(&plan)?),
+        PlanFormat::Text if json || ctx.is_json() => ctx.json_pretty(&plan),
+        PlanFormat::Text if matches!(ctx.mode(), OutputMode::Rich) => render_plan_rich(&plan, ctx),
+        PlanFormat::Text => render_plan_plain(&plan),
+    }
+    Ok(())
+}
+
+/// Gather open issues in scope and their precedence constraints.
+fn collect_tasks(
+    storage: &SqliteStorage,
+    target: Option<&str>,
+    default_estimate: u64,
+) -> Result<Vec<PlanTask>> {
+    let issues = storage.list_issues(&ListFilters::default())?;
+    let open: HashMap<&str, &Issue> = issues
+        .iter()
+        .filter(|issue| !issue.status.is_terminal())
+        .map(|issue| (issue.id.as_str(), issue))
+        .collect();
+    let all_dependencies = storage.get_all_dependency_records()?;
+
+    let mut children: HashMap<&str, Vec<&str>> = HashMap::new();
+    for dep in all_dependencies.values().flatten() {
+        if dep.dep_type == DependencyType::ParentChild {
+            children
+                .entry(dep.depends_on_id.as_str())
+                .or_default()
+                .push(dep.issue_id.as_str());
+        }
+    }
+
+    let scope: HashSet<&str> = if let Some(target) = target {
+        let mut scope = HashSet::new();
+        let mut queue = VecDeque::from([target]);
+        while let Some(id) = queue.pop_front() {
+            if !open.contains_key(id) || !scope.insert(id) {
+                continue;
+            }
+            let blockers = all_dependencies
+                .get(id)
+                .into_iter()
+                .flatten()
+                .filter(|dep| is_blocking(dep))
+                .map(|dep| dep.depends_on_id.as_str());
+            queue.extend(blockers);
+            queue.extend(children.get(id).into_iter().flatten().copied());
+        }
+        if scope.is_empty() {
+            let issue = storage
+                .get_issue(target)?
+                .ok_or_else(|| BeadsError::IssueNotFound {
+                    id: target.to_string(),
+                })?;
+            return Err(BeadsError::validation(
+                "target",
+                format!("{} is already {}", issue.id, issue.status.as_str()),
+            ));
+        }
+        scope
+    } else {
+        open.keys().copied().collect()
+    };
+
+    let mut tasks: Vec<PlanTask> = scope
+        .iter()
+        .map(|id| {
+            let issue = open[id];
+            let mut predecessors: Vec<String> = all_dependencies
+                .get(*id)
+                .into_iter()
+                .flatten()
+                .filter(|dep| is_blocking(dep) && scope.contains(dep.depends_on_id.as_str()))
+                .map(|dep| dep.depends_on_id.clone())
+                .collect();
+            // A parent finishes after its children.
+            predecessors.extend(
+                children
+                    .get(id)
+                    .into_iter()
+                    .flatten()
+                    .filter(|child| scope.contains(*child))
+                    .map(|child| (*child).to_string()),
+            );
+            predecessors.sort();
+            predecessors.dedup();
+
+            let estimate = issue
+                .estimated_minutes
+                .and_then(|minutes| u64::try_from(minutes).ok());
+            PlanTask {
+                id: issue.id.clone(),
+                title: issue.title.clone(),
+                status: issue.status.as_str().to_string(),
+                priority: issue.priority.0,
+                minutes: estimate.unwrap_or(default_estimate),
+                estimated: estimate.is_some(),
+                predecessors,
+            }
+        })
+        .collect();
+    tasks.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(tasks)
+}
+
+/// Dependency types that order work (parent-child is handled separately).
+fn is_blocking(dep: &Dependency) -> bool {
+    dep.dep_type.affects_ready_work() && dep.dep_type != DependencyType::ParentChild
+}
+
+/// Compute critical path, slack and an N-worker schedule.
+#[allow(clippy::too_many_lines)]
+fn build_plan(
+    tasks: &[PlanTask],
+    target: Option<String>,
+    workers: usize,
+    start: DateTime<Utc>,
+    hours_per_day: u32,
+) -> Result<PlanOutput> {
+    let index: HashMap<&str, usize> = tasks
+        .iter()
+        .enumerate()
+        .map(|(i, task)| (task.id.as_str(), i))
+        .collect();
+    let preds: Vec<Vec<usize>> = tasks
+        .iter()
+        .map(|task| {
+            task.predecessors
+                .iter()
+                .filter_map(|id| index.get(id.as_str()).copied())
+                .collect()
+        })
+        .collect();
+    let mut succs: Vec<Vec<usize>> = vec![Vec::new(); tasks.len()];
+    for (i, task_preds) in preds.iter().enumerate() {
+        for &p in task_preds {
+            succs[p].push(i);
+        }
+    }
+
+    // Kahn's algorithm; leftovers mean a cycle.
+    let mut indegree: Vec<usize> = preds.iter().map(Vec::len).collect();
+    let mut queue: VecDeque<usize> = (0..tasks.len()).filter(|&i| indegree[i] == 0).collect();
+    let mut order = Vec::with_capacity(tasks.len());
+    while let Some(i) = queue.pop_front() {
+        order.push(i);
+        for &s in &succs[i] {
+            indegree[s] -= 1;
+            if indegree[s] == 0 {
+                queue.push_back(s);
+            }
+        }
+    }
+    if order.len() != tasks.len() {
+        let cyclic: Vec<&str> = (0..tasks.len())
+            .filter(|&i| indegree[i] > 0)
+            .map(|i| tasks[i].id.as_str())
+            .collect();
+        return Err(BeadsError::validation(
+            "dependencies",
+            format!("cannot plan: dependency cycle among {}", cyclic.join(", ")),
+        ));
+    }
+
+    // Forward pass.
+    let mut earliest_start = vec![0u64; tasks.len()];
+    let mut earliest_finish = vec![0u64; tasks.len()];
+    for &i in &order {
+        earliest_start[i] = preds[i]
+            .iter()
+            .map(|&p| earliest_finish[p])
+            .max()
+            .unwrap_or(0);
+        earliest_finish[i] = earliest_start[i] + tasks[i].minutes;
+    }
+    let critical_length = earliest_finish.iter().copied().max().unwrap_or(0);
+
+    // Backward pass.
+    let mut latest_finish = vec![critical_length; tasks.len()];
+    for &i in order.iter().rev() {
+        if let Some(lf) = succs[i]
+            .iter()
+            .map(|&s| latest_finish[s] - tasks[s].minutes)
+            .min()
+        {
+            latest_finish[i] = lf;
+        }
+    }
+    let latest_start: Vec<u64> = (0..tasks.len())
+        .map(|i| latest_finish[i] - tasks[i].minutes)
+        .collect();
+    let slack: Vec<u64> = (0..tasks.len())
+        .map(|i| latest_start[i] - earliest_start[i])
+        .collect();
+
+    let critical_path = trace_critical_path(
+        tasks,
+        &preds,
+        &earliest_start,
+        &earliest_finish,
+        &slack,
+        critical_length,
+    );
+
+    // Greedy list schedule: the free worker takes the ready task with the
+    // least slack, then highest priority.
+    let mut worker_free = vec![0u64; workers];
+    let mut assigned: Vec<Option<(usize, u64, u64)>> = vec![None; tasks.len()];
+    let mut remaining = tasks.len();
+    while remaining > 0 {
+        let (worker, now) = worker_free
+            .iter()
+            .copied()
+            .enumerate()
+            .min_by_key(|&(w, free)| (free, w))
+            .unwrap_or((0, 0));
+
+        let mut next_release = u64::MAX;
+        let mut best: Option<usize> = None;
+        for i in 0..tasks.len() {
+            if assigned[i].is_some() {
+                continue;
+            }
+            let release = preds[i]
+                .iter()
+                .map(|&p| assigned[p].map(|(_, _, finish)| finish))
+                .try_fold(0u64, |acc, finish| finish.map(|f| acc.max(f)));
+            let Some(release) = release else { continue };
+            if release > now {
+                next_release = next_release.min(release);
+                continue;
+            }
+            let key = |j: usize| (latest_start[j], tasks[j].priority, tasks[j].id.as_str());
+            if best.is_none_or(|b| key(i) < key(b)) {
+                best = Some(i);
+            }
+        }
+
+        if let Some(i) = best {
+            let finish = now + tasks[i].minutes;
+            assigned[i] = Some((worker, now, finish));
+            worker_free[worker] = finish;
+            remaining -= 1;
+        } else {
+            // Nothing is ready yet; idle this worker until the next release.
+            worker_free[worker] = next_release;
+        }
+    }
+    let schedule_length = assigned
+        .iter()
+        .flatten()
+        .map(|&(_, _, finish)| finish)
+        .max()
+        .unwrap_or(0);
+
+    let mut issues: Vec<PlannedIssue> = tasks
+        .iter()
+        .enumerate()
+        .map(|(i, task)| {
+            let (worker, scheduled_start, scheduled_finish) = assigned[i].unwrap_or((0, 0, 0));
+            PlannedIssue {
+                id: task.id.clone(),
+                title: task.title.clone(),
+                status: task.status.clone(),
+                priority: task.priority,
+                estimate_minutes: task.minutes,
+                estimated: task.estimated,
+                predecessors: task.predecessors.clone(),
+                earliest_start: earliest_start[i],
+                earliest_finish: earliest_finish[i],
+                latest_start: latest_start[i],
+                slack_minutes: slack[i],
+                critical: slack[i] == 0,
+                worker: worker + 1,
+                scheduled_start,
+                scheduled_finish,
+                projected_start: project(start, scheduled_start, hours_per_day),
+                projected_finish: project(start, scheduled_finish, hours_per_day),
+            }
+        })
+        .collect();
+    issues.sort_by(|a, b| {
+        (a.scheduled_start, a.worker, a.id.as_str()).cmp(&(
+            b.scheduled_start,
+            b.worker,
+            b.id.as_str(),
+        ))
+    });
+
+    Ok(PlanOutput {
+        target,
+        workers,
+        start,
+        hours_per_day,
+        total_minutes: tasks.iter().map(|task| task.minutes).sum(),
+        critical_path_minutes: critical_length,
+        schedule_minutes: schedule_length,
+        projected_completion: project(start, schedule_length, hours_per_day),
+        critical_path,
+        unestimated: tasks
+            .iter()
+            .filter(|task| !task.estimated)
+            .map(|task| task.id.clone())
+            .collect(),
+        issues,
+    })
+}
+
+/// Walk back from the last task to finish (preferring zero-length sinks such
+/// as epics) along zero-slack predecessors.
+fn trace_critical_path(
+    tasks: &[PlanTask],
+    preds: &[Vec<usize>],
+    earliest_start: &[u64],
+    earliest_finish: &[u64],
+    slack: &[u64],
+    critical_length: u64,
+) -> Vec<String> {
+    let Some(mut current) = (0..tasks.len())
+        .filter(|&i| earliest_finish[i] == critical_length && slack[i] == 0)
+        .min_by_key(|&i| {
+            (
+                Reverse(earliest_start[i]),
+                tasks[i].priority,
+                tasks[i].id.as_str(),
+            )
+        })
+    else {
+        return Vec::new();
+    };
+
+    let mut path = vec![tasks[current].id.clone()];
+    while let Some(prev) = preds[current]
+        .iter()
+        .copied()
+        .filter(|&p| slack[p] == 0 && earliest_finish[p] == earliest_start[current])
+        .min_by_key(|&p| (tasks[p].priority, tasks[p].id.as_str()))
+    {
+        path.push(tasks[prev].id.clone());
+        current = prev;
+    }
+    path.reverse();
+    path
+}
+
+/// Map work minutes onto the calendar, `hours_per_day` working hours a day.
+fn project(start: DateTime<Utc>, work_minutes: u64, hours_per_day: u32) -> DateTime<Utc> {
+    let per_day = u64::from(hours_per_day) * 60;
+    let days = i64::try_from(work_minutes / per_day).unwrap_or(i64::MAX);
+    let minutes = i64::try_from(work_minutes % per_day).unwrap_or(0);
+    Duration::try_days(days)
+        .and_then(|offset| offset.checked_add(&Duration::minutes(minutes)))
+        .and_then(|offset| start.checked_add_signed(offset))
+        .unwrap_or(DateTime::<Utc>::MAX_UTC)
+}
+
+fn format_minutes(minutes: u64) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, m) => format!("{m}m"),
+        (h, 0) => format!("{h}h"),
+        (h, m) => format!("{h}h{m}m"),
+    }
+}
+
+/// Render a Mermaid Gantt chart with one section per worker.
+fn render_gantt(plan: &PlanOutput) -> String {
+    let mut out = String::from("gantt\n");
+    let title = plan
+        .target
+        .as_deref()
+        .map_or_else(|| "Plan".to_string(), |target| format!("Plan for {target}"));
+    let _ = writeln!(out, "    title {title}");
+    out.push_str("    dateFormat YYYY-MM-DD HH:mm\n");
+    out.push_str("    axisFormat %m-%d\n");
+
+    let mut by_worker: BTreeMap<usize, Vec<&PlannedIssue>> = BTreeMap::new();
+    for issue in &plan.issues {
+        by_worker.entry(issue.worker).or_default().push(issue);
+    }
+    for (worker, issues) in by_worker {
+        let _ = writeln!(out, "    section Worker {worker}");
+        for issue in issues {
+            // ':' and '#' end a task name in Mermaid; ';' ends a statement.
+            let name: String = format!("{} {}", issue.id, issue.title)
+                .chars()
+                .map(|c| if matches!(c, ':' | '#' | ';') { ' ' } else { c })
+                .collect();
+            let tags = if issue.critical { "crit, " } else { "" };
+            let task_id: String = issue
+                .id
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                .collect();
+            let _ = writeln!(
+                out,
+                "    {name} :{tags}{task_id}, {}, {}",
+                issue.projected_start.format("%Y-%m-%d %H:%M"),
+                issue.projected_finish.format("%Y-%m-%d %H:%M"),
+            );
+        }
+    }
+    out
+}
+
+fn render_plan_plain(plan: &PlanOutput) {
+    if plan.issues.is_empty() {
+        println!("No open work to plan");
+        return;
+    }
+    println!(
+        "Plan: {} issues, {} of work, {} worker(s)",
+        plan.issues.len(),
+        format_minutes(plan.total_minutes),
+        plan.workers
+    );
+    println!(
+        "Critical path ({}): {}",
+        format_minutes(plan.critical_path_minutes),
+        plan.critical_path.join(" -> ")
+    );
+    println!(
+        "Schedule: {} -> projected completion {}",
+        format_minutes(plan.schedule_minutes),
+        plan.projected_completion.format("%Y-%m-%d %H:%M")
+    );
+    if !plan.unestimated.is_empty() {
+        println!(
+            "Unestimated ({}): {}",
+            plan.unestimated.len(),
+            plan.unestimated.join(", ")
+        );
+    }
+    println!();
+    for issue in &plan.issues {
+        let marker = if issue.critical { "*" } else { " " };
+        println!(
+            "{marker} W{} {} -> {}  {}: {} [P{}] ({}, slack {})",
+            issue.worker,
+            issue.projected_start.format("%Y-%m-%d %H:%M"),
+            issue.projected_finish.format("%Y-%m-%d %H:%M"),
+            issue.id,
+            issue.title,
+            issue.priority,
+            format_minutes(issue.estimate_minutes),
+            format_minutes(issue.slack_minutes)
+        );
+    }
+}
+
+fn render_plan_rich(plan: &PlanOutput, ctx: &OutputContext) {
+    let theme = ctx.theme();
+    if plan.issues.is_empty() {
+        ctx.render(&Text::styled("No open work to plan", theme.success.clone()));
+        return;
+    }
+
+    let mut summary = Text::new("");
+    summary.append_styled("Critical path ", theme.emphasis.clone());
+    summary.append_styled(
+        &format_minutes(plan.critical_path_minutes),
+        theme.warning.clone().bold(),
+    );
+    summary.append(": ");
+    summary.append_styled(&plan.critical_path.join(" → "), theme.issue_id.clone());
+    summary.append("\n");
+    summary.append_styled("Schedule ", theme.emphasis.clone());
+    summary.append(&format!(
+        "{} on {} worker(s), {} of work total",
+        format_minutes(plan.schedule_minutes),
+        plan.workers,
+        format_minutes(plan.total_minutes)
+    ));
+    summary.append("\n");
+    summary.append_styled("Projected completion ", theme.emphasis.clone());
+    summary.append_styled(
+        &plan
+            .projected_completion
+            .format("%Y-%m-%d %H:%M")
+            .to_string(),
+        theme.success.clone().bold(),
+    );
+    if !plan.unestimated.is_empty() {
+        summary.append("\n");
+        summary.append_styled(
+            &format!(
+                "{} unestimated issue(s) use the default estimate",
+                plan.unestimated.len()
+            ),
+            theme.dimmed.clone(),
+        );
+    }
+    let panel = Panel::from_rich_text(&summary, ctx.width())
+        .title(Text::styled("Plan", theme.panel_title.clone()))
+        .box_style(theme.box_style)
+        .border_style(theme.panel_border.clone());
+    ctx.render(&panel);
+
+    let mut table = Table::new()
+        .box_style(theme.box_style)
+        .border_style(theme.panel_border.clone())
+        .with_column(Column::new("Worker"))
+        .with_column(Column::new("Start"))
+        .with_column(Column::new("Finish"))
+        .with_column(Column::new("Issue").min_width(10))
+        .with_column(Column::new("Title").min_width(16).max_width(50))
+        .with_column(Column::new("Est"))
+        .with_column(Column::new("Slack"));
+    for issue in &plan.issues {
+        let slack_style = if issue.critical {
+            theme.error.clone().bold()
+        } else {
+            theme.dimmed.clone()
+        };
+        table.add_row(Row::new(vec![
+            Cell::new(Text::new(format!("W{}", issue.worker))),
+            Cell::new(Text::styled(
+                issue.projected_start.format("%m-%d %H:%M").to_string(),
+                theme.timestamp.clone(),
+            )),
+            Cell::new(Text::styled(
+                issue.projected_finish.format("%m-%d %H:%M").to_string(),
+                theme.timestamp.clone(),
+            )),
+            Cell::new(Text::styled(&issue.id, theme.issue_id.clone())),
+            Cell::new(Text::styled(&issue.title, theme.issue_title.clone())),
+            Cell::new(Text::new(format_minutes(issue.estimate_minutes))),
+            Cell::new(Text::styled(
+                if issue.critical {
+                    "critical".to_string()
+                } else {
+                    format_minutes(issue.slack_minutes)
+                },
+                slack_style,
+            )),
+        ]));
+    }
+    ctx.render(&table);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn task(id: &str, minutes: u64, predecessors: &[&str]) -> PlanTask {
+        PlanTask {
+            id: id.to_string(),
+            title: format!("Task {id}"),
+            status: "open".to_string(),
+            priority: 2,
+            minutes,
+            estimated: true,
+            predecessors: predecessors.iter().map(|p| (*p).to_string()).collect(),
+        }
+    }
+
+    fn start() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 1, 5, 9, 0, 0).unwrap()
+    }
+
+    fn issue<'a>(plan: &'a PlanOutput, id: &str) -> &'a PlannedIssue {
+        plan.issues.iter().find(|i| i.id == id).unwrap()
+    }
+
+    #[test]
+    fn critical_path_and_slack() {
+        // a(60) -> c(60); b(30) -> c; c -> d(120)
+        let tasks = vec![
+            task("a", 60, &[]),
+            task("b", 30, &[]),
+            task("c", 60, &["a", "b"]),
+            task("d", 120, &["c"]),
+        ];
+        let plan = build_plan(&tasks, None, 2, start(), 8).unwrap();
+
+        assert_eq!(plan.critical_path, vec!["a", "c", "d"]);
+        assert_eq!(plan.critical_path_minutes, 240);
+        assert_eq!(plan.total_minutes, 270);
+        assert_eq!(issue(&plan, "b").slack_minutes, 30);
+        assert!(!issue(&plan, "b").critical);
+        assert!(issue(&plan, "d").critical);
+        assert_eq!(plan.schedule_minutes, 240);
+    }
+
+    #[test]
+    fn single_worker_serializes_and_projects_dates() {
+        let tasks = vec![task("a", 300, &[]), task("b", 300, &[])];
+        let plan = build_plan(&tasks, None, 1, start(), 8).unwrap();
+
+        assert_eq!(plan.schedule_minutes, 600);
+        assert_eq!(plan.critical_path_minutes, 300);
+        // 600 work minutes at 8h/day = 1 day + 2h
+        assert_eq!(
+            plan.projected_completion,
+            Utc.with_ymd_and_hms(2026, 1, 6, 11, 0, 0).unwrap()
+        );
+        assert!(plan.issues.iter().all(|i| i.worker == 1));
+    }
+
+    #[test]
+    fn idle_worker_waits_for_predecessor() {
+        let tasks = vec![task("a", 60, &[]), task("b", 10, &["a"])];
+        let plan = build_plan(&tasks, None, 3, start(), 8).unwrap();
+
+        assert_eq!(issue(&plan, "b").scheduled_start, 60);
+        assert_eq!(plan.schedule_minutes, 70);
+    }
+
+    #[test]
+    fn cycle_is_rejected() {
+        let tasks = vec![task("a", 10, &["b"]), task("b", 10, &["a"])];
+        let err = build_plan(&tasks, None, 1, start(), 8).unwrap_err();
+        assert!(err.to_string().contains("cycle"));
+    }
+
+    #[test]
+    fn gantt_marks_critical_tasks() {
+        let tasks = vec![task("bd-1", 60, &[]), task("bd-2", 30, &[])];
+        let plan = build_plan(&tasks, Some("bd-1".to_string()), 2, start(), 8).unwrap();
+        let gantt = render_gantt(&plan);
+
+        assert!(gantt.starts_with("gantt\n    title Plan for bd-1\n"));
+        assert!(gantt.contains("section Worker 1"));
+        assert!(gantt.contains("bd-1 Task bd-1 :crit, bd_1, 2026-01-05 09:00, 2026-01-05 10:00"));
+        assert!(gantt.contains("bd-2 Task bd-2 :bd_2, 2026-01-05 09:00, 2026-01-05 09:30"));
+    }
+}
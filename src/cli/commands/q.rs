@@ -7,6 +7,7 @@ use crate::util::id::IdGenerator;
 use crate::validation::LabelValidator;
 use chrono::Utc;
 use rich_rust::prelude::*;
+use std::collections::BTreeMap;
 use std::str::FromStr;
 
 fn split_labels(values: &[String]) -> Vec<String> {
@@ -80,6 +81,7 @@ pub fn execute(args: QuickArgs, cli: &config::CliOverrides, ctx: &OutputContext)
         created_by: None,
         closed_at: None,
         close_reason: None,
+        close_outcome: None,
         closed_by_session: None,
         due_at: None,
         defer_until: None,
@@ -101,6 +103,7 @@ pub fn execute(args: QuickArgs, cli: &config::CliOverrides, ctx: &OutputContext)
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        fields: BTreeMap::new(),
     };
 
     // Resolve actor and set created_by
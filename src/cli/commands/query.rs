@@ -6,6 +6,7 @@ use crate::cli::{ListArgs, QueryCommands, QueryDeleteArgs, QueryRunArgs, QuerySa
 use crate::config;
 use crate::error::{BeadsError, Result};
 use crate::output::{OutputContext, OutputMode};
+use crate::storage::FilterExpr;
 use chrono::{DateTime, Utc};
 use rich_rust::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -27,6 +28,10 @@ pub struct SavedQuery {
     pub created_at: DateTime<Utc>,
     /// Filter configuration (stored as serializable form)
     pub filters: SavedFilters,
+    /// `--where` expression, stored as written and re-parsed on each run
+    /// (so `me` and relative dates resolve at run time)
+    #[serde(rename = "where", default, skip_serializing_if = "Option::is_none")]
+    pub where_expr: Option<String>,
 }
 
 /// Serializable filter configuration.
@@ -49,6 +54,8 @@ pub struct SavedFilters {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub label_any: Vec<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub field: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub priority: Vec<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub priority_min: Option<u8>,
@@ -90,6 +97,7 @@ impl From<&ListArgs> for SavedFilters {
             id: args.id.clone(),
             label: args.label.clone(),
             label_any: args.label_any.clone(),
+            field: args.field.clone(),
             priority: args.priority.clone(),
             priority_min: args.priority_min,
             priority_max: args.priority_max,
@@ -118,6 +126,7 @@ impl SavedFilters {
             id: self.id.clone(),
             label: self.label.clone(),
             label_any: self.label_any.clone(),
+            field: self.field.clone(),
             priority: self.priority.clone(),
             priority_min: self.priority_min,
             priority_max: self.priority_max,
@@ -130,6 +139,11 @@ impl SavedFilters {
             reverse: self.reverse,
             deferred: self.deferred,
             overdue: self.overdue,
+            // The expression lives on `SavedQuery`, not in the filters
+            where_: None,
+            // A saved query always runs against the present unless asked
+            as_of: None,
+            all_routes: false,
             // Output-related fields use defaults
             long: false,
             pretty: false,
@@ -171,6 +185,11 @@ impl SavedFilters {
             } else {
                 cli.label_any.clone()
             },
+            field: if cli.field.is_empty() {
+                base.field
+            } else {
+                cli.field.clone()
+            },
             priority: if cli.priority.is_empty() {
                 base.priority
             } else {
@@ -191,6 +210,9 @@ impl SavedFilters {
             reverse: cli.reverse || base.reverse,
             deferred: cli.deferred || base.deferred,
             overdue: cli.overdue || base.overdue,
+            where_: cli.where_.clone(),
+            as_of: cli.as_of.clone(),
+            all_routes: cli.all_routes,
             // Output fields from CLI only
             long: cli.long,
             pretty: cli.pretty,
@@ -217,6 +239,8 @@ struct QueryListItem {
     description: Option<String>,
     created_at: String,
     filters: SavedFilters,
+    #[serde(rename = "where", skip_serializing_if = "Option::is_none")]
+    where_expr: Option<String>,
 }
 
 /// JSON output for query save/delete.
@@ -276,11 +300,17 @@ fn query_save(
         ));
     }
 
+    // Reject malformed expressions now rather than on every run.
+    if let Some(ref input) = args.filters.where_ {
+        FilterExpr::parse(input, "")?;
+    }
+
     let saved_query = SavedQuery {
         name: name.to_string(),
         description: args.description.clone(),
         created_at: Utc::now(),
         filters: SavedFilters::from(&args.filters),
+        where_expr: args.filters.where_.clone(),
     };
 
     let value = serde_json::to_string(&saved_query)?;
@@ -325,7 +355,10 @@ fn query_run(
     debug!(name, "Loaded saved query");
 
     // Merge saved filters with CLI overrides
-    let merged_args = saved_query.filters.merge_with_cli(&args.filters);
+    let mut merged_args = saved_query.filters.merge_with_cli(&args.filters);
+    if merged_args.where_.is_none() {
+        merged_args.where_ = saved_query.where_expr;
+    }
 
     debug!(?merged_args, "Merged filters");
 
@@ -348,6 +381,7 @@ fn query_list(storage: &crate::storage::SqliteStorage, ctx: &OutputContext) -> R
                         description: saved.description,
                         created_at: saved.created_at.to_rfc3339(),
                         filters: saved.filters,
+                        where_expr: saved.where_expr,
                     });
                 }
                 Err(e) => {
@@ -596,6 +630,7 @@ mod tests {
                 type_: vec!["bug".to_string()],
                 ..Default::default()
             },
+            where_expr: None,
         };
 
         let json = serde_json::to_string(&query).unwrap();
@@ -607,6 +642,27 @@ mod tests {
         assert_eq!(parsed.filters.type_, vec!["bug"]);
     }
 
+    #[test]
+    fn test_saved_query_where_roundtrip() {
+        let query = SavedQuery {
+            name: "mine".to_string(),
+            description: None,
+            created_at: Utc::now(),
+            filters: SavedFilters::default(),
+            where_expr: Some("assignee = me or assignee is null".to_string()),
+        };
+
+        let json = serde_json::to_string(&query).unwrap();
+        assert!(json.contains("\"where\""));
+        let parsed: SavedQuery = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.where_expr, query.where_expr);
+
+        // Queries saved before `--where` existed still load.
+        let legacy = r#"{"name":"old","created_at":"2025-01-01T00:00:00Z","filters":{}}"#;
+        let parsed: SavedQuery = serde_json::from_str(legacy).unwrap();
+        assert!(parsed.where_expr.is_none());
+    }
+
     // ============================================================
     // Additional tests for comprehensive query module coverage
     // ============================================================
@@ -795,6 +851,7 @@ mod tests {
             description: None,
             created_at: Utc::now(),
             filters: SavedFilters::default(),
+            where_expr: None,
         };
 
         let json = serde_json::to_string(&query).unwrap();
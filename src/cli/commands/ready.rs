@@ -2,14 +2,22 @@
 //!
 //! Shows issues ready to work on: unblocked, not deferred, not pinned, not ephemeral.
 
-use crate::cli::{OutputFormat, ReadyArgs, SortPolicy, resolve_output_format_basic};
+use crate::cli::{
+    OutputFormat, ReadyArgs, SortPolicy, print_as_of_notes, resolve_output_format_basic,
+};
 use crate::config;
 use crate::error::Result;
-use crate::format::{ReadyIssue, format_priority_badge, terminal_width, truncate_title};
-use crate::model::{IssueType, Priority};
+use crate::format::{
+    ReadyIssue, RoutedRecord, format_priority_badge, terminal_width, truncate_title,
+};
+use crate::model::{Claim, Issue, IssueType, Priority};
 use crate::output::{IssueTable, IssueTableColumns, OutputContext, OutputMode};
-use crate::storage::{ReadyFilters, ReadySortPolicy};
+use crate::storage::{FilterExpr, ReadyFilters, ReadySortPolicy, SqliteStorage};
+use crate::sync::as_of;
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, HashSet};
 use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use tracing::{debug, info, trace};
 use unicode_width::UnicodeWidthStr;
@@ -27,10 +35,18 @@ pub fn execute(
 ) -> Result<()> {
     // Open storage
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
-    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
     let storage = &storage_ctx.storage;
 
     let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
+    let snapshot = args
+        .as_of
+        .as_deref()
+        .map(|input| as_of::reconstruct_for_arg(input, &storage_ctx))
+        .transpose()?;
+    let storage = snapshot
+        .as_ref()
+        .map_or(storage, |snapshot| &snapshot.storage);
     let external_db_paths = config::external_project_db_paths(&config_layer, &beads_dir);
     let use_color = config::should_use_color(&config_layer);
     let max_width = if std::io::stdout().is_terminal() {
@@ -50,8 +66,14 @@ pub fn execute(
         types: parse_types(&args.type_)?,
         priorities: parse_priorities(&args.priority)?,
         include_deferred: args.include_deferred,
-        // Fetch all candidates to allow post-filtering of external blockers
+        // Applied after external blockers are filtered out
         limit: None,
+        where_expr: args
+            .where_
+            .as_deref()
+            .map(|input| FilterExpr::parse(input, &config::resolve_actor(&config_layer)))
+            .transpose()?,
+        claimable_by: None,
     };
 
     let sort_policy = match args.sort {
@@ -63,21 +85,56 @@ pub fn execute(
     info!("Fetching ready issues");
     debug!(filters = ?filters, sort = ?sort_policy, "Applied ready filters");
 
-    // Get ready issues from storage (blocked cache only)
-    let mut ready_issues = storage.get_ready_issues(&filters, sort_policy)?;
-
-    let external_statuses =
-        storage.resolve_external_dependency_statuses(&external_db_paths, true)?;
-    let external_blockers = storage.external_blockers(&external_statuses)?;
-    if !external_blockers.is_empty() {
-        ready_issues.retain(|issue| !external_blockers.contains_key(&issue.id));
+    if args.claim {
+        // External blockers are resolved outside SQL, so pass them in as skips
+        let external_blockers = external_blocker_ids(storage, &external_db_paths, &beads_dir)?;
+        let actor = config::resolve_actor(&config_layer);
+        let lease = super::update::claim_lease(args.lease.as_deref(), &config_layer)?;
+        let claim = storage_ctx.storage.claim_next_ready(
+            &filters,
+            sort_policy,
+            &actor,
+            args.session.as_deref(),
+            lease,
+            &external_blockers,
+        )?;
+        let claimed = match claim {
+            Some(claim) => storage_ctx
+                .storage
+                .get_issue(&claim.issue_id)?
+                .map(|issue| (issue, claim)),
+            None => None,
+        };
+        if let Some((issue, _)) = &claimed {
+            crate::util::set_last_touched_id(&beads_dir, &issue.id);
+        }
+        render_claimed(claimed.as_ref(), output_format, &ctx);
+        storage_ctx.flush_no_db_if_dirty()?;
+        return Ok(());
     }
 
-    // Apply limit after external filtering
-    if args.limit > 0 && ready_issues.len() > args.limit {
-        ready_issues.truncate(args.limit);
+    if args.all_routes {
+        let routed = collect_routed_ready(&beads_dir, &filters, sort_policy, args.limit)?;
+        render_routed_ready(&routed, args, output_format, &ctx, use_color, max_width);
+        return Ok(());
     }
 
+    let ready_issues = find_ready_issues(
+        storage,
+        &external_db_paths,
+        &beads_dir,
+        &ReadyFilters {
+            limit: Some(args.limit),
+            ..filters
+        },
+        sort_policy,
+        snapshot.as_ref().map(|snapshot| snapshot.as_of),
+    )?;
+
+    // conditional-blocks dependents are only ready because their blocker failed
+    let failed_blockers = storage.failed_conditional_blockers()?;
+    let condition_for = |id: &str| failed_blockers.get(id).map(|b| condition_text(b));
+
     info!(count = ready_issues.len(), "Found ready issues");
     for issue in ready_issues.iter().take(5) {
         trace!(id = %issue.id, priority = issue.priority.0, "Ready issue");
@@ -89,12 +146,23 @@ pub fn execute(
     }
     match output_format {
         OutputFormat::Json => {
-            let ready_output: Vec<ReadyIssue> = ready_issues.iter().map(ReadyIssue::from).collect();
-            ctx.json_pretty(&ready_output);
+            let ready_output = ready_records(storage, &ready_issues)?;
+            match &snapshot {
+                Some(snapshot) => {
+                    ctx.json_pretty(&snapshot.records(ready_output, |issue| issue.id.as_str()))
+                }
+                None => ctx.json_pretty(&ready_output),
+            }
         }
         OutputFormat::Toon => {
-            let ready_output: Vec<ReadyIssue> = ready_issues.iter().map(ReadyIssue::from).collect();
-            ctx.toon_with_stats(&ready_output, args.stats);
+            let ready_output = ready_records(storage, &ready_issues)?;
+            match &snapshot {
+                Some(snapshot) => ctx.toon_with_stats(
+                    &snapshot.records(ready_output, |issue| issue.id.as_str()),
+                    args.stats,
+                ),
+                None => ctx.toon_with_stats(&ready_output, args.stats),
+            }
         }
         OutputFormat::Text | OutputFormat::Csv => {
             if ready_issues.is_empty() {
@@ -132,17 +200,259 @@ pub fn execute(
                 for (i, issue) in ready_issues.iter().enumerate() {
                     let line = format_ready_line(i + 1, issue, use_color, max_width, args.wrap);
                     println!("{line}");
+                    if let Some(condition) = condition_for(&issue.id) {
+                        println!("   ↳ {condition}");
+                    }
                 }
             }
+            if let Some(snapshot) = &snapshot {
+                print_as_of_notes(
+                    &ctx,
+                    snapshot,
+                    ready_issues.iter().map(|issue| issue.id.as_str()),
+                );
+            }
         }
     }
 
     Ok(())
 }
 
+/// Print the issue taken by `ready --claim` (JSON `null` when none was free).
+fn render_claimed(
+    claimed: Option<&(Issue, Claim)>,
+    output_format: OutputFormat,
+    ctx: &OutputContext,
+) {
+    let payload = claimed.map(|(issue, claim)| {
+        serde_json::json!({
+            "issue": ReadyIssue::from(issue),
+            "claim": claim,
+        })
+    });
+    match output_format {
+        OutputFormat::Json => ctx.json_pretty(&payload),
+        OutputFormat::Toon => ctx.toon(&payload),
+        OutputFormat::Text | OutputFormat::Csv => match claimed {
+            Some((issue, claim)) => ctx.success(&format!(
+                "Claimed {}: {} (lease until {})",
+                issue.id,
+                issue.title,
+                claim.expires_at.to_rfc3339()
+            )),
+            None => ctx.info("No ready issues to claim."),
+        },
+    }
+}
+
+/// Issues blocked by unresolved cross-project dependencies: `external:` refs
+/// into configured projects and IDs routed to other workspaces.
+///
+/// # Errors
+///
+/// Returns an error if the dependencies cannot be listed.
+pub fn external_blocker_ids(
+    storage: &SqliteStorage,
+    external_db_paths: &HashMap<String, PathBuf>,
+    beads_dir: &Path,
+) -> Result<HashSet<String>> {
+    let mut external_statuses =
+        storage.resolve_external_dependency_statuses(external_db_paths, true)?;
+    external_statuses.extend(config::routed_dependency_statuses(storage, beads_dir)?);
+    Ok(storage
+        .external_blockers(&external_statuses)?
+        .into_keys()
+        .collect())
+}
+
+/// Ready issues as `br ready` lists them.
+///
+/// Issues with unresolved cross-project blockers are dropped before
+/// `filters.limit` is applied. With `as_of`, deferrals are compared against
+/// that time instead of the clock, for `--as-of` snapshots.
+///
+/// # Errors
+///
+/// Returns an error if a query fails.
+pub fn find_ready_issues(
+    storage: &SqliteStorage,
+    external_db_paths: &HashMap<String, PathBuf>,
+    beads_dir: &Path,
+    filters: &ReadyFilters,
+    sort_policy: ReadySortPolicy,
+    as_of: Option<DateTime<Utc>>,
+) -> Result<Vec<Issue>> {
+    // Fetch all candidates to allow post-filtering of external blockers
+    let unlimited = ReadyFilters {
+        limit: None,
+        ..filters.clone()
+    };
+    let mut issues = storage.get_ready_issues(&unlimited, sort_policy)?;
+    if let Some(as_of) = as_of
+        && !filters.include_deferred
+    {
+        issues.retain(|issue| issue.defer_until.is_none_or(|at| at <= as_of));
+    }
+
+    let external_blockers = external_blocker_ids(storage, external_db_paths, beads_dir)?;
+    if !external_blockers.is_empty() {
+        issues.retain(|issue| !external_blockers.contains(&issue.id));
+    }
+    if let Some(limit) = filters.limit
+        && limit > 0
+    {
+        issues.truncate(limit);
+    }
+    Ok(issues)
+}
+
+/// `issues` as output records, noting the ones that are ready only because a
+/// `conditional-blocks` blocker failed.
+///
+/// # Errors
+///
+/// Returns an error if the query fails.
+pub fn ready_records(storage: &SqliteStorage, issues: &[Issue]) -> Result<Vec<ReadyIssue>> {
+    let failed_blockers = storage.failed_conditional_blockers()?;
+    Ok(issues
+        .iter()
+        .map(|issue| ReadyIssue {
+            condition: failed_blockers.get(&issue.id).map(|b| condition_text(b)),
+            ..ReadyIssue::from(issue)
+        })
+        .collect())
+}
+
+fn condition_text(failed_blockers: &[String]) -> String {
+    format!("runs because {} failed", failed_blockers.join(", "))
+}
+
+/// Ready issue from one of the workspaces reached by `--all-routes`.
+struct RoutedReady {
+    project: String,
+    issue: Issue,
+    condition: Option<String>,
+}
+
+/// Query every routed workspace read-only and merge the results in
+/// `sort_policy` order. Ties keep route order.
+fn collect_routed_ready(
+    beads_dir: &Path,
+    filters: &ReadyFilters,
+    sort_policy: ReadySortPolicy,
+    limit: usize,
+) -> Result<Vec<RoutedReady>> {
+    let mut merged = Vec::new();
+    for routed in config::open_routed_storages(beads_dir)? {
+        let storage = &routed.storage;
+        let issues = find_ready_issues(
+            storage,
+            &routed.external_db_paths,
+            &routed.beads_dir,
+            filters,
+            sort_policy,
+            None,
+        )?;
+        let failed_blockers = storage.failed_conditional_blockers()?;
+        debug!(project = %routed.project, count = issues.len(), "Routed ready issues");
+
+        merged.extend(issues.into_iter().map(|issue| RoutedReady {
+            project: routed.project.clone(),
+            condition: failed_blockers.get(&issue.id).map(|b| condition_text(b)),
+            issue,
+        }));
+    }
+    merged.sort_by(|a, b| sort_policy.compare(&a.issue, &b.issue));
+    if limit > 0 {
+        merged.truncate(limit);
+    }
+    Ok(merged)
+}
+
+fn render_routed_ready(
+    routed: &[RoutedReady],
+    args: &ReadyArgs,
+    output_format: OutputFormat,
+    ctx: &OutputContext,
+    use_color: bool,
+    max_width: Option<usize>,
+) {
+    if matches!(ctx.mode(), OutputMode::Quiet) {
+        return;
+    }
+    let records = || -> Vec<RoutedRecord<ReadyIssue>> {
+        routed
+            .iter()
+            .map(|entry| RoutedRecord {
+                project: entry.project.clone(),
+                item: ReadyIssue {
+                    condition: entry.condition.clone(),
+                    ..ReadyIssue::from(&entry.issue)
+                },
+            })
+            .collect()
+    };
+    match output_format {
+        OutputFormat::Json => ctx.json_pretty(&records()),
+        OutputFormat::Toon => ctx.toon_with_stats(&records(), args.stats),
+        OutputFormat::Text | OutputFormat::Csv => {
+            if routed.is_empty() {
+                println!("✨ No open issues");
+                return;
+            }
+            let title = format!(
+                "Ready work across routes ({} issue{} with no blockers)",
+                routed.len(),
+                if routed.len() == 1 { "" } else { "s" }
+            );
+            if matches!(ctx.mode(), OutputMode::Rich) {
+                let issues: Vec<Issue> = routed.iter().map(|entry| entry.issue.clone()).collect();
+                let projects: HashMap<String, String> = routed
+                    .iter()
+                    .map(|entry| (entry.issue.id.clone(), entry.project.clone()))
+                    .collect();
+                let columns = IssueTableColumns {
+                    project: true,
+                    id: true,
+                    priority: true,
+                    status: true,
+                    issue_type: true,
+                    title: true,
+                    ..Default::default()
+                };
+                let mut table = IssueTable::new(&issues, ctx.theme())
+                    .columns(columns)
+                    .projects(projects)
+                    .title(title)
+                    .wrap(args.wrap);
+                if args.wrap {
+                    table = table.width(Some(ctx.width()));
+                }
+                ctx.render(&table.build());
+            } else {
+                println!("📋 {title}:\n");
+                let project_width = routed
+                    .iter()
+                    .map(|entry| UnicodeWidthStr::width(entry.project.as_str()))
+                    .max()
+                    .unwrap_or(0);
+                let line_width = max_width.map(|width| width.saturating_sub(project_width + 1));
+                for (i, entry) in routed.iter().enumerate() {
+                    let line =
+                        format_ready_line(i + 1, &entry.issue, use_color, line_width, args.wrap);
+                    println!("{:<project_width$} {line}", entry.project);
+                    if let Some(condition) = &entry.condition {
+                        println!("{:<project_width$}    ↳ {condition}", "");
+                    }
+                }
+            }
+        }
+    }
+}
+
 fn format_ready_line(
     index: usize,
-    issue: &crate::model::Issue,
+    issue: &Issue,
     use_color: bool,
     max_width: Option<usize>,
     wrap: bool,
@@ -1,18 +1,22 @@
 //! Search command implementation.
 //!
-//! Classic bd-style LIKE search across title/description/id with list-like filters.
+//! Full-text search (SQLite FTS5) across issue text and comments with list-like
+//! filters, BM25 ranking and highlighted snippets.
 
+use crate::cli::commands::list::attach_custom_fields;
 use crate::cli::{ListArgs, OutputFormat, SearchArgs, resolve_output_format};
 use crate::config;
+use crate::config::fields::FieldSchema;
 use crate::error::{BeadsError, Result};
 use crate::format::{
-    IssueWithCounts, TextFormatOptions, csv, format_issue_line_with, terminal_width,
+    IssueSearchHit, IssueWithCounts, TextFormatOptions, csv, format_issue_line_with, terminal_width,
 };
 use crate::model::{IssueType, Priority, Status};
 use crate::output::{IssueTable, IssueTableColumns, OutputContext, OutputMode};
-use crate::storage::{ListFilters, SqliteStorage};
+use crate::storage::sqlite::{SNIPPET_MATCH_END, SNIPPET_MATCH_START};
+use crate::storage::{FilterExpr, ListFilters, SqliteStorage};
 use chrono::Utc;
-use regex::{Regex, RegexBuilder};
+use crossterm::style::Stylize;
 use std::collections::{HashMap, HashSet};
 use std::io::IsTerminal;
 use std::str::FromStr;
@@ -37,6 +41,13 @@ pub fn execute(
             reason: "search query cannot be empty".to_string(),
         });
     }
+    // The full-text index only covers current content
+    if args.filters.as_of.is_some() {
+        return Err(BeadsError::validation(
+            "as_of",
+            "search does not support --as-of (use `br list --as-of --title-contains`)",
+        ));
+    }
 
     let beads_dir = config::discover_beads_dir_with_cli(cli)?;
     let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
@@ -54,7 +65,13 @@ pub fn execute(
         wrap: args.filters.wrap,
     };
 
-    let mut filters = build_filters(&args.filters)?;
+    let mut filters = build_filters(&args.filters, storage.field_schema())?;
+    if let Some(ref input) = args.filters.where_ {
+        filters.set_where(FilterExpr::parse(
+            input,
+            &config::resolve_actor(&config_layer),
+        )?);
+    }
     let client_filters = needs_client_filters(&args.filters);
     let limit = if client_filters {
         filters.limit.take()
@@ -62,7 +79,15 @@ pub fn execute(
         None
     };
 
-    let issues = storage.search_issues(query, &filters)?;
+    let hits = storage.search_issues_ranked(query, &filters)?;
+    let mut matches: HashMap<String, (f64, Option<String>)> = HashMap::with_capacity(hits.len());
+    let issues: Vec<_> = hits
+        .into_iter()
+        .map(|hit| {
+            matches.insert(hit.issue.id.clone(), (hit.score, hit.snippet));
+            hit.issue
+        })
+        .collect();
     let issues = if client_filters {
         apply_client_filters(storage, issues, &args.filters)?
     } else {
@@ -115,19 +140,23 @@ pub fn execute(
 
     match output_format {
         OutputFormat::Json => {
-            ctx.json_pretty(&issues_with_counts);
+            ctx.json_pretty(&to_search_hits(issues_with_counts, &matches));
             return Ok(());
         }
         OutputFormat::Toon => {
-            ctx.toon_with_stats(&issues_with_counts, args.filters.stats);
+            ctx.toon_with_stats(
+                &to_search_hits(issues_with_counts, &matches),
+                args.filters.stats,
+            );
             return Ok(());
         }
         OutputFormat::Csv => {
-            let issues: Vec<_> = issues_with_counts
+            let mut issues: Vec<_> = issues_with_counts
                 .iter()
                 .map(|iwc| iwc.issue.clone())
                 .collect();
             let fields = csv::parse_fields(args.filters.fields.as_deref());
+            attach_custom_fields(storage, &mut issues, &fields)?;
             let csv_output = csv::format_csv(&issues, &fields);
             print!("{csv_output}");
             return Ok(());
@@ -140,7 +169,7 @@ pub fn execute(
             .iter()
             .map(|iwc| iwc.issue.clone())
             .collect();
-        let context_snippets = build_context_snippets(&issues, query);
+        let context_snippets = build_context_snippets(&matches);
         let show_context = !context_snippets.is_empty();
         let columns = IssueTableColumns {
             id: true,
@@ -180,86 +209,74 @@ pub fn execute(
     for iwc in &issues_with_counts {
         let line = format_issue_line_with(&iwc.issue, format_options);
         ctx.print(&line);
+        if let Some((_, Some(snippet))) = matches.get(&iwc.issue.id) {
+            ctx.print(&format!("    {}", highlight_snippet(snippet, use_color)));
+        }
     }
 
     Ok(())
 }
 
-fn build_context_snippets(issues: &[crate::model::Issue], query: &str) -> HashMap<String, String> {
-    let Some(regex) = build_highlight_regex(query) else {
-        return HashMap::new();
-    };
-
-    let mut snippets = HashMap::new();
-    for issue in issues {
-        if let Some(description) = issue.description.as_deref() {
-            if let Some(mat) = regex.find(description) {
-                let snippet = snippet_around_match(description, mat.start(), mat.end(), 32);
-                if !snippet.is_empty() {
-                    snippets.insert(issue.id.clone(), snippet);
-                    continue;
-                }
+fn to_search_hits(
+    issues: Vec<IssueWithCounts>,
+    matches: &HashMap<String, (f64, Option<String>)>,
+) -> Vec<IssueSearchHit> {
+    issues
+        .into_iter()
+        .map(|issue| {
+            let (score, snippet) = matches.get(&issue.issue.id).cloned().unwrap_or_default();
+            IssueSearchHit {
+                issue,
+                score,
+                snippet,
             }
-        }
-
-        if regex.is_match(&issue.id) && !regex.is_match(&issue.title) {
-            snippets.insert(issue.id.clone(), "ID match".to_string());
-        }
-    }
-
-    snippets
-}
-
-fn build_highlight_regex(query: &str) -> Option<Regex> {
-    let trimmed = query.trim();
-    if trimmed.is_empty() {
-        return None;
-    }
-    let pattern = regex::escape(trimmed);
-    RegexBuilder::new(&pattern)
-        .case_insensitive(true)
-        .build()
-        .ok()
+        })
+        .collect()
 }
 
-fn snippet_around_match(text: &str, start: usize, end: usize, radius: usize) -> String {
-    if text.is_empty() {
-        return String::new();
+/// Render snippet match markers as bold (color) or leave them as-is (plain).
+fn highlight_snippet(snippet: &str, use_color: bool) -> String {
+    let snippet = normalize_whitespace(snippet);
+    if !use_color {
+        return snippet;
     }
-
-    let mut char_starts: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
-    char_starts.push(text.len());
-
-    let total_chars = char_starts.len().saturating_sub(1);
-    let start_char = char_starts.partition_point(|&idx| idx < start);
-    let end_char = char_starts.partition_point(|&idx| idx < end);
-
-    let snippet_start_char = start_char.saturating_sub(radius);
-    let snippet_end_char = (end_char + radius).min(total_chars);
-
-    let snippet_start_byte = char_starts[snippet_start_char];
-    let snippet_end_byte = char_starts[snippet_end_char];
-
-    let mut snippet = text[snippet_start_byte..snippet_end_byte]
-        .trim()
-        .to_string();
-    snippet = normalize_whitespace(&snippet);
-
-    if snippet_start_char > 0 {
-        snippet.insert_str(0, "...");
-    }
-    if snippet_end_char < total_chars {
-        snippet.push_str("...");
+    let mut out = String::with_capacity(snippet.len());
+    let mut rest = snippet.as_str();
+    while let Some(start) = rest.find(SNIPPET_MATCH_START) {
+        let after_start = &rest[start + SNIPPET_MATCH_START.len()..];
+        let Some(end) = after_start.find(SNIPPET_MATCH_END) else {
+            break;
+        };
+        out.push_str(&rest[..start]);
+        out.push_str(&after_start[..end].bold().to_string());
+        rest = &after_start[end + SNIPPET_MATCH_END.len()..];
     }
+    out.push_str(rest);
+    out
+}
 
-    snippet
+/// Context column for the rich table: FTS snippets without match markers
+/// (the table highlights query terms itself).
+fn build_context_snippets(
+    matches: &HashMap<String, (f64, Option<String>)>,
+) -> HashMap<String, String> {
+    matches
+        .iter()
+        .filter_map(|(id, (_, snippet))| {
+            let snippet = snippet
+                .as_deref()?
+                .replace(SNIPPET_MATCH_START, "")
+                .replace(SNIPPET_MATCH_END, "");
+            Some((id.clone(), normalize_whitespace(&snippet)))
+        })
+        .collect()
 }
 
 fn normalize_whitespace(input: &str) -> String {
     input.split_whitespace().collect::<Vec<_>>().join(" ")
 }
 
-fn build_filters(args: &ListArgs) -> Result<ListFilters> {
+fn build_filters(args: &ListArgs, field_schema: &FieldSchema) -> Result<ListFilters> {
     let statuses = if args.status.is_empty() {
         None
     } else {
@@ -326,6 +343,8 @@ fn build_filters(args: &ListArgs) -> Result<ListFilters> {
         labels_or: None,
         updated_before: None,
         updated_after: None,
+        where_expr: None,
+        fields: field_schema.parse_filters(&args.field)?,
     })
 }
 
@@ -475,6 +494,7 @@ mod tests {
     use super::*;
     use crate::model::{Issue, IssueType, Priority, Status};
     use chrono::{DateTime, TimeZone, Utc};
+    use std::collections::BTreeMap;
 
     fn make_issue(
         id: &str,
@@ -501,6 +521,7 @@ mod tests {
             updated_at: created_at,
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             due_at: None,
             defer_until: None,
@@ -522,6 +543,7 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -0,0 +1,936 @@
+//! Serve command implementation.
+//!
+//! `br serve --stdio` keeps one [`Workspace`] open and answers line-delimited
+//! JSON-RPC 2.0 requests: one request object per line on stdin, one response
+//! per line on stdout. Method names follow the CLI (`list`, `ready`, `show`,
+//! `create`, `update`, `close`, `dep.add`, `label.remove`, `comments.add`,
+//! `sync.flush`, ...) and parameters use the long flag names.
+//!
+//! Before each request the JSONL file is checked for outside changes and
+//! imported if stale. Mutations are flushed to JSONL once no new mutation has
+//! arrived for the debounce interval, and always before the server exits.
+
+use crate::cli::ServeArgs;
+use crate::config;
+use crate::error::{BeadsError, Result, StructuredError};
+use crate::model::{DependencyType, IssueType, Priority, Status};
+use crate::storage::{FilterExpr, IssueUpdate, ListFilters, ReadyFilters, ReadySortPolicy};
+use crate::util::time::parse_flexible_timestamp;
+use crate::workspace::{NewIssue, Workspace};
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Value, json};
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+pub(crate) const PARSE_ERROR: i64 = -32700;
+pub(crate) const INVALID_REQUEST: i64 = -32600;
+pub(crate) const METHOD_NOT_FOUND: i64 = -32601;
+pub(crate) const INVALID_PARAMS: i64 = -32602;
+pub(crate) const INTERNAL_ERROR: i64 = -32603;
+/// Command failures; `data` carries the same payload as `--json` errors.
+pub(crate) const COMMAND_ERROR: i64 = -32000;
+
+/// Execute the serve command.
+///
+/// # Errors
+///
+/// Returns an error if no transport is selected, the workspace cannot be
+/// opened, or the final flush fails.
+pub fn execute(args: &ServeArgs, cli: &config::CliOverrides) -> Result<()> {
+    if !args.stdio {
+        return Err(BeadsError::validation(
+            "transport",
+            "only --stdio is supported",
+        ));
+    }
+
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut workspace = Workspace::open_with(&beads_dir, cli)?;
+    workspace.set_auto_flush(false);
+    let mut server = Server::new(workspace);
+    let debounce = Duration::from_millis(args.flush_debounce_ms);
+    tracing::info!(path = %beads_dir.display(), "Serving JSON-RPC on stdio");
+    serve_stdio(&mut server, debounce, Server::handle_line)
+}
+
+/// Answer line-delimited requests on stdin until EOF or shutdown.
+///
+/// `handle` turns one input line into an optional response line. Pending
+/// mutations are flushed once no new mutation has arrived for `debounce`,
+/// and before returning.
+pub(crate) fn serve_stdio(
+    server: &mut Server,
+    debounce: Duration,
+    handle: impl FnMut(&mut Server, &str) -> Option<String>,
+) -> Result<()> {
+    // Read on a separate thread so pending changes can flush while idle
+    let (tx, rx) = mpsc::channel::<String>();
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            let Ok(line) = line else { break };
+            if tx.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    serve_lines(server, &rx, &mut io::stdout().lock(), debounce, handle)
+}
+
+/// Answer the lines received on `rx` on `out` until the sender hangs up or
+/// a shutdown request arrives.
+fn serve_lines(
+    server: &mut Server,
+    rx: &Receiver<String>,
+    out: &mut impl Write,
+    debounce: Duration,
+    mut handle: impl FnMut(&mut Server, &str) -> Option<String>,
+) -> Result<()> {
+    while !server.shutdown {
+        let received = match server.last_mutation {
+            Some(since) => rx.recv_timeout(debounce.saturating_sub(since.elapsed())),
+            None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match received {
+            Ok(line) => {
+                if let Some(response) = handle(server, &line) {
+                    writeln!(out, "{response}")?;
+                    out.flush()?;
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                if let Err(e) = server.flush_pending() {
+                    tracing::warn!(error = %e, "Debounced flush failed; will retry");
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+
+    server.flush_pending()
+}
+
+#[derive(Debug, Deserialize)]
+struct Request {
+    #[serde(default)]
+    jsonrpc: Option<String>,
+    #[serde(default)]
+    id: Option<Value>,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct RpcError {
+    pub(crate) code: i64,
+    pub(crate) message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) data: Option<Value>,
+}
+
+impl RpcError {
+    pub(crate) fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            data: None,
+        }
+    }
+}
+
+impl From<BeadsError> for RpcError {
+    fn from(err: BeadsError) -> Self {
+        let structured = StructuredError::from_error(&err);
+        let mut payload = structured.to_json();
+        Self {
+            code: COMMAND_ERROR,
+            message: structured.message,
+            data: Some(payload["error"].take()),
+        }
+    }
+}
+
+pub(crate) type RpcResult = std::result::Result<Value, RpcError>;
+
+/// Request handler shared by `br serve` and `br mcp`.
+pub(crate) struct Server {
+    workspace: Workspace,
+    /// When the latest unflushed mutation happened; each new one restarts
+    /// the debounce.
+    last_mutation: Option<Instant>,
+    /// JSONL modification time as of the last staleness check.
+    jsonl_mtime: Option<SystemTime>,
+    shutdown: bool,
+}
+
+impl Server {
+    pub(crate) fn new(workspace: Workspace) -> Self {
+        let mut server = Self {
+            workspace,
+            last_mutation: None,
+            jsonl_mtime: None,
+            shutdown: false,
+        };
+        server.jsonl_mtime = server.current_jsonl_mtime();
+        server
+    }
+
+    pub(crate) const fn workspace(&self) -> &Workspace {
+        &self.workspace
+    }
+
+    fn current_jsonl_mtime(&self) -> Option<SystemTime> {
+        std::fs::metadata(&self.workspace.paths().jsonl_path)
+            .and_then(|meta| meta.modified())
+            .ok()
+    }
+
+    /// Handle one input line, returning the response line if one is due.
+    fn handle_line(&mut self, line: &str) -> Option<String> {
+        let line = line.trim();
+        if line.is_empty() {
+            return None;
+        }
+
+        let (id, outcome) = match serde_json::from_str::<Value>(line) {
+            Err(e) => (Value::Null, Err(RpcError::new(PARSE_ERROR, e.to_string()))),
+            Ok(value) => match serde_json::from_value::<Request>(value) {
+                Err(e) => (
+                    Value::Null,
+                    Err(RpcError::new(INVALID_REQUEST, e.to_string())),
+                ),
+                Ok(request) => {
+                    let outcome = if request.jsonrpc.as_deref() == Some("2.0") {
+                        self.refresh()
+                            .map_err(RpcError::from)
+                            .and_then(|()| self.call(&request.method, request.params))
+                    } else {
+                        Err(RpcError::new(INVALID_REQUEST, "jsonrpc must be \"2.0\""))
+                    };
+                    // Notifications get no response
+                    let id = request.id?;
+                    (id, outcome)
+                }
+            },
+        };
+
+        let response = match outcome {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(error) => json!({ "jsonrpc": "2.0", "id": id, "error": error }),
+        };
+        Some(response.to_string())
+    }
+
+    /// Import the JSONL if another process changed it since the last check.
+    pub(crate) fn refresh(&mut self) -> Result<()> {
+        let mtime = self.current_jsonl_mtime();
+        if mtime == self.jsonl_mtime {
+            return Ok(());
+        }
+        let outcome = self.workspace.import_if_stale()?;
+        if outcome.attempted {
+            tracing::debug!(imported = outcome.imported_count, "Re-imported JSONL");
+        }
+        self.jsonl_mtime = mtime;
+        Ok(())
+    }
+
+    fn flush_pending(&mut self) -> Result<()> {
+        if self.last_mutation.is_none() {
+            return Ok(());
+        }
+        self.workspace.flush()?;
+        self.last_mutation = None;
+        self.jsonl_mtime = self.current_jsonl_mtime();
+        Ok(())
+    }
+
+    fn mutated(&mut self) {
+        self.last_mutation = Some(Instant::now());
+    }
+
+    pub(crate) fn call(&mut self, method: &str, params: Value) -> RpcResult {
+        match method {
+            "show" => {
+                let p: IdParams = parse_params(params)?;
+                to_result(&self.workspace.show(&p.id)?)
+            }
+            "list" => {
+                let p: ListParams = parse_params(params)?;
+                let filters = p.to_filters(self.workspace.actor())?;
+                to_result(&self.workspace.list(&filters)?)
+            }
+            "ready" => {
+                let p: ReadyParams = parse_params(params)?;
+                let (filters, sort) = p.to_filters(self.workspace.actor())?;
+                to_result(&self.workspace.ready(&filters, sort)?)
+            }
+            "create" => {
+                let p: CreateParams = parse_params(params)?;
+                let issue = self.workspace.create(&p.to_new_issue()?)?;
+                self.mutated();
+                to_result(&issue)
+            }
+            "update" => {
+                let p: UpdateParams = parse_params(params)?;
+                let issue = self.workspace.update(&p.id, &p.to_update()?)?;
+                self.mutated();
+                to_result(&issue)
+            }
+            "close" => {
+                let p: CloseParams = parse_params(params)?;
+                let issue = self.workspace.close(&p.id, p.reason.as_deref())?;
+                self.mutated();
+                to_result(&issue)
+            }
+            "dep.add" => {
+                let p: DepParams = parse_params(params)?;
+                let dep_type = p
+                    .dep_type
+                    .as_deref()
+                    .map_or(Ok(DependencyType::Blocks), DependencyType::from_str)?;
+                let added = self
+                    .workspace
+                    .add_dependency(&p.id, &p.depends_on, &dep_type)?;
+                self.mutated();
+                Ok(json!({ "added": added }))
+            }
+            "dep.remove" => {
+                let p: DepParams = parse_params(params)?;
+                let removed = self.workspace.remove_dependency(&p.id, &p.depends_on)?;
+                self.mutated();
+                Ok(json!({ "removed": removed }))
+            }
+            "label.add" => {
+                let p: LabelParams = parse_params(params)?;
+                let added = self.workspace.add_label(&p.id, &p.label)?;
+                self.mutated();
+                Ok(json!({ "added": added }))
+            }
+            "label.remove" => {
+                let p: LabelParams = parse_params(params)?;
+                let removed = self.workspace.remove_label(&p.id, &p.label)?;
+                self.mutated();
+                Ok(json!({ "removed": removed }))
+            }
+            "comments.add" => {
+                let p: CommentParams = parse_params(params)?;
+                let comment = self.workspace.add_comment(&p.id, &p.text)?;
+                self.mutated();
+                to_result(&comment)
+            }
+            "sync.flush" => {
+                let result = self.workspace.flush()?;
+                self.last_mutation = None;
+                self.jsonl_mtime = self.current_jsonl_mtime();
+                Ok(json!({
+                    "flushed": result.flushed,
+                    "exported_count": result.exported_count,
+                }))
+            }
+            "sync.import" => {
+                let result = self.workspace.import()?;
+                self.jsonl_mtime = self.current_jsonl_mtime();
+                Ok(json!({
+                    "imported_count": result.imported_count,
+                    "skipped_count": result.skipped_count,
+                    "events_imported": result.events_imported,
+                }))
+            }
+            "shutdown" => {
+                self.shutdown = true;
+                Ok(Value::Null)
+            }
+            other => Err(RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("unknown method '{other}'"),
+            )),
+        }
+    }
+}
+
+fn parse_params<T: DeserializeOwned>(params: Value) -> std::result::Result<T, RpcError> {
+    // Omitted params behave like an empty object
+    let params = if params.is_null() { json!({}) } else { params };
+    serde_json::from_value(params)
+        .map_err(|e| RpcError::new(INVALID_PARAMS, format!("invalid params: {e}")))
+}
+
+fn to_result<T: Serialize>(value: &T) -> RpcResult {
+    serde_json::to_value(value).map_err(|e| RpcError::new(INTERNAL_ERROR, e.to_string()))
+}
+
+/// Distinguish an absent field (`None`) from an explicit `null` (`Some(None)`).
+fn nullable<'de, D, T>(deserializer: D) -> std::result::Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+/// Priority given as a number (`1`) or as on the command line (`"P1"`).
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub(crate) enum PriorityParam {
+    Number(i32),
+    Text(String),
+}
+
+impl PriorityParam {
+    fn parse(&self) -> Result<Priority> {
+        match self {
+            Self::Number(value) => value.to_string().parse(),
+            Self::Text(value) => value.parse(),
+        }
+    }
+}
+
+fn parse_all<T: FromStr<Err = BeadsError>>(values: &[String]) -> Result<Option<Vec<T>>> {
+    if values.is_empty() {
+        return Ok(None);
+    }
+    values
+        .iter()
+        .map(|value| value.parse())
+        .collect::<Result<Vec<T>>>()
+        .map(Some)
+}
+
+fn parse_priorities(values: &[PriorityParam]) -> Result<Option<Vec<Priority>>> {
+    if values.is_empty() {
+        return Ok(None);
+    }
+    values
+        .iter()
+        .map(PriorityParam::parse)
+        .collect::<Result<Vec<_>>>()
+        .map(Some)
+}
+
+fn non_empty(values: &[String]) -> Option<Vec<String>> {
+    (!values.is_empty()).then(|| values.to_vec())
+}
+
+fn parse_time(value: Option<&str>, field: &str) -> Result<Option<chrono::DateTime<chrono::Utc>>> {
+    value
+        .map(|v| parse_flexible_timestamp(v, field))
+        .transpose()
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct IdParams {
+    /// Issue ID: full, without prefix, or a unique partial hash
+    id: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct CloseParams {
+    id: String,
+    /// Close reason (default "done")
+    #[serde(default)]
+    reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct DepParams {
+    /// Issue that gets the dependency
+    id: String,
+    /// Issue it depends on
+    depends_on: String,
+    /// Dependency type (default "blocks")
+    #[serde(default, rename = "type")]
+    dep_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct LabelParams {
+    id: String,
+    label: String,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct CommentParams {
+    id: String,
+    text: String,
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+#[allow(clippy::struct_excessive_bools)]
+pub(crate) struct ListParams {
+    /// Only these statuses
+    status: Vec<String>,
+    /// Only these issue types
+    #[serde(rename = "type")]
+    types: Vec<String>,
+    /// Only these priorities (0-4 or P0-P4)
+    priority: Vec<PriorityParam>,
+    assignee: Option<String>,
+    unassigned: bool,
+    /// Labels the issue must all have
+    label: Vec<String>,
+    /// Labels of which the issue needs at least one
+    label_any: Vec<String>,
+    title_contains: Option<String>,
+    /// Include closed issues
+    all: bool,
+    /// Include deferred issues
+    deferred: bool,
+    limit: Option<usize>,
+    sort: Option<String>,
+    reverse: bool,
+    /// Filter expression, as for `br list --where`
+    #[serde(rename = "where")]
+    where_: Option<String>,
+}
+
+impl ListParams {
+    /// Same defaults as `br list`: closed issues only with `all` or an
+    /// explicit terminal status.
+    fn to_filters(&self, actor: &str) -> Result<ListFilters> {
+        let statuses: Option<Vec<Status>> = parse_all(&self.status)?;
+        let include_closed = self.all
+            || statuses
+                .as_ref()
+                .is_some_and(|parsed| parsed.iter().any(Status::is_terminal));
+        let include_deferred = self.deferred
+            || self.all
+            || statuses
+                .as_ref()
+                .is_none_or(|parsed| parsed.contains(&Status::Deferred));
+
+        let mut filters = ListFilters {
+            statuses,
+            types: parse_all(&self.types)?,
+            priorities: parse_priorities(&self.priority)?,
+            assignee: self.assignee.clone(),
+            unassigned: self.unassigned,
+            include_closed,
+            include_deferred,
+            title_contains: self.title_contains.clone(),
+            limit: self.limit,
+            sort: self.sort.clone(),
+            reverse: self.reverse,
+            labels: non_empty(&self.label),
+            labels_or: non_empty(&self.label_any),
+            ..ListFilters::default()
+        };
+        if let Some(input) = &self.where_ {
+            filters.set_where(FilterExpr::parse(input, actor)?);
+        }
+        Ok(filters)
+    }
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub(crate) struct ReadyParams {
+    assignee: Option<String>,
+    unassigned: bool,
+    /// Labels the issue must all have
+    label: Vec<String>,
+    /// Labels of which the issue needs at least one
+    label_any: Vec<String>,
+    #[serde(rename = "type")]
+    types: Vec<String>,
+    priority: Vec<PriorityParam>,
+    include_deferred: bool,
+    limit: Option<usize>,
+    /// "hybrid" (default), "priority" or "oldest"
+    sort: Option<String>,
+    /// Filter expression, as for `br ready --where`
+    #[serde(rename = "where")]
+    where_: Option<String>,
+}
+
+impl ReadyParams {
+    fn to_filters(&self, actor: &str) -> Result<(ReadyFilters, ReadySortPolicy)> {
+        let sort = match self.sort.as_deref() {
+            None | Some("hybrid") => ReadySortPolicy::Hybrid,
+            Some("priority") => ReadySortPolicy::Priority,
+            Some("oldest") => ReadySortPolicy::Oldest,
+            Some(other) => {
+                return Err(BeadsError::validation(
+                    "sort",
+                    format!("unknown sort '{other}' (expected hybrid, priority or oldest)"),
+                ));
+            }
+        };
+        let filters = ReadyFilters {
+            assignee: self.assignee.clone(),
+            unassigned: self.unassigned,
+            labels_and: self.label.clone(),
+            labels_or: self.label_any.clone(),
+            types: parse_all(&self.types)?,
+            priorities: parse_priorities(&self.priority)?,
+            include_deferred: self.include_deferred,
+            limit: self.limit,
+            where_expr: self
+                .where_
+                .as_deref()
+                .map(|input| FilterExpr::parse(input, actor))
+                .transpose()?,
+            claimable_by: None,
+        };
+        Ok((filters, sort))
+    }
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct CreateParams {
+    title: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default, rename = "type")]
+    issue_type: Option<String>,
+    #[serde(default)]
+    priority: Option<PriorityParam>,
+    #[serde(default)]
+    status: Option<String>,
+    #[serde(default)]
+    assignee: Option<String>,
+    #[serde(default)]
+    owner: Option<String>,
+    #[serde(default)]
+    labels: Vec<String>,
+    /// Parent issue; the new issue gets a child ID under it
+    #[serde(default)]
+    parent: Option<String>,
+    /// Dependencies as `id` or `type:id`
+    #[serde(default)]
+    deps: Vec<String>,
+    /// Estimate in minutes
+    #[serde(default)]
+    estimate: Option<i32>,
+    /// Due date: 2025-01-15, +3d, RFC3339, ...
+    #[serde(default)]
+    due: Option<String>,
+    /// Hide from ready until this time
+    #[serde(default)]
+    defer: Option<String>,
+    #[serde(default)]
+    external_ref: Option<String>,
+    #[serde(default)]
+    ephemeral: bool,
+}
+
+impl CreateParams {
+    fn to_new_issue(&self) -> Result<NewIssue> {
+        Ok(NewIssue {
+            title: self.title.clone(),
+            description: self.description.clone(),
+            issue_type: self
+                .issue_type
+                .as_deref()
+                .map(IssueType::from_str)
+                .transpose()?,
+            priority: self
+                .priority
+                .as_ref()
+                .map(PriorityParam::parse)
+                .transpose()?,
+            status: self.status.as_deref().map(Status::from_str).transpose()?,
+            assignee: self.assignee.clone(),
+            owner: self.owner.clone(),
+            labels: self.labels.clone(),
+            parent: self.parent.clone(),
+            deps: self.deps.clone(),
+            estimated_minutes: self.estimate,
+            due_at: parse_time(self.due.as_deref(), "due")?,
+            defer_until: parse_time(self.defer.as_deref(), "defer")?,
+            external_ref: self.external_ref.clone(),
+            ephemeral: self.ephemeral,
+        })
+    }
+}
+
+/// Fields to change. An explicit `null` clears an optional field.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct UpdateParams {
+    id: String,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default, deserialize_with = "nullable")]
+    description: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
+    design: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
+    acceptance_criteria: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
+    notes: Option<Option<String>>,
+    #[serde(default)]
+    status: Option<String>,
+    #[serde(default)]
+    priority: Option<PriorityParam>,
+    #[serde(default, rename = "type")]
+    issue_type: Option<String>,
+    #[serde(default, deserialize_with = "nullable")]
+    assignee: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
+    owner: Option<Option<String>>,
+    /// Estimate in minutes
+    #[serde(default, deserialize_with = "nullable")]
+    estimate: Option<Option<i32>>,
+    #[serde(default, deserialize_with = "nullable")]
+    due: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
+    defer: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
+    external_ref: Option<Option<String>>,
+}
+
+impl UpdateParams {
+    fn to_update(&self) -> Result<IssueUpdate> {
+        let status = self.status.as_deref().map(Status::from_str).transpose()?;
+        // Same closed_at handling as `br update --status`
+        let closed_at = match &status {
+            Some(Status::Closed | Status::Tombstone) => Some(Some(chrono::Utc::now())),
+            Some(Status::Open | Status::InProgress) => Some(None),
+            _ => None,
+        };
+        let due_at = match &self.due {
+            Some(value) => Some(parse_time(value.as_deref(), "due")?),
+            None => None,
+        };
+        let defer_until = match &self.defer {
+            Some(value) => Some(parse_time(value.as_deref(), "defer")?),
+            None => None,
+        };
+
+        Ok(IssueUpdate {
+            title: self.title.clone(),
+            description: self.description.clone(),
+            design: self.design.clone(),
+            acceptance_criteria: self.acceptance_criteria.clone(),
+            notes: self.notes.clone(),
+            status,
+            priority: self
+                .priority
+                .as_ref()
+                .map(PriorityParam::parse)
+                .transpose()?,
+            issue_type: self
+                .issue_type
+                .as_deref()
+                .map(IssueType::from_str)
+                .transpose()?,
+            assignee: self.assignee.clone(),
+            owner: self.owner.clone(),
+            estimated_minutes: self.estimate,
+            due_at,
+            defer_until,
+            external_ref: self.external_ref.clone(),
+            closed_at,
+            ..IssueUpdate::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::SqliteStorage;
+    use tempfile::TempDir;
+
+    fn server() -> (TempDir, Server) {
+        let temp = TempDir::new().unwrap();
+        let beads_dir = temp.path().join(".beads");
+        std::fs::create_dir(&beads_dir).unwrap();
+        let mut storage = SqliteStorage::open(&beads_dir.join("beads.db")).unwrap();
+        storage.set_config("issue_prefix", "rpc").unwrap();
+        drop(storage);
+        let mut workspace = Workspace::open(&beads_dir).unwrap();
+        workspace.set_auto_flush(false);
+        (temp, Server::new(workspace))
+    }
+
+    fn call(server: &mut Server, request: &Value) -> Value {
+        let line = server.handle_line(&request.to_string()).unwrap();
+        serde_json::from_str(&line).unwrap()
+    }
+
+    #[test]
+    fn test_create_then_list_and_debounced_flush() {
+        let (_temp, mut server) = server();
+        let created = call(
+            &mut server,
+            &json!({"jsonrpc": "2.0", "id": 1, "method": "create",
+                    "params": {"title": "From RPC", "priority": 1, "labels": ["api"]}}),
+        );
+        assert_eq!(created["id"], 1);
+        let id = created["result"]["id"].as_str().unwrap().to_string();
+        assert!(id.starts_with("rpc-"));
+        assert!(server.last_mutation.is_some());
+
+        let listed = call(
+            &mut server,
+            &json!({"jsonrpc": "2.0", "id": 2, "method": "list", "params": {"priority": ["P1"]}}),
+        );
+        assert_eq!(listed["result"][0]["id"], id);
+
+        server.flush_pending().unwrap();
+        assert!(server.last_mutation.is_none());
+        assert!(server.workspace.paths().jsonl_path.exists());
+    }
+
+    #[test]
+    fn test_each_mutation_restarts_debounce() {
+        let (_temp, mut server) = server();
+        let create = |id: i64| json!({"jsonrpc": "2.0", "id": id, "method": "create", "params": {"title": "Again"}});
+        call(&mut server, &create(1));
+        let first = server.last_mutation.unwrap();
+        thread::sleep(Duration::from_millis(5));
+        call(&mut server, &create(2));
+        assert!(server.last_mutation.unwrap() > first);
+    }
+
+    #[test]
+    fn test_errors_use_jsonrpc_codes() {
+        let (_temp, mut server) = server();
+        let unknown = call(
+            &mut server,
+            &json!({"jsonrpc": "2.0", "id": 1, "method": "frobnicate"}),
+        );
+        assert_eq!(unknown["error"]["code"], METHOD_NOT_FOUND);
+
+        assert_eq!(unknown["error"]["message"], "unknown method 'frobnicate'");
+
+        let missing = call(
+            &mut server,
+            &json!({"jsonrpc": "2.0", "id": 2, "method": "show", "params": {"id": "rpc-zzz"}}),
+        );
+        assert_eq!(missing["error"]["code"], COMMAND_ERROR);
+        assert_eq!(missing["error"]["data"]["code"], "ISSUE_NOT_FOUND");
+
+        let bad = server.handle_line("{not json").unwrap();
+        assert!(bad.contains(&PARSE_ERROR.to_string()));
+
+        // Notifications are executed but not answered
+        assert!(
+            server
+                .handle_line(r#"{"jsonrpc": "2.0", "method": "shutdown"}"#)
+                .is_none()
+        );
+        assert!(server.shutdown);
+    }
+
+    #[test]
+    fn test_invalid_params() {
+        let (_temp, mut server) = server();
+        for (id, method, params) in [
+            (1, "show", json!({})),
+            (2, "create", json!({"title": 7})),
+            (3, "update", json!({"id": "rpc-1", "colour": "red"})),
+        ] {
+            let response = call(
+                &mut server,
+                &json!({"jsonrpc": "2.0", "id": id, "method": method, "params": params}),
+            );
+            assert_eq!(response["error"]["code"], INVALID_PARAMS, "{method}");
+            assert!(
+                response["error"]["message"]
+                    .as_str()
+                    .unwrap()
+                    .starts_with("invalid params"),
+                "{response}"
+            );
+        }
+        assert!(server.last_mutation.is_none());
+    }
+
+    #[test]
+    fn test_command_errors_carry_structured_payload() {
+        let (_temp, mut server) = server();
+        let missing = call(
+            &mut server,
+            &json!({"jsonrpc": "2.0", "id": 1, "method": "close", "params": {"id": "rpc-zzz"}}),
+        );
+        let expected = StructuredError::from_error(&BeadsError::IssueNotFound {
+            id: "rpc-zzz".to_string(),
+        });
+        assert_eq!(missing["error"]["code"], COMMAND_ERROR);
+        assert_eq!(missing["error"]["message"], expected.message);
+        assert_eq!(missing["error"]["data"], expected.to_json()["error"]);
+        assert!(missing["error"]["data"]["retryable"].is_boolean());
+    }
+
+    #[test]
+    fn test_reimports_jsonl_changed_by_another_process() {
+        let (_temp, mut server) = server();
+        let created = call(
+            &mut server,
+            &json!({"jsonrpc": "2.0", "id": 1, "method": "create", "params": {"title": "Before"}}),
+        );
+        let id = created["result"]["id"].as_str().unwrap().to_string();
+        server.flush_pending().unwrap();
+
+        // Another process retitles the issue in the JSONL
+        let jsonl_path = server.workspace.paths().jsonl_path.clone();
+        let mut issue: Value =
+            serde_json::from_str(std::fs::read_to_string(&jsonl_path).unwrap().trim()).unwrap();
+        issue["title"] = json!("After");
+        issue["updated_at"] = json!("2099-01-01T00:00:00Z");
+        std::fs::write(&jsonl_path, format!("{issue}\n")).unwrap();
+        std::fs::File::options()
+            .write(true)
+            .open(&jsonl_path)
+            .unwrap()
+            .set_modified(SystemTime::now() + Duration::from_secs(5))
+            .unwrap();
+
+        let shown = call(
+            &mut server,
+            &json!({"jsonrpc": "2.0", "id": 2, "method": "show", "params": {"id": id}}),
+        );
+        assert_eq!(shown["result"]["title"], "After", "{shown}");
+    }
+
+    #[test]
+    fn test_shutdown_flushes_pending_mutations() {
+        let (_temp, mut server) = server();
+        let (tx, rx) = mpsc::channel();
+        for line in [
+            json!({"jsonrpc": "2.0", "id": 1, "method": "create", "params": {"title": "Unsaved"}}),
+            json!({"jsonrpc": "2.0", "id": 2, "method": "shutdown"}),
+            json!({"jsonrpc": "2.0", "id": 3, "method": "list"}),
+        ] {
+            tx.send(line.to_string()).unwrap();
+        }
+
+        // The debounce never expires here, so only the shutdown can flush
+        let mut out = Vec::new();
+        serve_lines(
+            &mut server,
+            &rx,
+            &mut out,
+            Duration::from_secs(3600),
+            Server::handle_line,
+        )
+        .unwrap();
+
+        let responses: Vec<Value> = String::from_utf8(out)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(responses.len(), 2, "requests after shutdown were answered");
+        assert!(responses[1]["result"].is_null());
+        assert!(server.last_mutation.is_none());
+        let jsonl = std::fs::read_to_string(&server.workspace.paths().jsonl_path).unwrap();
+        assert!(jsonl.contains("Unsaved"));
+    }
+}
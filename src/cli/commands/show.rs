@@ -1,10 +1,12 @@
 //! Show command implementation.
 
-use crate::cli::{ShowArgs, resolve_output_format_basic};
+use crate::cli::{ShowArgs, print_as_of_notes, resolve_output_format_basic};
 use crate::config;
+use crate::config::fields::display_value;
 use crate::error::{BeadsError, Result};
 use crate::format::{format_priority_label, format_status_icon_colored};
 use crate::output::{IssuePanel, OutputContext, OutputMode};
+use crate::sync::as_of;
 use crate::util::id::{IdResolver, ResolverConfig};
 use std::fmt::Write as FmtWrite;
 
@@ -43,6 +45,16 @@ pub fn execute(
     let quiet = cli.quiet.unwrap_or(false);
     let ctx = OutputContext::from_output_format(output_format, quiet, !use_color);
 
+    let snapshot = args
+        .as_of
+        .as_deref()
+        .map(|input| as_of::reconstruct_for_arg(input, &storage_ctx))
+        .transpose()?;
+    let storage = snapshot
+        .as_ref()
+        .map_or(storage, |snapshot| &snapshot.storage);
+
+    let mut routed = config::RoutedIssueLookup::new(&beads_dir);
     let mut details_list = Vec::new();
     for id_input in target_ids {
         let resolution = resolver.resolve(
@@ -52,7 +64,8 @@ pub fn execute(
         )?;
 
         // Fetch full details including comments and events
-        if let Some(details) = storage.get_issue_details(&resolution.id, true, false, 10)? {
+        if let Some(mut details) = storage.get_issue_details(&resolution.id, true, false, 10)? {
+            fill_routed_dependencies(&mut details, &mut routed);
             details_list.push(details);
         } else {
             return Err(BeadsError::IssueNotFound { id: resolution.id });
@@ -63,12 +76,21 @@ pub fn execute(
         return Ok(());
     }
     match output_format {
-        crate::cli::OutputFormat::Json => {
-            ctx.json_pretty(&details_list);
-        }
-        crate::cli::OutputFormat::Toon => {
-            ctx.toon_with_stats(&details_list, args.stats);
-        }
+        crate::cli::OutputFormat::Json => match &snapshot {
+            Some(snapshot) => {
+                ctx.json_pretty(
+                    &snapshot.records(details_list, |details| details.issue.id.as_str()),
+                );
+            }
+            None => ctx.json_pretty(&details_list),
+        },
+        crate::cli::OutputFormat::Toon => match &snapshot {
+            Some(snapshot) => ctx.toon_with_stats(
+                &snapshot.records(details_list, |details| details.issue.id.as_str()),
+                args.stats,
+            ),
+            None => ctx.toon_with_stats(&details_list, args.stats),
+        },
         crate::cli::OutputFormat::Text | crate::cli::OutputFormat::Csv => {
             for (i, details) in details_list.iter().enumerate() {
                 if i > 0 {
@@ -81,12 +103,37 @@ pub fn execute(
                     print_issue_details(details, use_color);
                 }
             }
+            if let Some(snapshot) = &snapshot {
+                print_as_of_notes(
+                    &ctx,
+                    snapshot,
+                    details_list.iter().map(|details| details.issue.id.as_str()),
+                );
+            }
         }
     }
 
     Ok(())
 }
 
+/// Fill title, status and priority of dependencies that live in another
+/// routed workspace, which have no local row to join against.
+fn fill_routed_dependencies(
+    details: &mut crate::format::IssueDetails,
+    routed: &mut config::RoutedIssueLookup,
+) {
+    for dep in &mut details.dependencies {
+        if !dep.title.is_empty() || dep.id.starts_with("external:") {
+            continue;
+        }
+        if let Some(target) = routed.get_issue(&dep.id) {
+            dep.title = target.title;
+            dep.status = target.status;
+            dep.priority = target.priority;
+        }
+    }
+}
+
 fn print_issue_details(details: &crate::format::IssueDetails, use_color: bool) {
     let output = format_issue_details(details, use_color);
     print!("{output}");
@@ -134,6 +181,15 @@ fn format_issue_details(details: &crate::format::IssueDetails, use_color: bool)
         let _ = writeln!(output, "Labels: {}", details.labels.join(", "));
     }
 
+    if !issue.fields.is_empty() {
+        let fields: Vec<String> = issue
+            .fields
+            .iter()
+            .map(|(name, value)| format!("{name}={}", display_value(value)))
+            .collect();
+        let _ = writeln!(output, "Fields: {}", fields.join(", "));
+    }
+
     if let Some(desc) = &issue.description {
         output.push('\n');
         let _ = writeln!(output, "{desc}");
@@ -180,6 +236,7 @@ mod tests {
     use crate::storage::SqliteStorage;
     use crate::util::id::{IdResolver, ResolverConfig};
     use chrono::{TimeZone, Utc};
+    use std::collections::BTreeMap;
     use tracing::info;
 
     fn init_logging() {
@@ -206,6 +263,7 @@ mod tests {
             updated_at: Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             due_at: None,
             defer_until: None,
@@ -227,6 +285,7 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -171,6 +171,7 @@ fn render_stale_rich(
 mod tests {
     use super::*;
     use crate::model::{IssueType, Priority};
+    use std::collections::BTreeMap;
     use tracing::info;
 
     fn init_logging() {
@@ -196,6 +197,7 @@ mod tests {
             updated_at,
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             due_at: None,
             defer_until: None,
@@ -218,6 +220,7 @@ mod tests {
             dependencies: vec![],
             comments: vec![],
             content_hash: None,
+            fields: BTreeMap::new(),
         }
     }
 
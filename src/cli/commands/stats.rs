@@ -5,6 +5,7 @@
 
 use crate::cli::{OutputFormat, StatsArgs, resolve_output_format_basic};
 use crate::config;
+use crate::config::workflow::Workflow;
 use crate::error::Result;
 use crate::format::{
     Breakdown, BreakdownEntry, RecentActivity, Statistics, StatsSummary, truncate_title,
@@ -12,7 +13,8 @@ use crate::format::{
 use crate::model::{IssueType, Status};
 use crate::output::{OutputContext, OutputMode};
 use crate::storage::{ListFilters, SqliteStorage};
-use chrono::Utc;
+use crate::sync::as_of;
+use chrono::{DateTime, Utc};
 use rich_rust::prelude::*;
 use std::collections::BTreeMap;
 use std::io::{BufRead, BufReader};
@@ -35,6 +37,17 @@ pub fn execute(
     let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
     let storage = &storage_ctx.storage;
     let config_layer = config::load_config(&beads_dir, Some(storage), cli)?;
+    let snapshot = args
+        .as_of
+        .as_deref()
+        .map(|input| as_of::reconstruct_for_arg(input, &storage_ctx))
+        .transpose()?;
+    let storage = snapshot
+        .as_ref()
+        .map_or(storage, |snapshot| &snapshot.storage);
+    let now = snapshot
+        .as_ref()
+        .map_or_else(Utc::now, |snapshot| snapshot.as_of);
     let use_color = config::should_use_color(&config_layer);
     let output_format = resolve_output_format_basic(args.format, outer_ctx.is_json(), args.robot);
     let quiet = cli.quiet.unwrap_or(false);
@@ -53,7 +66,7 @@ pub fn execute(
     debug!(total = all_issues.len(), "Loaded all issues for stats");
 
     // Compute summary counts
-    let summary = compute_summary(storage, &all_issues)?;
+    let summary = compute_summary(storage, &all_issues, now)?;
 
     // Compute breakdowns if requested
     let mut breakdowns = Vec::new();
@@ -70,10 +83,17 @@ pub fn execute(
     if args.by_label {
         breakdowns.push(compute_label_breakdown(storage, &all_issues)?);
     }
+    // Custom statuses fall outside the summary counts, so list them whenever present
+    if let Some(breakdown) =
+        compute_custom_status_breakdown(&all_issues, storage_ctx.storage.workflow())
+    {
+        breakdowns.push(breakdown);
+    }
 
     // Compute recent activity by default (matches bd behavior).
-    // Use --no-activity to skip this (for performance).
-    let recent_activity = if args.no_activity {
+    // Use --no-activity to skip this (for performance). Git activity is
+    // measured back from now, so it means nothing for a past snapshot.
+    let recent_activity = if args.no_activity || snapshot.is_some() {
         None
     } else {
         compute_recent_activity(&beads_dir, args.activity_hours)
@@ -83,6 +103,17 @@ pub fn execute(
         summary,
         breakdowns,
         recent_activity,
+        as_of: snapshot.as_ref().map(|snapshot| snapshot.as_of),
+        unreconstructed_issues: snapshot
+            .as_ref()
+            .map(|snapshot| {
+                snapshot
+                    .partial(all_issues.iter().map(|issue| issue.id.as_str()))
+                    .into_iter()
+                    .map(|(id, _)| id)
+                    .collect()
+            })
+            .unwrap_or_default(),
     };
 
     // Output based on mode
@@ -109,6 +140,15 @@ pub fn execute(
             } else {
                 print_text_output(&output);
             }
+            if let Some(as_of) = output.as_of {
+                ctx.info(&format!("As of {}", as_of.format("%Y-%m-%d %H:%M UTC")));
+                if !output.unreconstructed_issues.is_empty() {
+                    ctx.warning(&format!(
+                        "~ {} issue(s) have fields that could not be reconstructed (see --json)",
+                        output.unreconstructed_issues.len()
+                    ));
+                }
+            }
         }
     }
 
@@ -120,6 +160,7 @@ pub fn execute(
 fn compute_summary(
     storage: &SqliteStorage,
     issues: &[crate::model::Issue],
+    now: DateTime<Utc>,
 ) -> Result<StatsSummary> {
     let mut open = 0;
     let mut in_progress = 0;
@@ -168,7 +209,6 @@ fn compute_summary(
     }
 
     // Ready count: status=open (not in_progress), no blockers (full definition).
-    let now = Utc::now();
     let ready = issues
         .iter()
         .filter(|i| {
@@ -269,6 +309,33 @@ fn compute_type_breakdown(issues: &[crate::model::Issue]) -> Breakdown {
     }
 }
 
+/// Compute breakdown by custom status, labelled with the workflow category.
+fn compute_custom_status_breakdown(
+    issues: &[crate::model::Issue],
+    workflow: &Workflow,
+) -> Option<Breakdown> {
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+
+    for issue in issues {
+        if let Status::Custom(name) = &issue.status {
+            let key = format!("{name} ({})", workflow.category(&issue.status));
+            *counts.entry(key).or_insert(0) += 1;
+        }
+    }
+
+    if counts.is_empty() {
+        return None;
+    }
+
+    Some(Breakdown {
+        dimension: "custom_status".to_string(),
+        counts: counts
+            .into_iter()
+            .map(|(key, count)| BreakdownEntry { key, count })
+            .collect(),
+    })
+}
+
 /// Compute breakdown by priority.
 fn compute_priority_breakdown(issues: &[crate::model::Issue]) -> Breakdown {
     let mut counts: BTreeMap<i32, usize> = BTreeMap::new();
@@ -418,6 +485,9 @@ fn compute_recent_activity(beads_dir: &Path, hours: u32) -> Option<RecentActivit
 
 /// Print robot-friendly key=value output for script consumption.
 fn print_robot_output(output: &Statistics) {
+    if let Some(as_of) = output.as_of {
+        println!("as_of={}", as_of.to_rfc3339());
+    }
     let s = &output.summary;
     println!(
         "total={} open={} in_progress={} closed={} ready={} blocked={} deferred={} tombstone={} pinned={}",
@@ -761,6 +831,7 @@ mod tests {
             updated_at: Utc::now(),
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             due_at: None,
             defer_until: None,
@@ -783,6 +854,7 @@ mod tests {
             dependencies: vec![],
             comments: vec![],
             content_hash: None,
+            fields: BTreeMap::new(),
         }
     }
 
@@ -867,7 +939,7 @@ mod tests {
         storage.create_issue(&third_issue, "tester").unwrap();
 
         let all_issues = vec![first_issue, second_issue, third_issue];
-        let summary = compute_summary(&storage, &all_issues).unwrap();
+        let summary = compute_summary(&storage, &all_issues, Utc::now()).unwrap();
 
         assert_eq!(summary.total_issues, 3);
         assert_eq!(summary.open_issues, 1);
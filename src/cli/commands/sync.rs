@@ -3,10 +3,14 @@
 //! Provides explicit JSONL sync actions without git operations.
 //! Supports `--flush-only` (export) and `--import-only` (import).
 
-use crate::cli::SyncArgs;
+use crate::cli::{SyncArgs, SyncCommands, SyncResolveArgs};
 use crate::config;
 use crate::error::{BeadsError, Result};
 use crate::output::OutputContext;
+use crate::sync::conflicts::{
+    ConflictRecord, apply_resolutions, conflicts_path, load_conflicts, save_conflicts,
+};
+use crate::sync::events::{events_path_for, import_events};
 use crate::sync::history::HistoryConfig;
 use crate::sync::{
     ConflictResolution, ExportConfig, ExportEntityType, ExportError, ExportErrorPolicy,
@@ -105,18 +109,26 @@ pub fn execute(
         "Resolved sync path policy"
     );
 
+    if let Some(SyncCommands::Resolve(resolve_args)) = &args.command {
+        return execute_resolve_choice(&path_policy, resolve_args, use_json, ctx);
+    }
+
     // Handle --status flag
     if args.status {
         return execute_status(&storage, &path_policy, json, ctx);
     }
 
     // Validate mutually exclusive modes
-    let mode_count = u8::from(args.flush_only) + u8::from(args.import_only) + u8::from(args.merge);
+    let mode_count = u8::from(args.flush_only)
+        + u8::from(args.import_only)
+        + u8::from(args.merge)
+        + u8::from(args.resolve);
     if mode_count > 1 {
         return Err(BeadsError::Validation {
             field: "mode".to_string(),
-            reason: "Must specify exactly one of --flush-only, --import-only, or --merge"
-                .to_string(),
+            reason:
+                "Must specify exactly one of --flush-only, --import-only, --merge, or --resolve"
+                    .to_string(),
         });
     }
 
@@ -131,7 +143,7 @@ pub fn execute(
             retention_days,
             ctx,
         )
-    } else if args.merge {
+    } else if args.merge || args.resolve {
         execute_merge(
             &mut storage,
             &path_policy,
@@ -577,6 +589,7 @@ fn execute_flush(
         allow_external_jsonl: args.allow_external_jsonl,
         show_progress,
         history: HistoryConfig::default(),
+        export_events: config::sync_events_enabled(&path_policy.beads_dir),
     };
 
     // Execute export
@@ -932,6 +945,7 @@ fn execute_import(
         beads_dir: Some(path_policy.beads_dir.clone()),
         allow_external_jsonl: args.allow_external_jsonl,
         show_progress,
+        import_events: config::sync_events_enabled(&path_policy.beads_dir),
     };
 
     // Get expected prefix from config, or auto-detect from JSONL
@@ -1145,12 +1159,27 @@ fn execute_merge(
 
     // 4. Perform Merge
     let context = MergeContext::new(base, left, right);
-    // Currently hardcoded to PreferNewer (Last Write Wins).
-    // Future work: support configurable conflict strategy via CLI args if needed.
-    let strategy = ConflictResolution::PreferNewer;
+    // --merge resolves overlapping field edits by PreferNewer (Last Write Wins);
+    // --resolve defers them to .beads/conflicts.jsonl for a human or agent.
+    let strategy = if args.resolve {
+        ConflictResolution::Manual
+    } else {
+        ConflictResolution::PreferNewer
+    };
     let tombstones = None;
 
-    let report = three_way_merge(&context, strategy, tombstones);
+    let mut report = three_way_merge(&context, strategy, tombstones);
+
+    if args.resolve {
+        let conflicts_file = conflicts_path(beads_dir);
+        let existing = load_conflicts(&conflicts_file)?;
+        let pending = apply_resolutions(&mut report, &context, &existing);
+        save_conflicts(&conflicts_file, &pending)?;
+        if !pending.is_empty() {
+            render_pending_conflicts(&pending, &conflicts_file, ctx);
+            return Ok(());
+        }
+    }
 
     // 5. Apply Changes to DB
     info!(
@@ -1163,13 +1192,18 @@ fn execute_merge(
     if report.has_conflicts() {
         // For now, fail on conflicts. Future: interactive resolution or force flags.
         if ctx.is_rich() {
-            render_merge_conflicts_rich(&report.conflicts, ctx);
+            render_merge_conflicts_rich(
+                &report.conflicts,
+                "Use `br sync --resolve` to resolve conflicts manually.",
+                ctx,
+            );
         }
         let mut msg = String::from("Merge conflicts detected:\n");
         for (id, kind) in &report.conflicts {
             use std::fmt::Write;
             let _ = writeln!(msg, "  - {id}: {kind:?}");
         }
+        msg.push_str("Hint: run `br sync --resolve` to resolve them manually.");
         return Err(BeadsError::Config(msg));
     }
 
@@ -1189,8 +1223,17 @@ fn execute_merge(
         storage.sync_comments_for_import(&issue.id, &issue.comments)?;
     }
 
-    // Rebuild cache
+    // Rebuild caches
     storage.rebuild_blocked_cache(true)?;
+    storage.rebuild_search_index()?;
+
+    // Pick up events recorded on the other side (opt-in event log)
+    if config::sync_events_enabled(&path_policy.beads_dir) {
+        let events_path = events_path_for(jsonl_path);
+        if events_path.is_file() {
+            import_events(storage, &events_path)?;
+        }
+    }
 
     // Save Base Snapshot
     let new_base: HashMap<_, _> = report
@@ -1211,6 +1254,7 @@ fn execute_merge(
         allow_external_jsonl: args.allow_external_jsonl,
         show_progress,
         history: HistoryConfig::default(),
+        export_events: config::sync_events_enabled(&path_policy.beads_dir),
     };
 
     let (export_result, _) = export_to_jsonl_with_policy(storage, jsonl_path, &export_config)?;
@@ -1224,6 +1268,7 @@ fn execute_merge(
             "deleted_issues": report.deleted.len(),
             "conflicts": report.conflicts.len(),
             "notes": report.notes,
+            "field_decisions": report.field_decisions,
         });
         ctx.json_pretty(&output);
     } else if ctx.is_rich() {
@@ -1238,6 +1283,17 @@ fn execute_merge(
                 println!("    - {id}: {note}");
             }
         }
+        if !report.field_decisions.is_empty() {
+            println!("  Field decisions:");
+            for decision in &report.field_decisions {
+                println!(
+                    "    - {} {}: {}",
+                    decision.issue_id,
+                    decision.field,
+                    decision.decision.as_str()
+                );
+            }
+        }
         println!("  Base snapshot updated.");
         println!("  JSONL exported.");
     }
@@ -1245,9 +1301,123 @@ fn execute_merge(
     Ok(())
 }
 
+/// Report conflicts written by `--resolve` that still need a decision.
+fn render_pending_conflicts(
+    pending: &[ConflictRecord],
+    conflicts_file: &Path,
+    ctx: &OutputContext,
+) {
+    if ctx.is_json() {
+        let conflicts: Vec<_> = pending
+            .iter()
+            .map(|record| {
+                serde_json::json!({
+                    "id": record.id,
+                    "conflict_type": record.conflict_type,
+                    "fields": record.fields,
+                })
+            })
+            .collect();
+        let output = serde_json::json!({
+            "status": "conflicts_pending",
+            "conflicts_file": conflicts_file.display().to_string(),
+            "conflicts": conflicts,
+        });
+        ctx.json_pretty(&output);
+    } else if ctx.is_rich() {
+        let conflicts: Vec<_> = pending
+            .iter()
+            .map(|record| (record.id.clone(), record.conflict_type))
+            .collect();
+        render_merge_conflicts_rich(
+            &conflicts,
+            "Run `br sync resolve <id> --take local|remote|base`, then `br sync --resolve`.",
+            ctx,
+        );
+    } else {
+        println!(
+            "{} unresolved conflict(s) written to {}:",
+            pending.len(),
+            conflicts_file.display()
+        );
+        for record in pending {
+            if record.fields.is_empty() {
+                println!("  - {}: {:?}", record.id, record.conflict_type);
+            } else {
+                println!(
+                    "  - {}: {:?} ({})",
+                    record.id,
+                    record.conflict_type,
+                    record.fields.join(", ")
+                );
+            }
+        }
+        println!("No changes applied.");
+        println!(
+            "Resolve with `br sync resolve <id> --take local|remote|base` (or edit the file), then re-run `br sync --resolve`."
+        );
+    }
+}
+
+/// Execute `br sync resolve <id> --take <side>`.
+fn execute_resolve_choice(
+    path_policy: &SyncPathPolicy,
+    args: &SyncResolveArgs,
+    json: bool,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let side = args.take;
+
+    let conflicts_file = conflicts_path(&path_policy.beads_dir);
+    let mut records = load_conflicts(&conflicts_file)?;
+    let record = records
+        .iter_mut()
+        .find(|record| record.id == args.id)
+        .ok_or_else(|| BeadsError::Validation {
+            field: "id".to_string(),
+            reason: format!(
+                "No pending conflict for {} (run `br sync --resolve` first)",
+                args.id
+            ),
+        })?;
+    record.take = Some(side);
+    record.resolved = None;
+    let deletes = matches!(record.resolution(), Some(None));
+    save_conflicts(&conflicts_file, &records)?;
+
+    let remaining = records
+        .iter()
+        .filter(|record| !record.is_resolved())
+        .count();
+
+    if json {
+        let output = serde_json::json!({
+            "id": args.id,
+            "take": side.as_str(),
+            "deletes_issue": deletes,
+            "unresolved": remaining,
+        });
+        ctx.json_pretty(&output);
+    } else {
+        let suffix = if deletes {
+            " (issue will be deleted)"
+        } else {
+            ""
+        };
+        println!("Resolved {}: take {}{suffix}", args.id, side.as_str());
+        if remaining == 0 {
+            println!("All conflicts resolved. Run `br sync --resolve` to apply.");
+        } else {
+            println!("{remaining} conflict(s) still unresolved.");
+        }
+    }
+    Ok(())
+}
+
 /// Render merge conflicts with rich formatting.
 fn render_merge_conflicts_rich(
     conflicts: &[(String, crate::sync::ConflictType)],
+    hint: &str,
     ctx: &OutputContext,
 ) {
     let console = Console::default();
@@ -1276,7 +1446,7 @@ fn render_merge_conflicts_rich(
 
     text.append("\n");
     text.append_styled("Hint: ", theme.dimmed.clone());
-    text.append("Use --force to override or resolve manually.");
+    text.append(hint);
 
     let panel = Panel::from_rich_text(&text, ctx.width())
         .title(Text::new("Merge Conflicts"))
@@ -1331,6 +1501,27 @@ fn render_merge_result_rich(report: &crate::sync::MergeReport, ctx: &OutputConte
         }
     }
 
+    // Field decisions section
+    if !report.field_decisions.is_empty() {
+        text.append("\n");
+        text.append_styled("Field decisions:\n", theme.dimmed.clone());
+        for (i, decision) in report.field_decisions.iter().enumerate() {
+            let prefix = if i == report.field_decisions.len() - 1 {
+                "└──"
+            } else {
+                "├──"
+            };
+            text.append_styled(prefix, theme.muted.clone());
+            text.append(" ");
+            text.append_styled(&decision.issue_id, theme.issue_id.clone());
+            text.append(" ");
+            text.append(&decision.field);
+            text.append(": ");
+            text.append_styled(decision.decision.as_str(), theme.muted.clone());
+            text.append("\n");
+        }
+    }
+
     // Final status
     text.append("\n");
     text.append_styled("✓ ", theme.success.clone());
@@ -1349,6 +1540,7 @@ mod tests {
     use crate::model::{Issue, IssueType, Priority, Status};
     use crate::storage::SqliteStorage;
     use chrono::Utc;
+    use std::collections::BTreeMap;
     use tempfile::TempDir;
 
     fn make_test_issue(id: &str, title: &str) -> Issue {
@@ -1371,6 +1563,7 @@ mod tests {
             updated_at: Utc::now(),
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             due_at: None,
             defer_until: None,
@@ -1392,6 +1585,7 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -0,0 +1,577 @@
+//! Template command implementation.
+//!
+//! Templates are ordinary issues with `is_template` set, so they are hidden
+//! from list/ready and never show up as work. `br create --from-template`
+//! copies them into real issues, filling `{{variable}}` placeholders from
+//! `--var key=value`. A template with child templates forms a molecule that
+//! can be instantiated as a whole.
+
+use crate::cli::commands::create::{CreateConfig, generate_issue_id};
+use crate::cli::{TemplateCommands, TemplateCreateArgs, TemplateDeleteArgs, TemplateShowArgs};
+use crate::config;
+use crate::error::{BeadsError, Result};
+use crate::model::{Dependency, DependencyType, Issue, IssueType, Priority, Status};
+use crate::output::OutputContext;
+use crate::storage::{ListFilters, SqliteStorage};
+use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use crate::validation::{IssueValidator, LabelValidator};
+use chrono::Utc;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+use std::str::FromStr;
+use tracing::info;
+
+/// Summary of a template for list/show output.
+#[derive(Debug, Clone, Serialize)]
+pub struct TemplateSummary {
+    pub id: String,
+    pub title: String,
+    pub issue_type: IssueType,
+    pub priority: Priority,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    pub children: usize,
+    pub variables: Vec<String>,
+}
+
+/// Full template view for `br template show`.
+#[derive(Debug, Clone, Serialize)]
+pub struct TemplateDetail {
+    #[serde(flatten)]
+    pub template: Issue,
+    pub variables: Vec<String>,
+    pub children: Vec<TemplateSummary>,
+}
+
+/// Result of `br template delete`.
+#[derive(Debug, Clone, Serialize)]
+pub struct TemplateDeleteOutput {
+    pub deleted: Vec<String>,
+}
+
+/// Execute a template subcommand.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be opened, an ID cannot be
+/// resolved to a template, or validation fails.
+pub fn execute(
+    command: &TemplateCommands,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+
+    let config = CreateConfig {
+        id_config: config::id_config_from_layer(&layer),
+        default_priority: config::default_priority_from_layer(&layer)?,
+        default_issue_type: config::default_issue_type_from_layer(&layer)?,
+        actor: config::resolve_actor(&layer),
+    };
+    let resolver = IdResolver::new(ResolverConfig::with_prefix(config.id_config.prefix.clone()));
+    let storage = &mut storage_ctx.storage;
+
+    match command {
+        TemplateCommands::Create(args) => {
+            let parent = args
+                .parent
+                .as_deref()
+                .map(|input| resolve_template(storage, &resolver, input))
+                .transpose()?;
+            let args = TemplateCreateArgs {
+                parent: parent.map(|template| template.id),
+                ..args.clone()
+            };
+            let template = create_template_impl(storage, &args, &config)?;
+            if ctx.is_json() {
+                let full = storage
+                    .get_issue_for_export(&template.id)?
+                    .unwrap_or(template);
+                ctx.json_pretty(&full);
+            } else {
+                ctx.success(&format!(
+                    "Created template {}: {}",
+                    template.id, template.title
+                ));
+                let variables = template_issue_variables(&template, &template.labels);
+                if !variables.is_empty() {
+                    ctx.print(&format!(
+                        "Variables: {}",
+                        variables.into_iter().collect::<Vec<_>>().join(", ")
+                    ));
+                }
+            }
+        }
+        TemplateCommands::List => template_list(storage, ctx)?,
+        TemplateCommands::Show(args) => template_show(args, storage, &resolver, ctx)?,
+        TemplateCommands::Delete(args) => {
+            template_delete(args, storage, &resolver, &config.actor, ctx)?;
+        }
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+    Ok(())
+}
+
+/// Create a template issue.
+///
+/// # Errors
+///
+/// Returns an error if the title is empty, a field fails validation, the
+/// parent is not a template, or the insert fails.
+pub fn create_template_impl(
+    storage: &mut SqliteStorage,
+    args: &TemplateCreateArgs,
+    config: &CreateConfig,
+) -> Result<Issue> {
+    let title = args.title.trim();
+    if title.is_empty() {
+        return Err(BeadsError::validation("title", "cannot be empty"));
+    }
+    if let Some(parent_id) = &args.parent {
+        let parent = storage
+            .get_issue(parent_id)?
+            .ok_or_else(|| BeadsError::IssueNotFound {
+                id: parent_id.clone(),
+            })?;
+        if !parent.is_template {
+            return Err(BeadsError::validation(
+                "parent",
+                format!("{parent_id} is not a template"),
+            ));
+        }
+    }
+
+    let now = Utc::now();
+    let id = generate_issue_id(
+        storage,
+        title,
+        args.parent.as_deref(),
+        &config.id_config,
+        now,
+    )?;
+
+    let mut issue = Issue {
+        id: id.clone(),
+        title: title.to_string(),
+        description: args.description.clone(),
+        design: args.design.clone(),
+        acceptance_criteria: args.acceptance_criteria.clone(),
+        priority: args
+            .priority
+            .as_deref()
+            .map(Priority::from_str)
+            .transpose()?
+            .unwrap_or(config.default_priority),
+        issue_type: args
+            .type_
+            .as_deref()
+            .map(IssueType::from_str)
+            .transpose()?
+            .unwrap_or_else(|| config.default_issue_type.clone()),
+        status: Status::Open,
+        created_at: now,
+        updated_at: now,
+        created_by: Some(config.actor.clone()),
+        is_template: true,
+        ..Issue::default()
+    };
+
+    for label in &args.labels {
+        let label = label.trim();
+        if label.is_empty() {
+            continue;
+        }
+        // Placeholders are checked as plain words; the rendered label is
+        // validated again when the template is instantiated
+        let probe = substitute(label, |_| Some("x".to_string()));
+        LabelValidator::validate(&probe).map_err(|e| BeadsError::validation("label", e.message))?;
+        issue.labels.push(label.to_string());
+    }
+
+    if let Some(parent_id) = &args.parent {
+        issue.dependencies.push(Dependency {
+            issue_id: id,
+            depends_on_id: parent_id.clone(),
+            dep_type: DependencyType::ParentChild,
+            created_at: now,
+            created_by: Some(config.actor.clone()),
+            metadata: None,
+            thread_id: None,
+        });
+    }
+
+    issue.content_hash = Some(issue.compute_content_hash());
+    IssueValidator::validate(&issue).map_err(BeadsError::from_validation_errors)?;
+
+    storage.create_issue(&issue, &config.actor)?;
+    info!(id = %issue.id, "Template created");
+    Ok(issue)
+}
+
+fn template_list(storage: &SqliteStorage, ctx: &OutputContext) -> Result<()> {
+    let filters = ListFilters {
+        include_closed: true,
+        include_deferred: true,
+        include_templates: true,
+        ..Default::default()
+    };
+    let mut summaries = Vec::new();
+    for template in storage.list_issues(&filters)? {
+        if template.is_template && template.status != Status::Tombstone {
+            summaries.push(template_summary(storage, &template)?);
+        }
+    }
+    summaries.sort_by(|a, b| a.id.cmp(&b.id));
+
+    if ctx.is_json() {
+        ctx.json_pretty(&summaries);
+        return Ok(());
+    }
+    if summaries.is_empty() {
+        ctx.info("No templates");
+        return Ok(());
+    }
+
+    ctx.print("Templates:");
+    for summary in &summaries {
+        let mut line = format!(
+            "  {} [{}] {}",
+            summary.id,
+            summary.issue_type.as_str(),
+            summary.title
+        );
+        if summary.children > 0 {
+            line.push_str(&format!(" ({} children)", summary.children));
+        }
+        if !summary.variables.is_empty() {
+            line.push_str(&format!(" {{{}}}", summary.variables.join(", ")));
+        }
+        ctx.print(&line);
+    }
+    ctx.print(&format!("\n{} template(s) total", summaries.len()));
+    Ok(())
+}
+
+fn template_show(
+    args: &TemplateShowArgs,
+    storage: &SqliteStorage,
+    resolver: &IdResolver,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let template = resolve_template(storage, resolver, &args.id)?;
+    let template = storage
+        .get_issue_for_export(&template.id)?
+        .unwrap_or(template);
+
+    let mut variables = BTreeSet::new();
+    let mut children = Vec::new();
+    for member in molecule_members(storage, template.clone())? {
+        let labels = storage.get_labels(&member.id)?;
+        variables.extend(template_issue_variables(&member, &labels));
+        if member.id != template.id
+            && storage.get_parent_id(&member.id)?.as_deref() == Some(template.id.as_str())
+        {
+            children.push(template_summary(storage, &member)?);
+        }
+    }
+
+    let detail = TemplateDetail {
+        template,
+        variables: variables.into_iter().collect(),
+        children,
+    };
+
+    if ctx.is_json() {
+        ctx.json_pretty(&detail);
+        return Ok(());
+    }
+
+    let template = &detail.template;
+    ctx.print(&format!("{}: {}", template.id, template.title));
+    ctx.print(&format!(
+        "Type: {}  Priority: {}",
+        template.issue_type, template.priority
+    ));
+    if !template.labels.is_empty() {
+        ctx.print(&format!("Labels: {}", template.labels.join(", ")));
+    }
+    for (heading, body) in [
+        ("Description", &template.description),
+        ("Design", &template.design),
+        ("Acceptance Criteria", &template.acceptance_criteria),
+    ] {
+        if let Some(body) = body.as_deref().filter(|body| !body.is_empty()) {
+            ctx.newline();
+            ctx.print(&format!("{heading}:"));
+            ctx.print(body);
+        }
+    }
+    if !detail.variables.is_empty() {
+        ctx.newline();
+        ctx.print(&format!("Variables: {}", detail.variables.join(", ")));
+    }
+    if !detail.children.is_empty() {
+        ctx.newline();
+        ctx.print("Children:");
+        for child in &detail.children {
+            ctx.print(&format!("  {}: {}", child.id, child.title));
+        }
+    }
+    Ok(())
+}
+
+fn template_delete(
+    args: &TemplateDeleteArgs,
+    storage: &mut SqliteStorage,
+    resolver: &IdResolver,
+    actor: &str,
+    ctx: &OutputContext,
+) -> Result<()> {
+    let template = resolve_template(storage, resolver, &args.id)?;
+    let members = molecule_members(storage, template)?;
+
+    // Children first so no child template is left pointing at a deleted parent
+    let mut deleted = Vec::new();
+    for member in members.iter().rev() {
+        storage.delete_issue(&member.id, actor, "template deleted", None)?;
+        deleted.push(member.id.clone());
+    }
+    deleted.reverse();
+    info!(count = deleted.len(), "Templates deleted");
+
+    if ctx.is_json() {
+        ctx.json_pretty(&TemplateDeleteOutput { deleted });
+    } else {
+        for id in &deleted {
+            ctx.success(&format!("Deleted template {id}"));
+        }
+    }
+    Ok(())
+}
+
+fn template_summary(storage: &SqliteStorage, template: &Issue) -> Result<TemplateSummary> {
+    let labels = storage.get_labels(&template.id)?;
+    let children = storage
+        .get_dependents_with_metadata(&template.id)?
+        .into_iter()
+        .filter(|dep| dep.dep_type == DependencyType::ParentChild.as_str())
+        .count();
+    Ok(TemplateSummary {
+        id: template.id.clone(),
+        title: template.title.clone(),
+        issue_type: template.issue_type.clone(),
+        priority: template.priority,
+        parent: storage.get_parent_id(&template.id)?,
+        children,
+        variables: template_issue_variables(template, &labels)
+            .into_iter()
+            .collect(),
+    })
+}
+
+/// Resolve user input to a live template issue.
+///
+/// # Errors
+///
+/// Returns an error if the ID cannot be resolved or the issue is not a template.
+pub fn resolve_template(
+    storage: &SqliteStorage,
+    resolver: &IdResolver,
+    input: &str,
+) -> Result<Issue> {
+    let all_ids = storage.get_all_ids()?;
+    let id = resolver
+        .resolve(
+            input,
+            |id| storage.id_exists(id).unwrap_or(false),
+            |hash| find_matching_ids(&all_ids, hash),
+        )?
+        .id;
+    let issue = storage
+        .get_issue(&id)?
+        .ok_or_else(|| BeadsError::IssueNotFound { id: id.clone() })?;
+    if !issue.is_template || issue.status == Status::Tombstone {
+        return Err(BeadsError::validation(
+            "template",
+            format!("{id} is not a template"),
+        ));
+    }
+    Ok(issue)
+}
+
+/// Collect a template and all of its descendant templates (via parent-child),
+/// parents before children.
+///
+/// # Errors
+///
+/// Returns an error if a database query fails.
+pub fn molecule_members(storage: &SqliteStorage, root: Issue) -> Result<Vec<Issue>> {
+    let mut members = vec![root];
+    let mut index = 0;
+    while index < members.len() {
+        let parent_id = members[index].id.clone();
+        for dependent in storage.get_dependents_with_metadata(&parent_id)? {
+            if dependent.dep_type != DependencyType::ParentChild.as_str()
+                || members.iter().any(|member| member.id == dependent.id)
+            {
+                continue;
+            }
+            if let Some(child) = storage.get_issue(&dependent.id)?
+                && child.is_template
+                && child.status != Status::Tombstone
+            {
+                members.push(child);
+            }
+        }
+        index += 1;
+    }
+    Ok(members)
+}
+
+/// Parse `--var key=value` arguments.
+///
+/// # Errors
+///
+/// Returns an error if an argument has no `=` or an invalid variable name.
+pub fn parse_vars(raw: &[String]) -> Result<HashMap<String, String>> {
+    let mut vars = HashMap::new();
+    for entry in raw {
+        let Some((key, value)) = entry.split_once('=') else {
+            return Err(BeadsError::validation(
+                "var",
+                format!("expected key=value, got '{entry}'"),
+            ));
+        };
+        let key = key.trim();
+        if !is_variable_name(key) {
+            return Err(BeadsError::validation(
+                "var",
+                format!("invalid variable name '{key}'"),
+            ));
+        }
+        vars.insert(key.to_string(), value.to_string());
+    }
+    Ok(vars)
+}
+
+/// Names of the `{{variable}}` placeholders used in a piece of text.
+#[must_use]
+pub fn template_variables(text: &str) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    substitute(text, |name| {
+        names.insert(name.to_string());
+        None
+    });
+    names
+}
+
+/// Names of the placeholders used anywhere in a template's copied fields.
+#[must_use]
+pub fn template_issue_variables(template: &Issue, labels: &[String]) -> BTreeSet<String> {
+    let mut names = template_variables(&template.title);
+    for text in [
+        &template.description,
+        &template.design,
+        &template.acceptance_criteria,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        names.extend(template_variables(text));
+    }
+    for label in labels {
+        names.extend(template_variables(label));
+    }
+    names
+}
+
+/// Replace `{{variable}}` placeholders with their values.
+///
+/// Placeholders without a value are left as written.
+#[must_use]
+pub fn render_template(text: &str, vars: &HashMap<String, String>) -> String {
+    substitute(text, |name| vars.get(name).cloned())
+}
+
+fn substitute(text: &str, mut resolve: impl FnMut(&str) -> Option<String>) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            break;
+        };
+        let name = after[..end].trim();
+        out.push_str(&rest[..start]);
+        let value = if is_variable_name(name) {
+            resolve(name)
+        } else {
+            None
+        };
+        match value {
+            Some(value) => out.push_str(&value),
+            None => out.push_str(&rest[start..start + end + 4]),
+        }
+        rest = &after[end + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn is_variable_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_render_template_substitutes_known_variables() {
+        let rendered = render_template(
+            "Release {{version}} for {{ component }} ({{missing}})",
+            &vars(&[("version", "1.2"), ("component", "api")]),
+        );
+        assert_eq!(rendered, "Release 1.2 for api ({{missing}})");
+    }
+
+    #[test]
+    fn test_render_template_ignores_non_placeholders() {
+        let text = "json {{ \"a\": 1 }} and {{unterminated";
+        assert_eq!(render_template(text, &vars(&[("a", "x")])), text);
+        assert!(template_variables(text).is_empty());
+    }
+
+    #[test]
+    fn test_template_variables_collects_names() {
+        let names = template_variables("{{b}} {{a}} {{b}} {{ c.d }}");
+        assert_eq!(
+            names.into_iter().collect::<Vec<_>>(),
+            vec!["a".to_string(), "b".to_string(), "c.d".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_parse_vars() {
+        let parsed = parse_vars(&["version=1.2=rc".to_string(), " env =prod".to_string()])
+            .expect("parse vars");
+        assert_eq!(parsed.get("version").map(String::as_str), Some("1.2=rc"));
+        assert_eq!(parsed.get("env").map(String::as_str), Some("prod"));
+
+        assert!(parse_vars(&["novalue".to_string()]).is_err());
+        assert!(parse_vars(&["bad name=x".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,152 @@
+//! Tombstones command implementation.
+//!
+//! Lists issues removed with `br delete` that have not been purged yet.
+
+use crate::cli::TombstonesCommands;
+use crate::config;
+use crate::error::Result;
+use crate::model::Issue;
+use crate::output::OutputContext;
+use chrono::{DateTime, Utc};
+use rich_rust::prelude::*;
+use serde::Serialize;
+
+/// A tombstone with its age, for `tombstones list` and `gc`.
+#[derive(Debug, Clone, Serialize)]
+pub struct TombstoneEntry {
+    pub id: String,
+    pub title: String,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub deleted_by: Option<String>,
+    pub delete_reason: Option<String>,
+    pub original_type: Option<String>,
+    /// Whole days since deletion (unknown when `deleted_at` is missing).
+    pub age_days: Option<i64>,
+    /// Past the retention window, so `gc` would purge it.
+    pub expired: bool,
+}
+
+impl TombstoneEntry {
+    #[must_use]
+    pub fn new(issue: &Issue, retention_days: Option<u64>, now: DateTime<Utc>) -> Self {
+        Self {
+            id: issue.id.clone(),
+            title: issue.title.clone(),
+            deleted_at: issue.deleted_at,
+            deleted_by: issue.deleted_by.clone(),
+            delete_reason: issue.delete_reason.clone(),
+            original_type: issue.original_type.clone(),
+            age_days: issue.deleted_at.map(|at| (now - at).num_days()),
+            expired: issue.is_expired_tombstone(retention_days),
+        }
+    }
+
+    fn age_label(&self) -> String {
+        self.age_days
+            .map_or_else(|| "?".to_string(), |days| format!("{days}d"))
+    }
+}
+
+/// Execute a tombstones subcommand.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be opened or queried.
+pub fn execute(
+    command: &TombstonesCommands,
+    cli: &config::CliOverrides,
+    ctx: &OutputContext,
+) -> Result<()> {
+    match command {
+        TombstonesCommands::List => list(cli, ctx),
+    }
+}
+
+fn list(cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+    let retention_days = storage_ctx.paths.metadata.deletions_retention_days;
+
+    let now = Utc::now();
+    let entries: Vec<TombstoneEntry> = storage_ctx
+        .storage
+        .get_tombstones()?
+        .iter()
+        .map(|issue| TombstoneEntry::new(issue, retention_days, now))
+        .collect();
+
+    if ctx.is_json() {
+        ctx.json_pretty(&entries);
+        return Ok(());
+    }
+    if ctx.is_quiet() {
+        return Ok(());
+    }
+    if entries.is_empty() {
+        println!("No tombstones.");
+        return Ok(());
+    }
+    if ctx.is_rich() {
+        render_table(&entries, "Tombstones", ctx);
+    } else {
+        print_plain(&entries);
+    }
+    Ok(())
+}
+
+/// One line per tombstone: ID, age, who deleted it, original type, title, reason.
+pub fn print_plain(entries: &[TombstoneEntry]) {
+    for entry in entries {
+        println!(
+            "{}  {:>5}  {}  [{}] {}{}",
+            entry.id,
+            entry.age_label(),
+            entry.deleted_by.as_deref().unwrap_or("-"),
+            entry.original_type.as_deref().unwrap_or("-"),
+            entry.title,
+            entry
+                .delete_reason
+                .as_deref()
+                .map(|reason| format!(" ({reason})"))
+                .unwrap_or_default()
+        );
+    }
+}
+
+/// Render tombstones as a table, dimming entries still inside retention.
+pub fn render_table(entries: &[TombstoneEntry], title: &str, ctx: &OutputContext) {
+    let theme = ctx.theme();
+    let mut table = Table::new()
+        .title(Text::styled(title, theme.panel_title.clone()))
+        .box_style(theme.box_style)
+        .border_style(theme.panel_border.clone())
+        .with_column(Column::new("ID").min_width(10).max_width(20))
+        .with_column(Column::new("Age").min_width(4).max_width(8))
+        .with_column(Column::new("Deleted by").min_width(8).max_width(20))
+        .with_column(Column::new("Type").min_width(4).max_width(10))
+        .with_column(Column::new("Title").min_width(15))
+        .with_column(Column::new("Reason").min_width(10));
+
+    for entry in entries {
+        let age_style = if entry.expired {
+            theme.warning.clone()
+        } else {
+            theme.timestamp.clone()
+        };
+        table.add_row(Row::new(vec![
+            Cell::new(Text::styled(entry.id.clone(), theme.issue_id.clone())),
+            Cell::new(Text::styled(entry.age_label(), age_style)),
+            Cell::new(Text::new(entry.deleted_by.clone().unwrap_or_default())),
+            Cell::new(Text::styled(
+                entry.original_type.clone().unwrap_or_default(),
+                theme.dimmed.clone(),
+            )),
+            Cell::new(Text::new(entry.title.clone())),
+            Cell::new(Text::styled(
+                entry.delete_reason.clone().unwrap_or_default(),
+                theme.dimmed.clone(),
+            )),
+        ]));
+    }
+    ctx.render(&table);
+}
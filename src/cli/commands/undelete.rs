@@ -0,0 +1,146 @@
+//! Undelete command implementation.
+//!
+//! Turns tombstones created by `br delete` back into live issues.
+
+use crate::cli::UndeleteArgs;
+use crate::config;
+use crate::error::Result;
+use crate::model::Status;
+use crate::output::OutputContext;
+use crate::util::id::{IdResolver, ResolverConfig, find_matching_ids};
+use rich_rust::prelude::*;
+use serde::Serialize;
+
+/// An issue brought back from a tombstone.
+#[derive(Debug, Serialize)]
+pub struct UndeletedIssue {
+    pub id: String,
+    pub title: String,
+    pub status: String,
+    pub issue_type: String,
+    pub dependencies_restored: usize,
+}
+
+/// Issue that was skipped during undelete.
+#[derive(Debug, Serialize)]
+pub struct SkippedIssue {
+    pub id: String,
+    pub reason: String,
+}
+
+/// JSON output for the undelete command.
+#[derive(Debug, Serialize)]
+pub struct UndeleteResult {
+    pub undeleted: Vec<UndeletedIssue>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub skipped: Vec<SkippedIssue>,
+}
+
+/// Execute the undelete command.
+///
+/// # Errors
+///
+/// Returns an error if IDs cannot be resolved or the database update fails.
+pub fn execute(args: &UndeleteArgs, cli: &config::CliOverrides, ctx: &OutputContext) -> Result<()> {
+    let beads_dir = config::discover_beads_dir_with_cli(cli)?;
+    let mut storage_ctx = config::open_storage_with_cli(&beads_dir, cli)?;
+
+    let config_layer = config::load_config(&beads_dir, Some(&storage_ctx.storage), cli)?;
+    let actor = config::resolve_actor(&config_layer);
+    let id_config = config::id_config_from_layer(&config_layer);
+    let resolver = IdResolver::new(ResolverConfig::with_prefix(id_config.prefix));
+    let all_ids = storage_ctx.storage.get_all_ids()?;
+    let storage = &mut storage_ctx.storage;
+
+    let resolved_ids = resolver.resolve_all(
+        &args.ids,
+        |id| all_ids.binary_search_by(|p| p.as_str().cmp(id)).is_ok(),
+        |hash| find_matching_ids(&all_ids, hash),
+    )?;
+
+    let mut result = UndeleteResult {
+        undeleted: Vec::new(),
+        skipped: Vec::new(),
+    };
+    for resolved in &resolved_ids {
+        let id = &resolved.id;
+        let Some(issue) = storage.get_issue(id)? else {
+            result.skipped.push(SkippedIssue {
+                id: id.clone(),
+                reason: "issue not found".to_string(),
+            });
+            continue;
+        };
+        if issue.status != Status::Tombstone {
+            result.skipped.push(SkippedIssue {
+                id: id.clone(),
+                reason: format!("not deleted (status: {})", issue.status),
+            });
+            continue;
+        }
+
+        let dependencies_restored = storage.undelete_issue(id, &actor)?;
+        let restored = storage.get_issue(id)?.unwrap_or(issue);
+        crate::util::set_last_touched_id(&beads_dir, id);
+        result.undeleted.push(UndeletedIssue {
+            id: id.clone(),
+            title: restored.title,
+            status: restored.status.as_str().to_string(),
+            issue_type: restored.issue_type.as_str().to_string(),
+            dependencies_restored,
+        });
+    }
+
+    if ctx.is_json() {
+        ctx.json_pretty(&result);
+    } else if ctx.is_rich() {
+        render_rich(&result, ctx);
+    } else if !ctx.is_quiet() {
+        for item in &result.undeleted {
+            println!(
+                "\u{2713} Undeleted {}: {} ({}, {}; {} dependencies restored)",
+                item.id, item.title, item.status, item.issue_type, item.dependencies_restored
+            );
+        }
+        for skipped in &result.skipped {
+            println!("\u{2298} Skipped {}: {}", skipped.id, skipped.reason);
+        }
+    }
+
+    storage_ctx.flush_no_db_if_dirty()?;
+    Ok(())
+}
+
+fn render_rich(result: &UndeleteResult, ctx: &OutputContext) {
+    let theme = ctx.theme();
+    let mut content = Text::new("");
+
+    for item in &result.undeleted {
+        content.append_styled("\u{2713} Undeleted ", theme.success.clone());
+        content.append_styled(&item.id, theme.issue_id.clone());
+        content.append(": ");
+        content.append(&item.title);
+        content.append("\n");
+        content.append_styled("  Restored as: ", theme.dimmed.clone());
+        content.append(&format!("{} {}", item.status, item.issue_type));
+        if item.dependencies_restored > 0 {
+            content.append_styled(
+                &format!(" · {} dependencies", item.dependencies_restored),
+                theme.dimmed.clone(),
+            );
+        }
+        content.append("\n");
+    }
+    for item in &result.skipped {
+        content.append_styled("\u{2298} Skipped ", theme.warning.clone());
+        content.append_styled(&item.id, theme.issue_id.clone());
+        content.append_styled(&format!(": {}", item.reason), theme.dimmed.clone());
+        content.append("\n");
+    }
+
+    let panel = Panel::from_rich_text(&content, ctx.width())
+        .title(Text::styled("Undelete", theme.panel_title.clone()))
+        .box_style(theme.box_style)
+        .border_style(theme.panel_border.clone());
+    ctx.render(&panel);
+}
@@ -2,15 +2,18 @@
 
 use crate::cli::UpdateArgs;
 use crate::config;
+use crate::config::fields::FieldSchema;
+use crate::config::workflow::Workflow;
 use crate::error::{BeadsError, Result};
 use crate::model::{DependencyType, Issue, Status};
 use crate::output::OutputContext;
 use crate::storage::{IssueUpdate, SqliteStorage};
 use crate::util::id::{IdResolver, ResolverConfig};
 use crate::util::time::parse_flexible_timestamp;
-use crate::validation::LabelValidator;
+use crate::validation::{IssueValidator, LabelValidator};
 use chrono::{DateTime, Utc};
 use serde::Serialize;
+use std::collections::BTreeMap;
 
 /// JSON output structure for updated issues.
 #[derive(Serialize)]
@@ -49,11 +52,25 @@ pub fn execute(args: &UpdateArgs, cli: &config::CliOverrides, ctx: &OutputContex
     let resolver = build_resolver(&config_layer, &storage_ctx.storage);
     let resolved_ids = resolve_target_ids(args, &beads_dir, &resolver, &storage_ctx.storage)?;
 
-    let update = build_update(args, &actor)?;
-    let has_updates = !update.is_empty()
+    let mut update = build_update(args, &actor, storage_ctx.storage.workflow())?;
+    let lease = if args.claim {
+        // `claim_issue` sets status and assignee together with the lease, with
+        // the same done-status and workflow checks `update_issue` applies
+        update.status = None;
+        update.assignee = None;
+        update.closed_at = None;
+        update.closed_by_session = None;
+        Some(claim_lease(args.lease.as_deref(), &config_layer)?)
+    } else {
+        None
+    };
+    let field_changes = build_field_changes(args, storage_ctx.storage.field_schema())?;
+    let has_updates = args.claim
+        || !update.is_empty()
         || !args.add_label.is_empty()
         || !args.remove_label.is_empty()
         || !args.set_labels.is_empty()
+        || !field_changes.is_empty()
         || args.parent.is_some();
 
     let mut updated_issues: Vec<UpdatedIssueOutput> = Vec::new();
@@ -64,17 +81,8 @@ pub fn execute(args: &UpdateArgs, cli: &config::CliOverrides, ctx: &OutputContex
         // Get issue before update for change tracking
         let issue_before = storage.get_issue(id)?;
 
-        if args.claim {
-            if let Some(ref issue) = issue_before {
-                if let Some(ref current_assignee) = issue.assignee {
-                    if current_assignee != &actor {
-                        return Err(BeadsError::validation(
-                            "claim",
-                            format!("issue already assigned to {current_assignee}"),
-                        ));
-                    }
-                }
-            }
+        if let Some(lease) = lease {
+            storage.claim_issue(id, &actor, args.session.as_deref(), lease)?;
         }
 
         // Apply basic field updates
@@ -106,6 +114,11 @@ pub fn execute(args: &UpdateArgs, cli: &config::CliOverrides, ctx: &OutputContex
             }
         }
 
+        // Apply custom fields
+        if !field_changes.is_empty() {
+            storage.set_fields(id, &field_changes, &actor)?;
+        }
+
         // Apply parent
         apply_parent_update(storage, id, args.parent.as_deref(), &resolver, &actor)?;
 
@@ -174,6 +187,17 @@ fn print_update_summary(id: &str, title: &str, before: Option<&Issue>, after: &I
     }
 }
 
+/// Lease length from `--lease`, else the `claim.lease` config.
+pub(crate) fn claim_lease(
+    flag: Option<&str>,
+    config_layer: &config::ConfigLayer,
+) -> Result<chrono::Duration> {
+    flag.map_or_else(
+        || config::claim_lease_from_layer(config_layer),
+        |value| crate::util::time::parse_duration(value, "lease"),
+    )
+}
+
 fn build_resolver(config_layer: &config::ConfigLayer, _storage: &SqliteStorage) -> IdResolver {
     let id_config = config::id_config_from_layer(config_layer);
     IdResolver::new(ResolverConfig::with_prefix(id_config.prefix))
@@ -206,11 +230,18 @@ fn resolve_target_ids(
     Ok(resolved_ids.into_iter().map(|r| r.id).collect())
 }
 
-fn build_update(args: &UpdateArgs, actor: &str) -> Result<IssueUpdate> {
+pub(crate) fn build_update(
+    args: &UpdateArgs,
+    actor: &str,
+    workflow: &Workflow,
+) -> Result<IssueUpdate> {
     let status = if args.claim {
         Some(Status::InProgress)
     } else {
-        args.status.as_ref().map(|s| s.parse()).transpose()?
+        args.status
+            .as_deref()
+            .map(|s| workflow.parse_status(s))
+            .transpose()?
     };
 
     let priority = args.priority.as_ref().map(|p| p.parse()).transpose()?;
@@ -250,7 +281,8 @@ fn build_update(args: &UpdateArgs, actor: &str) -> Result<IssueUpdate> {
         defer_until,
         external_ref: optional_string_field(args.external_ref.as_deref()),
         closed_at,
-        close_reason: None,
+        close_reason: optional_string_field(args.reason.as_deref()),
+        close_outcome: None,
         closed_by_session: args.session.clone().map(Some),
         deleted_at: None,
         deleted_by: None,
@@ -260,6 +292,24 @@ fn build_update(args: &UpdateArgs, actor: &str) -> Result<IssueUpdate> {
 }
 
 #[allow(clippy::option_option, clippy::single_option_map)]
+/// Parse `--field key=value` flags and validate the values being set.
+pub(crate) fn build_field_changes(
+    args: &UpdateArgs,
+    schema: &FieldSchema,
+) -> Result<BTreeMap<String, Option<serde_json::Value>>> {
+    let changes = schema.parse_assignments(&args.fields)?;
+    let assigned = Issue {
+        fields: changes
+            .iter()
+            .filter_map(|(name, value)| Some((name.clone(), value.clone()?)))
+            .collect(),
+        ..Issue::default()
+    };
+    IssueValidator::validate_fields(&assigned, schema)
+        .map_err(BeadsError::from_validation_errors)?;
+    Ok(changes)
+}
+
 fn optional_string_field(value: Option<&str>) -> Option<Option<String>> {
     value.map(|v| {
         if v.is_empty() {
@@ -462,7 +512,7 @@ mod tests {
             claim: true,
             ..Default::default()
         };
-        let update = build_update(&args, "test_actor").unwrap();
+        let update = build_update(&args, "test_actor", &Workflow::default()).unwrap();
         assert_eq!(update.status, Some(Status::InProgress));
         assert_eq!(update.assignee, Some(Some("test_actor".to_string())));
         info!("test_build_update_with_claim: assertions passed");
@@ -476,7 +526,7 @@ mod tests {
             status: Some("closed".to_string()),
             ..Default::default()
         };
-        let update = build_update(&args, "test_actor").unwrap();
+        let update = build_update(&args, "test_actor", &Workflow::default()).unwrap();
         assert_eq!(update.status, Some(Status::Closed));
         // closed_at should be set
         assert!(update.closed_at.is_some());
@@ -491,7 +541,7 @@ mod tests {
             priority: Some("1".to_string()),
             ..Default::default()
         };
-        let update = build_update(&args, "test_actor").unwrap();
+        let update = build_update(&args, "test_actor", &Workflow::default()).unwrap();
         assert_eq!(update.priority, Some(Priority(1)));
         info!("test_build_update_with_priority: assertions passed");
     }
@@ -501,8 +551,46 @@ mod tests {
         init_test_logging();
         info!("test_build_update_empty: starting");
         let args = UpdateArgs::default();
-        let update = build_update(&args, "test_actor").unwrap();
+        let update = build_update(&args, "test_actor", &Workflow::default()).unwrap();
         assert!(update.is_empty());
         info!("test_build_update_empty: assertions passed");
     }
+
+    #[test]
+    fn test_build_field_changes_validates_set_values() {
+        init_test_logging();
+        info!("test_build_field_changes_validates_set_values: starting");
+        let mut layer = config::ConfigLayer::default();
+        layer
+            .startup
+            .insert("fields.severity.type".to_string(), "enum".to_string());
+        layer
+            .startup
+            .insert("fields.severity.values".to_string(), "low,high".to_string());
+        let schema = FieldSchema::from_layer(&layer).unwrap();
+
+        let args = UpdateArgs {
+            fields: vec!["severity=HIGH".to_string(), "severity=".to_string()],
+            ..Default::default()
+        };
+        let changes = build_field_changes(&args, &schema).unwrap();
+        assert_eq!(changes.get("severity"), Some(&None));
+
+        let args = UpdateArgs {
+            fields: vec!["severity=HIGH".to_string()],
+            ..Default::default()
+        };
+        let changes = build_field_changes(&args, &schema).unwrap();
+        assert_eq!(
+            changes.get("severity"),
+            Some(&Some(serde_json::Value::from("high")))
+        );
+
+        let args = UpdateArgs {
+            fields: vec!["severity=urgent".to_string()],
+            ..Default::default()
+        };
+        assert!(build_field_changes(&args, &schema).is_err());
+        info!("test_build_field_changes_validates_set_values: assertions passed");
+    }
 }
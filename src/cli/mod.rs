@@ -1,9 +1,13 @@
 //! CLI definitions and entry point.
 
+use crate::model::CloseOutcome;
+use crate::sync::conflicts::ConflictSide;
+use clap::builder::{EnumValueParser, TypedValueParser};
 use clap::{Args, Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
 
 pub mod commands;
+pub mod dispatch;
 
 /// Agent-first issue tracker (`SQLite` + JSONL)
 #[derive(Parser, Debug)]
@@ -77,6 +81,10 @@ pub enum Commands {
         /// Backend type (ignored, always sqlite)
         #[arg(long)]
         backend: Option<String>,
+
+        /// Print the git setup for `br merge-driver` instead of initializing
+        #[arg(long)]
+        merge_driver: bool,
     },
 
     /// Create a new issue
@@ -103,13 +111,37 @@ pub enum Commands {
     /// Delete an issue (creates tombstone)
     Delete(DeleteArgs),
 
+    /// Restore deleted issues (type, status and dependencies)
+    Undelete(UndeleteArgs),
+
+    /// Extend the lease on claimed issues
+    Heartbeat(HeartbeatArgs),
+
+    /// Inspect claim leases
+    Claims {
+        #[command(subcommand)]
+        command: ClaimsCommands,
+    },
+
+    /// Inspect deleted issues
+    Tombstones {
+        #[command(subcommand)]
+        command: TombstonesCommands,
+    },
+
+    /// Purge tombstones past retention from the database and JSONL
+    Gc(GcArgs),
+
+    /// Apply a JSONL file of operations in one all-or-nothing transaction
+    Batch(BatchArgs),
+
     /// List ready issues (unblocked, not deferred)
     Ready(ReadyArgs),
 
     /// List blocked issues
     Blocked(BlockedArgs),
 
-    /// Search issues
+    /// Search issues (full-text, ranked)
     Search(SearchArgs),
 
     /// Manage dependencies
@@ -146,6 +178,9 @@ pub enum Commands {
     /// List stale issues
     Stale(StaleArgs),
 
+    /// Compact old closed issues (summarize long text, keep originals locally)
+    Compact(CompactArgs),
+
     /// Check issues for missing template sections
     Lint(LintArgs),
 
@@ -178,6 +213,8 @@ MODES (one required unless --status):
   --flush-only    Export database to JSONL (safe by default)
   --import-only   Import JSONL into database (validates first)
   --status        Show sync status (read-only)
+  --merge         3-way merge (Base + Local DB + Remote JSONL)
+  --resolve       3-way merge with manual conflict resolution
 
 SAFETY GUARDS:
   Export guards (bypassed with --force):
@@ -196,7 +233,10 @@ EXAMPLES:
   br sync --flush-only           Export database to .beads/issues.jsonl
   br sync --flush-only -v        Export with safety logging
   br sync --import-only          Import from JSONL (validates first)
-  br sync --status               Show current sync status")]
+  br sync --status               Show current sync status
+  br sync --resolve              Write merge conflicts to .beads/conflicts.jsonl
+  br sync resolve bd-abc --take local
+                                 Keep the local version of a conflicting issue")]
     Sync(SyncArgs),
 
     /// Run read-only diagnostics
@@ -230,8 +270,16 @@ EXAMPLES:
 
     /// Manage local history backups
     History(HistoryArgs),
+    /// Issue-level diff between JSONL files, history backups and the database
+    Diff(DiffArgs),
+    /// Git merge driver for issues.jsonl (called by git as `%O %A %B`)
+    #[command(name = "merge-driver")]
+    MergeDriver(MergeDriverArgs),
     /// List orphan issues (referenced in commits but open)
     Orphans(OrphansArgs),
+    /// Close or annotate issues from commit trailers (`Closes:`, `Refs:`)
+    #[command(name = "git-sync")]
+    GitSync(GitSyncArgs),
     /// Generate changelog from closed issues
     Changelog(ChangelogArgs),
 
@@ -241,9 +289,30 @@ EXAMPLES:
         command: QueryCommands,
     },
 
+    /// Manage issue templates (instantiate with `create --from-template`)
+    Template {
+        #[command(subcommand)]
+        command: TemplateCommands,
+    },
+
+    /// Send and read messages between agents
+    Mail {
+        #[command(subcommand)]
+        command: MailCommands,
+    },
+
     /// Visualize dependency graph
     Graph(GraphArgs),
 
+    /// Forecast critical path, slack and a parallel schedule from estimates
+    Plan(PlanArgs),
+
+    /// Serve JSON-RPC requests over stdio with a single open database
+    Serve(ServeArgs),
+
+    /// Run a Model Context Protocol server over stdio
+    Mcp(McpArgs),
+
     /// Manage AGENTS.md workflow instructions
     Agents(AgentsArgs),
 }
@@ -334,6 +403,10 @@ pub struct CreateArgs {
     #[arg(long)]
     pub external_ref: Option<String>,
 
+    /// Custom field value (repeatable, key=value; see `fields:` in config.yaml)
+    #[arg(long = "field", value_name = "KEY=VALUE")]
+    pub fields: Vec<String>,
+
     /// Mark as ephemeral (not exported to JSONL)
     #[arg(long)]
     pub ephemeral: bool,
@@ -353,6 +426,18 @@ pub struct CreateArgs {
     /// Create issues from a markdown file (bulk import)
     #[arg(long, short = 'f')]
     pub file: Option<std::path::PathBuf>,
+
+    /// Instantiate a template (copies its fields, labels, type and priority)
+    #[arg(long, value_name = "TEMPLATE_ID", conflicts_with = "file")]
+    pub from_template: Option<String>,
+
+    /// Template variable for `{{name}}` placeholders (repeatable, key=value)
+    #[arg(long = "var", value_name = "KEY=VALUE", requires = "from_template")]
+    pub vars: Vec<String>,
+
+    /// Also instantiate the template's child templates and the dependencies between them
+    #[arg(long, requires = "from_template")]
+    pub molecule: bool,
 }
 
 #[derive(Args, Debug)]
@@ -419,10 +504,14 @@ pub struct UpdateArgs {
     #[arg(long)]
     pub owner: Option<String>,
 
-    /// Atomic claim (assignee=actor + `status=in_progress`)
+    /// Atomic claim (assignee=actor + `status=in_progress`) with an expiring lease
     #[arg(long)]
     pub claim: bool,
 
+    /// Claim lease length, e.g. 30m or 2h (default: `claim.lease` config, 30m)
+    #[arg(long, value_name = "DURATION", requires = "claim")]
+    pub lease: Option<String>,
+
     /// Set due date (empty string clears)
     #[arg(long)]
     pub due: Option<String>,
@@ -455,9 +544,17 @@ pub struct UpdateArgs {
     #[arg(long)]
     pub external_ref: Option<String>,
 
-    /// Set `closed_by_session` when closing
+    /// Set a custom field (repeatable, key=value; an empty value clears it)
+    #[arg(long = "field", value_name = "KEY=VALUE")]
+    pub fields: Vec<String>,
+
+    /// Session ID (`closed_by_session` when closing, `claimed_by_session` with --claim)
     #[arg(long)]
     pub session: Option<String>,
+
+    /// Close reason (for closing updates or workflows that require it)
+    #[arg(long)]
+    pub reason: Option<String>,
 }
 
 #[derive(Args, Debug)]
@@ -491,6 +588,60 @@ pub struct DeleteArgs {
     pub dry_run: bool,
 }
 
+/// Arguments for the undelete command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct UndeleteArgs {
+    /// Issue IDs to restore
+    #[arg(required = true)]
+    pub ids: Vec<String>,
+}
+
+/// Arguments for the heartbeat command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct HeartbeatArgs {
+    /// Claimed issue IDs to renew
+    #[arg(required = true)]
+    pub ids: Vec<String>,
+
+    /// New lease length from now, e.g. 30m or 2h (default: `claim.lease` config, 30m)
+    #[arg(long, value_name = "DURATION")]
+    pub lease: Option<String>,
+}
+
+/// Subcommands for the claims command.
+#[derive(Subcommand, Debug, Clone)]
+pub enum ClaimsCommands {
+    /// List claims with holder, session and expiry
+    List,
+}
+
+/// Subcommands for the tombstones command.
+#[derive(Subcommand, Debug, Clone)]
+pub enum TombstonesCommands {
+    /// List deleted issues with age and reason
+    List,
+}
+
+/// Arguments for the gc command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct GcArgs {
+    /// Show which tombstones would be purged without changing anything
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Purge tombstones deleted more than DAYS ago (default: `deletions_retention_days`)
+    #[arg(long, value_name = "DAYS")]
+    pub older_than: Option<u64>,
+}
+
+/// Arguments for the batch command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct BatchArgs {
+    /// JSONL file with one operation per line ('-' for stdin)
+    #[arg(value_name = "FILE")]
+    pub file: PathBuf,
+}
+
 /// Arguments for the info command.
 #[derive(Args, Debug, Default, Clone)]
 pub struct InfoArgs {
@@ -645,6 +796,26 @@ pub fn resolve_output_format_basic(
     }
 }
 
+/// Note the instant an `--as-of` result describes and mark the issues whose
+/// fields could not be reconstructed. Text output only: JSON carries the same
+/// information per record.
+pub fn print_as_of_notes<'a>(
+    ctx: &crate::output::OutputContext,
+    snapshot: &crate::sync::as_of::Snapshot,
+    ids: impl IntoIterator<Item = &'a str>,
+) {
+    ctx.info(&format!(
+        "As of {}",
+        snapshot.as_of.format("%Y-%m-%d %H:%M UTC")
+    ));
+    for (id, fields) in snapshot.partial(ids) {
+        ctx.warning(&format!(
+            "~ {id}: could not reconstruct {} (current values shown)",
+            fields.join(", ")
+        ));
+    }
+}
+
 /// Arguments for the list command.
 #[derive(Args, Debug, Default, Clone)]
 #[allow(clippy::struct_excessive_bools)]
@@ -677,6 +848,10 @@ pub struct ListArgs {
     #[arg(long)]
     pub label_any: Vec<String>,
 
+    /// Filter by custom field value (AND logic, can be repeated, key=value)
+    #[arg(long, value_name = "KEY=VALUE")]
+    pub field: Vec<String>,
+
     /// Filter by priority (can be repeated)
     #[arg(long, short = 'p')]
     pub priority: Vec<String>,
@@ -709,7 +884,7 @@ pub struct ListArgs {
     #[arg(long)]
     pub limit: Option<usize>,
 
-    /// Sort field (`priority`, `created_at`, `updated_at`, `title`)
+    /// Sort field (`priority`, `created_at`, `updated_at`, `title`, `field:<name>`)
     #[arg(long)]
     pub sort: Option<String>,
 
@@ -725,6 +900,26 @@ pub struct ListArgs {
     #[arg(long)]
     pub overdue: bool,
 
+    /// Filter expression, e.g. `priority <= 1 and (assignee = me or assignee is null)`
+    ///
+    /// Combine predicates with `and`, `or`, `not` and parentheses. Predicates:
+    /// `field OP value` (OP: = != < <= > >= ~ !~), `field:value`,
+    /// `field is [not] null`, `field [not] in (a, b)`. `~` matches a substring,
+    /// `me` is the current actor, and dates accept `-7d`, `2025-01-15`, RFC3339.
+    /// Fields: id, title, desc, design, acceptance, notes, status, type,
+    /// priority, assignee, owner, creator, `external_ref`, estimate, created,
+    /// updated, closed, due, defer, label.
+    #[arg(long = "where", value_name = "EXPR")]
+    pub where_: Option<String>,
+
+    /// Show the state at a past time instead of now (e.g. -7d, 2025-01-15, RFC3339)
+    #[arg(long, value_name = "TIME")]
+    pub as_of: Option<String>,
+
+    /// Fan out over every workspace in local and town-level routes.jsonl (read-only)
+    #[arg(long, conflicts_with = "as_of")]
+    pub all_routes: bool,
+
     /// Use long output format
     #[arg(long)]
     pub long: bool,
@@ -749,7 +944,7 @@ pub struct ListArgs {
     ///
     /// Available: id, title, description, status, priority, `issue_type`,
     /// assignee, owner, `created_at`, `updated_at`, `closed_at`, `due_at`,
-    /// `defer_until`, notes, `external_ref`
+    /// `defer_until`, notes, `external_ref`, and `fields.<name>` for custom fields
     ///
     /// Default: id, title, status, priority, `issue_type`, assignee, `created_at`, `updated_at`
     #[arg(long, value_name = "FIELDS")]
@@ -760,6 +955,10 @@ pub struct ListArgs {
 #[derive(Args, Debug, Default)]
 pub struct SearchArgs {
     /// Search query
+    ///
+    /// Bare terms match word prefixes; `"exact phrase"`, `term*`, `AND`/`OR`/`NOT`,
+    /// parentheses, and field scopes (`title:`, `desc:`, `design:`, `acceptance:`,
+    /// `notes:`, `comments:`, `id:`) are supported.
     pub query: String,
 
     #[command(flatten)]
@@ -783,6 +982,10 @@ pub struct ShowArgs {
     /// Show token savings stats when using TOON output
     #[arg(long)]
     pub stats: bool,
+
+    /// Show the state at a past time instead of now (e.g. -7d, 2025-01-15, RFC3339)
+    #[arg(long, value_name = "TIME")]
+    pub as_of: Option<String>,
 }
 
 #[derive(Subcommand, Debug)]
@@ -1143,6 +1346,10 @@ pub struct CountArgs {
     /// Title contains substring
     #[arg(long)]
     pub title_contains: Option<String>,
+
+    /// Filter expression (same syntax as `br list --where`)
+    #[arg(long = "where", value_name = "EXPR")]
+    pub where_: Option<String>,
 }
 
 #[derive(ValueEnum, Debug, Clone, Copy, Eq, PartialEq)]
@@ -1165,6 +1372,33 @@ pub struct StaleArgs {
     pub status: Vec<String>,
 }
 
+/// Arguments for the compact command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct CompactArgs {
+    /// Issue IDs to compact (defaults to closed issues older than --older-than)
+    pub ids: Vec<String>,
+
+    /// Compaction level: 1 keeps leading sentences, 2 keeps a one-line summary
+    #[arg(long, default_value_t = 1)]
+    pub level: i32,
+
+    /// Minimum days since the issue was closed
+    #[arg(long, default_value_t = 90)]
+    pub older_than: i64,
+
+    /// Use this summary as the description instead of extracting one (file path or '-' for stdin)
+    #[arg(long, value_name = "FILE")]
+    pub summary: Option<String>,
+
+    /// Preview what would be compacted without changing anything
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Restore an issue's original text from the local compaction archive
+    #[arg(long, value_name = "ID", conflicts_with_all = ["ids", "summary", "dry_run"])]
+    pub restore: Option<String>,
+}
+
 #[derive(Args, Debug, Clone, Default)]
 pub struct LintArgs {
     /// Issue IDs to lint (defaults to open issues)
@@ -1245,6 +1479,30 @@ pub struct ReadyArgs {
     #[arg(long)]
     pub include_deferred: bool,
 
+    /// Filter expression (same syntax as `br list --where`)
+    #[arg(long = "where", value_name = "EXPR")]
+    pub where_: Option<String>,
+
+    /// Show the state at a past time instead of now (e.g. -7d, 2025-01-15, RFC3339)
+    #[arg(long, value_name = "TIME")]
+    pub as_of: Option<String>,
+
+    /// Fan out over every workspace in local and town-level routes.jsonl (read-only)
+    #[arg(long, conflicts_with = "as_of")]
+    pub all_routes: bool,
+
+    /// Atomically claim the top ready issue (assignee=actor, `in_progress`, leased)
+    #[arg(long, conflicts_with_all = ["as_of", "all_routes"])]
+    pub claim: bool,
+
+    /// Claim lease length, e.g. 30m or 2h (default: `claim.lease` config, 30m)
+    #[arg(long, value_name = "DURATION", requires = "claim")]
+    pub lease: Option<String>,
+
+    /// Session ID recorded on the claim
+    #[arg(long, requires = "claim")]
+    pub session: Option<String>,
+
     /// Wrap long lines instead of truncating in text output
     #[arg(long)]
     pub wrap: bool,
@@ -1290,6 +1548,10 @@ pub struct BlockedArgs {
     #[arg(long, short = 'l')]
     pub label: Vec<String>,
 
+    /// Fan out over every workspace in local and town-level routes.jsonl (read-only)
+    #[arg(long)]
+    pub all_routes: bool,
+
     /// Output format (text, json, toon). Env: BR_OUTPUT_FORMAT, TOON_DEFAULT_FORMAT.
     #[arg(long, value_enum)]
     pub format: Option<OutputFormatBasic>,
@@ -1313,6 +1575,16 @@ pub struct CloseArgs {
     #[arg(long, short = 'r')]
     pub reason: Option<String>,
 
+    /// How the work ended.
+    ///
+    /// `conditional-blocks` dependents become ready only when their blocker
+    /// closes with `failure`; closing with `success` auto-closes them as moot.
+    #[arg(
+        long,
+        value_parser = EnumValueParser::<CloseOutcomeArg>::new().map(CloseOutcome::from)
+    )]
+    pub outcome: Option<CloseOutcome>,
+
     /// Close even if blocked by open dependencies
     #[arg(long, short = 'f')]
     pub force: bool,
@@ -1357,6 +1629,50 @@ pub enum SortPolicy {
     Oldest,
 }
 
+/// Values accepted by `--outcome`.
+#[derive(ValueEnum, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CloseOutcomeArg {
+    #[value(alias = "succeeded", alias = "done")]
+    Success,
+    #[value(alias = "failed", alias = "fail")]
+    Failure,
+    #[value(alias = "wont-fix", alias = "wont_fix")]
+    Wontfix,
+}
+
+impl From<CloseOutcomeArg> for CloseOutcome {
+    fn from(outcome: CloseOutcomeArg) -> Self {
+        match outcome {
+            CloseOutcomeArg::Success => Self::Success,
+            CloseOutcomeArg::Failure => Self::Failure,
+            CloseOutcomeArg::Wontfix => Self::Wontfix,
+        }
+    }
+}
+
+/// Values accepted by `sync resolve --take`.
+#[derive(ValueEnum, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ConflictSideArg {
+    /// The local (`SQLite`) version
+    #[value(alias = "ours")]
+    Local,
+    /// The remote (JSONL) version
+    #[value(alias = "theirs", alias = "external")]
+    Remote,
+    /// The common ancestor from the base snapshot
+    Base,
+}
+
+impl From<ConflictSideArg> for ConflictSide {
+    fn from(side: ConflictSideArg) -> Self {
+        match side {
+            ConflictSideArg::Local => Self::Local,
+            ConflictSideArg::Remote => Self::Remote,
+            ConflictSideArg::Base => Self::Base,
+        }
+    }
+}
+
 /// Arguments for the sync command.
 #[derive(Args, Debug, Clone, Default)]
 #[allow(clippy::struct_excessive_bools)]
@@ -1383,6 +1699,15 @@ pub struct SyncArgs {
     #[arg(long)]
     pub merge: bool,
 
+    /// Perform a 3-way merge without auto-resolving conflicts
+    ///
+    /// Writes pending conflicts (base/local/remote) to `.beads/conflicts.jsonl`
+    /// and applies nothing until every conflict has a resolution. Choose a side
+    /// with `br sync resolve <id> --take local|remote|base` (or edit the file),
+    /// then run `br sync --resolve` again to apply.
+    #[arg(long)]
+    pub resolve: bool,
+
     /// Show sync status (read-only)
     ///
     /// Displays hash comparison and freshness info without modifications.
@@ -1422,6 +1747,29 @@ pub struct SyncArgs {
     /// Machine-readable output (alias for --json)
     #[arg(long)]
     pub robot: bool,
+
+    #[command(subcommand)]
+    pub command: Option<SyncCommands>,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum SyncCommands {
+    /// Record a resolution for a pending merge conflict
+    Resolve(SyncResolveArgs),
+}
+
+/// Arguments for `br sync resolve`.
+#[derive(Args, Debug, Clone)]
+pub struct SyncResolveArgs {
+    /// Issue ID with a pending conflict
+    pub id: String,
+
+    /// Version to keep
+    #[arg(
+        long,
+        value_parser = EnumValueParser::<ConflictSideArg>::new().map(ConflictSide::from)
+    )]
+    pub take: ConflictSide,
 }
 
 #[derive(Subcommand, Debug, Clone)]
@@ -1496,6 +1844,10 @@ pub struct StatsArgs {
     #[arg(long, default_value_t = 24)]
     pub activity_hours: u32,
 
+    /// Show the state at a past time instead of now (e.g. -7d, 2025-01-15, RFC3339)
+    #[arg(long, value_name = "TIME")]
+    pub as_of: Option<String>,
+
     /// Output format (text, json, toon). Env: BR_OUTPUT_FORMAT, TOON_DEFAULT_FORMAT.
     #[arg(long, value_enum)]
     pub format: Option<OutputFormatBasic>,
@@ -1515,6 +1867,29 @@ pub struct HistoryArgs {
     pub command: Option<HistoryCommands>,
 }
 
+/// Arguments for the diff command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct DiffArgs {
+    /// Old side: a JSONL path, a history backup name, or `db`
+    pub old: String,
+
+    /// New side: a JSONL path, a history backup name, or `db`
+    pub new: String,
+}
+
+/// Arguments for the merge-driver command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct MergeDriverArgs {
+    /// Common ancestor version (`%O`)
+    pub base: PathBuf,
+
+    /// Our version (`%A`); receives the merged result
+    pub ours: PathBuf,
+
+    /// Their version (`%B`)
+    pub theirs: PathBuf,
+}
+
 #[derive(Subcommand, Debug)]
 pub enum HistoryCommands {
     /// List history backups
@@ -1587,11 +1962,31 @@ pub struct OrphansArgs {
     #[arg(long)]
     pub fix: bool,
 
+    /// Outcome to record on issues closed by `--fix`
+    #[arg(
+        long,
+        requires = "fix",
+        value_parser = EnumValueParser::<CloseOutcomeArg>::new().map(CloseOutcome::from)
+    )]
+    pub outcome: Option<CloseOutcome>,
+
     /// Machine-readable output (alias for --json)
     #[arg(long)]
     pub robot: bool,
 }
 
+/// Arguments for the git-sync command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct GitSyncArgs {
+    /// Show what would change without closing or commenting
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Rescan all history instead of resuming after the last processed commit
+    #[arg(long)]
+    pub full: bool,
+}
+
 /// Arguments for the changelog command.
 #[derive(Args, Debug, Clone, Default)]
 pub struct ChangelogArgs {
@@ -1658,6 +2053,149 @@ pub struct QueryDeleteArgs {
     pub name: String,
 }
 
+/// Template subcommands.
+#[derive(Subcommand, Debug, Clone)]
+pub enum TemplateCommands {
+    /// Create a template (use --parent to add a child to a template epic)
+    Create(TemplateCreateArgs),
+    /// List templates
+    List,
+    /// Show a template, its variables and its children
+    Show(TemplateShowArgs),
+    /// Delete a template and its child templates
+    Delete(TemplateDeleteArgs),
+}
+
+/// Arguments for the template create command.
+#[derive(Args, Debug, Clone, Default)]
+pub struct TemplateCreateArgs {
+    /// Template title (may contain `{{variable}}` placeholders)
+    pub title: String,
+
+    /// Issue type for instances
+    #[arg(long = "type", short = 't')]
+    pub type_: Option<String>,
+
+    /// Priority for instances (0-4 or P0-P4)
+    #[arg(long, short = 'p')]
+    pub priority: Option<String>,
+
+    /// Description
+    #[arg(long, short = 'd')]
+    pub description: Option<String>,
+
+    /// Design notes
+    #[arg(long)]
+    pub design: Option<String>,
+
+    /// Acceptance criteria
+    #[arg(long = "acceptance-criteria", alias = "acceptance")]
+    pub acceptance_criteria: Option<String>,
+
+    /// Labels (comma-separated)
+    #[arg(long, short = 'l', value_delimiter = ',')]
+    pub labels: Vec<String>,
+
+    /// Parent template (makes this template part of a molecule)
+    #[arg(long)]
+    pub parent: Option<String>,
+}
+
+/// Arguments for the template show command.
+#[derive(Args, Debug, Clone)]
+pub struct TemplateShowArgs {
+    /// Template ID
+    pub id: String,
+}
+
+/// Arguments for the template delete command.
+#[derive(Args, Debug, Clone)]
+pub struct TemplateDeleteArgs {
+    /// Template ID
+    pub id: String,
+}
+
+/// Mail subcommands.
+#[derive(Subcommand, Debug, Clone)]
+pub enum MailCommands {
+    /// Send a message to another agent
+    Send(MailSendArgs),
+
+    /// List unread messages for the current actor
+    Inbox(MailInboxArgs),
+
+    /// Reply to a message in the same thread
+    Reply(MailReplyArgs),
+
+    /// Show the whole conversation a message belongs to
+    Thread(MailThreadArgs),
+
+    /// Show a message and mark it read
+    Read(MailReadArgs),
+
+    /// Acknowledge (close) messages
+    Ack(MailAckArgs),
+}
+
+#[derive(Args, Debug, Clone, Default)]
+pub struct MailSendArgs {
+    /// Recipient (agent or actor name)
+    #[arg(long)]
+    pub to: String,
+
+    /// Message subject
+    #[arg(long, short = 's')]
+    pub subject: String,
+
+    /// Message body
+    #[arg(long, short = 'b')]
+    pub body: Option<String>,
+}
+
+#[derive(Args, Debug, Clone, Default)]
+pub struct MailInboxArgs {
+    /// Include read and acknowledged messages
+    #[arg(long, short = 'a')]
+    pub all: bool,
+}
+
+#[derive(Args, Debug, Clone, Default)]
+pub struct MailReplyArgs {
+    /// Message ID to reply to
+    pub id: String,
+
+    /// Reply body
+    #[arg(long, short = 'b')]
+    pub body: String,
+
+    /// Subject (defaults to "Re: <original subject>")
+    #[arg(long, short = 's')]
+    pub subject: Option<String>,
+
+    /// Recipient (defaults to the original sender)
+    #[arg(long)]
+    pub to: Option<String>,
+}
+
+#[derive(Args, Debug, Clone, Default)]
+pub struct MailThreadArgs {
+    /// Any message ID in the thread
+    pub id: String,
+}
+
+#[derive(Args, Debug, Clone, Default)]
+pub struct MailReadArgs {
+    /// Message ID
+    pub id: String,
+}
+
+#[derive(Args, Debug, Clone, Default)]
+pub struct MailAckArgs {
+    /// Message IDs to acknowledge
+    #[arg(required = true)]
+    pub ids: Vec<String>,
+}
+
 /// Arguments for the graph command.
 #[derive(Args, Debug, Clone, Default)]
 pub struct GraphArgs {
@@ -1671,6 +2209,93 @@ pub struct GraphArgs {
     /// One line per issue (compact output)
     #[arg(long)]
     pub compact: bool,
+
+    /// Export format (dot, mermaid, graphml, json); defaults to terminal output
+    #[arg(long, value_enum)]
+    pub format: Option<GraphFormat>,
+
+    /// Include closed issues as nodes
+    #[arg(long)]
+    pub include_closed: bool,
+
+    /// Limit traversal depth from the root (or from component roots with --all)
+    #[arg(long, value_name = "N")]
+    pub max_depth: Option<usize>,
+}
+
+/// Export formats for `br graph --format`.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum GraphFormat {
+    /// Terminal output (same as omitting --format)
+    #[default]
+    Text,
+    /// Graphviz DOT
+    Dot,
+    /// Mermaid flowchart
+    Mermaid,
+    /// `GraphML` XML
+    Graphml,
+    /// JSON nodes, typed edges and clusters
+    Json,
+}
+
+/// Arguments for the plan command.
+#[derive(Args, Debug, Clone)]
+pub struct PlanArgs {
+    /// Plan only the work needed to finish this issue or epic (default: all open work)
+    pub target: Option<String>,
+
+    /// Number of workers/agents to schedule in parallel
+    #[arg(long, short = 'w', default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    pub workers: u32,
+
+    /// Schedule start (RFC3339 or YYYY-MM-DD; default: now)
+    #[arg(long)]
+    pub start: Option<String>,
+
+    /// Working hours per calendar day used to project dates
+    #[arg(long, default_value_t = 8, value_parser = clap::value_parser!(u32).range(1..=24))]
+    pub hours_per_day: u32,
+
+    /// Estimate in minutes for issues without `estimated_minutes`
+    #[arg(long, value_name = "MINUTES", default_value_t = 60)]
+    pub default_estimate: u32,
+
+    /// Output format (text, json, mermaid); --json implies json
+    #[arg(long, value_enum)]
+    pub format: Option<PlanFormat>,
+}
+
+/// Output formats for `br plan`.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum PlanFormat {
+    /// Rich or plain terminal output
+    #[default]
+    Text,
+    /// JSON plan document
+    Json,
+    /// Mermaid Gantt chart, one section per worker
+    Mermaid,
+}
+
+/// Arguments for the serve command.
+#[derive(Args, Debug, Clone)]
+pub struct ServeArgs {
+    /// Read line-delimited JSON-RPC requests from stdin and answer on stdout
+    #[arg(long)]
+    pub stdio: bool,
+
+    /// Flush changes to JSONL after this many milliseconds without a new mutation
+    #[arg(long, value_name = "MS", default_value_t = 500)]
+    pub flush_debounce_ms: u64,
+}
+
+/// Arguments for the mcp command.
+#[derive(Args, Debug, Clone)]
+pub struct McpArgs {
+    /// Flush changes to JSONL after this many milliseconds without a new mutation
+    #[arg(long, value_name = "MS", default_value_t = 500)]
+    pub flush_debounce_ms: u64,
 }
 
 /// Arguments for the agents command.
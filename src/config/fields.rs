@@ -0,0 +1,428 @@
+//! Custom issue fields and their typed schema.
+//!
+//! Declared in `.beads/config.yaml`:
+//!
+//! ```yaml
+//! fields:
+//!   severity:
+//!     type: enum
+//!     values: [low, medium, high, critical]
+//!   component: string
+//!   story_points: int
+//!   customer_facing: bool
+//!   review_by: date
+//! ```
+//!
+//! Values are stored as JSON in the `custom_fields` table and exported under
+//! each issue's `fields` object. Enum fields sort in declaration order.
+
+use super::ConfigLayer;
+use crate::error::{BeadsError, Result};
+use chrono::{DateTime, NaiveDate};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+const FIELDS_PREFIX: &str = "fields.";
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Value type of a custom field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    String,
+    Int,
+    Enum,
+    Date,
+    Bool,
+}
+
+impl FieldKind {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Int => "int",
+            Self::Enum => "enum",
+            Self::Date => "date",
+            Self::Bool => "bool",
+        }
+    }
+}
+
+impl fmt::Display for FieldKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for FieldKind {
+    type Err = BeadsError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "string" | "text" => Ok(Self::String),
+            "int" | "integer" => Ok(Self::Int),
+            "enum" => Ok(Self::Enum),
+            "date" => Ok(Self::Date),
+            "bool" | "boolean" => Ok(Self::Bool),
+            other => Err(BeadsError::Config(format!(
+                "unknown field type '{other}' (expected string, int, enum, date or bool)"
+            ))),
+        }
+    }
+}
+
+/// Declaration of a single custom field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDef {
+    pub kind: FieldKind,
+    /// Allowed values, in declaration order (enum fields only).
+    pub values: Vec<String>,
+}
+
+impl FieldDef {
+    /// Convert command-line text to a typed value.
+    ///
+    /// Conversion is best-effort: text that does not fit the type is kept as
+    /// a string so that [`Self::check`] can report it.
+    #[must_use]
+    pub fn coerce(&self, raw: &str) -> Value {
+        let raw = raw.trim();
+        match self.kind {
+            FieldKind::String => Value::from(raw),
+            FieldKind::Int => raw
+                .parse::<i64>()
+                .map_or_else(|_| Value::from(raw), Value::from),
+            FieldKind::Bool => match raw.to_lowercase().as_str() {
+                "true" | "yes" | "on" | "1" => Value::Bool(true),
+                "false" | "no" | "off" | "0" => Value::Bool(false),
+                _ => Value::from(raw),
+            },
+            FieldKind::Date => parse_date(raw).map_or_else(
+                || Value::from(raw),
+                |date| Value::from(date.format(DATE_FORMAT).to_string()),
+            ),
+            FieldKind::Enum => self
+                .values
+                .iter()
+                .find(|value| value.eq_ignore_ascii_case(raw))
+                .map_or_else(|| Value::from(raw), |value| Value::from(value.as_str())),
+        }
+    }
+
+    /// Check that a stored value matches the declared type.
+    ///
+    /// # Errors
+    ///
+    /// Returns a human-readable reason when the value does not fit.
+    pub fn check(&self, value: &Value) -> std::result::Result<(), String> {
+        let ok = match (self.kind, value) {
+            (FieldKind::String, Value::String(_)) | (FieldKind::Bool, Value::Bool(_)) => true,
+            (FieldKind::Int, Value::Number(number)) => number.is_i64(),
+            (FieldKind::Date, Value::String(text)) => {
+                NaiveDate::parse_from_str(text, DATE_FORMAT).is_ok()
+            }
+            (FieldKind::Enum, Value::String(text)) => {
+                if !self.values.contains(text) {
+                    return Err(format!(
+                        "'{text}' is not one of: {}",
+                        self.values.join(", ")
+                    ));
+                }
+                true
+            }
+            _ => false,
+        };
+        if ok {
+            Ok(())
+        } else {
+            Err(format!(
+                "expected {}, got {}",
+                self.kind,
+                display_value(value)
+            ))
+        }
+    }
+
+    /// Position of an enum value, used for sorting.
+    #[must_use]
+    pub fn rank(&self, value: &str) -> Option<usize> {
+        self.values.iter().position(|v| v == value)
+    }
+}
+
+/// Custom fields declared under `fields:` in config.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FieldSchema {
+    fields: BTreeMap<String, FieldDef>,
+}
+
+impl FieldSchema {
+    /// Build the schema from the `fields.*` config keys.
+    ///
+    /// Accepts the short form `fields.<name>: <type>` and the long form with
+    /// `fields.<name>.type` and `fields.<name>.values`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a config error for unknown types or attributes, invalid names,
+    /// enums without values, or values declared on a non-enum field.
+    pub fn from_layer(layer: &ConfigLayer) -> Result<Self> {
+        let mut entries: Vec<(String, &String)> = layer
+            .runtime
+            .iter()
+            .chain(layer.startup.iter())
+            .map(|(key, value)| (key.trim().to_lowercase(), value))
+            .filter(|(key, _)| key.starts_with(FIELDS_PREFIX))
+            .collect();
+        entries.sort();
+
+        let mut kinds: BTreeMap<String, FieldKind> = BTreeMap::new();
+        let mut values: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for (key, value) in &entries {
+            let rest = &key[FIELDS_PREFIX.len()..];
+            let (name, attr) = rest.split_once('.').unwrap_or((rest, "type"));
+            if !is_valid_field_name(name) {
+                return Err(BeadsError::Config(format!(
+                    "{key}: invalid field name '{name}'"
+                )));
+            }
+            match attr {
+                "type" => {
+                    let kind = value
+                        .parse::<FieldKind>()
+                        .map_err(|err| BeadsError::Config(format!("{key}: {err}")))?;
+                    kinds.insert(name.to_string(), kind);
+                }
+                "values" => {
+                    let list = value
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|item| !item.is_empty())
+                        .map(str::to_string)
+                        .collect();
+                    values.insert(name.to_string(), list);
+                }
+                other => {
+                    return Err(BeadsError::Config(format!(
+                        "{key}: unknown field attribute '{other}' (expected type or values)"
+                    )));
+                }
+            }
+        }
+
+        let mut schema = Self::default();
+        for (name, kind) in kinds {
+            let allowed = values.remove(&name).unwrap_or_default();
+            match kind {
+                FieldKind::Enum if allowed.is_empty() => {
+                    return Err(BeadsError::Config(format!(
+                        "fields.{name}: enum fields need a values list"
+                    )));
+                }
+                FieldKind::Enum => {}
+                _ if !allowed.is_empty() => {
+                    return Err(BeadsError::Config(format!(
+                        "fields.{name}.values: only enum fields take values"
+                    )));
+                }
+                _ => {}
+            }
+            schema.fields.insert(
+                name,
+                FieldDef {
+                    kind,
+                    values: allowed,
+                },
+            );
+        }
+        if let Some(name) = values.keys().next() {
+            return Err(BeadsError::Config(format!(
+                "fields.{name}.values: missing fields.{name}.type"
+            )));
+        }
+
+        Ok(schema)
+    }
+
+    /// Whether no custom fields are declared.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Look up a declared field.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&FieldDef> {
+        self.fields.get(name)
+    }
+
+    /// Declared fields, sorted by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &FieldDef)> {
+        self.fields.iter().map(|(name, def)| (name.as_str(), def))
+    }
+
+    /// Parse a `key=value` assignment from `--field`.
+    ///
+    /// An empty value (`key=`) yields `None`, meaning "clear the field".
+    /// Undeclared fields are kept as strings; [`crate::validation::IssueValidator`]
+    /// rejects them.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the assignment has no `=` or no name.
+    pub fn parse_assignment(&self, input: &str) -> Result<(String, Option<Value>)> {
+        let (name, raw) = input.split_once('=').ok_or_else(|| {
+            BeadsError::validation("field", format!("expected key=value, got '{input}'"))
+        })?;
+        let name = name.trim().to_lowercase();
+        if name.is_empty() {
+            return Err(BeadsError::validation(
+                "field",
+                format!("missing field name in '{input}'"),
+            ));
+        }
+        if raw.trim().is_empty() {
+            return Ok((name, None));
+        }
+        let value = self
+            .get(&name)
+            .map_or_else(|| Value::from(raw.trim()), |def| def.coerce(raw));
+        Ok((name, Some(value)))
+    }
+
+    /// Parse several `--field` assignments, later ones winning.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error for malformed assignments.
+    pub fn parse_assignments(&self, inputs: &[String]) -> Result<BTreeMap<String, Option<Value>>> {
+        inputs
+            .iter()
+            .map(|input| self.parse_assignment(input))
+            .collect()
+    }
+
+    /// Parse `--field key=value` list filters into typed values.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error for malformed filters or empty values.
+    pub fn parse_filters(&self, inputs: &[String]) -> Result<Vec<(String, Value)>> {
+        inputs
+            .iter()
+            .map(|input| match self.parse_assignment(input)? {
+                (name, Some(value)) => Ok((name, value)),
+                (name, None) => Err(BeadsError::validation(
+                    "field",
+                    format!("missing value for '{name}'"),
+                )),
+            })
+            .collect()
+    }
+}
+
+/// Render a field value for text and CSV output.
+#[must_use]
+pub fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+fn parse_date(raw: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(raw, DATE_FORMAT)
+        .ok()
+        .or_else(|| {
+            DateTime::parse_from_rfc3339(raw)
+                .ok()
+                .map(|dt| dt.date_naive())
+        })
+}
+
+fn is_valid_field_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layer(entries: &[(&str, &str)]) -> ConfigLayer {
+        let mut layer = ConfigLayer::default();
+        for (key, value) in entries {
+            layer
+                .startup
+                .insert((*key).to_string(), (*value).to_string());
+        }
+        layer
+    }
+
+    fn schema() -> FieldSchema {
+        FieldSchema::from_layer(&layer(&[
+            ("fields.severity.type", "enum"),
+            ("fields.severity.values", "low,medium,high"),
+            ("fields.story_points", "int"),
+            ("fields.customer_facing", "bool"),
+            ("fields.review_by", "date"),
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn parses_and_coerces_assignments() {
+        let schema = schema();
+        assert_eq!(
+            schema.parse_assignment("severity=High").unwrap(),
+            ("severity".to_string(), Some(Value::from("high")))
+        );
+        assert_eq!(
+            schema.parse_assignment("story_points=5").unwrap().1,
+            Some(Value::from(5))
+        );
+        assert_eq!(
+            schema.parse_assignment("customer_facing=yes").unwrap().1,
+            Some(Value::Bool(true))
+        );
+        assert_eq!(
+            schema.parse_assignment("review_by=2026-03-01").unwrap().1,
+            Some(Value::from("2026-03-01"))
+        );
+        assert_eq!(schema.parse_assignment("severity=").unwrap().1, None);
+        assert!(schema.parse_assignment("severity").is_err());
+    }
+
+    #[test]
+    fn checks_values_against_types() {
+        let schema = schema();
+        let severity = schema.get("severity").unwrap();
+        assert!(severity.check(&Value::from("medium")).is_ok());
+        assert!(severity.check(&Value::from("urgent")).is_err());
+        assert_eq!(severity.rank("high"), Some(2));
+
+        let points = schema.get("story_points").unwrap();
+        assert!(points.check(&points.coerce("many")).is_err());
+        let review_by = schema.get("review_by").unwrap();
+        assert!(review_by.check(&review_by.coerce("soon")).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_declarations() {
+        assert!(FieldSchema::from_layer(&layer(&[("fields.size", "huge")])).is_err());
+        assert!(FieldSchema::from_layer(&layer(&[("fields.size", "enum")])).is_err());
+        assert!(
+            FieldSchema::from_layer(&layer(&[
+                ("fields.size.type", "int"),
+                ("fields.size.values", "1,2"),
+            ]))
+            .is_err()
+        );
+        assert!(FieldSchema::from_layer(&layer(&[("fields.size.label", "Size")])).is_err());
+    }
+}
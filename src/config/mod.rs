@@ -9,10 +9,12 @@
 //! 6. DB config table
 //! 7. Defaults
 
+pub mod fields;
 pub mod routing;
+pub mod workflow;
 
 use crate::error::{BeadsError, Result};
-use crate::model::{IssueType, Priority};
+use crate::model::{Issue, IssueType, Priority};
 use crate::storage::SqliteStorage;
 use crate::sync::{
     ExportConfig, ImportConfig, export_to_jsonl_with_policy, finalize_export, import_from_jsonl,
@@ -35,7 +37,7 @@ const DEFAULT_JSONL_FILENAME: &str = "issues.jsonl";
 const LEGACY_JSONL_FILENAME: &str = "beads.jsonl";
 
 /// JSONL files that should never be treated as the main export file.
-/// Includes merge artifacts, deletion logs, and interaction logs.
+/// Includes merge artifacts, pending merge conflicts, event, deletion and interaction logs.
 const EXCLUDED_JSONL_FILES: &[&str] = &[
     "deletions.jsonl",
     "interactions.jsonl",
@@ -43,6 +45,8 @@ const EXCLUDED_JSONL_FILES: &[&str] = &[
     "beads.left.jsonl",
     "beads.right.jsonl",
     "sync_base.jsonl",
+    "conflicts.jsonl",
+    "events.jsonl",
 ];
 
 /// Startup metadata describing DB + JSONL paths.
@@ -307,6 +311,159 @@ pub fn open_storage(
     Ok((storage, paths))
 }
 
+/// A read-only storage handle for one workspace reached through routes.
+#[derive(Debug)]
+pub struct RoutedStorage {
+    pub project: String,
+    pub beads_dir: PathBuf,
+    pub storage: SqliteStorage,
+    /// External project DBs configured for this workspace.
+    pub external_db_paths: HashMap<String, PathBuf>,
+}
+
+/// Open every workspace listed in local and town-level `routes.jsonl`,
+/// starting with the local one, read-only.
+///
+/// Workspaces without a database are skipped with a warning.
+///
+/// # Errors
+///
+/// Returns an error if route files cannot be read.
+pub fn open_routed_storages(local_beads_dir: &Path) -> Result<Vec<RoutedStorage>> {
+    let mut opened = Vec::new();
+    for workspace in routing::routed_workspaces(local_beads_dir)? {
+        let paths = match ConfigPaths::resolve(&workspace.beads_dir, None) {
+            Ok(paths) => paths,
+            Err(err) => {
+                warn!(project = %workspace.project, error = %err, "Skipping route");
+                continue;
+            }
+        };
+        if !paths.db_path.is_file() {
+            warn!(
+                project = %workspace.project,
+                path = %paths.db_path.display(),
+                "Skipping route without a database"
+            );
+            continue;
+        }
+        let storage = match SqliteStorage::open_read_only(&paths.db_path) {
+            Ok(storage) => storage,
+            Err(err) => {
+                warn!(project = %workspace.project, error = %err, "Skipping route");
+                continue;
+            }
+        };
+        let layer = load_config(
+            &workspace.beads_dir,
+            Some(&storage),
+            &CliOverrides::default(),
+        )
+        .unwrap_or_default();
+        opened.push(RoutedStorage {
+            external_db_paths: external_project_db_paths(&layer, &workspace.beads_dir),
+            project: workspace.project,
+            beads_dir: workspace.beads_dir,
+            storage,
+        });
+    }
+    Ok(opened)
+}
+
+/// Read-only lookup of issues that live in other routed workspaces.
+///
+/// Each target workspace is opened at most once; failures are logged and
+/// remembered so the lookup simply reports the issue as unknown.
+#[derive(Debug)]
+pub struct RoutedIssueLookup {
+    local_beads_dir: PathBuf,
+    storages: HashMap<PathBuf, Option<SqliteStorage>>,
+}
+
+impl RoutedIssueLookup {
+    #[must_use]
+    pub fn new(local_beads_dir: &Path) -> Self {
+        Self {
+            local_beads_dir: local_beads_dir.to_path_buf(),
+            storages: HashMap::new(),
+        }
+    }
+
+    /// Storage of the workspace `issue_id` routes to, if that is not the
+    /// local one.
+    pub fn storage_for(&mut self, issue_id: &str) -> Option<&SqliteStorage> {
+        let route = match routing::resolve_route(issue_id, &self.local_beads_dir) {
+            Ok(route) if route.is_external => route,
+            Ok(_) => return None,
+            Err(err) => {
+                warn!(id = %issue_id, error = %err, "Failed to resolve route");
+                return None;
+            }
+        };
+        self.storages
+            .entry(route.beads_dir)
+            .or_insert_with_key(|beads_dir| open_routed_read_only(beads_dir))
+            .as_ref()
+    }
+
+    /// Fetch `issue_id` from the workspace it routes to.
+    pub fn get_issue(&mut self, issue_id: &str) -> Option<Issue> {
+        let storage = self.storage_for(issue_id)?;
+        match storage.get_issue(issue_id) {
+            Ok(issue) => issue,
+            Err(err) => {
+                warn!(id = %issue_id, error = %err, "Failed to read routed issue");
+                None
+            }
+        }
+    }
+}
+
+fn open_routed_read_only(beads_dir: &Path) -> Option<SqliteStorage> {
+    let db_path = match ConfigPaths::resolve(beads_dir, None) {
+        Ok(paths) => paths.db_path,
+        Err(err) => {
+            warn!(path = %beads_dir.display(), error = %err, "Skipping route");
+            return None;
+        }
+    };
+    if !db_path.is_file() {
+        warn!(path = %db_path.display(), "Routed workspace has no database");
+        return None;
+    }
+    match SqliteStorage::open_read_only(&db_path) {
+        Ok(storage) => Some(storage),
+        Err(err) => {
+            warn!(path = %db_path.display(), error = %err, "Skipping route");
+            None
+        }
+    }
+}
+
+/// Satisfaction of routed dependencies, keyed by target issue ID.
+///
+/// A routed target is satisfied once it is closed or tombstoned in its own
+/// workspace. Targets that cannot be found are left out, so
+/// [`SqliteStorage::external_blockers`] keeps treating them as blockers.
+///
+/// # Errors
+///
+/// Returns an error if listing local dependencies fails.
+pub fn routed_dependency_statuses(
+    storage: &SqliteStorage,
+    beads_dir: &Path,
+) -> Result<HashMap<String, bool>> {
+    let ids = storage.list_routed_dependency_ids()?;
+    let mut lookup = RoutedIssueLookup::new(beads_dir);
+    Ok(ids
+        .into_iter()
+        .filter_map(|id| {
+            let issue = lookup.get_issue(&id)?;
+            Some((id, issue.status.is_terminal()))
+        })
+        .collect())
+}
+
 /// Storage handle with no-db awareness.
 #[derive(Debug)]
 pub struct OpenStorageResult {
@@ -373,9 +530,13 @@ pub fn open_storage_with_cli(beads_dir: &Path, cli: &CliOverrides) -> Result<Ope
         .or(Some(30000));
 
     let paths = ConfigPaths::resolve(beads_dir, resolved_db_override.as_ref())?;
+    let workflow = workflow::Workflow::from_layer(&merged_layer)?;
+    let field_schema = fields::FieldSchema::from_layer(&merged_layer)?;
 
     if no_db {
         let mut storage = SqliteStorage::open_memory()?;
+        storage.set_workflow(workflow);
+        storage.set_field_schema(field_schema);
         let prefix = resolve_no_db_prefix(beads_dir, &paths.jsonl_path)?;
         storage.set_config("issue_prefix", &prefix)?;
 
@@ -400,7 +561,9 @@ pub fn open_storage_with_cli(beads_dir: &Path, cli: &CliOverrides) -> Result<Ope
             no_db,
         })
     } else {
-        let storage = SqliteStorage::open_with_timeout(&paths.db_path, resolved_lock_timeout)?;
+        let mut storage = SqliteStorage::open_with_timeout(&paths.db_path, resolved_lock_timeout)?;
+        storage.set_workflow(workflow);
+        storage.set_field_schema(field_schema);
         Ok(OpenStorageResult {
             storage,
             paths,
@@ -764,6 +927,22 @@ pub fn load_startup_config(beads_dir: &Path) -> Result<ConfigLayer> {
     ]))
 }
 
+/// Whether the opt-in `events.jsonl` audit log is enabled (`sync.events`).
+#[must_use]
+pub fn sync_events_from_layer(layer: &ConfigLayer) -> bool {
+    get_startup_value(layer, &["sync.events", "sync-events"])
+        .and_then(|value| parse_bool(value))
+        .unwrap_or(false)
+}
+
+/// Whether `sync.events` is enabled for a workspace.
+///
+/// Unreadable config files count as disabled.
+#[must_use]
+pub fn sync_events_enabled(beads_dir: &Path) -> bool {
+    load_startup_config(beads_dir).is_ok_and(|layer| sync_events_from_layer(&layer))
+}
+
 /// Default config layer (lowest precedence).
 #[must_use]
 pub fn default_config_layer() -> ConfigLayer {
@@ -847,6 +1026,21 @@ pub fn default_issue_type_from_layer(layer: &ConfigLayer) -> Result<IssueType> {
         .map_or_else(|| Ok(IssueType::Task), |value| IssueType::from_str(value))
 }
 
+/// Default length of a claim lease (`claim.lease`, e.g. `30m` or `2h`).
+pub const DEFAULT_CLAIM_LEASE_MINUTES: i64 = 30;
+
+/// Resolve how long a claim lasts without a heartbeat.
+///
+/// # Errors
+///
+/// Returns an error if the configured value is not a valid duration.
+pub fn claim_lease_from_layer(layer: &ConfigLayer) -> Result<chrono::Duration> {
+    get_value(layer, &["claim.lease", "claim-lease", "claim_lease"]).map_or_else(
+        || Ok(chrono::Duration::minutes(DEFAULT_CLAIM_LEASE_MINUTES)),
+        |value| crate::util::time::parse_duration(value, "claim.lease"),
+    )
+}
+
 /// Resolve display color preference from a merged config layer.
 ///
 /// Accepts keys: `display.color`, `display-color`, `display_color`.
@@ -993,6 +1187,9 @@ pub fn is_startup_key(key: &str) -> bool {
         || normalized.starts_with("directory.")
         || normalized.starts_with("sync.")
         || normalized.starts_with("external-projects.")
+        || normalized.starts_with("statuses.")
+        || normalized.starts_with("workflow.")
+        || normalized.starts_with("fields.")
     {
         return true;
     }
@@ -1325,6 +1522,17 @@ labels:
         assert_eq!(timeout, 2500);
     }
 
+    #[test]
+    fn sync_events_is_opt_in() {
+        let mut layer = ConfigLayer::default();
+        assert!(!sync_events_from_layer(&layer));
+
+        layer
+            .startup
+            .insert("sync.events".to_string(), "yes".to_string());
+        assert!(sync_events_from_layer(&layer));
+    }
+
     // ==================== Additional Config Unit Tests ====================
     // Tests for beads_rust-7h9: Config unit tests - Layered configuration
 
@@ -318,6 +318,83 @@ fn resolve_route_entry(
     }
 }
 
+/// A workspace reachable from the local one, for `--all-routes` fan-out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoutedWorkspace {
+    /// Display name: the project directory containing `.beads`.
+    pub project: String,
+    /// Resolved beads directory (redirects followed).
+    pub beads_dir: PathBuf,
+    /// Whether this is the local workspace.
+    pub is_local: bool,
+}
+
+/// List the local workspace plus every route target in the local and
+/// town-level `routes.jsonl`, deduplicated by resolved beads directory.
+///
+/// Route targets that do not exist are skipped with a warning so one stale
+/// route does not hide the rest.
+///
+/// # Errors
+///
+/// Returns an error if a route file cannot be read or parsed.
+pub fn routed_workspaces(local_beads_dir: &Path) -> Result<Vec<RoutedWorkspace>> {
+    let project_root = local_beads_dir.parent().unwrap_or(local_beads_dir);
+    let mut sources = vec![(
+        load_routes(&local_beads_dir.join("routes.jsonl"))?,
+        project_root.to_path_buf(),
+    )];
+    if let Some(town_root) = find_town_root(project_root) {
+        let town_beads_dir = town_root.join(".beads");
+        if town_beads_dir != *local_beads_dir && town_beads_dir.is_dir() {
+            sources.push((
+                load_routes(&town_beads_dir.join("routes.jsonl"))?,
+                town_root,
+            ));
+        }
+    }
+
+    let canonical = |path: &Path| fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let local_key = canonical(local_beads_dir);
+    let mut seen = vec![local_key];
+    let mut workspaces = vec![RoutedWorkspace {
+        project: project_name(local_beads_dir),
+        beads_dir: local_beads_dir.to_path_buf(),
+        is_local: true,
+    }];
+
+    for (routes, base_dir) in sources {
+        for route in routes {
+            let resolved = match resolve_route_entry(&route, &base_dir, local_beads_dir) {
+                Ok(resolved) => resolved,
+                Err(err) => {
+                    warn!(prefix = %route.prefix, path = %route.path, error = %err, "Skipping unreachable route");
+                    continue;
+                }
+            };
+            let key = canonical(&resolved.beads_dir);
+            if seen.contains(&key) {
+                continue;
+            }
+            seen.push(key);
+            workspaces.push(RoutedWorkspace {
+                project: project_name(&resolved.beads_dir),
+                beads_dir: resolved.beads_dir,
+                is_local: false,
+            });
+        }
+    }
+
+    Ok(workspaces)
+}
+
+fn project_name(beads_dir: &Path) -> String {
+    beads_dir.parent().and_then(Path::file_name).map_or_else(
+        || beads_dir.display().to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    )
+}
+
 /// Check if an issue ID would be routed externally.
 ///
 /// Quick check without fully resolving the route.
@@ -507,4 +584,39 @@ mod tests {
         let result = find_town_root(dir.path());
         assert!(result.is_none());
     }
+
+    #[test]
+    fn routed_workspaces_merges_local_and_town_routes() {
+        let dir = TempDir::new().unwrap();
+        let town_root = dir.path().join("town");
+        fs::create_dir_all(town_root.join("mayor")).unwrap();
+        fs::write(town_root.join("mayor/town.json"), "{}").unwrap();
+        for project in ["backend", "frontend", "ops"] {
+            fs::create_dir_all(town_root.join(project).join(".beads")).unwrap();
+        }
+        fs::create_dir_all(town_root.join(".beads")).unwrap();
+
+        let local_beads = town_root.join("backend/.beads");
+        fs::write(
+            local_beads.join("routes.jsonl"),
+            r#"{"prefix":"be-","path":"."}
+{"prefix":"fe-","path":"../frontend"}
+"#,
+        )
+        .unwrap();
+        fs::write(
+            town_root.join(".beads/routes.jsonl"),
+            r#"{"prefix":"fe-","path":"frontend"}
+{"prefix":"op-","path":"ops"}
+{"prefix":"gone-","path":"missing"}
+"#,
+        )
+        .unwrap();
+
+        let workspaces = routed_workspaces(&local_beads).unwrap();
+        let projects: Vec<&str> = workspaces.iter().map(|w| w.project.as_str()).collect();
+        assert_eq!(projects, vec!["backend", "frontend", "ops"]);
+        assert!(workspaces[0].is_local);
+        assert!(workspaces[1..].iter().all(|w| !w.is_local));
+    }
 }
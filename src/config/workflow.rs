@@ -0,0 +1,465 @@
+//! Custom statuses and the workflow state machine.
+//!
+//! Declared in `.beads/config.yaml`:
+//!
+//! ```yaml
+//! statuses:
+//!   review: active      # active | waiting | done
+//!   qa: waiting
+//!   shipped: done
+//! workflow:
+//!   transitions:
+//!     in_progress: [review, blocked]
+//!     review: [in_progress, qa, closed]
+//!   required:
+//!     review:
+//!       closed: [close_reason]
+//!     "*":
+//!       shipped: [external_ref]
+//! ```
+//!
+//! # Semantics
+//!
+//! - `active` custom statuses are ready-work candidates, like `in_progress`
+//! - `waiting` custom statuses stay out of `br ready`, like `blocked`
+//! - `done` custom statuses satisfy blocking dependencies, like `closed`
+//! - A status with no `transitions` entry may move to any status
+//! - `required` fields must be set (on the issue or in the same update)
+//!   when entering `<to>` from `<from>`; `*` matches any source status
+
+use super::ConfigLayer;
+use crate::error::{BeadsError, Result};
+use crate::model::{Issue, Status};
+use crate::storage::IssueUpdate;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::str::FromStr;
+
+const STATUSES_PREFIX: &str = "statuses.";
+const TRANSITIONS_PREFIX: &str = "workflow.transitions.";
+const REQUIRED_PREFIX: &str = "workflow.required.";
+const ANY_STATUS: &str = "*";
+
+/// Fields a transition may require.
+pub const REQUIRABLE_FIELDS: &[&str] = &[
+    "close_reason",
+    "close_outcome",
+    "closed_by_session",
+    "assignee",
+    "owner",
+    "description",
+    "design",
+    "acceptance_criteria",
+    "notes",
+    "estimated_minutes",
+    "due_at",
+    "external_ref",
+];
+
+/// How a status behaves for ready work and blocking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatusCategory {
+    Active,
+    Waiting,
+    Done,
+}
+
+impl StatusCategory {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Waiting => "waiting",
+            Self::Done => "done",
+        }
+    }
+}
+
+impl fmt::Display for StatusCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for StatusCategory {
+    type Err = BeadsError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "active" => Ok(Self::Active),
+            "waiting" => Ok(Self::Waiting),
+            "done" => Ok(Self::Done),
+            other => Err(BeadsError::Config(format!(
+                "unknown status category '{other}' (expected active, waiting or done)"
+            ))),
+        }
+    }
+}
+
+/// Custom statuses, allowed transitions and per-transition required fields.
+///
+/// The default workflow has no custom statuses and allows every transition.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Workflow {
+    custom: BTreeMap<String, StatusCategory>,
+    transitions: BTreeMap<String, BTreeSet<String>>,
+    required: BTreeMap<(String, String), Vec<String>>,
+}
+
+impl Workflow {
+    /// Build the workflow from the `statuses.*` and `workflow.*` config keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns a config error for unknown categories, statuses or fields, or
+    /// when a custom status reuses a built-in name.
+    pub fn from_layer(layer: &ConfigLayer) -> Result<Self> {
+        let mut entries: Vec<(String, &String)> = layer
+            .runtime
+            .iter()
+            .chain(layer.startup.iter())
+            .map(|(key, value)| (key.trim().to_lowercase(), value))
+            .collect();
+        entries.sort();
+
+        let mut workflow = Self::default();
+        for (key, value) in &entries {
+            let Some(rest) = key.strip_prefix(STATUSES_PREFIX) else {
+                continue;
+            };
+            let name = rest.strip_suffix(".category").unwrap_or(rest);
+            if Status::from_str(name).is_ok() {
+                return Err(BeadsError::Config(format!(
+                    "{key}: '{name}' is a built-in status"
+                )));
+            }
+            if !is_valid_status_name(name) {
+                return Err(BeadsError::Config(format!(
+                    "{key}: invalid status name '{name}'"
+                )));
+            }
+            let category = value
+                .parse::<StatusCategory>()
+                .map_err(|err| BeadsError::Config(format!("{key}: {err}")))?;
+            workflow.custom.insert(name.to_string(), category);
+        }
+
+        for (key, value) in &entries {
+            if let Some(from) = key.strip_prefix(TRANSITIONS_PREFIX) {
+                let from = workflow.canonical(key, from)?;
+                let targets = split_list(value)
+                    .map(|to| workflow.canonical(key, to))
+                    .collect::<Result<BTreeSet<_>>>()?;
+                workflow.transitions.insert(from, targets);
+            } else if let Some(rest) = key.strip_prefix(REQUIRED_PREFIX) {
+                let Some((from, to)) = rest.split_once('.') else {
+                    return Err(BeadsError::Config(format!(
+                        "{key}: expected workflow.required.<from>.<to>"
+                    )));
+                };
+                let from = if from == ANY_STATUS {
+                    ANY_STATUS.to_string()
+                } else {
+                    workflow.canonical(key, from)?
+                };
+                let to = workflow.canonical(key, to)?;
+                let fields = split_list(value)
+                    .map(|field| {
+                        REQUIRABLE_FIELDS
+                            .contains(&field)
+                            .then(|| field.to_string())
+                            .ok_or_else(|| {
+                                BeadsError::Config(format!(
+                                    "{key}: unknown field '{field}' (expected one of: {})",
+                                    REQUIRABLE_FIELDS.join(", ")
+                                ))
+                            })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                workflow.required.insert((from, to), fields);
+            }
+        }
+
+        Ok(workflow)
+    }
+
+    /// Whether no custom statuses or rules are configured.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.custom.is_empty() && self.transitions.is_empty() && self.required.is_empty()
+    }
+
+    /// Parse a status, accepting declared custom statuses.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidStatus` if the value is neither built-in nor declared.
+    pub fn parse_status(&self, value: &str) -> Result<Status> {
+        Status::from_str(value).or_else(|err| {
+            let name = value.trim().to_lowercase();
+            if self.custom.contains_key(&name) {
+                Ok(Status::Custom(name))
+            } else {
+                Err(err)
+            }
+        })
+    }
+
+    /// Category of a status. Undeclared custom statuses count as waiting.
+    #[must_use]
+    pub fn category(&self, status: &Status) -> StatusCategory {
+        match status {
+            Status::Open | Status::InProgress => StatusCategory::Active,
+            Status::Blocked | Status::Deferred | Status::Pinned => StatusCategory::Waiting,
+            Status::Closed | Status::Tombstone => StatusCategory::Done,
+            Status::Custom(name) => self
+                .custom
+                .get(name)
+                .copied()
+                .unwrap_or(StatusCategory::Waiting),
+        }
+    }
+
+    /// Declared custom statuses with their categories, sorted by name.
+    pub fn custom_statuses(&self) -> impl Iterator<Item = (&str, StatusCategory)> {
+        self.custom
+            .iter()
+            .map(|(name, category)| (name.as_str(), *category))
+    }
+
+    /// Declared custom statuses in the given category.
+    #[must_use]
+    pub fn custom_statuses_in(&self, category: StatusCategory) -> Vec<String> {
+        self.custom_statuses()
+            .filter(|(_, c)| *c == category)
+            .map(|(name, _)| name.to_string())
+            .collect()
+    }
+
+    /// Statuses reachable from `from`, or `None` when unrestricted.
+    #[must_use]
+    pub fn allowed_transitions(&self, from: &Status) -> Option<Vec<String>> {
+        self.transitions
+            .get(from.as_str())
+            .map(|targets| targets.iter().cloned().collect())
+    }
+
+    /// Fields required when moving from `from` to `to`.
+    #[must_use]
+    pub fn required_fields(&self, from: &Status, to: &Status) -> Vec<String> {
+        let mut fields = Vec::new();
+        for source in [from.as_str(), ANY_STATUS] {
+            let key = (source.to_string(), to.as_str().to_string());
+            for field in self.required.get(&key).into_iter().flatten() {
+                if !fields.contains(field) {
+                    fields.push(field.clone());
+                }
+            }
+        }
+        fields
+    }
+
+    /// Check that applying `updates` to `issue` is an allowed transition.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidTransition` if the target status is not reachable from
+    /// the current one, or `TransitionFieldRequired` if a required field is
+    /// missing from both the issue and the update.
+    pub fn check_transition(&self, issue: &Issue, updates: &IssueUpdate) -> Result<()> {
+        let Some(to) = &updates.status else {
+            return Ok(());
+        };
+        if *to == issue.status {
+            return Ok(());
+        }
+
+        if let Some(allowed) = self.allowed_transitions(&issue.status)
+            && !allowed.iter().any(|status| status == to.as_str())
+        {
+            return Err(BeadsError::InvalidTransition {
+                from: issue.status.as_str().to_string(),
+                to: to.as_str().to_string(),
+                allowed,
+            });
+        }
+
+        for field in self.required_fields(&issue.status, to) {
+            if !field_is_set(issue, updates, &field) {
+                return Err(BeadsError::TransitionFieldRequired {
+                    from: issue.status.as_str().to_string(),
+                    to: to.as_str().to_string(),
+                    field,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Resolve a status name used in a rule to its canonical form.
+    fn canonical(&self, key: &str, name: &str) -> Result<String> {
+        if let Ok(status) = Status::from_str(name) {
+            return Ok(status.as_str().to_string());
+        }
+        if self.custom.contains_key(name) {
+            return Ok(name.to_string());
+        }
+        Err(BeadsError::Config(format!(
+            "{key}: unknown status '{name}' (declare it under statuses)"
+        )))
+    }
+}
+
+fn is_valid_status_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-')
+}
+
+fn split_list(value: &str) -> impl Iterator<Item = &str> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+}
+
+/// The value a field will have after the update, falling back to the issue.
+fn effective<'a, T>(update: Option<&'a Option<T>>, current: Option<&'a T>) -> Option<&'a T> {
+    update.map_or(current, Option::as_ref)
+}
+
+fn has_text(value: Option<&String>) -> bool {
+    value.is_some_and(|text| !text.trim().is_empty())
+}
+
+fn field_is_set(issue: &Issue, updates: &IssueUpdate, field: &str) -> bool {
+    match field {
+        "close_reason" => has_text(effective(
+            updates.close_reason.as_ref(),
+            issue.close_reason.as_ref(),
+        )),
+        "close_outcome" => {
+            effective(updates.close_outcome.as_ref(), issue.close_outcome.as_ref()).is_some()
+        }
+        "closed_by_session" => has_text(effective(
+            updates.closed_by_session.as_ref(),
+            issue.closed_by_session.as_ref(),
+        )),
+        "assignee" => has_text(effective(
+            updates.assignee.as_ref(),
+            issue.assignee.as_ref(),
+        )),
+        "owner" => has_text(effective(updates.owner.as_ref(), issue.owner.as_ref())),
+        "description" => has_text(effective(
+            updates.description.as_ref(),
+            issue.description.as_ref(),
+        )),
+        "design" => has_text(effective(updates.design.as_ref(), issue.design.as_ref())),
+        "acceptance_criteria" => has_text(effective(
+            updates.acceptance_criteria.as_ref(),
+            issue.acceptance_criteria.as_ref(),
+        )),
+        "notes" => has_text(effective(updates.notes.as_ref(), issue.notes.as_ref())),
+        "estimated_minutes" => effective(
+            updates.estimated_minutes.as_ref(),
+            issue.estimated_minutes.as_ref(),
+        )
+        .is_some(),
+        "due_at" => effective(updates.due_at.as_ref(), issue.due_at.as_ref()).is_some(),
+        "external_ref" => has_text(effective(
+            updates.external_ref.as_ref(),
+            issue.external_ref.as_ref(),
+        )),
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layer(entries: &[(&str, &str)]) -> ConfigLayer {
+        let mut layer = ConfigLayer::default();
+        for (key, value) in entries {
+            layer
+                .startup
+                .insert((*key).to_string(), (*value).to_string());
+        }
+        layer
+    }
+
+    fn issue(status: Status) -> Issue {
+        Issue {
+            id: "bd-1".to_string(),
+            title: "Workflow".to_string(),
+            status,
+            ..Issue::default()
+        }
+    }
+
+    fn to(status: Status) -> IssueUpdate {
+        IssueUpdate {
+            status: Some(status),
+            ..IssueUpdate::default()
+        }
+    }
+
+    #[test]
+    fn parses_custom_statuses_and_rules() {
+        let workflow = Workflow::from_layer(&layer(&[
+            ("statuses.review", "active"),
+            ("statuses.qa.category", "waiting"),
+            ("workflow.transitions.review", "in_progress,closed"),
+            ("workflow.required.review.closed", "close_reason"),
+        ]))
+        .unwrap();
+
+        let review = workflow.parse_status("Review").unwrap();
+        assert_eq!(review, Status::Custom("review".to_string()));
+        assert_eq!(workflow.category(&review), StatusCategory::Active);
+        assert_eq!(
+            workflow.category(&Status::Custom("qa".to_string())),
+            StatusCategory::Waiting
+        );
+        assert!(workflow.parse_status("unknown").is_err());
+
+        let err = workflow
+            .check_transition(&issue(review.clone()), &to(Status::Open))
+            .unwrap_err();
+        assert!(
+            matches!(err, BeadsError::InvalidTransition { ref allowed, .. }
+            if allowed == &["closed".to_string(), "in_progress".to_string()])
+        );
+
+        let err = workflow
+            .check_transition(&issue(review.clone()), &to(Status::Closed))
+            .unwrap_err();
+        assert!(
+            matches!(err, BeadsError::TransitionFieldRequired { ref field, .. }
+            if field == "close_reason")
+        );
+
+        let mut close = to(Status::Closed);
+        close.close_reason = Some(Some("Shipped".to_string()));
+        assert!(workflow.check_transition(&issue(review), &close).is_ok());
+
+        // Statuses without a transitions entry are unrestricted
+        assert!(
+            workflow
+                .check_transition(&issue(Status::Open), &to(Status::Custom("qa".to_string())))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_declarations() {
+        assert!(Workflow::from_layer(&layer(&[("statuses.closed", "done")])).is_err());
+        assert!(Workflow::from_layer(&layer(&[("statuses.review", "sometimes")])).is_err());
+        assert!(Workflow::from_layer(&layer(&[("workflow.transitions.open", "review")])).is_err());
+        assert!(Workflow::from_layer(&layer(&[("workflow.required.*.closed", "mood")])).is_err());
+    }
+}
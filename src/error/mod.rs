@@ -80,6 +80,22 @@ pub enum BeadsError {
     #[error("Invalid issue type: {issue_type}")]
     InvalidType { issue_type: String },
 
+    /// Status change not allowed by the configured workflow.
+    #[error("Transition not allowed: {from} -> {to}")]
+    InvalidTransition {
+        from: String,
+        to: String,
+        allowed: Vec<String>,
+    },
+
+    /// Status change requires a field that is not set.
+    #[error("Transition {from} -> {to} requires {field}")]
+    TransitionFieldRequired {
+        from: String,
+        to: String,
+        field: String,
+    },
+
     /// Priority out of valid range (0-4).
     #[error("Priority must be 0-4, got: {priority}")]
     InvalidPriority { priority: i32 },
@@ -97,6 +113,10 @@ pub enum BeadsError {
     #[error("Import collision: {count} issues have conflicting content")]
     ImportCollision { count: usize },
 
+    /// A merge left issues between conflict markers.
+    #[error("Merge conflict: {} issue(s) left between conflict markers: {}", ids.len(), ids.join(", "))]
+    MergeConflicts { ids: Vec<String> },
+
     // === Dependency Errors ===
     /// Adding the dependency would create a cycle.
     #[error("Cycle detected in dependencies: {path}")]
@@ -197,6 +217,8 @@ impl BeadsError {
                 | Self::IssueNotFound { .. }
                 | Self::Validation { .. }
                 | Self::InvalidStatus { .. }
+                | Self::InvalidTransition { .. }
+                | Self::TransitionFieldRequired { .. }
                 | Self::InvalidType { .. }
                 | Self::InvalidPriority { .. }
                 | Self::PrefixMismatch { .. }
@@ -224,6 +246,9 @@ impl BeadsError {
             Self::AmbiguousId { .. } => Some("Provide more characters of the ID"),
             Self::HasDependents { .. } => Some("Use --force or --cascade to delete anyway"),
             Self::ImportCollision { .. } => Some("Use --force to overwrite or resolve manually"),
+            Self::MergeConflicts { .. } => {
+                Some("Keep one version of each conflicting issue and remove the markers")
+            }
             Self::DependencyCycle { .. } => Some("Remove one dependency to break the cycle"),
             Self::SelfDependency { .. } => Some("An issue cannot depend on itself"),
             Self::AlreadyInitialized { .. } => Some("Use --force to reinitialize"),
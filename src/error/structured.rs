@@ -59,6 +59,8 @@ pub enum ErrorCode {
     ValidationFailed,
     /// Invalid status value
     InvalidStatus,
+    /// Status change not allowed by the workflow
+    InvalidTransition,
     /// Invalid issue type value
     InvalidType,
     /// Priority out of range (0-4)
@@ -131,6 +133,7 @@ impl ErrorCode {
             // Validation
             Self::ValidationFailed => "VALIDATION_FAILED",
             Self::InvalidStatus => "INVALID_STATUS",
+            Self::InvalidTransition => "INVALID_TRANSITION",
             Self::InvalidType => "INVALID_TYPE",
             Self::InvalidPriority => "INVALID_PRIORITY",
             Self::RequiredField => "REQUIRED_FIELD",
@@ -171,6 +174,7 @@ impl ErrorCode {
             Self::DatabaseLocked
                 | Self::ValidationFailed
                 | Self::InvalidStatus
+                | Self::InvalidTransition
                 | Self::InvalidType
                 | Self::InvalidPriority
                 | Self::RequiredField
@@ -204,6 +208,7 @@ impl ErrorCode {
             // Validation (4)
             Self::ValidationFailed
             | Self::InvalidStatus
+            | Self::InvalidTransition
             | Self::InvalidType
             | Self::InvalidPriority
             | Self::RequiredField => 4,
@@ -532,6 +537,14 @@ impl StructuredError {
                     })),
                 )
             }
+            BeadsError::InvalidTransition { from, to, allowed } => (
+                ErrorCode::InvalidTransition,
+                Some(json!({"from": from, "to": to, "allowed": allowed})),
+            ),
+            BeadsError::TransitionFieldRequired { from, to, field } => (
+                ErrorCode::RequiredField,
+                Some(json!({"from": from, "to": to, "field": field})),
+            ),
             BeadsError::InvalidType { issue_type } => {
                 let hint = detect_type_intent(issue_type)
                     .map(|detected| format!("Did you mean --type {detected}?"));
@@ -570,6 +583,9 @@ impl StructuredError {
                 ErrorCode::ImportCollision,
                 Some(json!({"collision_count": count})),
             ),
+            BeadsError::MergeConflicts { ids } => {
+                (ErrorCode::ConflictMarkers, Some(json!({"conflicts": ids})))
+            }
             BeadsError::DependencyCycle { path } => {
                 (ErrorCode::CycleDetected, Some(json!({"cycle_path": path})))
             }
@@ -624,6 +640,16 @@ impl StructuredError {
                     None
                 }
             }
+            BeadsError::InvalidTransition { from, allowed, .. } => {
+                if allowed.is_empty() {
+                    Some(format!(
+                        "The workflow allows no transitions from '{from}'. See workflow.transitions in .beads/config.yaml."
+                    ))
+                } else {
+                    Some(format!("Allowed from '{from}': {}", allowed.join(", ")))
+                }
+            }
+            BeadsError::TransitionFieldRequired { field, .. } => Some(required_field_hint(field)),
             BeadsError::InvalidType { issue_type } => {
                 if let Some(detected) = detect_type_intent(issue_type) {
                     Some(format!("Did you mean --type {detected}?"))
@@ -649,6 +675,28 @@ impl StructuredError {
     }
 }
 
+/// Point at the CLI flag that sets a workflow-required field.
+fn required_field_hint(field: &str) -> String {
+    let flag = match field {
+        "close_reason" => Some("--reason"),
+        "closed_by_session" => Some("--session"),
+        "estimated_minutes" => Some("--estimate"),
+        "due_at" => Some("--due"),
+        "acceptance_criteria" => Some("--acceptance-criteria"),
+        "external_ref" => Some("--external-ref"),
+        "assignee" => Some("--assignee"),
+        "owner" => Some("--owner"),
+        "description" => Some("--description"),
+        "design" => Some("--design"),
+        "notes" => Some("--notes"),
+        _ => None,
+    };
+    flag.map_or_else(
+        || format!("Set {field} before changing the status."),
+        |flag| format!("Set {field} in the same update (e.g. {flag})."),
+    )
+}
+
 // === Precomputed Valid Values (O(1) lookup) ===
 
 /// Valid status values.
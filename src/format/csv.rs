@@ -3,9 +3,13 @@
 //! Provides CSV output for list/export commands. Handles proper escaping
 //! of fields containing commas, quotes, or newlines.
 
+use crate::config::fields::display_value;
 use crate::model::Issue;
 use std::io::{self, Write};
 
+/// Prefix selecting a custom field column, e.g. `fields.severity`.
+pub const CUSTOM_FIELD_PREFIX: &str = "fields.";
+
 /// Default fields for CSV export.
 pub const DEFAULT_FIELDS: &[&str] = &[
     "id",
@@ -55,8 +59,17 @@ pub fn escape_field(value: &str) -> String {
 }
 
 /// Get a field value from an issue by field name.
+///
+/// `fields.<name>` reads a custom field; unset custom fields are empty.
 #[must_use]
 pub fn get_field_value(issue: &Issue, field: &str) -> String {
+    if let Some(name) = field.strip_prefix(CUSTOM_FIELD_PREFIX) {
+        return issue
+            .fields
+            .get(name)
+            .map(display_value)
+            .unwrap_or_default();
+    }
     match field {
         "id" => issue.id.clone(),
         "title" => issue.title.clone(),
@@ -83,14 +96,19 @@ pub fn get_field_value(issue: &Issue, field: &str) -> String {
 
 /// Parse a comma-separated list of field names.
 ///
+/// Keeps known fields and `fields.<name>` custom fields, dropping the rest.
 /// Returns the default fields if the input is empty.
 #[must_use]
-pub fn parse_fields(fields_arg: Option<&str>) -> Vec<&'static str> {
+pub fn parse_fields(fields_arg: Option<&str>) -> Vec<&str> {
     match fields_arg {
         Some(arg) if !arg.is_empty() => arg
             .split(',')
             .map(str::trim)
-            .filter_map(|f| ALL_FIELDS.iter().find(|&&af| af == f).copied())
+            .filter(|f| {
+                ALL_FIELDS.contains(f)
+                    || f.strip_prefix(CUSTOM_FIELD_PREFIX)
+                        .is_some_and(|name| !name.is_empty())
+            })
             .collect(),
         _ => DEFAULT_FIELDS.to_vec(),
     }
@@ -148,6 +166,7 @@ mod tests {
     use super::*;
     use crate::model::{IssueType, Priority, Status};
     use chrono::{TimeZone, Utc};
+    use std::collections::BTreeMap;
 
     fn make_test_issue(id: &str, title: &str) -> Issue {
         Issue {
@@ -169,6 +188,7 @@ mod tests {
             updated_at: Utc.with_ymd_and_hms(2025, 1, 15, 14, 30, 0).unwrap(),
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             due_at: None,
             defer_until: None,
@@ -190,6 +210,7 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -254,6 +275,27 @@ mod tests {
         assert_eq!(fields, vec!["id", "title"]);
     }
 
+    #[test]
+    fn test_parse_fields_keeps_custom_fields() {
+        let fields = parse_fields(Some("id,fields.severity,fields."));
+        assert_eq!(fields, vec!["id", "fields.severity"]);
+    }
+
+    #[test]
+    fn test_get_field_value_custom_field() {
+        let mut issue = make_test_issue("bd-1", "Custom");
+        issue
+            .fields
+            .insert("severity".to_string(), serde_json::Value::from("high"));
+        issue
+            .fields
+            .insert("story_points".to_string(), serde_json::Value::from(5));
+
+        assert_eq!(get_field_value(&issue, "fields.severity"), "high");
+        assert_eq!(get_field_value(&issue, "fields.story_points"), "5");
+        assert_eq!(get_field_value(&issue, "fields.component"), "");
+    }
+
     #[test]
     fn test_format_issue_row() {
         let issue = make_test_issue("bd-456", "Simple Task");
@@ -0,0 +1,401 @@
+//! Dependency graph export formats.
+//!
+//! `br graph --format` builds a [`GraphExport`] and renders it here as
+//! Graphviz DOT, Mermaid, `GraphML` or JSON. Nodes are styled by status and
+//! priority, edges are labeled with their dependency type, and children of a
+//! parent-child relationship are grouped into one cluster per parent.
+
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fmt::Write as _;
+
+/// A node in an exported graph.
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphExportNode {
+    pub id: String,
+    pub title: String,
+    pub status: String,
+    pub priority: i32,
+    pub issue_type: String,
+    /// Distance from the root (single-issue mode) or from the nearest
+    /// component root (`--all`).
+    pub depth: usize,
+}
+
+/// A dependency edge: `from` depends on `to`.
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphExportEdge {
+    pub from: String,
+    pub to: String,
+    #[serde(rename = "type")]
+    pub dep_type: String,
+}
+
+/// Children grouped under their parent (typically an epic).
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphCluster {
+    pub id: String,
+    pub title: String,
+    pub members: Vec<String>,
+}
+
+/// A graph ready for export.
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphExport {
+    /// Root issue in single-issue mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub root: Option<String>,
+    pub nodes: Vec<GraphExportNode>,
+    pub edges: Vec<GraphExportEdge>,
+    pub clusters: Vec<GraphCluster>,
+}
+
+/// Fill color used for a status in DOT and Mermaid output.
+fn status_color(status: &str) -> &'static str {
+    match status {
+        "open" => "#dbeafe",
+        "in_progress" => "#fef3c7",
+        "blocked" => "#fee2e2",
+        "deferred" => "#e5e7eb",
+        "closed" | "tombstone" => "#d1fae5",
+        _ => "#ffffff",
+    }
+}
+
+/// Border width by priority, so P0/P1 stand out.
+const fn priority_penwidth(priority: i32) -> u8 {
+    match priority {
+        0 => 3,
+        1 => 2,
+        _ => 1,
+    }
+}
+
+fn dot_edge_style(dep_type: &str) -> &'static str {
+    match dep_type {
+        "blocks" | "conditional-blocks" => "solid",
+        "parent-child" => "dashed",
+        _ => "dotted",
+    }
+}
+
+fn node_label(node: &GraphExportNode) -> String {
+    format!("{}: {} [P{}]", node.id, node.title, node.priority)
+}
+
+fn dot_escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Render as a Graphviz DOT digraph.
+#[must_use]
+pub fn render_dot(graph: &GraphExport) -> String {
+    let mut out = String::from("digraph beads {\n");
+    out.push_str("  rankdir=LR;\n");
+    out.push_str("  node [shape=box, style=\"rounded,filled\", fontname=\"Helvetica\"];\n");
+    out.push_str("  edge [fontname=\"Helvetica\", fontsize=10];\n");
+
+    let write_node = |out: &mut String, node: &GraphExportNode, indent: &str| {
+        let style = if matches!(node.status.as_str(), "closed" | "tombstone") {
+            "rounded,filled,dashed"
+        } else {
+            "rounded,filled"
+        };
+        let _ = writeln!(
+            out,
+            "{indent}\"{}\" [label=\"{}\", fillcolor=\"{}\", penwidth={}, style=\"{style}\"];",
+            dot_escape(&node.id),
+            dot_escape(&node_label(node)),
+            status_color(&node.status),
+            priority_penwidth(node.priority),
+        );
+    };
+
+    let clustered: HashSet<&str> = graph
+        .clusters
+        .iter()
+        .flat_map(|cluster| cluster.members.iter().map(String::as_str))
+        .collect();
+    for (i, cluster) in graph.clusters.iter().enumerate() {
+        let _ = writeln!(out, "  subgraph cluster_{i} {{");
+        let _ = writeln!(
+            out,
+            "    label=\"{}: {}\";",
+            dot_escape(&cluster.id),
+            dot_escape(&cluster.title)
+        );
+        out.push_str("    style=\"rounded\";\n");
+        for node in graph
+            .nodes
+            .iter()
+            .filter(|node| cluster.members.contains(&node.id))
+        {
+            write_node(&mut out, node, "    ");
+        }
+        out.push_str("  }\n");
+    }
+    for node in graph
+        .nodes
+        .iter()
+        .filter(|node| !clustered.contains(node.id.as_str()))
+    {
+        write_node(&mut out, node, "  ");
+    }
+
+    for edge in &graph.edges {
+        let _ = writeln!(
+            out,
+            "  \"{}\" -> \"{}\" [label=\"{}\", style={}];",
+            dot_escape(&edge.from),
+            dot_escape(&edge.to),
+            dot_escape(&edge.dep_type),
+            dot_edge_style(&edge.dep_type),
+        );
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Mermaid node IDs may not contain most punctuation.
+fn mermaid_id(id: &str) -> String {
+    id.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+fn mermaid_text(value: &str) -> String {
+    value.replace('"', "#quot;")
+}
+
+/// Render as a Mermaid flowchart.
+#[must_use]
+pub fn render_mermaid(graph: &GraphExport) -> String {
+    let mut out = String::from("graph TD\n");
+
+    let clustered: HashSet<&str> = graph
+        .clusters
+        .iter()
+        .flat_map(|cluster| cluster.members.iter().map(String::as_str))
+        .collect();
+    let write_node = |out: &mut String, node: &GraphExportNode, indent: &str| {
+        let _ = writeln!(
+            out,
+            "{indent}{}[\"{}\"]",
+            mermaid_id(&node.id),
+            mermaid_text(&node_label(node))
+        );
+    };
+
+    for cluster in &graph.clusters {
+        let _ = writeln!(
+            out,
+            "    subgraph cluster_{}[\"{}: {}\"]",
+            mermaid_id(&cluster.id),
+            mermaid_text(&cluster.id),
+            mermaid_text(&cluster.title)
+        );
+        for node in graph
+            .nodes
+            .iter()
+            .filter(|node| cluster.members.contains(&node.id))
+        {
+            write_node(&mut out, node, "        ");
+        }
+        out.push_str("    end\n");
+    }
+    for node in graph
+        .nodes
+        .iter()
+        .filter(|node| !clustered.contains(node.id.as_str()))
+    {
+        write_node(&mut out, node, "    ");
+    }
+
+    for edge in &graph.edges {
+        let arrow = match edge.dep_type.as_str() {
+            "blocks" | "conditional-blocks" => "-->",
+            "parent-child" => "-.->",
+            _ => "-.-",
+        };
+        let _ = writeln!(
+            out,
+            "    {} {arrow}|{}| {}",
+            mermaid_id(&edge.from),
+            edge.dep_type,
+            mermaid_id(&edge.to)
+        );
+    }
+
+    let mut statuses: Vec<&str> = graph.nodes.iter().map(|n| n.status.as_str()).collect();
+    statuses.sort_unstable();
+    statuses.dedup();
+    for status in statuses {
+        let _ = writeln!(
+            out,
+            "    classDef status_{} fill:{},stroke:#333",
+            mermaid_id(status),
+            status_color(status)
+        );
+    }
+    for node in &graph.nodes {
+        let _ = writeln!(
+            out,
+            "    class {} status_{}",
+            mermaid_id(&node.id),
+            mermaid_id(&node.status)
+        );
+        if node.priority <= 1 {
+            let _ = writeln!(
+                out,
+                "    style {} stroke-width:{}px",
+                mermaid_id(&node.id),
+                priority_penwidth(node.priority)
+            );
+        }
+    }
+    out
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Render as `GraphML`. Cluster membership is stored in the `cluster` node key.
+#[must_use]
+pub fn render_graphml(graph: &GraphExport) -> String {
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+    for (id, target, name, kind) in [
+        ("title", "node", "title", "string"),
+        ("status", "node", "status", "string"),
+        ("priority", "node", "priority", "int"),
+        ("issue_type", "node", "issue_type", "string"),
+        ("depth", "node", "depth", "int"),
+        ("cluster", "node", "cluster", "string"),
+        ("type", "edge", "type", "string"),
+    ] {
+        let _ = writeln!(
+            out,
+            "  <key id=\"{id}\" for=\"{target}\" attr.name=\"{name}\" attr.type=\"{kind}\"/>"
+        );
+    }
+    out.push_str("  <graph id=\"beads\" edgedefault=\"directed\">\n");
+
+    for node in &graph.nodes {
+        let _ = writeln!(out, "    <node id=\"{}\">", xml_escape(&node.id));
+        let _ = writeln!(
+            out,
+            "      <data key=\"title\">{}</data>",
+            xml_escape(&node.title)
+        );
+        let _ = writeln!(
+            out,
+            "      <data key=\"status\">{}</data>",
+            xml_escape(&node.status)
+        );
+        let _ = writeln!(out, "      <data key=\"priority\">{}</data>", node.priority);
+        let _ = writeln!(
+            out,
+            "      <data key=\"issue_type\">{}</data>",
+            xml_escape(&node.issue_type)
+        );
+        let _ = writeln!(out, "      <data key=\"depth\">{}</data>", node.depth);
+        if let Some(cluster) = graph
+            .clusters
+            .iter()
+            .find(|cluster| cluster.members.contains(&node.id))
+        {
+            let _ = writeln!(
+                out,
+                "      <data key=\"cluster\">{}</data>",
+                xml_escape(&cluster.id)
+            );
+        }
+        out.push_str("    </node>\n");
+    }
+    for (i, edge) in graph.edges.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "    <edge id=\"e{i}\" source=\"{}\" target=\"{}\">",
+            xml_escape(&edge.from),
+            xml_escape(&edge.to)
+        );
+        let _ = writeln!(
+            out,
+            "      <data key=\"type\">{}</data>",
+            xml_escape(&edge.dep_type)
+        );
+        out.push_str("    </edge>\n");
+    }
+
+    out.push_str("  </graph>\n</graphml>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> GraphExport {
+        let node = |id: &str, title: &str, status: &str, priority| GraphExportNode {
+            id: id.to_string(),
+            title: title.to_string(),
+            status: status.to_string(),
+            priority,
+            issue_type: "task".to_string(),
+            depth: 0,
+        };
+        let edge = |from: &str, to: &str, dep_type: &str| GraphExportEdge {
+            from: from.to_string(),
+            to: to.to_string(),
+            dep_type: dep_type.to_string(),
+        };
+        GraphExport {
+            root: None,
+            nodes: vec![
+                node("bd-epic", "Epic", "open", 1),
+                node("bd-a", "Say \"hi\"", "in_progress", 0),
+                node("bd-b", "B & <C>", "closed", 2),
+            ],
+            edges: vec![
+                edge("bd-a", "bd-epic", "parent-child"),
+                edge("bd-a", "bd-b", "blocks"),
+            ],
+            clusters: vec![GraphCluster {
+                id: "bd-epic".to_string(),
+                title: "Epic".to_string(),
+                members: vec!["bd-a".to_string()],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_render_dot_clusters_and_labels() {
+        let dot = render_dot(&sample());
+        assert!(dot.starts_with("digraph beads {"));
+        assert!(dot.contains("subgraph cluster_0 {"));
+        assert!(dot.contains(r#"label="bd-a: Say \"hi\" [P0]""#));
+        assert!(dot.contains(r#""bd-a" -> "bd-b" [label="blocks", style=solid];"#));
+        assert!(dot.contains(r#""bd-a" -> "bd-epic" [label="parent-child", style=dashed];"#));
+        assert!(dot.contains("penwidth=3"));
+    }
+
+    #[test]
+    fn test_render_mermaid_and_graphml_escape() {
+        let mermaid = render_mermaid(&sample());
+        assert!(mermaid.contains("subgraph cluster_bd_epic"));
+        assert!(mermaid.contains("bd_a -->|blocks| bd_b"));
+        assert!(mermaid.contains("#quot;hi#quot;"));
+        assert!(mermaid.contains("class bd_b status_closed"));
+
+        let graphml = render_graphml(&sample());
+        assert!(graphml.contains("<data key=\"title\">B &amp; &lt;C&gt;</data>"));
+        assert!(graphml.contains("<data key=\"cluster\">bd-epic</data>"));
+        assert!(graphml.contains("source=\"bd-a\" target=\"bd-b\""));
+    }
+}
@@ -6,11 +6,14 @@
 //! # Output Types
 //!
 //! These types match the classic bd JSON schemas for CLI compatibility:
-//! - [`IssueWithCounts`] - Issue with dependency/dependent counts (list/search)
+//! - [`IssueWithCounts`] - Issue with dependency/dependent counts (list)
+//! - [`IssueSearchHit`] - `IssueWithCounts` plus relevance score and snippet (search)
 //! - [`IssueDetails`] - Issue with full relations (show)
 //! - [`BlockedIssue`] - Issue with blocking info (blocked)
 //! - [`TreeNode`] - Issue in dependency tree (dep tree)
 //! - [`Statistics`] - Aggregate stats (stats/status)
+//! - [`AsOfRecord`] - Any of the above reconstructed at a past instant (`--as-of`)
+//! - [`RoutedRecord`] - Any of the above tagged with its project (`--all-routes`)
 //!
 //! # CSV Output
 //!
@@ -31,9 +34,15 @@
 //! - Plain: TTY with `--no-color` or not a TTY
 //! - JSON: `--json` flag
 //! - Quiet: `--quiet` flag
+//!
+//! # Graph Export
+//!
+//! The [`graph`] module renders dependency graphs as DOT, Mermaid and `GraphML`
+//! for `br graph --format`.
 
 pub mod context;
 pub mod csv;
+pub mod graph;
 pub mod markdown;
 mod output;
 pub mod rich;
@@ -42,9 +51,9 @@ mod text;
 pub mod theme;
 
 pub use output::{
-    BlockedIssue, BlockedIssueOutput, Breakdown, BreakdownEntry, IssueDetails, IssueWithCounts,
-    IssueWithDependencyMetadata, ReadyIssue, RecentActivity, StaleIssue, Statistics, StatsSummary,
-    TreeNode,
+    AsOfRecord, BlockedIssue, BlockedIssueOutput, Breakdown, BreakdownEntry, IssueDetails,
+    IssueSearchHit, IssueWithCounts, IssueWithDependencyMetadata, ReadyIssue, RecentActivity,
+    RoutedRecord, StaleIssue, Statistics, StatsSummary, TreeNode,
 };
 pub use text::{
     TextFormatOptions, format_issue_line, format_issue_line_with, format_priority,
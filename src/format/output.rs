@@ -26,6 +26,9 @@ pub struct ReadyIssue {
     pub acceptance_criteria: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub assignee: Option<String>,
+    /// Why a `conditional-blocks` dependent is ready (its blocker failed).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
     pub created_at: DateTime<Utc>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_by: Option<String>,
@@ -50,6 +53,7 @@ impl From<&Issue> for ReadyIssue {
         Self {
             acceptance_criteria: issue.acceptance_criteria.clone(),
             assignee: issue.assignee.clone(),
+            condition: None,
             created_at: issue.created_at,
             created_by: issue.created_by.clone(),
             description: issue.description.clone(),
@@ -74,6 +78,9 @@ impl From<&Issue> for ReadyIssue {
 pub struct BlockedIssueOutput {
     pub blocked_by: Vec<String>,
     pub blocked_by_count: usize,
+    /// Explanations for `conditional-blocks` blockers (waiting on a failure, or moot).
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub conditions: Vec<String>,
     pub created_at: DateTime<Utc>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_by: Option<String>,
@@ -110,6 +117,18 @@ pub struct IssueWithCounts {
     pub dependent_count: usize,
 }
 
+/// Issue with counts plus full-text relevance for search views.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct IssueSearchHit {
+    #[serde(flatten)]
+    pub issue: IssueWithCounts,
+    /// BM25 relevance score (higher is better).
+    pub score: f64,
+    /// Best-matching excerpt with matched terms wrapped in `**`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+}
+
 /// Issue details with full relations for show view.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct IssueDetails {
@@ -158,6 +177,26 @@ pub struct TreeNode {
     pub truncated: bool,
 }
 
+/// Item reconstructed by an `--as-of` query.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AsOfRecord<T> {
+    #[serde(flatten)]
+    pub item: T,
+    pub as_of: DateTime<Utc>,
+    /// Fields that could not be reconstructed and show their current value.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unreconstructed: Vec<String>,
+}
+
+/// Item from another workspace, merged by `--all-routes`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RoutedRecord<T> {
+    /// Project directory the item came from.
+    pub project: String,
+    #[serde(flatten)]
+    pub item: T,
+}
+
 /// Summary statistics for the project.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct StatsSummary {
@@ -209,12 +248,19 @@ pub struct Statistics {
     pub breakdowns: Vec<Breakdown>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub recent_activity: Option<RecentActivity>,
+    /// Set when the statistics describe a past instant (`--as-of`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub as_of: Option<DateTime<Utc>>,
+    /// Issues counted with at least one unreconstructed field.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unreconstructed_issues: Vec<String>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use chrono::{TimeZone, Utc};
+    use std::collections::BTreeMap;
 
     fn base_issue(id: &str, title: &str) -> Issue {
         Issue {
@@ -236,6 +282,7 @@ mod tests {
             updated_at: Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             due_at: None,
             defer_until: None,
@@ -257,6 +304,7 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
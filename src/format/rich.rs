@@ -323,6 +323,7 @@ mod tests {
     use super::*;
     use crate::model::{IssueType, Priority};
     use chrono::Utc;
+    use std::collections::BTreeMap;
 
     fn make_test_issue(id: &str, title: &str) -> Issue {
         Issue {
@@ -345,6 +346,7 @@ mod tests {
             created_by: None,
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             due_at: None,
             defer_until: None,
@@ -365,6 +367,7 @@ mod tests {
             is_template: false,
             dependencies: vec![],
             comments: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
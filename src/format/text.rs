@@ -285,6 +285,7 @@ pub fn format_issue_line(issue: &Issue) -> String {
 mod tests {
     use super::*;
     use chrono::Utc;
+    use std::collections::BTreeMap;
 
     fn make_test_issue() -> Issue {
         Issue {
@@ -306,6 +307,7 @@ mod tests {
             updated_at: Utc::now(),
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             due_at: None,
             defer_until: None,
@@ -327,6 +329,7 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
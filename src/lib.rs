@@ -15,6 +15,7 @@
 //! - [`error`] - Error types and handling
 //! - [`format`] - Output formatting (text, JSON)
 //! - [`util`] - Utility functions (hashing, time, paths)
+//! - [`workspace`] - Typed library API over a beads workspace
 
 #![forbid(unsafe_code)]
 // Lint configuration is in Cargo.toml [lints.clippy] section
@@ -31,21 +32,23 @@ pub mod storage;
 pub mod sync;
 pub mod util;
 pub mod validation;
+pub mod workspace;
 
 pub use error::{BeadsError, ErrorCode, Result, StructuredError};
+pub use workspace::Workspace;
 
 /// Run the CLI application.
 ///
-/// This is the main entry point called from `main()`.
+/// This is the entry point used by the `br` binary. Library callers and
+/// tests can build a [`cli::Cli`] with `Cli::parse_from` and run it in
+/// process; the current directory is used to discover the workspace.
 ///
 /// # Errors
 ///
-/// Returns an error if command execution fails.
-#[allow(clippy::missing_const_for_fn)] // Will have side effects once implemented
-pub fn run() -> Result<()> {
-    // CLI execution is currently handled in main.rs directly.
-    // This function can be used for library-level integration tests or embedding.
-    Ok(())
+/// Returns an error if command execution fails. Nothing is printed for the
+/// error; use [`StructuredError`] to report it.
+pub fn run(cli: cli::Cli) -> Result<()> {
+    cli::dispatch::run(cli)
 }
 
 #[cfg(test)]
@@ -53,8 +56,11 @@ mod tests {
     use super::*;
 
     #[test]
-    fn run_returns_ok() {
-        assert!(run().is_ok());
+    fn run_executes_parsed_command() {
+        use clap::Parser;
+
+        let cli = cli::Cli::parse_from(["br", "version"]);
+        assert!(run(cli).is_ok());
     }
 
     #[test]
@@ -3,6 +3,7 @@
 //! This module defines the fundamental types used throughout the application:
 //! - `Issue` - The core work item
 //! - `Status` - Issue lifecycle states
+//! - `CloseOutcome` - How a closed issue ended
 //! - `IssueType` - Categories of issues
 //! - `Dependency` - Relationships between issues
 //! - `Comment` - Issue comments
@@ -11,6 +12,7 @@
 use chrono::{DateTime, Utc};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
 use std::fmt;
 use std::str::FromStr;
 
@@ -97,6 +99,51 @@ impl FromStr for Status {
     }
 }
 
+/// Outcome recorded when an issue is closed.
+///
+/// A `conditional-blocks` dependent runs only if its blocker closed with
+/// [`CloseOutcome::Failure`]; any other closure makes it moot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CloseOutcome {
+    Success,
+    Failure,
+    Wontfix,
+}
+
+impl CloseOutcome {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Failure => "failure",
+            Self::Wontfix => "wontfix",
+        }
+    }
+}
+
+impl fmt::Display for CloseOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for CloseOutcome {
+    type Err = crate::error::BeadsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "success" | "succeeded" | "done" => Ok(Self::Success),
+            "failure" | "failed" | "fail" => Ok(Self::Failure),
+            "wontfix" | "won't-fix" | "wont-fix" | "wont_fix" => Ok(Self::Wontfix),
+            other => Err(crate::error::BeadsError::validation(
+                "outcome",
+                format!("invalid outcome '{other}' (expected success, failure or wontfix)"),
+            )),
+        }
+    }
+}
+
 /// Issue priority (0=Critical, 4=Backlog).
 #[derive(
     Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Default, JsonSchema,
@@ -282,6 +329,8 @@ impl FromStr for DependencyType {
 pub enum EventType {
     Created,
     Updated,
+    TitleChanged,
+    FieldChanged,
     StatusChanged,
     PriorityChanged,
     AssigneeChanged,
@@ -295,6 +344,7 @@ pub enum EventType {
     Compacted,
     Deleted,
     Restored,
+    Uncompacted,
     Custom(String),
 }
 
@@ -304,6 +354,8 @@ impl EventType {
         match self {
             Self::Created => "created",
             Self::Updated => "updated",
+            Self::TitleChanged => "title_changed",
+            Self::FieldChanged => "field_changed",
             Self::StatusChanged => "status_changed",
             Self::PriorityChanged => "priority_changed",
             Self::AssigneeChanged => "assignee_changed",
@@ -317,6 +369,7 @@ impl EventType {
             Self::Compacted => "compacted",
             Self::Deleted => "deleted",
             Self::Restored => "restored",
+            Self::Uncompacted => "uncompacted",
             Self::Custom(value) => value,
         }
     }
@@ -334,6 +387,8 @@ impl<'de> Deserialize<'de> for EventType {
         let event_type = match value.as_str() {
             "created" => Self::Created,
             "updated" => Self::Updated,
+            "title_changed" => Self::TitleChanged,
+            "field_changed" => Self::FieldChanged,
             "status_changed" => Self::StatusChanged,
             "priority_changed" => Self::PriorityChanged,
             "assignee_changed" => Self::AssigneeChanged,
@@ -347,6 +402,7 @@ impl<'de> Deserialize<'de> for EventType {
             "compacted" => Self::Compacted,
             "deleted" => Self::Deleted,
             "restored" => Self::Restored,
+            "uncompacted" => Self::Uncompacted,
             _ => Self::Custom(value),
         };
         Ok(event_type)
@@ -435,6 +491,10 @@ pub struct Issue {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub close_reason: Option<String>,
 
+    /// How the work ended; drives `conditional-blocks` dependents.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub close_outcome: Option<CloseOutcome>,
+
     /// Session ID that closed this issue.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub closed_by_session: Option<String>,
@@ -500,6 +560,9 @@ pub struct Issue {
     pub dependencies: Vec<Dependency>,
     #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub comments: Vec<Comment>,
+    /// Custom field values, typed by the schema in `config.yaml`
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub fields: BTreeMap<String, serde_json::Value>,
 }
 
 impl Default for Issue {
@@ -523,6 +586,7 @@ impl Default for Issue {
             updated_at: Utc::now(),
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             due_at: None,
             defer_until: None,
@@ -544,6 +608,7 @@ impl Default for Issue {
             labels: Vec::new(),
             dependencies: Vec::new(),
             comments: Vec::new(),
+            fields: BTreeMap::new(),
         }
     }
 }
@@ -651,6 +716,28 @@ pub struct Event {
     pub created_at: DateTime<Utc>,
 }
 
+/// A lease on an issue taken with `--claim`.
+///
+/// Leases live only in the local database. A claim that is not renewed
+/// before `expires_at` lapses and the issue becomes available again.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+pub struct Claim {
+    pub issue_id: String,
+    pub claimed_by: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub claimed_by_session: Option<String>,
+    pub claimed_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl Claim {
+    /// Whether the lease has lapsed at `now`.
+    #[must_use]
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -710,6 +797,7 @@ mod tests {
             updated_at: Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             due_at: None,
             defer_until: None,
@@ -731,6 +819,7 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            fields: BTreeMap::new(),
         };
 
         let json = serde_json::to_string(&issue).unwrap();
@@ -1165,6 +1254,7 @@ mod tests {
             updated_at: Utc.timestamp_opt(1_700_000_000, 0).unwrap(),
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             due_at: None,
             defer_until: None,
@@ -1186,6 +1276,7 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -1356,6 +1447,9 @@ mod tests {
         assert_eq!(EventType::Compacted.as_str(), "compacted");
         assert_eq!(EventType::Deleted.as_str(), "deleted");
         assert_eq!(EventType::Restored.as_str(), "restored");
+        assert_eq!(EventType::Uncompacted.as_str(), "uncompacted");
+        assert_eq!(EventType::TitleChanged.as_str(), "title_changed");
+        assert_eq!(EventType::FieldChanged.as_str(), "field_changed");
         assert_eq!(
             EventType::Custom("my_event".to_string()).as_str(),
             "my_event"
@@ -1380,6 +1474,9 @@ mod tests {
             ("\"compacted\"", EventType::Compacted),
             ("\"deleted\"", EventType::Deleted),
             ("\"restored\"", EventType::Restored),
+            ("\"uncompacted\"", EventType::Uncompacted),
+            ("\"title_changed\"", EventType::TitleChanged),
+            ("\"field_changed\"", EventType::FieldChanged),
         ];
 
         for (json, expected) in events {
@@ -1,3 +1,4 @@
+use crate::config::fields::display_value;
 use crate::format::{IssueDetails, IssueWithDependencyMetadata};
 use crate::model::{Comment, Dependency, Issue};
 use crate::output::{OutputContext, Theme};
@@ -112,6 +113,18 @@ impl<'a> IssuePanel<'a> {
             content.append("\n");
         }
 
+        // Custom fields
+        if !self.issue.fields.is_empty() {
+            content.append_styled("Fields:   ", self.theme.dimmed.clone());
+            for (i, (name, value)) in self.issue.fields.iter().enumerate() {
+                if i > 0 {
+                    content.append(", ");
+                }
+                content.append(&format!("{name}={}", display_value(value)));
+            }
+            content.append("\n");
+        }
+
         // Timestamps
         content.append_styled("Created:  ", self.theme.dimmed.clone());
         content.append_styled(
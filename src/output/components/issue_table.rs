@@ -14,6 +14,7 @@ pub struct IssueTable<'a> {
     title: Option<String>,
     highlight_query: Option<String>,
     context_snippets: Option<HashMap<String, String>>,
+    projects: Option<HashMap<String, String>>,
     width: Option<usize>,
     wrap: bool,
 }
@@ -21,6 +22,7 @@ pub struct IssueTable<'a> {
 #[derive(Default, Clone)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct IssueTableColumns {
+    pub project: bool,
     pub id: bool,
     pub priority: bool,
     pub status: bool,
@@ -61,6 +63,7 @@ impl IssueTableColumns {
     #[must_use]
     pub fn full() -> Self {
         Self {
+            project: false,
             id: true,
             priority: true,
             status: true,
@@ -85,6 +88,7 @@ impl<'a> IssueTable<'a> {
             title: None,
             highlight_query: None,
             context_snippets: None,
+            projects: None,
             width: None,
             wrap: false,
         }
@@ -131,6 +135,13 @@ impl<'a> IssueTable<'a> {
         self
     }
 
+    /// Project name per issue ID, shown in the `Project` column when enabled.
+    #[must_use]
+    pub fn projects(mut self, projects: HashMap<String, String>) -> Self {
+        self.projects = Some(projects);
+        self
+    }
+
     #[must_use]
     #[allow(clippy::too_many_lines)]
     pub fn build(&self) -> Table {
@@ -152,6 +163,9 @@ impl<'a> IssueTable<'a> {
         }
 
         // Add columns based on config
+        if self.columns.project {
+            table = table.with_column(Column::new("Project").max_width(20));
+        }
         if self.columns.id {
             table = table.with_column(Column::new("ID").min_width(10));
         }
@@ -191,6 +205,14 @@ impl<'a> IssueTable<'a> {
         for issue in self.issues {
             let mut cells: Vec<Cell> = vec![];
 
+            if self.columns.project {
+                let project = self
+                    .projects
+                    .as_ref()
+                    .and_then(|projects| projects.get(&issue.id))
+                    .map_or("", String::as_str);
+                cells.push(Cell::new(Text::new(project)).style(self.theme.accent.clone()));
+            }
             if self.columns.id {
                 cells.push(Cell::new(Text::new(&issue.id)).style(self.theme.issue_id.clone()));
             }
@@ -5,7 +5,8 @@
 //! - Event retrieval (newest first, DESC ordering)
 //! - Schema definitions for the events table
 //!
-//! Events are local DB only - never exported to JSONL.
+//! Events live in the local DB. They are only written to JSONL when the
+//! opt-in `events.jsonl` log is enabled (see `sync::events`).
 
 use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
 use rusqlite::{Connection, Transaction, params};
@@ -457,6 +458,55 @@ pub fn get_all_events(conn: &Connection, limit: usize) -> Result<Vec<Event>> {
     Ok(events)
 }
 
+/// Get events for issues that are exported to JSONL, oldest first.
+///
+/// Events of ephemeral issues and wisps are skipped, matching the issue export.
+///
+/// # Errors
+///
+/// Returns an error if the database query fails.
+pub fn get_exportable_events(conn: &Connection) -> Result<Vec<Event>> {
+    let mut stmt = conn.prepare(
+        r"
+        SELECT e.id, e.issue_id, e.event_type, e.actor, e.old_value, e.new_value, e.comment, e.created_at
+        FROM events e
+        JOIN issues i ON i.id = e.issue_id
+        WHERE (i.ephemeral = 0 OR i.ephemeral IS NULL)
+          AND i.id NOT LIKE '%-wisp-%'
+        ORDER BY e.created_at ASC, e.id ASC
+        ",
+    )?;
+    let events = stmt
+        .query_map([], event_from_row)?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(events)
+}
+
+/// Insert an event as recorded elsewhere, keeping its original timestamp.
+///
+/// # Errors
+///
+/// Returns an error if the database insert fails.
+pub fn insert_event_record(tx: &Transaction<'_>, event: &Event) -> Result<i64> {
+    tx.execute(
+        r"
+        INSERT INTO events (issue_id, event_type, actor, old_value, new_value, comment, created_at)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+        ",
+        params![
+            event.issue_id,
+            event.event_type.as_str(),
+            event.actor,
+            event.old_value,
+            event.new_value,
+            event.comment,
+            event.created_at.to_rfc3339(),
+        ],
+    )?;
+
+    Ok(tx.last_insert_rowid())
+}
+
 /// Get event count for an issue.
 ///
 /// # Errors
@@ -476,6 +526,8 @@ fn parse_event_type(s: &str) -> EventType {
     match s {
         "created" => EventType::Created,
         "updated" => EventType::Updated,
+        "title_changed" => EventType::TitleChanged,
+        "field_changed" => EventType::FieldChanged,
         "status_changed" => EventType::StatusChanged,
         "priority_changed" => EventType::PriorityChanged,
         "assignee_changed" => EventType::AssigneeChanged,
@@ -489,6 +541,7 @@ fn parse_event_type(s: &str) -> EventType {
         "compacted" => EventType::Compacted,
         "deleted" => EventType::Deleted,
         "restored" => EventType::Restored,
+        "uncompacted" => EventType::Uncompacted,
         other => EventType::Custom(other.to_string()),
     }
 }
@@ -0,0 +1,776 @@
+//! Filter expressions for `--where`.
+//!
+//! A small boolean language over issue fields:
+//!
+//! ```text
+//! priority <= 1 and (assignee = me or assignee is null) and updated > -7d and not label:wontfix
+//! ```
+//!
+//! [`FilterExpr::parse`] produces a typed AST whose values are already
+//! validated and normalized for their field (statuses, types, priorities,
+//! timestamps). The `SQLite` backend compiles the AST to parameterized SQL.
+//!
+//! Grammar (keywords are case-insensitive):
+//!
+//! ```text
+//! expr      := and_expr ("or" and_expr)*
+//! and_expr  := unary ("and" unary)*
+//! unary     := "not" unary | "(" expr ")" | predicate
+//! predicate := field op value
+//!            | field ":" value                      (same as field = value)
+//!            | field "is" ["not"] "null"
+//!            | field ["not"] "in" "(" value ("," value)* ")"
+//! op        := "=" | "!=" | "<" | "<=" | ">" | ">=" | "~" | "!~"
+//! ```
+//!
+//! `~` is a case-insensitive substring match. Values are bare words or
+//! double-quoted strings. A bare `me` on a person field means the current
+//! actor; timestamps accept the same forms as `--due`/`--defer` (`-7d`,
+//! `2025-01-15`, RFC3339, ...).
+
+use crate::error::{BeadsError, Result};
+use crate::model::{IssueType, Priority, Status};
+use crate::util::time::parse_flexible_timestamp;
+use chrono::{DateTime, Utc};
+
+/// Issue field that can appear in a filter expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterField {
+    Id,
+    Title,
+    Description,
+    Design,
+    AcceptanceCriteria,
+    Notes,
+    Status,
+    Priority,
+    IssueType,
+    Assignee,
+    Owner,
+    CreatedBy,
+    ExternalRef,
+    EstimatedMinutes,
+    CreatedAt,
+    UpdatedAt,
+    ClosedAt,
+    DueAt,
+    DeferUntil,
+    /// Matches if any of the issue's labels satisfies the predicate.
+    Label,
+}
+
+/// How values of a field are parsed and which operators apply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FieldKind {
+    Text,
+    Person,
+    Status,
+    Type,
+    Priority,
+    Integer,
+    Timestamp,
+    Label,
+}
+
+impl FilterField {
+    const ALL: &[Self] = &[
+        Self::Id,
+        Self::Title,
+        Self::Description,
+        Self::Design,
+        Self::AcceptanceCriteria,
+        Self::Notes,
+        Self::Status,
+        Self::Priority,
+        Self::IssueType,
+        Self::Assignee,
+        Self::Owner,
+        Self::CreatedBy,
+        Self::ExternalRef,
+        Self::EstimatedMinutes,
+        Self::CreatedAt,
+        Self::UpdatedAt,
+        Self::ClosedAt,
+        Self::DueAt,
+        Self::DeferUntil,
+        Self::Label,
+    ];
+
+    /// Look up a field by name (case-insensitive, common aliases accepted).
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        let field = match name.to_ascii_lowercase().as_str() {
+            "id" => Self::Id,
+            "title" => Self::Title,
+            "description" | "desc" => Self::Description,
+            "design" => Self::Design,
+            "acceptance" | "acceptance_criteria" => Self::AcceptanceCriteria,
+            "notes" => Self::Notes,
+            "status" => Self::Status,
+            "priority" => Self::Priority,
+            "type" | "issue_type" => Self::IssueType,
+            "assignee" => Self::Assignee,
+            "owner" => Self::Owner,
+            "creator" | "created_by" => Self::CreatedBy,
+            "external_ref" | "ref" => Self::ExternalRef,
+            "estimate" | "estimated_minutes" => Self::EstimatedMinutes,
+            "created" | "created_at" => Self::CreatedAt,
+            "updated" | "updated_at" => Self::UpdatedAt,
+            "closed" | "closed_at" => Self::ClosedAt,
+            "due" | "due_at" => Self::DueAt,
+            "defer" | "defer_until" => Self::DeferUntil,
+            "label" | "labels" => Self::Label,
+            _ => return None,
+        };
+        Some(field)
+    }
+
+    /// Canonical name, as accepted by [`Self::from_name`].
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Id => "id",
+            Self::Title => "title",
+            Self::Description => "description",
+            Self::Design => "design",
+            Self::AcceptanceCriteria => "acceptance",
+            Self::Notes => "notes",
+            Self::Status => "status",
+            Self::Priority => "priority",
+            Self::IssueType => "type",
+            Self::Assignee => "assignee",
+            Self::Owner => "owner",
+            Self::CreatedBy => "creator",
+            Self::ExternalRef => "external_ref",
+            Self::EstimatedMinutes => "estimate",
+            Self::CreatedAt => "created",
+            Self::UpdatedAt => "updated",
+            Self::ClosedAt => "closed",
+            Self::DueAt => "due",
+            Self::DeferUntil => "defer",
+            Self::Label => "label",
+        }
+    }
+
+    /// Backing column (`labels.label` for [`Self::Label`]).
+    #[must_use]
+    pub const fn column(self) -> &'static str {
+        match self {
+            Self::Id => "id",
+            Self::Title => "title",
+            Self::Description => "description",
+            Self::Design => "design",
+            Self::AcceptanceCriteria => "acceptance_criteria",
+            Self::Notes => "notes",
+            Self::Status => "status",
+            Self::Priority => "priority",
+            Self::IssueType => "issue_type",
+            Self::Assignee => "assignee",
+            Self::Owner => "owner",
+            Self::CreatedBy => "created_by",
+            Self::ExternalRef => "external_ref",
+            Self::EstimatedMinutes => "estimated_minutes",
+            Self::CreatedAt => "created_at",
+            Self::UpdatedAt => "updated_at",
+            Self::ClosedAt => "closed_at",
+            Self::DueAt => "due_at",
+            Self::DeferUntil => "defer_until",
+            Self::Label => "labels.label",
+        }
+    }
+
+    const fn kind(self) -> FieldKind {
+        match self {
+            Self::Id
+            | Self::Title
+            | Self::Description
+            | Self::Design
+            | Self::AcceptanceCriteria
+            | Self::Notes
+            | Self::ExternalRef => FieldKind::Text,
+            Self::Assignee | Self::Owner | Self::CreatedBy => FieldKind::Person,
+            Self::Status => FieldKind::Status,
+            Self::IssueType => FieldKind::Type,
+            Self::Priority => FieldKind::Priority,
+            Self::EstimatedMinutes => FieldKind::Integer,
+            Self::CreatedAt | Self::UpdatedAt | Self::ClosedAt | Self::DueAt | Self::DeferUntil => {
+                FieldKind::Timestamp
+            }
+            Self::Label => FieldKind::Label,
+        }
+    }
+}
+
+/// Comparison operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    /// Case-insensitive substring match (`~`).
+    Contains,
+    /// Negated substring match (`!~`).
+    NotContains,
+}
+
+impl CompareOp {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Eq => "=",
+            Self::Ne => "!=",
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+            Self::Contains => "~",
+            Self::NotContains => "!~",
+        }
+    }
+
+    const fn is_ordering(self) -> bool {
+        matches!(self, Self::Lt | Self::Le | Self::Gt | Self::Ge)
+    }
+
+    /// Whether this is `~` or `!~`.
+    #[must_use]
+    pub const fn is_substring(self) -> bool {
+        matches!(self, Self::Contains | Self::NotContains)
+    }
+}
+
+/// A value normalized for the field it is compared against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FilterValue {
+    Text(String),
+    Integer(i64),
+    Timestamp(DateTime<Utc>),
+}
+
+/// Parsed `--where` expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FilterExpr {
+    And(Box<Self>, Box<Self>),
+    Or(Box<Self>, Box<Self>),
+    Not(Box<Self>),
+    Compare {
+        field: FilterField,
+        op: CompareOp,
+        value: FilterValue,
+    },
+    In {
+        field: FilterField,
+        values: Vec<FilterValue>,
+    },
+    /// Field is unset (for [`FilterField::Label`]: the issue has no labels).
+    IsNull(FilterField),
+}
+
+impl FilterExpr {
+    /// Parse an expression. `actor` is substituted for a bare `me`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error describing the first syntax or type error.
+    pub fn parse(input: &str, actor: &str) -> Result<Self> {
+        let tokens = tokenize(input)?;
+        if tokens.is_empty() {
+            return Err(invalid("expression is empty"));
+        }
+        let mut parser = Parser {
+            tokens,
+            pos: 0,
+            actor,
+        };
+        let expr = parser.or_expr()?;
+        if let Some(token) = parser.peek() {
+            return Err(invalid(format!("unexpected {}", token.describe())));
+        }
+        Ok(expr)
+    }
+
+    /// Whether any predicate in the expression tests `field`.
+    #[must_use]
+    pub fn references(&self, field: FilterField) -> bool {
+        match self {
+            Self::And(left, right) | Self::Or(left, right) => {
+                left.references(field) || right.references(field)
+            }
+            Self::Not(inner) => inner.references(field),
+            Self::Compare { field: f, .. } | Self::In { field: f, .. } | Self::IsNull(f) => {
+                *f == field
+            }
+        }
+    }
+}
+
+fn invalid(reason: impl Into<String>) -> BeadsError {
+    BeadsError::validation("where", reason)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    Op(CompareOp),
+    LParen,
+    RParen,
+    Comma,
+}
+
+impl Token {
+    fn describe(&self) -> String {
+        match self {
+            Self::Word(word) => format!("'{word}'"),
+            Self::Quoted(text) => format!("\"{text}\""),
+            Self::Op(op) => format!("'{}'", op.as_str()),
+            Self::LParen => "'('".to_string(),
+            Self::RParen => "')'".to_string(),
+            Self::Comma => "','".to_string(),
+        }
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self, Self::Word(word) if word.eq_ignore_ascii_case(keyword))
+    }
+}
+
+const fn is_word_char(c: char) -> bool {
+    !c.is_whitespace() && !matches!(c, '(' | ')' | ',' | '"' | '=' | '!' | '<' | '>' | '~')
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        match c {
+            '(' | ')' | ',' => {
+                chars.next();
+                tokens.push(match c {
+                    '(' => Token::LParen,
+                    ')' => Token::RParen,
+                    _ => Token::Comma,
+                });
+            }
+            '"' => {
+                chars.next();
+                let mut text = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => {
+                            if let Some(escaped) = chars.next() {
+                                text.push(escaped);
+                            }
+                        }
+                        Some(other) => text.push(other),
+                        None => return Err(invalid("unterminated quoted string")),
+                    }
+                }
+                tokens.push(Token::Quoted(text));
+            }
+            '=' | '!' | '<' | '>' | '~' => {
+                chars.next();
+                let next = chars.peek().copied();
+                let (op, consumed) = match (c, next) {
+                    ('=', Some('=')) => (CompareOp::Eq, true),
+                    ('=', _) => (CompareOp::Eq, false),
+                    ('!', Some('=')) => (CompareOp::Ne, true),
+                    ('!', Some('~')) => (CompareOp::NotContains, true),
+                    ('<', Some('=')) => (CompareOp::Le, true),
+                    ('<', Some('>')) => (CompareOp::Ne, true),
+                    ('<', _) => (CompareOp::Lt, false),
+                    ('>', Some('=')) => (CompareOp::Ge, true),
+                    ('>', _) => (CompareOp::Gt, false),
+                    ('~', _) => (CompareOp::Contains, false),
+                    _ => return Err(invalid("expected '!=' or '!~'")),
+                };
+                if consumed {
+                    chars.next();
+                }
+                tokens.push(Token::Op(op));
+            }
+            _ => {
+                let mut word = String::new();
+                while let Some(&c) = chars.peek() {
+                    if !is_word_char(c) {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+                tokens.push(Token::Word(word));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// A literal as written, before it is typed against a field.
+struct RawValue {
+    text: String,
+    quoted: bool,
+}
+
+struct Parser<'a> {
+    tokens: Vec<Token>,
+    pos: usize,
+    actor: &'a str,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        if self.peek().is_some_and(|token| token.is_keyword(keyword)) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: &Token) -> Result<()> {
+        match self.advance() {
+            Some(token) if token == *expected => Ok(()),
+            Some(token) => Err(invalid(format!(
+                "expected {}, found {}",
+                expected.describe(),
+                token.describe()
+            ))),
+            None => Err(invalid(format!(
+                "expected {}, found end of expression",
+                expected.describe()
+            ))),
+        }
+    }
+
+    fn or_expr(&mut self) -> Result<FilterExpr> {
+        let mut expr = self.and_expr()?;
+        while self.eat_keyword("or") {
+            let right = self.and_expr()?;
+            expr = FilterExpr::Or(Box::new(expr), Box::new(right));
+        }
+        Ok(expr)
+    }
+
+    fn and_expr(&mut self) -> Result<FilterExpr> {
+        let mut expr = self.unary()?;
+        while self.eat_keyword("and") {
+            let right = self.unary()?;
+            expr = FilterExpr::And(Box::new(expr), Box::new(right));
+        }
+        Ok(expr)
+    }
+
+    fn unary(&mut self) -> Result<FilterExpr> {
+        if self.eat_keyword("not") {
+            return Ok(FilterExpr::Not(Box::new(self.unary()?)));
+        }
+        if self.peek() == Some(&Token::LParen) {
+            self.pos += 1;
+            let expr = self.or_expr()?;
+            self.expect(&Token::RParen)?;
+            return Ok(expr);
+        }
+        self.predicate()
+    }
+
+    fn predicate(&mut self) -> Result<FilterExpr> {
+        let word = match self.advance() {
+            Some(Token::Word(word)) => word,
+            Some(token) => {
+                return Err(invalid(format!(
+                    "expected a field name, found {}",
+                    token.describe()
+                )));
+            }
+            None => return Err(invalid("expected a field name, found end of expression")),
+        };
+
+        // `field:value` shorthand (the value may also follow as a separate token).
+        if let Some((name, inline)) = word.split_once(':') {
+            let field = parse_field(name)?;
+            let raw = if inline.is_empty() {
+                self.value()?
+            } else {
+                RawValue {
+                    text: inline.to_string(),
+                    quoted: false,
+                }
+            };
+            return self.compare(field, CompareOp::Eq, &raw);
+        }
+
+        let field = parse_field(&word)?;
+        match self.advance() {
+            Some(Token::Op(op)) => {
+                let raw = self.value()?;
+                self.compare(field, op, &raw)
+            }
+            Some(token) if token.is_keyword("is") => {
+                let negated = self.eat_keyword("not");
+                if !self.eat_keyword("null") {
+                    return Err(invalid(format!(
+                        "expected 'null' after '{} is'",
+                        field.name()
+                    )));
+                }
+                let expr = FilterExpr::IsNull(field);
+                Ok(if negated {
+                    FilterExpr::Not(Box::new(expr))
+                } else {
+                    expr
+                })
+            }
+            Some(token) if token.is_keyword("in") => self.in_list(field),
+            Some(token) if token.is_keyword("not") => {
+                if !self.eat_keyword("in") {
+                    return Err(invalid(format!(
+                        "expected 'in' after '{} not'",
+                        field.name()
+                    )));
+                }
+                Ok(FilterExpr::Not(Box::new(self.in_list(field)?)))
+            }
+            Some(token) => Err(invalid(format!(
+                "expected an operator after '{}', found {}",
+                field.name(),
+                token.describe()
+            ))),
+            None => Err(invalid(format!(
+                "expected an operator after '{}'",
+                field.name()
+            ))),
+        }
+    }
+
+    fn in_list(&mut self, field: FilterField) -> Result<FilterExpr> {
+        self.expect(&Token::LParen)?;
+        let mut values = Vec::new();
+        loop {
+            let raw = self.value()?;
+            values.push(self.typed_value(field, CompareOp::Eq, &raw)?);
+            match self.advance() {
+                Some(Token::Comma) => {}
+                Some(Token::RParen) => break,
+                Some(token) => {
+                    return Err(invalid(format!(
+                        "expected ',' or ')', found {}",
+                        token.describe()
+                    )));
+                }
+                None => return Err(invalid("unterminated 'in' list")),
+            }
+        }
+        Ok(FilterExpr::In { field, values })
+    }
+
+    fn value(&mut self) -> Result<RawValue> {
+        match self.advance() {
+            Some(Token::Word(text)) => Ok(RawValue {
+                text,
+                quoted: false,
+            }),
+            Some(Token::Quoted(text)) => Ok(RawValue { text, quoted: true }),
+            Some(token) => Err(invalid(format!(
+                "expected a value, found {}",
+                token.describe()
+            ))),
+            None => Err(invalid("expected a value, found end of expression")),
+        }
+    }
+
+    fn compare(&self, field: FilterField, op: CompareOp, raw: &RawValue) -> Result<FilterExpr> {
+        let value = self.typed_value(field, op, raw)?;
+        Ok(FilterExpr::Compare { field, op, value })
+    }
+
+    fn typed_value(
+        &self,
+        field: FilterField,
+        op: CompareOp,
+        raw: &RawValue,
+    ) -> Result<FilterValue> {
+        let kind = field.kind();
+        let op_allowed = match kind {
+            FieldKind::Text | FieldKind::Person | FieldKind::Label => !op.is_ordering(),
+            FieldKind::Status | FieldKind::Type => matches!(op, CompareOp::Eq | CompareOp::Ne),
+            FieldKind::Priority | FieldKind::Integer | FieldKind::Timestamp => !op.is_substring(),
+        };
+        if !op_allowed {
+            return Err(invalid(format!(
+                "operator '{}' is not supported for '{}'",
+                op.as_str(),
+                field.name()
+            )));
+        }
+
+        let text = raw.text.as_str();
+        let value = match kind {
+            FieldKind::Text | FieldKind::Label => FilterValue::Text(text.to_string()),
+            FieldKind::Person => {
+                if !raw.quoted && text.eq_ignore_ascii_case("me") {
+                    FilterValue::Text(self.actor.to_string())
+                } else {
+                    FilterValue::Text(text.to_string())
+                }
+            }
+            FieldKind::Status => FilterValue::Text(text.parse::<Status>()?.as_str().to_string()),
+            FieldKind::Type => FilterValue::Text(text.parse::<IssueType>()?.as_str().to_string()),
+            FieldKind::Priority => FilterValue::Integer(i64::from(text.parse::<Priority>()?.0)),
+            FieldKind::Integer => FilterValue::Integer(text.parse().map_err(|_| {
+                invalid(format!("'{}' expects a number, got '{text}'", field.name()))
+            })?),
+            FieldKind::Timestamp => {
+                FilterValue::Timestamp(parse_flexible_timestamp(text, "where")?)
+            }
+        };
+        Ok(value)
+    }
+}
+
+fn parse_field(name: &str) -> Result<FilterField> {
+    FilterField::from_name(name).ok_or_else(|| {
+        let known: Vec<&str> = FilterField::ALL.iter().map(|f| f.name()).collect();
+        invalid(format!(
+            "unknown field '{name}' (expected one of: {})",
+            known.join(", ")
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Result<FilterExpr> {
+        FilterExpr::parse(input, "alice")
+    }
+
+    fn text(field: FilterField, op: CompareOp, value: &str) -> FilterExpr {
+        FilterExpr::Compare {
+            field,
+            op,
+            value: FilterValue::Text(value.to_string()),
+        }
+    }
+
+    #[test]
+    fn parses_precedence_and_shorthand() {
+        let expr =
+            parse("priority <= P1 and (assignee = me or assignee is null) and not label:wontfix")
+                .unwrap();
+
+        let priority = FilterExpr::Compare {
+            field: FilterField::Priority,
+            op: CompareOp::Le,
+            value: FilterValue::Integer(1),
+        };
+        let assignee = FilterExpr::Or(
+            Box::new(text(FilterField::Assignee, CompareOp::Eq, "alice")),
+            Box::new(FilterExpr::IsNull(FilterField::Assignee)),
+        );
+        let label = FilterExpr::Not(Box::new(text(FilterField::Label, CompareOp::Eq, "wontfix")));
+        let expected = FilterExpr::And(
+            Box::new(FilterExpr::And(Box::new(priority), Box::new(assignee))),
+            Box::new(label),
+        );
+        assert_eq!(expr, expected);
+    }
+
+    #[test]
+    fn or_binds_looser_than_and() {
+        let expr = parse("status = open or status = blocked and type = bug").unwrap();
+        assert!(
+            matches!(expr, FilterExpr::Or(_, ref right) if matches!(**right, FilterExpr::And(..)))
+        );
+    }
+
+    #[test]
+    fn normalizes_values_for_field() {
+        let expr =
+            parse("status in (OPEN, inprogress) and title ~ \"me\" and owner = \"me\"").unwrap();
+        let FilterExpr::And(left, owner) = expr else {
+            panic!("expected and");
+        };
+        assert_eq!(*owner, text(FilterField::Owner, CompareOp::Eq, "me"));
+        let FilterExpr::And(status, title) = *left else {
+            panic!("expected and");
+        };
+        assert_eq!(
+            *status,
+            FilterExpr::In {
+                field: FilterField::Status,
+                values: vec![
+                    FilterValue::Text("open".to_string()),
+                    FilterValue::Text("in_progress".to_string()),
+                ],
+            }
+        );
+        assert_eq!(*title, text(FilterField::Title, CompareOp::Contains, "me"));
+    }
+
+    #[test]
+    fn relative_timestamps_are_resolved() {
+        let before = Utc::now();
+        let expr = parse("updated > -7d").unwrap();
+        let FilterExpr::Compare {
+            field: FilterField::UpdatedAt,
+            op: CompareOp::Gt,
+            value: FilterValue::Timestamp(ts),
+        } = expr
+        else {
+            panic!("expected timestamp comparison");
+        };
+        assert!(ts < before - chrono::Duration::days(6));
+    }
+
+    #[test]
+    fn reports_errors() {
+        for input in [
+            "",
+            "priority <=",
+            "bogus = 1",
+            "status > open",
+            "priority ~ 1",
+            "title = \"open",
+            "(status = open",
+            "status = open extra",
+            "assignee is empty",
+        ] {
+            let err = parse(input).unwrap_err();
+            assert!(
+                matches!(
+                    err,
+                    BeadsError::Validation { .. } | BeadsError::InvalidStatus { .. }
+                ),
+                "unexpected error for {input:?}: {err:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn references_detects_nested_fields() {
+        let expr = parse("not (status = closed or priority = 0)").unwrap();
+        assert!(expr.references(FilterField::Status));
+        assert!(!expr.references(FilterField::Assignee));
+    }
+}
@@ -5,15 +5,22 @@
 //! - Transaction discipline for atomic writes
 //! - Dirty tracking for JSONL export
 //! - Blocked cache for ready/blocked queries
+//! - FTS5 full-text index for search
 //!
 //! # Submodules
 //!
 //! - [`events`] - Audit event storage (insertion, retrieval)
+//! - [`filter`] - `--where` filter expression parser
 //! - [`schema`] - Database schema definitions
 //! - [`sqlite`] - Main `SQLite` storage implementation
 
 pub mod events;
+pub mod filter;
 pub mod schema;
 pub mod sqlite;
 
-pub use sqlite::{IssueUpdate, ListFilters, ReadyFilters, ReadySortPolicy, SqliteStorage};
+pub use filter::{FilterExpr, FilterField};
+pub use sqlite::{
+    BatchOp, FIELD_SORT_PREFIX, IssueUpdate, ListFilters, ReadyFilters, ReadySortPolicy, SearchHit,
+    SqliteStorage,
+};
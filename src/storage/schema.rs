@@ -29,6 +29,7 @@ pub const SCHEMA_SQL: &str = r"
         updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
         closed_at DATETIME,
         close_reason TEXT DEFAULT '',
+        close_outcome TEXT,
         closed_by_session TEXT DEFAULT '',
         due_at DATETIME,
         defer_until DATETIME,
@@ -118,6 +119,16 @@ pub const SCHEMA_SQL: &str = r"
     CREATE INDEX IF NOT EXISTS idx_labels_label ON labels(label);
     CREATE INDEX IF NOT EXISTS idx_labels_issue ON labels(issue_id);
 
+    -- Custom fields (br extension). Values are JSON, typed by `fields:` in config.yaml.
+    CREATE TABLE IF NOT EXISTS custom_fields (
+        issue_id TEXT NOT NULL,
+        name TEXT NOT NULL,
+        value TEXT NOT NULL,
+        PRIMARY KEY (issue_id, name),
+        FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
+    );
+    CREATE INDEX IF NOT EXISTS idx_custom_fields_name ON custom_fields(name, value);
+
     -- Comments
     CREATE TABLE IF NOT EXISTS comments (
         id INTEGER PRIMARY KEY AUTOINCREMENT,
@@ -191,8 +202,68 @@ pub const SCHEMA_SQL: &str = r"
         last_child INTEGER NOT NULL DEFAULT 0,
         FOREIGN KEY (parent_id) REFERENCES issues(id) ON DELETE CASCADE
     );
+
+    -- Compaction archive (br extension, local-only; never exported to JSONL).
+    -- Full text of an issue before each compaction level, for `br compact --restore`.
+    CREATE TABLE IF NOT EXISTS compaction_archive (
+        issue_id TEXT NOT NULL,
+        level INTEGER NOT NULL,
+        description TEXT NOT NULL DEFAULT '',
+        design TEXT NOT NULL DEFAULT '',
+        acceptance_criteria TEXT NOT NULL DEFAULT '',
+        notes TEXT NOT NULL DEFAULT '',
+        archived_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        PRIMARY KEY (issue_id, level),
+        FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
+    );
+
+    -- Message read receipts for `br mail` (br extension, local-only).
+    CREATE TABLE IF NOT EXISTS message_reads (
+        message_id TEXT NOT NULL,
+        reader TEXT NOT NULL,
+        read_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+        PRIMARY KEY (message_id, reader),
+        FOREIGN KEY (message_id) REFERENCES issues(id) ON DELETE CASCADE
+    );
+
+    -- Claim leases for `--claim` (br extension, local-only; never exported to JSONL).
+    -- A claim past expires_at no longer holds the issue.
+    CREATE TABLE IF NOT EXISTS claims (
+        issue_id TEXT PRIMARY KEY,
+        claimed_by TEXT NOT NULL,
+        claimed_by_session TEXT,
+        claimed_at DATETIME NOT NULL,
+        expires_at DATETIME NOT NULL,
+        FOREIGN KEY (issue_id) REFERENCES issues(id) ON DELETE CASCADE
+    );
+    CREATE INDEX IF NOT EXISTS idx_claims_expires_at ON claims(expires_at);
+
+    -- Full-text search index (br extension, not used by bd).
+    -- One row per issue keyed by id; comments are concatenated into one column.
+    -- Maintained by SqliteStorage (mutate path + rebuild on import).
+    CREATE VIRTUAL TABLE IF NOT EXISTS issues_fts USING fts5(
+        id,
+        title,
+        description,
+        design,
+        acceptance_criteria,
+        notes,
+        comments,
+        tokenize = 'unicode61 remove_diacritics 2'
+    );
 ";
 
+/// Populate `issues_fts` from `issues` (aliased `i`).
+///
+/// Append `WHERE i.id = ?` to index a single issue.
+pub const SEARCH_INDEX_INSERT_SQL: &str = r"
+    INSERT INTO issues_fts (id, title, description, design, acceptance_criteria, notes, comments)
+    SELECT i.id, i.title,
+           COALESCE(i.description, ''), COALESCE(i.design, ''),
+           COALESCE(i.acceptance_criteria, ''), COALESCE(i.notes, ''),
+           COALESCE((SELECT group_concat(c.text, ' ') FROM comments c WHERE c.issue_id = i.id), '')
+    FROM issues i";
+
 /// Apply the schema to the database.
 ///
 /// This uses `execute_batch` to run the entire DDL script.
@@ -207,11 +278,18 @@ pub fn apply_schema(conn: &Connection) -> Result<()> {
     // statements that will fail if old tables have missing columns
     run_pre_schema_migrations(conn)?;
 
+    // Databases created before the search index existed need a one-time backfill.
+    let backfill_search_index = table_exists(conn, "issues") && !table_exists(conn, "issues_fts");
+
     conn.execute_batch(SCHEMA_SQL)?;
 
     // Run migrations for existing databases
     run_migrations(conn)?;
 
+    if backfill_search_index {
+        conn.execute(SEARCH_INDEX_INSERT_SQL, [])?;
+    }
+
     // Set journal mode to WAL for concurrency
     conn.pragma_update(None, "journal_mode", "WAL")?;
 
@@ -340,6 +418,11 @@ fn run_migrations(conn: &Connection) -> Result<()> {
         )?;
     }
 
+    // Migration: ensure close_outcome column exists (conditional-blocks semantics)
+    if !column_exists(conn, "issues", "close_outcome") {
+        conn.execute("ALTER TABLE issues ADD COLUMN close_outcome TEXT", [])?;
+    }
+
     // Migration: Add missing indexes for bd parity
     // These use IF NOT EXISTS so they're safe to run multiple times
     conn.execute_batch(
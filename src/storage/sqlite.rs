@@ -1,13 +1,20 @@
 //! `SQLite` storage implementation.
 
-use crate::error::{BeadsError, Result};
+use crate::config::fields::{FieldKind, FieldSchema};
+use crate::config::workflow::{StatusCategory, Workflow};
+use crate::error::{BeadsError, Result, ResultExt};
 use crate::format::{IssueDetails, IssueWithDependencyMetadata};
-use crate::model::{Comment, DependencyType, Event, EventType, Issue, IssueType, Priority, Status};
+use crate::model::{
+    Claim, CloseOutcome, Comment, Dependency, DependencyType, Event, EventType, Issue, IssueType,
+    Priority, Status,
+};
 use crate::storage::events::get_events;
-use crate::storage::schema::apply_schema;
+use crate::storage::filter::{CompareOp, FilterExpr, FilterField, FilterValue};
+use crate::storage::schema::{SEARCH_INDEX_INSERT_SQL, apply_schema};
 use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
 use rusqlite::{Connection, OpenFlags, OptionalExtension, Transaction};
-use std::collections::{HashMap, HashSet};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt::Write as _;
 use std::path::{Path, PathBuf};
 use std::time::Duration;
@@ -17,6 +24,8 @@ use tracing::warn;
 #[derive(Debug)]
 pub struct SqliteStorage {
     conn: Connection,
+    workflow: Workflow,
+    field_schema: FieldSchema,
 }
 
 /// Context for a mutation operation, tracking side effects.
@@ -104,7 +113,30 @@ impl SqliteStorage {
             conn.busy_timeout(Duration::from_millis(timeout))?;
         }
         apply_schema(&conn)?;
-        Ok(Self { conn })
+        Ok(Self {
+            conn,
+            workflow: Workflow::default(),
+            field_schema: FieldSchema::default(),
+        })
+    }
+
+    /// Open an existing database read-only, without applying the schema.
+    ///
+    /// Used when fanning out over other workspaces, which must not be modified.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database does not exist or cannot be opened.
+    pub fn open_read_only(path: &Path) -> Result<Self> {
+        let conn = Connection::open_with_flags(
+            path,
+            OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+        )?;
+        Ok(Self {
+            conn,
+            workflow: Workflow::default(),
+            field_schema: FieldSchema::default(),
+        })
     }
 
     /// Open an in-memory database for testing.
@@ -115,7 +147,33 @@ impl SqliteStorage {
     pub fn open_memory() -> Result<Self> {
         let conn = Connection::open_in_memory()?;
         apply_schema(&conn)?;
-        Ok(Self { conn })
+        Ok(Self {
+            conn,
+            workflow: Workflow::default(),
+            field_schema: FieldSchema::default(),
+        })
+    }
+
+    /// Apply the configured workflow (custom statuses and transition rules).
+    pub fn set_workflow(&mut self, workflow: Workflow) {
+        self.workflow = workflow;
+    }
+
+    /// The workflow enforced by `update_issue`.
+    #[must_use]
+    pub const fn workflow(&self) -> &Workflow {
+        &self.workflow
+    }
+
+    /// Apply the configured custom field schema.
+    pub fn set_field_schema(&mut self, schema: FieldSchema) {
+        self.field_schema = schema;
+    }
+
+    /// The custom field schema used for `--field` parsing and sorting.
+    #[must_use]
+    pub const fn field_schema(&self) -> &FieldSchema {
+        &self.field_schema
     }
 
     /// Get audit events for a specific issue.
@@ -136,6 +194,41 @@ impl SqliteStorage {
         crate::storage::events::get_all_events(&self.conn, limit)
     }
 
+    /// Get audit events of exportable issues, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_exportable_events(&self) -> Result<Vec<Event>> {
+        crate::storage::events::get_exportable_events(&self.conn)
+    }
+
+    /// Insert events imported from `events.jsonl`.
+    ///
+    /// Events for issues missing from the database are skipped. Returns the
+    /// number of events inserted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database write fails.
+    pub fn import_events(&mut self, events: &[Event]) -> Result<usize> {
+        let tx = self.conn.transaction()?;
+        let mut inserted = 0;
+        for event in events {
+            let exists: bool = tx.query_row(
+                "SELECT EXISTS(SELECT 1 FROM issues WHERE id = ?)",
+                [&event.issue_id],
+                |row| row.get(0),
+            )?;
+            if exists {
+                crate::storage::events::insert_event_record(&tx, event)?;
+                inserted += 1;
+            }
+        }
+        tx.commit()?;
+        Ok(inserted)
+    }
+
     /// Execute a mutation with the 4-step transaction protocol.
     ///
     /// # Errors
@@ -170,12 +263,13 @@ impl SqliteStorage {
             )?;
         }
 
-        // Mark dirty
+        // Mark dirty and re-index touched issues for full-text search
         for id in ctx.dirty_ids {
             tx.execute(
                 "INSERT OR REPLACE INTO dirty_issues (issue_id, marked_at) VALUES (?, ?)",
                 rusqlite::params![id, Utc::now().to_rfc3339()],
             )?;
+            Self::refresh_search_index_impl(&tx, &id)?;
         }
 
         // Track if we need to rebuild cache
@@ -183,7 +277,8 @@ impl SqliteStorage {
 
         // Rebuild blocked cache inside the transaction if needed
         if needs_cache_rebuild {
-            Self::rebuild_blocked_cache_impl(&tx)?;
+            let done_statuses = self.workflow.custom_statuses_in(StatusCategory::Done);
+            Self::rebuild_blocked_cache_impl(&tx, &done_statuses)?;
         }
 
         tx.commit()?;
@@ -196,146 +291,183 @@ impl SqliteStorage {
     /// # Errors
     ///
     /// Returns an error if the issue cannot be inserted (e.g. ID collision).
-    #[allow(clippy::too_many_lines)]
     pub fn create_issue(&mut self, issue: &Issue, actor: &str) -> Result<()> {
         self.mutate("create_issue", actor, |tx, ctx| {
-            let status_str = issue.status.as_str();
-            let issue_type_str = issue.issue_type.as_str();
-            let created_at_str = issue.created_at.to_rfc3339();
-            let updated_at_str = issue.updated_at.to_rfc3339();
-            let closed_at_str = issue.closed_at.map(|dt| dt.to_rfc3339());
-            let due_at_str = issue.due_at.map(|dt| dt.to_rfc3339());
-            let defer_until_str = issue.defer_until.map(|dt| dt.to_rfc3339());
-            let deleted_at_str = issue.deleted_at.map(|dt| dt.to_rfc3339());
-            let compacted_at_str = issue.compacted_at.map(|dt| dt.to_rfc3339());
-
-            tx.execute(
-                "INSERT INTO issues (
-                    id, content_hash, title, description, design, acceptance_criteria, notes,
-                    status, priority, issue_type, assignee, owner, estimated_minutes,
-                    created_at, created_by, updated_at, closed_at, close_reason,
-                    closed_by_session, due_at, defer_until, external_ref, source_system,
-                    source_repo, deleted_at, deleted_by, delete_reason, original_type,
-                    compaction_level, compacted_at, compacted_at_commit, original_size,
-                    sender, ephemeral, pinned, is_template
-                            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",                rusqlite::params![
-                    issue.id,
-                    issue.content_hash,
-                    issue.title,
-                    issue.description.as_deref().unwrap_or(""),
-                    issue.design.as_deref().unwrap_or(""),
-                    issue.acceptance_criteria.as_deref().unwrap_or(""),
-                    issue.notes.as_deref().unwrap_or(""),
-                    status_str,
-                    issue.priority.0,
-                    issue_type_str,
-                    issue.assignee,
-                    issue.owner.as_deref().unwrap_or(""),
-                    issue.estimated_minutes,
-                    created_at_str,
-                    issue.created_by.as_deref().unwrap_or(""),
-                    updated_at_str,
-                    closed_at_str,
-                    issue.close_reason.as_deref().unwrap_or(""),
-                    issue.closed_by_session.as_deref().unwrap_or(""),
-                    due_at_str,
-                    defer_until_str,
-                    issue.external_ref,
-                    issue.source_system.as_deref().unwrap_or(""),
-                    issue.source_repo.as_deref().unwrap_or("."),
-                    deleted_at_str,
-                    issue.deleted_by.as_deref().unwrap_or(""),
-                    issue.delete_reason.as_deref().unwrap_or(""),
-                    issue.original_type.as_deref().unwrap_or(""),
-                    issue.compaction_level.unwrap_or(0),
-                    compacted_at_str,
-                    issue.compacted_at_commit,
-                    issue.original_size.unwrap_or(0),
-                    issue.sender.as_deref().unwrap_or(""),
-                    i32::from(issue.ephemeral),
-                    i32::from(issue.pinned),
-                    i32::from(issue.is_template),
-                ],
-            )?;
+            Self::insert_issue_impl(tx, ctx, issue, actor)
+        })
+    }
 
-            // Insert Labels
-            for label in &issue.labels {
-                tx.execute(
-                    "INSERT INTO labels (issue_id, label) VALUES (?, ?)",
-                    rusqlite::params![issue.id, label],
-                )?;
-                ctx.record_event(
-                    EventType::LabelAdded,
-                    &issue.id,
-                    Some(format!("Added label {label}")),
-                );
+    /// Create several issues in one transaction.
+    ///
+    /// Issues are inserted in order, so a later issue may depend on an earlier
+    /// one. If any insert fails, none of the issues are created.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any issue cannot be inserted (e.g. ID collision).
+    pub fn create_issues(&mut self, issues: &[Issue], actor: &str) -> Result<()> {
+        self.mutate("create_issues", actor, |tx, ctx| {
+            for issue in issues {
+                Self::insert_issue_impl(tx, ctx, issue, actor)?;
             }
+            Ok(())
+        })
+    }
 
-            // Insert Dependencies
-            for dep in &issue.dependencies {
-                // Check cycle if blocking
-                if dep.dep_type.is_blocking()
-                    && Self::check_cycle(tx, &issue.id, &dep.depends_on_id, true)?
-                {
-                    return Err(BeadsError::DependencyCycle {
-                        path: format!(
-                            "Adding dependency {} -> {} would create a cycle",
-                            issue.id, dep.depends_on_id
-                        ),
-                    });
-                }
+    /// Insert an issue with its labels, fields, dependencies and comments.
+    #[allow(clippy::too_many_lines)]
+    fn insert_issue_impl(
+        tx: &Transaction,
+        ctx: &mut MutationContext,
+        issue: &Issue,
+        actor: &str,
+    ) -> Result<()> {
+        let status_str = issue.status.as_str();
+        let issue_type_str = issue.issue_type.as_str();
+        let created_at_str = issue.created_at.to_rfc3339();
+        let updated_at_str = issue.updated_at.to_rfc3339();
+        let closed_at_str = issue.closed_at.map(|dt| dt.to_rfc3339());
+        let due_at_str = issue.due_at.map(|dt| dt.to_rfc3339());
+        let defer_until_str = issue.defer_until.map(|dt| dt.to_rfc3339());
+        let deleted_at_str = issue.deleted_at.map(|dt| dt.to_rfc3339());
+        let compacted_at_str = issue.compacted_at.map(|dt| dt.to_rfc3339());
 
-                tx.execute(
-                    "INSERT INTO dependencies (issue_id, depends_on_id, type, created_at, created_by)
-                     VALUES (?, ?, ?, ?, ?)",
-                    rusqlite::params![
-                        issue.id,
-                        dep.depends_on_id,
-                        dep.dep_type.as_str(),
-                        dep.created_at.to_rfc3339(),
-                        dep.created_by.as_deref().unwrap_or(actor)
-                    ],
-                )?;
+        tx.execute(
+            "INSERT INTO issues (
+                id, content_hash, title, description, design, acceptance_criteria, notes,
+                status, priority, issue_type, assignee, owner, estimated_minutes,
+                created_at, created_by, updated_at, closed_at, close_reason,
+                closed_by_session, due_at, defer_until, external_ref, source_system,
+                source_repo, deleted_at, deleted_by, delete_reason, original_type,
+                compaction_level, compacted_at, compacted_at_commit, original_size,
+                sender, ephemeral, pinned, is_template, close_outcome
+                        ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",                rusqlite::params![
+                issue.id,
+                issue.content_hash,
+                issue.title,
+                issue.description.as_deref().unwrap_or(""),
+                issue.design.as_deref().unwrap_or(""),
+                issue.acceptance_criteria.as_deref().unwrap_or(""),
+                issue.notes.as_deref().unwrap_or(""),
+                status_str,
+                issue.priority.0,
+                issue_type_str,
+                issue.assignee,
+                issue.owner.as_deref().unwrap_or(""),
+                issue.estimated_minutes,
+                created_at_str,
+                issue.created_by.as_deref().unwrap_or(""),
+                updated_at_str,
+                closed_at_str,
+                issue.close_reason.as_deref().unwrap_or(""),
+                issue.closed_by_session.as_deref().unwrap_or(""),
+                due_at_str,
+                defer_until_str,
+                issue.external_ref,
+                issue.source_system.as_deref().unwrap_or(""),
+                issue.source_repo.as_deref().unwrap_or("."),
+                deleted_at_str,
+                issue.deleted_by.as_deref().unwrap_or(""),
+                issue.delete_reason.as_deref().unwrap_or(""),
+                issue.original_type.as_deref().unwrap_or(""),
+                issue.compaction_level.unwrap_or(0),
+                compacted_at_str,
+                issue.compacted_at_commit,
+                issue.original_size.unwrap_or(0),
+                issue.sender.as_deref().unwrap_or(""),
+                i32::from(issue.ephemeral),
+                i32::from(issue.pinned),
+                i32::from(issue.is_template),
+                issue.close_outcome.map(CloseOutcome::as_str),
+            ],
+        )?;
 
-                ctx.record_event(
-                    EventType::DependencyAdded,
-                    &issue.id,
-                    Some(format!(
-                        "Added dependency on {} ({})",
-                        dep.depends_on_id, dep.dep_type
-                    )),
-                );
-                ctx.invalidate_cache();
-            }
+        // Insert Labels
+        for label in &issue.labels {
+            tx.execute(
+                "INSERT INTO labels (issue_id, label) VALUES (?, ?)",
+                rusqlite::params![issue.id, label],
+            )?;
+            ctx.record_field_change(
+                EventType::LabelAdded,
+                &issue.id,
+                None,
+                Some(label.clone()),
+                Some(format!("Added label {label}")),
+            );
+        }
 
-            // Insert Comments
-            for comment in &issue.comments {
-                tx.execute(
-                    "INSERT INTO comments (issue_id, author, text, created_at) VALUES (?, ?, ?, ?)",
-                    rusqlite::params![
-                        issue.id,
-                        comment.author,
-                        comment.body,
-                        comment.created_at.to_rfc3339()
-                    ],
-                )?;
-                ctx.record_event(
-                    EventType::Commented,
-                    &issue.id,
-                    Some(comment.body.clone()),
-                );
+        // Insert Custom Fields
+        for (name, value) in &issue.fields {
+            tx.execute(
+                "INSERT INTO custom_fields (issue_id, name, value) VALUES (?, ?, ?)",
+                rusqlite::params![issue.id, name, value.to_string()],
+            )?;
+        }
+
+        // Insert Dependencies
+        for dep in &issue.dependencies {
+            // Check cycle if blocking
+            if dep.dep_type.is_blocking()
+                && Self::check_cycle(tx, &issue.id, &dep.depends_on_id, true)?
+            {
+                return Err(BeadsError::DependencyCycle {
+                    path: format!(
+                        "Adding dependency {} -> {} would create a cycle",
+                        issue.id, dep.depends_on_id
+                    ),
+                });
             }
 
-            ctx.record_event(
-                EventType::Created,
+            tx.execute(
+                "INSERT INTO dependencies (issue_id, depends_on_id, type, created_at, created_by, thread_id)
+                 VALUES (?, ?, ?, ?, ?, ?)",
+                rusqlite::params![
+                    issue.id,
+                    dep.depends_on_id,
+                    dep.dep_type.as_str(),
+                    dep.created_at.to_rfc3339(),
+                    dep.created_by.as_deref().unwrap_or(actor),
+                    dep.thread_id.as_deref().unwrap_or("")
+                ],
+            )?;
+
+            ctx.record_field_change(
+                EventType::DependencyAdded,
                 &issue.id,
-                Some(format!("Created issue: {}", issue.title)),
+                None,
+                Some(dep.depends_on_id.clone()),
+                Some(format!(
+                    "Added dependency on {} ({})",
+                    dep.depends_on_id, dep.dep_type
+                )),
             );
+            ctx.invalidate_cache();
+        }
+
+        // Insert Comments
+        for comment in &issue.comments {
+            tx.execute(
+                "INSERT INTO comments (issue_id, author, text, created_at) VALUES (?, ?, ?, ?)",
+                rusqlite::params![
+                    issue.id,
+                    comment.author,
+                    comment.body,
+                    comment.created_at.to_rfc3339()
+                ],
+            )?;
+            ctx.record_event(EventType::Commented, &issue.id, Some(comment.body.clone()));
+        }
 
-            ctx.mark_dirty(&issue.id);
+        ctx.record_event(
+            EventType::Created,
+            &issue.id,
+            Some(format!("Created issue: {}", issue.title)),
+        );
 
-            Ok(())
-        })
+        ctx.mark_dirty(&issue.id);
+
+        Ok(())
     }
 
     // Helper for cycle detection (refactored from would_create_cycle)
@@ -384,7 +516,6 @@ impl SqliteStorage {
     /// # Errors
     ///
     /// Returns an error if the issue doesn't exist or the update fails.
-    #[allow(clippy::too_many_lines)]
     pub fn update_issue(&mut self, id: &str, updates: &IssueUpdate, actor: &str) -> Result<Issue> {
         let mut issue = self
             .get_issue(id)?
@@ -393,217 +524,298 @@ impl SqliteStorage {
         if updates.is_empty() {
             return Ok(issue);
         }
+        self.workflow.check_transition(&issue, updates)?;
 
         self.mutate("update_issue", actor, |tx, ctx| {
-            let mut set_clauses: Vec<String> = vec![];
-            let mut params: Vec<Box<dyn rusqlite::ToSql>> = vec![];
+            Self::update_issue_impl(tx, ctx, &mut issue, updates)
+        })?;
 
-            // Helper to add update
-            let mut add_update = |field: &str, val: Box<dyn rusqlite::ToSql>| {
-                set_clauses.push(format!("{field} = ?"));
-                params.push(val);
-            };
+        // Return updated issue
+        self.get_issue(id)?
+            .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })
+    }
 
-            // Title
-            if let Some(ref title) = updates.title {
-                let old_title = issue.title.clone();
-                issue.title.clone_from(title);
-                add_update("title", Box::new(title.clone()));
-                ctx.record_field_change(
-                    EventType::Updated,
-                    id,
-                    Some(old_title),
-                    Some(title.clone()),
-                    Some("Title changed".to_string()),
-                );
-            }
+    /// Apply `updates` to `issue` (its state before the update) and write the
+    /// changed columns.
+    #[allow(clippy::too_many_lines)]
+    fn update_issue_impl(
+        tx: &Transaction,
+        ctx: &mut MutationContext,
+        issue: &mut Issue,
+        updates: &IssueUpdate,
+    ) -> Result<()> {
+        let id: &str = &issue.id.clone();
+        let was_closed = issue.status == Status::Closed;
+        let mut set_clauses: Vec<String> = vec![];
+        let mut params: Vec<Box<dyn rusqlite::ToSql>> = vec![];
+
+        // Helper to add update
+        let mut add_update = |field: &str, val: Box<dyn rusqlite::ToSql>| {
+            set_clauses.push(format!("{field} = ?"));
+            params.push(val);
+        };
 
-            // Simple text fields - use empty string instead of NULL for bd compatibility
-            if let Some(ref val) = updates.description {
-                issue.description.clone_from(val);
-                add_update(
-                    "description",
-                    Box::new(val.as_deref().unwrap_or("").to_string()),
-                );
-            }
-            if let Some(ref val) = updates.design {
-                issue.design.clone_from(val);
-                add_update("design", Box::new(val.as_deref().unwrap_or("").to_string()));
-            }
-            if let Some(ref val) = updates.acceptance_criteria {
-                issue.acceptance_criteria.clone_from(val);
-                add_update(
-                    "acceptance_criteria",
-                    Box::new(val.as_deref().unwrap_or("").to_string()),
-                );
-            }
-            if let Some(ref val) = updates.notes {
-                issue.notes.clone_from(val);
-                add_update("notes", Box::new(val.as_deref().unwrap_or("").to_string()));
-            }
+        // Title
+        if let Some(ref title) = updates.title {
+            let old_title = issue.title.clone();
+            issue.title.clone_from(title);
+            add_update("title", Box::new(title.clone()));
+            ctx.record_field_change(
+                EventType::TitleChanged,
+                id,
+                Some(old_title),
+                Some(title.clone()),
+                None,
+            );
+        }
 
-            // Status
-            if let Some(ref status) = updates.status {
-                let old_status = issue.status.as_str().to_string();
-                issue.status.clone_from(status);
-                add_update("status", Box::new(status.as_str().to_string()));
-                ctx.record_field_change(
-                    EventType::StatusChanged,
-                    id,
-                    Some(old_status),
-                    Some(status.as_str().to_string()),
-                    None,
-                );
+        // Simple text fields - use empty string instead of NULL for bd compatibility
+        if let Some(ref val) = updates.description {
+            issue.description.clone_from(val);
+            add_update(
+                "description",
+                Box::new(val.as_deref().unwrap_or("").to_string()),
+            );
+        }
+        if let Some(ref val) = updates.design {
+            issue.design.clone_from(val);
+            add_update("design", Box::new(val.as_deref().unwrap_or("").to_string()));
+        }
+        if let Some(ref val) = updates.acceptance_criteria {
+            issue.acceptance_criteria.clone_from(val);
+            add_update(
+                "acceptance_criteria",
+                Box::new(val.as_deref().unwrap_or("").to_string()),
+            );
+        }
+        if let Some(ref val) = updates.notes {
+            issue.notes.clone_from(val);
+            add_update("notes", Box::new(val.as_deref().unwrap_or("").to_string()));
+        }
 
-                // Record Closed event if status is now Closed
-                if *status == Status::Closed {
-                    let reason = updates.close_reason.as_ref().and_then(Clone::clone);
-                    ctx.record_event(EventType::Closed, id, reason);
+        // Status
+        if let Some(ref status) = updates.status {
+            let old_status = issue.status.as_str().to_string();
+            issue.status.clone_from(status);
+            add_update("status", Box::new(status.as_str().to_string()));
+            ctx.record_field_change(
+                EventType::StatusChanged,
+                id,
+                Some(old_status),
+                Some(status.as_str().to_string()),
+                None,
+            );
 
-                    // Auto-set closed_at if not provided
-                    if updates.closed_at.is_none() && issue.closed_at.is_none() {
-                        let now = Utc::now();
-                        issue.closed_at = Some(now);
-                        add_update("closed_at", Box::new(Some(now.to_rfc3339())));
-                    }
-                } else if issue.closed_at.is_some() && updates.closed_at.is_none() {
+            // Record Closed event if status is now Closed
+            if *status == Status::Closed {
+                let reason = updates.close_reason.as_ref().and_then(Clone::clone);
+                ctx.record_event(EventType::Closed, id, reason);
+                // Finished work releases its claim
+                tx.execute("DELETE FROM claims WHERE issue_id = ?", [id])?;
+
+                // Auto-set closed_at if not provided
+                if updates.closed_at.is_none() && issue.closed_at.is_none() {
+                    let now = Utc::now();
+                    issue.closed_at = Some(now);
+                    add_update("closed_at", Box::new(Some(now.to_rfc3339())));
+                }
+            } else {
+                if issue.closed_at.is_some() && updates.closed_at.is_none() {
                     // Reopening (or fixing state): Clear closed_at if it was set
                     issue.closed_at = None;
                     add_update("closed_at", Box::new(None::<String>));
                 }
-
-                if !updates.skip_cache_rebuild {
-                    ctx.invalidate_cache();
+                // An outcome only describes a closed issue
+                if issue.close_outcome.is_some() && updates.close_outcome.is_none() {
+                    issue.close_outcome = None;
+                    add_update("close_outcome", Box::new(None::<String>));
                 }
             }
 
-            // Priority
-            if let Some(priority) = updates.priority {
-                let old_priority = issue.priority.0;
-                issue.priority = priority;
-                add_update("priority", Box::new(priority.0));
-                if priority.0 != old_priority {
-                    ctx.record_field_change(
-                        EventType::PriorityChanged,
-                        id,
-                        Some(old_priority.to_string()),
-                        Some(priority.0.to_string()),
-                        None,
-                    );
-                }
+            if !updates.skip_cache_rebuild {
+                ctx.invalidate_cache();
             }
+        }
 
-            // Issue type
-            if let Some(ref issue_type) = updates.issue_type {
-                issue.issue_type.clone_from(issue_type);
-                add_update("issue_type", Box::new(issue_type.as_str().to_string()));
+        // Priority
+        if let Some(priority) = updates.priority {
+            let old_priority = issue.priority.0;
+            issue.priority = priority;
+            add_update("priority", Box::new(priority.0));
+            if priority.0 != old_priority {
+                ctx.record_field_change(
+                    EventType::PriorityChanged,
+                    id,
+                    Some(old_priority.to_string()),
+                    Some(priority.0.to_string()),
+                    None,
+                );
             }
+        }
 
-            // Assignee
-            if let Some(ref assignee_opt) = updates.assignee {
-                let old_assignee = issue.assignee.clone();
-                issue.assignee.clone_from(assignee_opt);
-                add_update("assignee", Box::new(assignee_opt.clone()));
-                if old_assignee != *assignee_opt {
-                    ctx.record_field_change(
-                        EventType::AssigneeChanged,
-                        id,
-                        old_assignee,
-                        assignee_opt.clone(),
-                        None,
-                    );
-                }
-            }
+        // Issue type
+        if let Some(ref issue_type) = updates.issue_type {
+            issue.issue_type.clone_from(issue_type);
+            add_update("issue_type", Box::new(issue_type.as_str().to_string()));
+        }
 
-            // Simple Option fields - use empty string instead of NULL for bd compatibility
-            if let Some(ref val) = updates.owner {
-                issue.owner.clone_from(val);
-                add_update("owner", Box::new(val.as_deref().unwrap_or("").to_string()));
-            }
-            if let Some(ref val) = updates.estimated_minutes {
-                issue.estimated_minutes = *val;
-                add_update("estimated_minutes", Box::new(*val));
-            }
-            if let Some(ref val) = updates.external_ref {
-                issue.external_ref.clone_from(val);
-                add_update("external_ref", Box::new(val.clone()));
-            }
-            // Use empty string instead of NULL for bd compatibility
-            if let Some(ref val) = updates.close_reason {
-                issue.close_reason.clone_from(val);
-                add_update(
-                    "close_reason",
-                    Box::new(val.as_deref().unwrap_or("").to_string()),
-                );
-            }
-            if let Some(ref val) = updates.closed_by_session {
-                issue.closed_by_session.clone_from(val);
-                add_update(
-                    "closed_by_session",
-                    Box::new(val.as_deref().unwrap_or("").to_string()),
+        // Assignee
+        if let Some(ref assignee_opt) = updates.assignee {
+            let old_assignee = issue.assignee.clone();
+            issue.assignee.clone_from(assignee_opt);
+            add_update("assignee", Box::new(assignee_opt.clone()));
+            if old_assignee != *assignee_opt {
+                ctx.record_field_change(
+                    EventType::AssigneeChanged,
+                    id,
+                    old_assignee,
+                    assignee_opt.clone(),
+                    None,
                 );
             }
+        }
 
-            // Tombstone fields
-            if let Some(ref val) = updates.deleted_at {
-                issue.deleted_at = *val;
-                add_update("deleted_at", Box::new(val.map(|d| d.to_rfc3339())));
-            }
-            // Use empty string instead of NULL for bd compatibility
-            if let Some(ref val) = updates.deleted_by {
-                issue.deleted_by.clone_from(val);
-                add_update(
-                    "deleted_by",
-                    Box::new(val.as_deref().unwrap_or("").to_string()),
-                );
-            }
-            if let Some(ref val) = updates.delete_reason {
-                issue.delete_reason.clone_from(val);
-                add_update(
-                    "delete_reason",
-                    Box::new(val.as_deref().unwrap_or("").to_string()),
-                );
+        // Simple Option fields - use empty string instead of NULL for bd compatibility
+        if let Some(ref val) = updates.owner {
+            issue.owner.clone_from(val);
+            add_update("owner", Box::new(val.as_deref().unwrap_or("").to_string()));
+        }
+        if let Some(ref val) = updates.estimated_minutes {
+            issue.estimated_minutes = *val;
+            add_update("estimated_minutes", Box::new(*val));
+        }
+        if let Some(ref val) = updates.external_ref {
+            issue.external_ref.clone_from(val);
+            add_update("external_ref", Box::new(val.clone()));
+        }
+        // Use empty string instead of NULL for bd compatibility
+        if let Some(ref val) = updates.close_reason {
+            issue.close_reason.clone_from(val);
+            add_update(
+                "close_reason",
+                Box::new(val.as_deref().unwrap_or("").to_string()),
+            );
+        }
+        if let Some(val) = updates.close_outcome {
+            issue.close_outcome = val;
+            add_update(
+                "close_outcome",
+                Box::new(val.map(|outcome| outcome.as_str().to_string())),
+            );
+            // The outcome decides whether conditional dependents become ready
+            if !updates.skip_cache_rebuild {
+                ctx.invalidate_cache();
             }
+        }
+        if let Some(ref val) = updates.closed_by_session {
+            issue.closed_by_session.clone_from(val);
+            add_update(
+                "closed_by_session",
+                Box::new(val.as_deref().unwrap_or("").to_string()),
+            );
+        }
 
-            // Date fields
-            if let Some(ref val) = updates.due_at {
-                issue.due_at = *val;
-                add_update("due_at", Box::new(val.map(|d| d.to_rfc3339())));
-            }
-            if let Some(ref val) = updates.defer_until {
-                issue.defer_until = *val;
-                add_update("defer_until", Box::new(val.map(|d| d.to_rfc3339())));
-            }
-            if let Some(ref val) = updates.closed_at {
-                issue.closed_at = *val;
-                add_update("closed_at", Box::new(val.map(|d| d.to_rfc3339())));
-            }
+        // Tombstone fields
+        if let Some(ref val) = updates.deleted_at {
+            issue.deleted_at = *val;
+            add_update("deleted_at", Box::new(val.map(|d| d.to_rfc3339())));
+        }
+        // Use empty string instead of NULL for bd compatibility
+        if let Some(ref val) = updates.deleted_by {
+            issue.deleted_by.clone_from(val);
+            add_update(
+                "deleted_by",
+                Box::new(val.as_deref().unwrap_or("").to_string()),
+            );
+        }
+        if let Some(ref val) = updates.delete_reason {
+            issue.delete_reason.clone_from(val);
+            add_update(
+                "delete_reason",
+                Box::new(val.as_deref().unwrap_or("").to_string()),
+            );
+        }
 
-            // Always update updated_at
-            set_clauses.push("updated_at = ?".to_string());
-            params.push(Box::new(Utc::now().to_rfc3339()));
+        // Date fields
+        if let Some(ref val) = updates.due_at {
+            issue.due_at = *val;
+            add_update("due_at", Box::new(val.map(|d| d.to_rfc3339())));
+        }
+        if let Some(ref val) = updates.defer_until {
+            issue.defer_until = *val;
+            add_update("defer_until", Box::new(val.map(|d| d.to_rfc3339())));
+        }
+        if let Some(ref val) = updates.closed_at {
+            issue.closed_at = *val;
+            add_update("closed_at", Box::new(val.map(|d| d.to_rfc3339())));
+        }
 
-            // Update content hash
-            let new_hash = issue.compute_content_hash();
-            set_clauses.push("content_hash = ?".to_string());
-            params.push(Box::new(new_hash));
+        // Always update updated_at
+        set_clauses.push("updated_at = ?".to_string());
+        params.push(Box::new(Utc::now().to_rfc3339()));
 
-            // Build and execute SQL
-            let sql = format!("UPDATE issues SET {} WHERE id = ? ", set_clauses.join(", "));
-            params.push(Box::new(id.to_string()));
+        // Update content hash
+        let new_hash = issue.compute_content_hash();
+        set_clauses.push("content_hash = ?".to_string());
+        params.push(Box::new(new_hash));
 
-            let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
-            tx.execute(&sql, params_refs.as_slice())?;
+        // Build and execute SQL
+        let sql = format!("UPDATE issues SET {} WHERE id = ? ", set_clauses.join(", "));
+        params.push(Box::new(id.to_string()));
 
-            ctx.mark_dirty(id);
+        let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
+        tx.execute(&sql, params_refs.as_slice())?;
 
-            Ok(())
-        })?;
+        ctx.mark_dirty(id);
 
-        // Return updated issue
-        self.get_issue(id)?
-            .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })
-    }
+        // A blocker that succeeded makes its "run only if this fails" dependents moot
+        if !was_closed
+            && issue.status == Status::Closed
+            && issue.close_outcome == Some(CloseOutcome::Success)
+        {
+            Self::close_moot_dependents_impl(tx, ctx, id)?;
+        }
+
+        Ok(())
+    }
+
+    /// Close the open `conditional-blocks` dependents of `blocker_id`, which
+    /// has just closed with success.
+    ///
+    /// They are closed as `wontfix`, so their own conditional dependents stay
+    /// moot rather than being closed in turn.
+    fn close_moot_dependents_impl(
+        tx: &Transaction,
+        ctx: &mut MutationContext,
+        blocker_id: &str,
+    ) -> Result<()> {
+        let dependent_ids: Vec<String> = tx
+            .prepare_cached(
+                "SELECT d.issue_id
+                 FROM dependencies d
+                 INNER JOIN issues i ON d.issue_id = i.id
+                 WHERE d.depends_on_id = ? AND d.type = 'conditional-blocks'
+                   AND i.status NOT IN ('closed', 'tombstone')",
+            )?
+            .query_map([blocker_id], |row| row.get(0))?
+            .collect::<std::result::Result<_, _>>()?;
+
+        let now = Utc::now();
+        for dependent_id in dependent_ids {
+            let Some(mut dependent) = Self::get_issue_impl(tx, &dependent_id)? else {
+                continue;
+            };
+            let update = IssueUpdate::close(
+                &format!("moot: {blocker_id} closed with success"),
+                Some(CloseOutcome::Wontfix),
+                None,
+                now,
+            );
+            Self::update_issue_impl(tx, ctx, &mut dependent, &update)?;
+            tracing::info!(id = %dependent_id, blocker = %blocker_id, "Closed moot conditional dependent");
+        }
+        Ok(())
+    }
 
     /// Delete an issue by creating a tombstone.
     ///
@@ -622,6 +834,7 @@ impl SqliteStorage {
             .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })?;
 
         let original_type = issue.issue_type.as_str().to_string();
+        let original_status = issue.status.as_str().to_string();
         let timestamp = deleted_at.unwrap_or_else(Utc::now);
 
         self.mutate("delete_issue", actor, |tx, ctx| {
@@ -644,9 +857,14 @@ impl SqliteStorage {
                 ],
             )?;
 
-            ctx.record_event(
+            tx.execute("DELETE FROM claims WHERE issue_id = ?", [id])?;
+
+            // The old status lets `undelete` put the issue back where it was
+            ctx.record_field_change(
                 EventType::Deleted,
                 id,
+                Some(original_status),
+                Some(Status::Tombstone.as_str().to_string()),
                 Some(format!("Deleted issue: {reason}")),
             );
             ctx.mark_dirty(id);
@@ -659,12 +877,223 @@ impl SqliteStorage {
             .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })
     }
 
+    /// Restore a tombstoned issue to its original type and status.
+    ///
+    /// The status comes from the `deleted` event (falling back to closed or
+    /// open), and dependency links removed by `br delete` are re-added when
+    /// the other end still exists and the link would not create a cycle.
+    /// Returns the number of dependency links restored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue doesn't exist or is not a tombstone.
+    pub fn undelete_issue(&mut self, id: &str, actor: &str) -> Result<usize> {
+        let issue = self
+            .get_issue(id)?
+            .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })?;
+        if issue.status != Status::Tombstone {
+            return Err(BeadsError::validation(
+                "id",
+                format!("{id} is not deleted (status: {})", issue.status),
+            ));
+        }
+
+        let events = self.get_events(id, 0)?;
+        let deleted_pos = events
+            .iter()
+            .position(|event| event.event_type == EventType::Deleted);
+        let status = deleted_pos
+            .and_then(|pos| events[pos].old_value.as_deref())
+            .and_then(|old| old.parse::<Status>().ok())
+            .filter(|status| *status != Status::Tombstone)
+            .unwrap_or(if issue.closed_at.is_some() {
+                Status::Closed
+            } else {
+                Status::Open
+            });
+        let issue_type = issue
+            .original_type
+            .as_deref()
+            .and_then(|original| original.parse::<IssueType>().ok())
+            .unwrap_or_else(|| issue.issue_type.clone());
+        let closed_at = if status.is_terminal() {
+            issue.closed_at
+        } else {
+            None
+        };
+
+        // Links removed just before the tombstone was written (see `remove_all_dependencies`)
+        let removed: Vec<Dependency> = deleted_pos
+            .map_or(&[][..], |pos| &events[pos + 1..])
+            .iter()
+            .take_while(|event| {
+                !matches!(event.event_type, EventType::Deleted | EventType::Restored)
+            })
+            .find(|event| event.event_type == EventType::DependencyRemoved)
+            .and_then(|event| event.old_value.as_deref())
+            .and_then(|old| serde_json::from_str(old).ok())
+            .unwrap_or_default();
+        let mut links = Vec::new();
+        for dep in removed {
+            let other = if dep.issue_id == id {
+                &dep.depends_on_id
+            } else {
+                &dep.issue_id
+            };
+            let other_alive = match self.get_issue(other)? {
+                Some(other_issue) => other_issue.status != Status::Tombstone,
+                // Outgoing links may point at external references
+                None => dep.issue_id == id,
+            };
+            if !other_alive
+                || (dep.dep_type.is_blocking()
+                    && self.would_create_cycle(&dep.issue_id, &dep.depends_on_id, true)?)
+            {
+                continue;
+            }
+            links.push(dep);
+        }
+
+        self.mutate("undelete_issue", actor, |tx, ctx| {
+            tx.execute(
+                "UPDATE issues SET
+                    status = ?,
+                    issue_type = ?,
+                    closed_at = ?,
+                    deleted_at = NULL,
+                    deleted_by = NULL,
+                    delete_reason = NULL,
+                    original_type = NULL,
+                    updated_at = ?
+                 WHERE id = ?",
+                rusqlite::params![
+                    status.as_str(),
+                    issue_type.as_str(),
+                    closed_at.map(|dt| dt.to_rfc3339()),
+                    Utc::now().to_rfc3339(),
+                    id
+                ],
+            )?;
+
+            let mut restored = 0;
+            for dep in &links {
+                restored += tx.execute(
+                    "INSERT OR IGNORE INTO dependencies
+                        (issue_id, depends_on_id, type, created_at, created_by, metadata, thread_id)
+                     VALUES (?, ?, ?, ?, ?, ?, ?)",
+                    rusqlite::params![
+                        dep.issue_id,
+                        dep.depends_on_id,
+                        dep.dep_type.as_str(),
+                        dep.created_at.to_rfc3339(),
+                        dep.created_by.as_deref().unwrap_or(""),
+                        dep.metadata.as_deref().unwrap_or("{}"),
+                        dep.thread_id.as_deref().unwrap_or(""),
+                    ],
+                )?;
+                for endpoint in [&dep.issue_id, &dep.depends_on_id] {
+                    if endpoint != id {
+                        ctx.mark_dirty(endpoint);
+                    }
+                }
+            }
+
+            ctx.record_field_change(
+                EventType::Restored,
+                id,
+                Some(Status::Tombstone.as_str().to_string()),
+                Some(status.as_str().to_string()),
+                Some(format!(
+                    "Undeleted as {status}; restored {restored} dependency links"
+                )),
+            );
+            ctx.mark_dirty(id);
+            ctx.invalidate_cache();
+
+            Ok(restored)
+        })
+    }
+
+    /// Tombstoned issues, most recently deleted first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_tombstones(&self) -> Result<Vec<Issue>> {
+        let sql = r"
+            SELECT id, content_hash, title, description, design, acceptance_criteria, notes,
+                   status, priority, issue_type, assignee, owner, estimated_minutes,
+                   created_at, created_by, updated_at, closed_at, close_reason, closed_by_session,
+                   due_at, defer_until, external_ref, source_system, source_repo,
+                   deleted_at, deleted_by, delete_reason, original_type,
+                   compaction_level, compacted_at, compacted_at_commit, original_size,
+                   sender, ephemeral, pinned, is_template, close_outcome
+            FROM issues WHERE status = 'tombstone'
+            ORDER BY deleted_at DESC, id ASC
+        ";
+
+        let mut stmt = self.conn.prepare_cached(sql)?;
+        let issues = stmt
+            .query_map([], Self::issue_from_row)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(issues)
+    }
+
+    /// Permanently remove tombstones and everything attached to them.
+    ///
+    /// Only issues that are still tombstones are removed. Links from other
+    /// issues to a purged tombstone are dropped and those issues marked dirty.
+    /// Returns the number of issues removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete fails.
+    pub fn purge_tombstones(&mut self, ids: &[String], actor: &str) -> Result<usize> {
+        self.mutate("purge_tombstones", actor, |tx, ctx| {
+            let mut purged = 0;
+            for id in ids {
+                let is_tombstone: bool = tx
+                    .query_row(
+                        "SELECT status = 'tombstone' FROM issues WHERE id = ?",
+                        [id],
+                        |row| row.get(0),
+                    )
+                    .optional()?
+                    .unwrap_or(false);
+                if !is_tombstone {
+                    continue;
+                }
+
+                let mut stmt = tx.prepare(
+                    "SELECT DISTINCT issue_id FROM dependencies WHERE depends_on_id = ?",
+                )?;
+                let dependents: Vec<String> = stmt
+                    .query_map([id], |row| row.get(0))?
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                tx.execute("DELETE FROM dependencies WHERE depends_on_id = ?", [id])?;
+                // Labels, comments, events and the rest cascade
+                tx.execute("DELETE FROM issues WHERE id = ?", [id])?;
+                tx.execute("DELETE FROM issues_fts WHERE id = ?", [id])?;
+                for dependent in dependents.iter().filter(|dep| !ids.contains(*dep)) {
+                    ctx.mark_dirty(dependent);
+                }
+                purged += 1;
+            }
+            ctx.invalidate_cache();
+            Ok(purged)
+        })
+    }
+
     /// Get an issue by ID.
     ///
     /// # Errors
     ///
     /// Returns an error if the database query fails.
     pub fn get_issue(&self, id: &str) -> Result<Option<Issue>> {
+        Self::get_issue_impl(&self.conn, id)
+    }
+
+    fn get_issue_impl(conn: &Connection, id: &str) -> Result<Option<Issue>> {
         let sql = r"
             SELECT id, content_hash, title, description, design, acceptance_criteria, notes,
                    status, priority, issue_type, assignee, owner, estimated_minutes,
@@ -672,12 +1101,12 @@ impl SqliteStorage {
                    due_at, defer_until, external_ref, source_system, source_repo,
                    deleted_at, deleted_by, delete_reason, original_type,
                    compaction_level, compacted_at, compacted_at_commit, original_size,
-                   sender, ephemeral, pinned, is_template
+                   sender, ephemeral, pinned, is_template, close_outcome
             FROM issues WHERE id = ?
         ";
 
-        let mut stmt = self.conn.prepare_cached(sql)?;
-        let result = stmt.query_row([id], |row| self.issue_from_row(row));
+        let mut stmt = conn.prepare_cached(sql)?;
+        let result = stmt.query_row([id], Self::issue_from_row);
 
         match result {
             Ok(issue) => Ok(Some(issue)),
@@ -709,7 +1138,7 @@ impl SqliteStorage {
                          due_at, defer_until, external_ref, source_system, source_repo,
                          deleted_at, deleted_by, delete_reason, original_type,
                          compaction_level, compacted_at, compacted_at_commit, original_size,
-                         sender, ephemeral, pinned, is_template
+                         sender, ephemeral, pinned, is_template, close_outcome
                   FROM issues WHERE id IN ({})",
                 placeholders.join(",")
             );
@@ -719,7 +1148,7 @@ impl SqliteStorage {
 
             let mut stmt = self.conn.prepare(&sql)?;
             let chunk_issues = stmt
-                .query_map(params.as_slice(), |row| self.issue_from_row(row))?
+                .query_map(params.as_slice(), Self::issue_from_row)?
                 .collect::<std::result::Result<Vec<_>, _>>()?;
             issues.extend(chunk_issues);
         }
@@ -741,7 +1170,7 @@ impl SqliteStorage {
                      due_at, defer_until, external_ref, source_system, source_repo,
                      deleted_at, deleted_by, delete_reason, original_type,
                      compaction_level, compacted_at, compacted_at_commit, original_size,
-                     sender, ephemeral, pinned, is_template
+                     sender, ephemeral, pinned, is_template, close_outcome
             FROM issues WHERE 1=1",
         );
 
@@ -820,6 +1249,12 @@ impl SqliteStorage {
             }
         }
 
+        for (name, value) in &filters.fields {
+            sql.push_str(" AND EXISTS (SELECT 1 FROM custom_fields WHERE custom_fields.issue_id = issues.id AND custom_fields.name = ? AND custom_fields.value = ?)");
+            params.push(Box::new(name.clone()));
+            params.push(Box::new(value.to_string()));
+        }
+
         if let Some(ref title_contains) = filters.title_contains {
             sql.push_str(" AND title LIKE ? ESCAPE '\\'");
             let escaped = escape_like_pattern(title_contains);
@@ -836,8 +1271,26 @@ impl SqliteStorage {
             params.push(Box::new(ts.to_rfc3339()));
         }
 
+        if let Some(ref expr) = filters.where_expr {
+            sql.push_str(" AND ");
+            push_filter_expr(expr, &mut sql, &mut params);
+        }
+
         // Apply custom sort if provided
-        if let Some(ref sort_field) = filters.sort {
+        if let Some(name) = filters
+            .sort
+            .as_deref()
+            .and_then(|sort| sort.strip_prefix(FIELD_SORT_PREFIX))
+        {
+            // Issues without the field sort last in either direction
+            let order = if filters.reverse { "DESC" } else { "ASC" };
+            let missing = field_sort_expr(&self.field_schema, name, &mut params);
+            let value = field_sort_expr(&self.field_schema, name, &mut params);
+            let _ = write!(
+                sql,
+                " ORDER BY {missing} IS NULL, {value} {order}, priority ASC, created_at DESC"
+            );
+        } else if let Some(ref sort_field) = filters.sort {
             let order = if filters.reverse { "DESC" } else { "ASC" };
             // Simple validation to prevent injection (though params should handle it,
             // column names can't be parameterized)
@@ -882,7 +1335,7 @@ impl SqliteStorage {
         let mut stmt = self.conn.prepare(&sql)?;
         let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
         let issues = stmt
-            .query_map(params_refs.as_slice(), |row| self.issue_from_row(row))?
+            .query_map(params_refs.as_slice(), Self::issue_from_row)?
             .collect::<std::result::Result<Vec<_>, _>>()?;
 
         Ok(issues)
@@ -890,38 +1343,58 @@ impl SqliteStorage {
 
     /// Search issues by query with optional filters.
     ///
+    /// Thin wrapper over [`Self::search_issues_ranked`] for callers that only
+    /// need the matching issues (in relevance order).
+    ///
     /// # Errors
     ///
-    /// Returns an error if the database query fails.
-    #[allow(clippy::too_many_lines)]
+    /// Returns an error if the query is malformed or the database query fails.
     pub fn search_issues(&self, query: &str, filters: &ListFilters) -> Result<Vec<Issue>> {
-        let trimmed = query.trim();
-        if trimmed.is_empty() {
-            return Ok(Vec::new());
-        }
+        Ok(self
+            .search_issues_ranked(query, filters)?
+            .into_iter()
+            .map(|hit| hit.issue)
+            .collect())
+    }
 
-        let mut sql = String::from(
-            r"SELECT id, content_hash, title, description, design, acceptance_criteria, notes,
-                     status, priority, issue_type, assignee, owner, estimated_minutes,
-                     created_at, created_by, updated_at, closed_at, close_reason, closed_by_session,
-                     due_at, defer_until, external_ref, source_system, source_repo,
-                     deleted_at, deleted_by, delete_reason, original_type,
-                     compaction_level, compacted_at, compacted_at_commit, original_size,
-                     sender, ephemeral, pinned, is_template
+    /// Full-text search over the `issues_fts` index, ranked by BM25.
+    ///
+    /// Searches id, title, description, design, acceptance criteria, notes and
+    /// comments. See [`build_fts_query`] for the accepted query syntax. Results
+    /// are ordered by relevance (title/id matches weigh most), then priority.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the query is malformed, or an error if the
+    /// database query fails.
+    #[allow(clippy::too_many_lines)]
+    pub fn search_issues_ranked(
+        &self,
+        query: &str,
+        filters: &ListFilters,
+    ) -> Result<Vec<SearchHit>> {
+        let Some(match_expr) = build_fts_query(query) else {
+            return Ok(Vec::new());
+        };
+        self.check_fts_query(query, &match_expr)?;
+
+        // Column weights follow the issues_fts column order:
+        // id, title, description, design, acceptance_criteria, notes, comments
+        let mut sql = format!(
+            r"WITH hits AS (
+                SELECT id AS hit_id,
+                       bm25(issues_fts, 10.0, 10.0, 4.0, 2.0, 2.0, 2.0, 1.0) AS rank,
+                       snippet(issues_fts, -1, '{SNIPPET_MATCH_START}', '{SNIPPET_MATCH_END}', '...', 16) AS snippet
+                FROM issues_fts
+                WHERE issues_fts MATCH ?
+              )
+              SELECT {ISSUE_COLUMNS}, hits.rank, hits.snippet
               FROM issues
-              WHERE 1=1",
+              JOIN hits ON hits.hit_id = issues.id
+              WHERE 1=1"
         );
 
-        let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
-
-        sql.push_str(
-            " AND (title LIKE ? ESCAPE '\\' OR description LIKE ? ESCAPE '\\' OR id LIKE ? ESCAPE '\\')",
-        );
-        let escaped = escape_like_pattern(trimmed);
-        let pattern = format!("%{escaped}%");
-        params.push(Box::new(pattern.clone()));
-        params.push(Box::new(pattern.clone()));
-        params.push(Box::new(pattern));
+        let mut params: Vec<Box<dyn rusqlite::ToSql>> = vec![Box::new(match_expr)];
 
         if let Some(ref statuses) = filters.statuses {
             if !statuses.is_empty() {
@@ -992,13 +1465,24 @@ impl SqliteStorage {
             }
         }
 
+        for (name, value) in &filters.fields {
+            sql.push_str(" AND EXISTS (SELECT 1 FROM custom_fields WHERE custom_fields.issue_id = issues.id AND custom_fields.name = ? AND custom_fields.value = ?)");
+            params.push(Box::new(name.clone()));
+            params.push(Box::new(value.to_string()));
+        }
+
         if let Some(ref title_contains) = filters.title_contains {
             sql.push_str(" AND title LIKE ? ESCAPE '\\'");
             let escaped = escape_like_pattern(title_contains);
             params.push(Box::new(format!("%{escaped}%")));
         }
 
-        sql.push_str(" ORDER BY priority ASC, created_at DESC");
+        if let Some(ref expr) = filters.where_expr {
+            sql.push_str(" AND ");
+            push_filter_expr(expr, &mut sql, &mut params);
+        }
+
+        sql.push_str(" ORDER BY hits.rank ASC, priority ASC, created_at DESC");
 
         if let Some(limit) = filters.limit {
             if limit > 0 {
@@ -1007,51 +1491,107 @@ impl SqliteStorage {
             }
         }
 
-        let mut stmt = self.conn.prepare(&sql)?;
         let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
-        let issues = stmt
-            .query_map(params_refs.as_slice(), |row| self.issue_from_row(row))?
-            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(self.query_search_hits(&sql, &params_refs)?)
+    }
 
-        Ok(issues)
+    /// Run `match_expr` against the index alone so a malformed query is
+    /// reported as such, separately from failures in the rest of the search.
+    fn check_fts_query(&self, query: &str, match_expr: &str) -> Result<()> {
+        let checked = self
+            .conn
+            .prepare_cached("SELECT 1 FROM issues_fts WHERE issues_fts MATCH ? LIMIT 1")
+            .and_then(|mut stmt| stmt.exists([match_expr]));
+        match checked {
+            Ok(_) => Ok(()),
+            // FTS5 reports query syntax errors as a generic SQLITE_ERROR
+            Err(rusqlite::Error::SqliteFailure(err, detail))
+                if err.code == rusqlite::ErrorCode::Unknown =>
+            {
+                let detail = detail.unwrap_or_else(|| err.to_string());
+                Err(BeadsError::validation(
+                    "query",
+                    format!("invalid search query '{}': {detail}", query.trim()),
+                ))
+            }
+            Err(err) => Err(err.into()),
+        }
     }
 
-    /// Get ready issues (unblocked, not deferred, not pinned, not ephemeral).
+    fn query_search_hits(
+        &self,
+        sql: &str,
+        params: &[&dyn rusqlite::ToSql],
+    ) -> rusqlite::Result<Vec<SearchHit>> {
+        let mut stmt = self.conn.prepare(sql)?;
+        stmt.query_map(params, |row| {
+            // rank and snippet follow the issue columns
+            let rank: f64 = row.get(ISSUE_COLUMN_COUNT)?;
+            let snippet: Option<String> = row.get(ISSUE_COLUMN_COUNT + 1)?;
+            Ok(SearchHit {
+                issue: Self::issue_from_row(row)?,
+                // bm25() is negative with better matches lower; flip it so
+                // callers see "higher is better".
+                score: -rank,
+                snippet: snippet.filter(|s| !s.trim().is_empty()),
+            })
+        })?
+        .collect()
+    }
+
+    /// Rebuild the full-text search index from scratch.
     ///
-    /// Ready definition:
-    /// 1. Status is `open` OR `in_progress`
-    /// 2. NOT in `blocked_issues_cache`
-    /// 3. `defer_until` is NULL or <= now (unless `include_deferred`)
-    /// 4. `pinned = 0` (not pinned)
-    /// 5. `ephemeral = 0` AND ID does not contain `-wisp-`
+    /// Used after bulk writes that bypass `mutate` (JSONL import, merge).
     ///
     /// # Errors
     ///
-    /// Returns an error if the database query fails.
+    /// Returns an error if the database operation fails.
+    pub fn rebuild_search_index(&mut self) -> Result<usize> {
+        let tx = self.conn.transaction()?;
+        tx.execute("DELETE FROM issues_fts", [])?;
+        let count = tx.execute(SEARCH_INDEX_INSERT_SQL, [])?;
+        tx.commit()?;
+        Ok(count)
+    }
+
+    /// Re-index a single issue (or drop it from the index if it no longer exists).
+    fn refresh_search_index_impl(conn: &Connection, issue_id: &str) -> Result<()> {
+        conn.execute("DELETE FROM issues_fts WHERE id = ?", [issue_id])?;
+        conn.execute(
+            &format!("{SEARCH_INDEX_INSERT_SQL} WHERE i.id = ?"),
+            [issue_id],
+        )?;
+        Ok(())
+    }
+
+    /// SQL and parameters selecting `columns` of the ready issues.
     #[allow(clippy::too_many_lines)]
-    pub fn get_ready_issues(
+    fn ready_query(
         &self,
+        columns: &str,
         filters: &ReadyFilters,
         sort: ReadySortPolicy,
-    ) -> Result<Vec<Issue>> {
-        let mut sql = String::from(
-            r"SELECT id, content_hash, title, description, design, acceptance_criteria, notes,
-                     status, priority, issue_type, assignee, owner, estimated_minutes,
-                     created_at, created_by, updated_at, closed_at, close_reason, closed_by_session,
-                     due_at, defer_until, external_ref, source_system, source_repo,
-                     deleted_at, deleted_by, delete_reason, original_type,
-                     compaction_level, compacted_at, compacted_at_commit, original_size,
-                     sender, ephemeral, pinned, is_template
-              FROM issues WHERE 1=1",
-        );
+    ) -> (String, Vec<Box<dyn rusqlite::ToSql>>) {
+        let mut sql = format!("SELECT {columns} FROM issues WHERE 1=1");
 
         let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
 
-        // Ready condition 1: status is `open` OR `in_progress`
+        // Ready condition 1: status is `open` OR `in_progress` (or an active custom status)
+        let active_custom = self.workflow.custom_statuses_in(StatusCategory::Active);
+        let custom_placeholders: String = active_custom.iter().map(|_| ", ?").collect();
         if filters.include_deferred {
-            sql.push_str(" AND status IN ('open', 'in_progress', 'deferred')");
+            let _ = write!(
+                sql,
+                " AND status IN ('open', 'in_progress', 'deferred'{custom_placeholders})"
+            );
         } else {
-            sql.push_str(" AND status IN ('open', 'in_progress')");
+            let _ = write!(
+                sql,
+                " AND status IN ('open', 'in_progress'{custom_placeholders})"
+            );
+        }
+        for status in active_custom {
+            params.push(Box::new(status));
         }
 
         // Ready condition 2: NOT in blocked_issues_cache (NOT EXISTS is faster than NOT IN)
@@ -1065,10 +1605,16 @@ impl SqliteStorage {
             sql.push_str(" AND (defer_until IS NULL OR datetime(defer_until) <= datetime('now'))");
         }
 
-        // Ready condition 4: not pinned
+        // Ready condition 4: not held by a live claim (expired claims are available again)
+        sql.push_str(
+            " AND NOT EXISTS (SELECT 1 FROM claims WHERE claims.issue_id = issues.id \
+               AND datetime(claims.expires_at) > datetime('now'))",
+        );
+
+        // Ready condition 5: not pinned
         sql.push_str(" AND (pinned = 0 OR pinned IS NULL)");
 
-        // Ready condition 5: not ephemeral and not wisp
+        // Ready condition 6: not ephemeral and not wisp
         sql.push_str(" AND (ephemeral = 0 OR ephemeral IS NULL)");
         sql.push_str(" AND id NOT LIKE '%-wisp-%'");
 
@@ -1109,6 +1655,16 @@ impl SqliteStorage {
             sql.push_str(" AND assignee IS NULL");
         }
 
+        // Only issues the holder may take over: unassigned, already theirs,
+        // or left behind by a lapsed claim
+        if let Some(ref holder) = filters.claimable_by {
+            sql.push_str(
+                " AND (assignee IS NULL OR assignee = '' OR assignee = ? \
+                   OR EXISTS (SELECT 1 FROM claims WHERE claims.issue_id = issues.id))",
+            );
+            params.push(Box::new(holder.clone()));
+        }
+
         // Filter by labels (AND logic)
         for label in &filters.labels_and {
             sql.push_str(" AND EXISTS (SELECT 1 FROM labels WHERE labels.issue_id = issues.id AND labels.label = ?)");
@@ -1129,6 +1685,12 @@ impl SqliteStorage {
             }
         }
 
+        // Filter by --where expression
+        if let Some(ref expr) = filters.where_expr {
+            sql.push_str(" AND ");
+            push_filter_expr(expr, &mut sql, &mut params);
+        }
+
         // Sorting
         match sort {
             ReadySortPolicy::Hybrid => {
@@ -1152,10 +1714,32 @@ impl SqliteStorage {
             }
         }
 
+        (sql, params)
+    }
+
+    /// Get ready issues (unblocked, not deferred, not pinned, not ephemeral).
+    ///
+    /// Ready definition:
+    /// 1. Status is `open` OR `in_progress`, or a custom status in the `active` category
+    /// 2. NOT in `blocked_issues_cache`
+    /// 3. `defer_until` is NULL or <= now (unless `include_deferred`)
+    /// 4. No unexpired claim lease
+    /// 5. `pinned = 0` (not pinned)
+    /// 6. `ephemeral = 0` AND ID does not contain `-wisp-`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_ready_issues(
+        &self,
+        filters: &ReadyFilters,
+        sort: ReadySortPolicy,
+    ) -> Result<Vec<Issue>> {
+        let (sql, params) = self.ready_query(ISSUE_COLUMNS, filters, sort);
         let mut stmt = self.conn.prepare(&sql)?;
         let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
         let issues: Vec<Issue> = stmt
-            .query_map(params_refs.as_slice(), |row| self.issue_from_row(row))?
+            .query_map(params_refs.as_slice(), Self::issue_from_row)?
             .collect::<std::result::Result<Vec<_>, _>>()?;
 
         Ok(issues)
@@ -1218,6 +1802,34 @@ impl SqliteStorage {
         Ok(exists)
     }
 
+    /// Map issues to the `conditional-blocks` blockers that closed with a
+    /// failure outcome, i.e. the reason those issues are ready to run.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn failed_conditional_blockers(&self) -> Result<HashMap<String, Vec<String>>> {
+        let mut stmt = self.conn.prepare_cached(
+            r"SELECT d.issue_id, d.depends_on_id
+              FROM dependencies d
+              INNER JOIN issues blocker ON d.depends_on_id = blocker.id
+              WHERE d.type = 'conditional-blocks'
+                AND blocker.status = 'closed'
+                AND blocker.close_outcome = 'failure'
+              ORDER BY d.issue_id, d.depends_on_id",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?;
+
+        let mut result: HashMap<String, Vec<String>> = HashMap::new();
+        for row in rows {
+            let (issue_id, blocker_id) = row?;
+            result.entry(issue_id).or_default().push(blocker_id);
+        }
+        Ok(result)
+    }
+
     /// Rebuild the blocked issues cache from scratch.
     ///
     /// This computes which issues are blocked based on their dependencies
@@ -1227,6 +1839,9 @@ impl SqliteStorage {
     /// Blocking dependency types: blocks, parent-child, conditional-blocks, waits-for
     /// Blocking statuses: any non-terminal status (not closed/tombstone)
     ///
+    /// A `conditional-blocks` dependent additionally stays blocked (as moot)
+    /// when its blocker closed with any outcome other than `failure`.
+    ///
     /// # Errors
     ///
     /// Returns an error if the database operation fails.
@@ -1235,13 +1850,15 @@ impl SqliteStorage {
         if !force_rebuild {
             return Ok(0);
         }
+        let done_statuses = self.workflow.custom_statuses_in(StatusCategory::Done);
         let tx = self.conn.transaction()?;
-        let count = Self::rebuild_blocked_cache_impl(&tx)?;
+        let count = Self::rebuild_blocked_cache_impl(&tx, &done_statuses)?;
         tx.commit()?;
         Ok(count)
     }
 
-    fn rebuild_blocked_cache_impl(conn: &Connection) -> Result<usize> {
+    #[allow(clippy::too_many_lines)]
+    fn rebuild_blocked_cache_impl(conn: &Connection, done_statuses: &[String]) -> Result<usize> {
         const MAX_DEPTH: i32 = 50;
 
         // Clear existing cache
@@ -1254,31 +1871,63 @@ impl SqliteStorage {
         // its parent epic is open. However, if the parent is blocked by something else,
         // that blocking propagates to children (handled in the transitive section below).
         //
-        // For conditional-blocks, we also need to check if the blocker closed with failure
-        // but for simplicity in this initial implementation, we treat it like blocks.
+        // conditional-blocks is the "run only if the blocker fails" edge. While the
+        // blocker is open the dependent waits (`:if-failure`); once it closes with
+        // any outcome other than failure the dependent stays out of the ready queue
+        // as moot (`:moot`). Only a failed blocker releases it.
+        //
+        // Missing blockers whose prefix differs from the dependent's are routed
+        // dependencies; like `external:` refs they are resolved at runtime.
+        //
+        // Custom statuses in the workflow's `done` category release dependents
+        // the same way `closed` does.
         let mut blocked_issues_map: std::collections::HashMap<String, Vec<String>> =
             std::collections::HashMap::new();
         {
-            let mut stmt = conn.prepare(
-                r"SELECT DISTINCT d.issue_id, d.depends_on_id || ':' || COALESCE(i.status, 'unknown')
+            let done_placeholders: String = done_statuses.iter().map(|_| ", ?").collect();
+            let mut stmt = conn.prepare(&format!(
+                r"SELECT DISTINCT d.issue_id, d.depends_on_id, i.id IS NULL, d.depends_on_id || ':' || CASE
+                      WHEN i.id IS NULL THEN 'unknown'
+                      WHEN d.type = 'conditional-blocks' AND i.status = 'closed' THEN 'moot'
+                      WHEN d.type = 'conditional-blocks' THEN 'if-failure'
+                      ELSE i.status
+                  END
                   FROM dependencies d
                   LEFT JOIN issues i ON d.depends_on_id = i.id
                   WHERE d.type IN ('blocks', 'conditional-blocks', 'waits-for')
                     AND (
                       -- The blocker is in a blocking state (anything not terminal)
-                      i.status NOT IN ('closed', 'tombstone')
+                      i.status NOT IN ('closed', 'tombstone'{done_placeholders})
                       -- Or it's a missing local dependency (orphan)
                       -- External dependencies are resolved at runtime in the CLI
                       OR (i.id IS NULL AND d.depends_on_id NOT LIKE 'external:%')
-                    )",
-            )?;
-
-            let rows = stmt.query_map([], |row| {
-                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+                      -- Or a conditional blocker that finished without failing
+                      OR (
+                        d.type = 'conditional-blocks'
+                        AND i.status = 'closed'
+                        AND COALESCE(i.close_outcome, '') != 'failure'
+                      )
+                    )"
+            ))?;
+
+            let params: Vec<&dyn rusqlite::ToSql> = done_statuses
+                .iter()
+                .map(|status| status as &dyn rusqlite::ToSql)
+                .collect();
+            let rows = stmt.query_map(params.as_slice(), |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, bool>(2)?,
+                    row.get::<_, String>(3)?,
+                ))
             })?;
 
             for row in rows {
-                let (issue_id, blocker_ref) = row?;
+                let (issue_id, depends_on_id, missing, blocker_ref) = row?;
+                if missing && is_routed_dependency(&issue_id, &depends_on_id) {
+                    continue;
+                }
                 blocked_issues_map
                     .entry(issue_id)
                     .or_default()
@@ -1374,25 +2023,31 @@ impl SqliteStorage {
     ///
     /// Returns an error if the database query fails.
     pub fn get_blocked_issues(&self) -> Result<Vec<(Issue, Vec<String>)>> {
-        let mut stmt = self.conn.prepare(
+        let active_custom = self.workflow.custom_statuses_in(StatusCategory::Active);
+        let custom_placeholders: String = active_custom.iter().map(|_| ", ?").collect();
+        let mut stmt = self.conn.prepare(&format!(
             r"SELECT i.id, i.content_hash, i.title, i.description, i.design, i.acceptance_criteria, i.notes,
                      i.status, i.priority, i.issue_type, i.assignee, i.owner, i.estimated_minutes,
                      i.created_at, i.created_by, i.updated_at, i.closed_at, i.close_reason, i.closed_by_session,
                      i.due_at, i.defer_until, i.external_ref, i.source_system, i.source_repo,
                      i.deleted_at, i.deleted_by, i.delete_reason, i.original_type, i.compaction_level,
                      i.compacted_at, i.compacted_at_commit, i.original_size, i.sender, i.ephemeral,
-                     i.pinned, i.is_template,
+                     i.pinned, i.is_template, i.close_outcome,
                      bc.blocked_by
               FROM issues i
               INNER JOIN blocked_issues_cache bc ON i.id = bc.issue_id
-              WHERE i.status IN ('open', 'in_progress')
-              ORDER BY i.priority ASC, i.created_at ASC",
-        )?;
+              WHERE i.status IN ('open', 'in_progress'{custom_placeholders})
+              ORDER BY i.priority ASC, i.created_at ASC"
+        ))?;
 
+        let params: Vec<&dyn rusqlite::ToSql> = active_custom
+            .iter()
+            .map(|status| status as &dyn rusqlite::ToSql)
+            .collect();
         let results = stmt
-            .query_map([], |row| {
-                let issue = self.issue_from_row(row)?;
-                let blockers_json: String = row.get(36)?;
+            .query_map(params.as_slice(), |row| {
+                let issue = Self::issue_from_row(row)?;
+                let blockers_json: String = row.get(ISSUE_COLUMN_COUNT)?;
                 Ok((issue, blockers_json))
             })?
             .collect::<std::result::Result<Vec<_>, _>>()?;
@@ -1479,7 +2134,9 @@ impl SqliteStorage {
     /// Compute blockers caused by unsatisfied external dependencies.
     ///
     /// This excludes external dependencies from the blocked cache and evaluates
-    /// them at query time, including parent-child propagation.
+    /// them at query time, including parent-child propagation. Routed
+    /// dependencies (concrete IDs living in another workspace) are looked up in
+    /// `external_statuses` by ID; ones missing from the map count as blockers.
     ///
     /// # Errors
     ///
@@ -1502,11 +2159,30 @@ impl SqliteStorage {
             Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
         })?;
 
-        for row in rows {
-            let (issue_id, depends_on_id) = row?;
-            let satisfied = external_statuses
-                .get(&depends_on_id)
-                .copied()
+        let mut direct = rows.collect::<std::result::Result<Vec<_>, _>>()?;
+
+        // Routed blockers follow the same edge types as the blocked cache.
+        let mut routed_stmt = self.conn.prepare(
+            "SELECT d.issue_id, d.depends_on_id
+             FROM dependencies d
+             LEFT JOIN issues i ON d.depends_on_id = i.id
+             WHERE i.id IS NULL
+               AND d.depends_on_id NOT LIKE 'external:%'
+               AND d.type IN ('blocks', 'conditional-blocks', 'waits-for')",
+        )?;
+        for row in routed_stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })? {
+            let (issue_id, depends_on_id) = row?;
+            if is_routed_dependency(&issue_id, &depends_on_id) {
+                direct.push((issue_id, depends_on_id));
+            }
+        }
+
+        for (issue_id, depends_on_id) in direct {
+            let satisfied = external_statuses
+                .get(&depends_on_id)
+                .copied()
                 .unwrap_or(false);
             if !satisfied {
                 blockers
@@ -1562,6 +2238,35 @@ impl SqliteStorage {
             .collect())
     }
 
+    /// List dependency targets that live in another routed workspace.
+    ///
+    /// These are concrete issue IDs with no local row whose prefix differs
+    /// from the prefix of the issue depending on them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn list_routed_dependency_ids(&self) -> Result<HashSet<String>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT DISTINCT d.issue_id, d.depends_on_id
+             FROM dependencies d
+             LEFT JOIN issues i ON d.depends_on_id = i.id
+             WHERE i.id IS NULL AND d.depends_on_id NOT LIKE 'external:%'",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?;
+
+        let mut ids = HashSet::new();
+        for row in rows {
+            let (issue_id, depends_on_id) = row?;
+            if is_routed_dependency(&issue_id, &depends_on_id) {
+                ids.insert(depends_on_id);
+            }
+        }
+        Ok(ids)
+    }
+
     fn list_external_dependency_ids(&self, blocking_only: bool) -> Result<HashSet<String>> {
         let mut ids = HashSet::new();
         let sql = if blocking_only {
@@ -1687,57 +2392,72 @@ impl SqliteStorage {
         depends_on_id: &str,
         dep_type: &str,
         actor: &str,
+    ) -> Result<bool> {
+        self.mutate("add_dependency", actor, |tx, ctx| {
+            Self::add_dependency_impl(tx, ctx, issue_id, depends_on_id, dep_type, actor)
+        })
+    }
+
+    /// Insert a dependency link unless it already exists, refusing cycles.
+    fn add_dependency_impl(
+        tx: &Transaction,
+        ctx: &mut MutationContext,
+        issue_id: &str,
+        depends_on_id: &str,
+        dep_type: &str,
+        actor: &str,
     ) -> Result<bool> {
         // Check for cycles if this is a blocking dependency
-        if let Ok(dt) = dep_type.parse::<DependencyType>() {
-            if dt.is_blocking() && self.would_create_cycle(issue_id, depends_on_id, true)? {
-                return Err(BeadsError::DependencyCycle {
-                    path: format!(
-                        "Adding dependency {issue_id} -> {depends_on_id} would create a cycle"
-                    ),
-                });
-            }
+        if let Ok(dt) = dep_type.parse::<DependencyType>()
+            && dt.is_blocking()
+            && Self::check_cycle(tx, issue_id, depends_on_id, true)?
+        {
+            return Err(BeadsError::DependencyCycle {
+                path: format!(
+                    "Adding dependency {issue_id} -> {depends_on_id} would create a cycle"
+                ),
+            });
         }
 
-        self.mutate("add_dependency", actor, |tx, ctx| {
-            let exists: i64 = tx.query_row(
-                "SELECT count(*) FROM dependencies WHERE issue_id = ? AND depends_on_id = ?",
-                rusqlite::params![issue_id, depends_on_id],
-                |row| row.get(0),
-            )?;
+        let exists: i64 = tx.query_row(
+            "SELECT count(*) FROM dependencies WHERE issue_id = ? AND depends_on_id = ?",
+            rusqlite::params![issue_id, depends_on_id],
+            |row| row.get(0),
+        )?;
 
-            if exists > 0 {
-                return Ok(false);
-            }
+        if exists > 0 {
+            return Ok(false);
+        }
 
-            tx.execute(
-                "INSERT INTO dependencies (issue_id, depends_on_id, type, created_at, created_by)
-                 VALUES (?, ?, ?, ?, ?)",
-                rusqlite::params![
-                    issue_id,
-                    depends_on_id,
-                    dep_type,
-                    Utc::now().to_rfc3339(),
-                    actor
-                ],
-            )?;
+        tx.execute(
+            "INSERT INTO dependencies (issue_id, depends_on_id, type, created_at, created_by)
+             VALUES (?, ?, ?, ?, ?)",
+            rusqlite::params![
+                issue_id,
+                depends_on_id,
+                dep_type,
+                Utc::now().to_rfc3339(),
+                actor
+            ],
+        )?;
 
-            // Bump updated_at
-            tx.execute(
-                "UPDATE issues SET updated_at = ? WHERE id = ?",
-                rusqlite::params![Utc::now().to_rfc3339(), issue_id],
-            )?;
+        // Bump updated_at
+        tx.execute(
+            "UPDATE issues SET updated_at = ? WHERE id = ?",
+            rusqlite::params![Utc::now().to_rfc3339(), issue_id],
+        )?;
 
-            ctx.record_event(
-                EventType::DependencyAdded,
-                issue_id,
-                Some(format!("Added dependency on {depends_on_id} ({dep_type})")),
-            );
-            ctx.mark_dirty(issue_id);
-            ctx.invalidate_cache();
+        ctx.record_field_change(
+            EventType::DependencyAdded,
+            issue_id,
+            None,
+            Some(depends_on_id.to_string()),
+            Some(format!("Added dependency on {depends_on_id} ({dep_type})")),
+        );
+        ctx.mark_dirty(issue_id);
+        ctx.invalidate_cache();
 
-            Ok(true)
-        })
+        Ok(true)
     }
 
     /// Remove a dependency link.
@@ -1752,6 +2472,10 @@ impl SqliteStorage {
         actor: &str,
     ) -> Result<bool> {
         self.mutate("remove_dependency", actor, |tx, ctx| {
+            let removed: Vec<Dependency> = Self::dependency_links_impl(tx, issue_id)?
+                .into_iter()
+                .filter(|dep| dep.issue_id == issue_id && dep.depends_on_id == depends_on_id)
+                .collect();
             let rows = tx.execute(
                 "DELETE FROM dependencies WHERE issue_id = ? AND depends_on_id = ?",
                 rusqlite::params![issue_id, depends_on_id],
@@ -1764,9 +2488,12 @@ impl SqliteStorage {
                     rusqlite::params![Utc::now().to_rfc3339(), issue_id],
                 )?;
 
-                ctx.record_event(
+                // The removed row, type included, so `--as-of` can put it back
+                ctx.record_field_change(
                     EventType::DependencyRemoved,
                     issue_id,
+                    serde_json::to_string(&removed).ok(),
+                    None,
                     Some(format!("Removed dependency on {depends_on_id}")),
                 );
                 ctx.mark_dirty(issue_id);
@@ -1777,6 +2504,33 @@ impl SqliteStorage {
         })
     }
 
+    /// All dependency rows touching an issue, in either direction.
+    fn dependency_links_impl(conn: &Connection, issue_id: &str) -> Result<Vec<Dependency>> {
+        let mut stmt = conn.prepare(
+            "SELECT issue_id, depends_on_id, type, created_at, created_by, metadata, thread_id
+             FROM dependencies
+             WHERE issue_id = ?1 OR depends_on_id = ?1
+             ORDER BY issue_id, depends_on_id",
+        )?;
+        let deps = stmt
+            .query_map([issue_id], |row| {
+                Ok(Dependency {
+                    issue_id: row.get(0)?,
+                    depends_on_id: row.get(1)?,
+                    dep_type: row
+                        .get::<_, Option<String>>(2)?
+                        .and_then(|s| s.parse().ok())
+                        .unwrap_or(DependencyType::Blocks),
+                    created_at: parse_datetime(&row.get::<_, String>(3)?),
+                    created_by: row.get(4)?,
+                    metadata: row.get(5)?,
+                    thread_id: row.get(6)?,
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(deps)
+    }
+
     /// Remove all dependencies for an issue.
     ///
     /// # Errors
@@ -1784,6 +2538,7 @@ impl SqliteStorage {
     /// Returns an error if the database update fails.
     pub fn remove_all_dependencies(&mut self, issue_id: &str, actor: &str) -> Result<usize> {
         self.mutate("remove_all_dependencies", actor, |tx, ctx| {
+            let removed = Self::dependency_links_impl(tx, issue_id)?;
             let mut stmt = tx.prepare(
                 "SELECT DISTINCT issue_id FROM dependencies WHERE depends_on_id = ?
                  UNION
@@ -1817,9 +2572,12 @@ impl SqliteStorage {
                     )?;
                 }
 
-                ctx.record_event(
+                // The removed links are kept so `undelete` can put them back
+                ctx.record_field_change(
                     EventType::DependencyRemoved,
                     issue_id,
+                    serde_json::to_string(&removed).ok(),
+                    None,
                     Some(format!("Removed {total} dependency links")),
                 );
                 ctx.mark_dirty(issue_id);
@@ -1840,6 +2598,12 @@ impl SqliteStorage {
     /// Returns an error if the database update fails.
     pub fn remove_parent(&mut self, issue_id: &str, actor: &str) -> Result<bool> {
         self.mutate("remove_parent", actor, |tx, ctx| {
+            let removed: Vec<Dependency> = Self::dependency_links_impl(tx, issue_id)?
+                .into_iter()
+                .filter(|dep| {
+                    dep.issue_id == issue_id && dep.dep_type == DependencyType::ParentChild
+                })
+                .collect();
             let rows = tx.execute(
                 "DELETE FROM dependencies WHERE issue_id = ? AND type = 'parent-child'",
                 rusqlite::params![issue_id],
@@ -1852,9 +2616,11 @@ impl SqliteStorage {
                     rusqlite::params![Utc::now().to_rfc3339(), issue_id],
                 )?;
 
-                ctx.record_event(
+                ctx.record_field_change(
                     EventType::DependencyRemoved,
                     issue_id,
+                    serde_json::to_string(&removed).ok(),
+                    None,
                     Some("Removed parent".to_string()),
                 );
                 ctx.mark_dirty(issue_id);
@@ -1872,36 +2638,47 @@ impl SqliteStorage {
     /// Returns an error if the database update fails.
     pub fn add_label(&mut self, issue_id: &str, label: &str, actor: &str) -> Result<bool> {
         self.mutate("add_label", actor, |tx, ctx| {
-            let exists: i64 = tx.query_row(
-                "SELECT count(*) FROM labels WHERE issue_id = ? AND label = ?",
-                rusqlite::params![issue_id, label],
-                |row| row.get(0),
-            )?;
+            Self::add_label_impl(tx, ctx, issue_id, label)
+        })
+    }
 
-            if exists > 0 {
-                return Ok(false);
-            }
+    fn add_label_impl(
+        tx: &Transaction,
+        ctx: &mut MutationContext,
+        issue_id: &str,
+        label: &str,
+    ) -> Result<bool> {
+        let exists: i64 = tx.query_row(
+            "SELECT count(*) FROM labels WHERE issue_id = ? AND label = ?",
+            rusqlite::params![issue_id, label],
+            |row| row.get(0),
+        )?;
 
-            tx.execute(
-                "INSERT INTO labels (issue_id, label) VALUES (?, ?)",
-                rusqlite::params![issue_id, label],
-            )?;
+        if exists > 0 {
+            return Ok(false);
+        }
 
-            ctx.record_event(
-                EventType::LabelAdded,
-                issue_id,
-                Some(format!("Added label {label}")),
-            );
-            ctx.mark_dirty(issue_id);
+        tx.execute(
+            "INSERT INTO labels (issue_id, label) VALUES (?, ?)",
+            rusqlite::params![issue_id, label],
+        )?;
 
-            // Update timestamp
-            tx.execute(
-                "UPDATE issues SET updated_at = ? WHERE id = ?",
-                rusqlite::params![Utc::now().to_rfc3339(), issue_id],
-            )?;
+        ctx.record_field_change(
+            EventType::LabelAdded,
+            issue_id,
+            None,
+            Some(label.to_string()),
+            Some(format!("Added label {label}")),
+        );
+        ctx.mark_dirty(issue_id);
 
-            Ok(true)
-        })
+        // Update timestamp
+        tx.execute(
+            "UPDATE issues SET updated_at = ? WHERE id = ?",
+            rusqlite::params![Utc::now().to_rfc3339(), issue_id],
+        )?;
+
+        Ok(true)
     }
 
     /// Remove a label from an issue.
@@ -1923,9 +2700,11 @@ impl SqliteStorage {
                     rusqlite::params![Utc::now().to_rfc3339(), issue_id],
                 )?;
 
-                ctx.record_event(
+                ctx.record_field_change(
                     EventType::LabelRemoved,
                     issue_id,
+                    Some(label.to_string()),
+                    None,
                     Some(format!("Removed label {label}")),
                 );
                 ctx.mark_dirty(issue_id);
@@ -1942,6 +2721,12 @@ impl SqliteStorage {
     /// Returns an error if the database update fails.
     pub fn remove_all_labels(&mut self, issue_id: &str, actor: &str) -> Result<usize> {
         self.mutate("remove_all_labels", actor, |tx, ctx| {
+            let mut stmt = tx.prepare("SELECT label FROM labels WHERE issue_id = ?")?;
+            let labels: Vec<String> = stmt
+                .query_map([issue_id], |row| row.get(0))?
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            drop(stmt);
+
             let rows = tx.execute(
                 "DELETE FROM labels WHERE issue_id = ?",
                 rusqlite::params![issue_id],
@@ -1954,11 +2739,16 @@ impl SqliteStorage {
                     rusqlite::params![Utc::now().to_rfc3339(), issue_id],
                 )?;
 
-                ctx.record_event(
-                    EventType::LabelRemoved,
-                    issue_id,
-                    Some(format!("Removed {rows} labels")),
-                );
+                for label in labels {
+                    let comment = format!("Removed label {label}");
+                    ctx.record_field_change(
+                        EventType::LabelRemoved,
+                        issue_id,
+                        Some(label),
+                        None,
+                        Some(comment),
+                    );
+                }
                 ctx.mark_dirty(issue_id);
             }
 
@@ -1993,32 +2783,24 @@ impl SqliteStorage {
             let added: Vec<_> = labels.iter().filter(|l| !old_labels.contains(l)).collect();
 
             if !removed.is_empty() || !added.is_empty() {
-                let mut details = Vec::new();
-                if !removed.is_empty() {
-                    details.push(format!(
-                        "removed: {}",
-                        removed
-                            .iter()
-                            .map(|s| s.as_str())
-                            .collect::<Vec<_>>()
-                            .join(", ")
-                    ));
+                for label in removed {
+                    ctx.record_field_change(
+                        EventType::LabelRemoved,
+                        issue_id,
+                        Some(label.clone()),
+                        None,
+                        Some(format!("Removed label {label}")),
+                    );
                 }
-                if !added.is_empty() {
-                    details.push(format!(
-                        "added: {}",
-                        added
-                            .iter()
-                            .map(|s| s.as_str())
-                            .collect::<Vec<_>>()
-                            .join(", ")
-                    ));
+                for label in added {
+                    ctx.record_field_change(
+                        EventType::LabelAdded,
+                        issue_id,
+                        None,
+                        Some(label.clone()),
+                        Some(format!("Added label {label}")),
+                    );
                 }
-                ctx.record_event(
-                    EventType::Updated,
-                    issue_id,
-                    Some(format!("Labels {}", details.join("; "))),
-                );
                 ctx.mark_dirty(issue_id);
 
                 // Bump updated_at
@@ -2112,92 +2894,274 @@ impl SqliteStorage {
         Ok(map)
     }
 
-    /// Get all unique labels with their issue counts.
-    ///
-    /// Returns a vector of (label, count) pairs sorted alphabetically by label.
-    /// Excludes labels on tombstoned (deleted) issues.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the database query fails.
-    pub fn get_unique_labels_with_counts(&self) -> Result<Vec<(String, i64)>> {
-        let mut stmt = self.conn.prepare_cached(
-            r"SELECT l.label, COUNT(*) as count
-              FROM labels l
-              JOIN issues i ON l.issue_id = i.id
-              WHERE i.status != 'tombstone'
-              GROUP BY l.label
-              ORDER BY l.label",
-        )?;
-        let results = stmt
-            .query_map([], |row| {
-                Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
-            })?
-            .collect::<std::result::Result<Vec<_>, _>>()?;
-        Ok(results)
-    }
-
-    /// Rename a label across all issues.
+    /// Set or clear custom field values on an issue.
     ///
-    /// Returns the number of issues affected.
+    /// A `None` value removes the field. Returns the number of fields that
+    /// actually changed.
     ///
     /// # Errors
     ///
     /// Returns an error if the database update fails.
-    pub fn rename_label(&mut self, old_name: &str, new_name: &str, actor: &str) -> Result<usize> {
-        self.mutate("rename_label", actor, |tx, ctx| {
-            // Get all issue IDs that have the old label
-            let mut stmt = tx.prepare("SELECT issue_id FROM labels WHERE label = ?")?;
-            let issue_ids: Vec<String> = stmt
-                .query_map([old_name], |row| row.get(0))?
-                .collect::<std::result::Result<Vec<_>, _>>()?;
-            drop(stmt);
+    pub fn set_fields(
+        &mut self,
+        issue_id: &str,
+        changes: &BTreeMap<String, Option<Value>>,
+        actor: &str,
+    ) -> Result<usize> {
+        self.mutate("set_fields", actor, |tx, ctx| {
+            Self::set_fields_impl(tx, ctx, issue_id, changes)
+        })
+    }
 
-            // Check if any issues already have the new label (would cause duplicates)
-            let mut check_stmt =
-                tx.prepare("SELECT issue_id FROM labels WHERE label = ? AND issue_id IN (SELECT issue_id FROM labels WHERE label = ?)")?;
-            let conflicts: Vec<String> = check_stmt
-                .query_map(rusqlite::params![new_name, old_name], |row| row.get(0))?
-                .collect::<std::result::Result<Vec<_>, _>>()?;
-            drop(check_stmt);
+    fn set_fields_impl(
+        tx: &Transaction,
+        ctx: &mut MutationContext,
+        issue_id: &str,
+        changes: &BTreeMap<String, Option<Value>>,
+    ) -> Result<usize> {
+        let mut changed = 0;
+        for (name, value) in changes {
+            let old: Option<String> = tx
+                .query_row(
+                    "SELECT value FROM custom_fields WHERE issue_id = ? AND name = ?",
+                    rusqlite::params![issue_id, name],
+                    |row| row.get(0),
+                )
+                .optional()?;
+            let new = value.as_ref().map(Value::to_string);
+            if old == new {
+                continue;
+            }
 
-            // For issues that already have the new label, just remove the old one
-            for conflict_id in &conflicts {
+            if let Some(ref json) = new {
                 tx.execute(
-                    "DELETE FROM labels WHERE issue_id = ? AND label = ?",
-                    rusqlite::params![conflict_id, old_name],
+                    "INSERT OR REPLACE INTO custom_fields (issue_id, name, value) VALUES (?, ?, ?)",
+                    rusqlite::params![issue_id, name, json],
                 )?;
-                ctx.mark_dirty(conflict_id);
-            }
-
-            // Rename the label for issues that don't have conflicts
-            let renamed = tx.execute(
-                "UPDATE labels SET label = ? WHERE label = ?",
-                rusqlite::params![new_name, old_name],
-            )?;
-
-            // Mark all affected issues as dirty and record events
-            let now = Utc::now().to_rfc3339();
-            for issue_id in &issue_ids {
-                ctx.record_event(
-                    EventType::LabelRemoved,
-                    issue_id,
-                    Some(format!("Renamed label {old_name} to {new_name}")),
-                );
-                ctx.mark_dirty(issue_id);
-
-                // Update timestamp
+            } else {
                 tx.execute(
-                    "UPDATE issues SET updated_at = ? WHERE id = ?",
-                    rusqlite::params![now, issue_id],
+                    "DELETE FROM custom_fields WHERE issue_id = ? AND name = ?",
+                    rusqlite::params![issue_id, name],
                 )?;
             }
+            // Values are keyed by the field name: `{"<name>": <value>}`
+            let keyed = |json: Option<String>| {
+                json.map(|json| {
+                    let value: Value = serde_json::from_str(&json).unwrap_or(Value::Null);
+                    serde_json::json!({ name: value }).to_string()
+                })
+            };
+            ctx.record_field_change(
+                EventType::FieldChanged,
+                issue_id,
+                keyed(old),
+                keyed(new),
+                Some(format!("Field {name} changed")),
+            );
+            changed += 1;
+        }
 
-            Ok(renamed + conflicts.len())
-        })
+        if changed > 0 {
+            ctx.mark_dirty(issue_id);
+            tx.execute(
+                "UPDATE issues SET updated_at = ? WHERE id = ?",
+                rusqlite::params![Utc::now().to_rfc3339(), issue_id],
+            )?;
+        }
+
+        Ok(changed)
     }
 
-    /// Get comments for an issue.
+    /// Get custom field values for an issue.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_fields(&self, issue_id: &str) -> Result<BTreeMap<String, Value>> {
+        let mut stmt = self
+            .conn
+            .prepare_cached("SELECT name, value FROM custom_fields WHERE issue_id = ?")?;
+        let rows = stmt.query_map([issue_id], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?;
+
+        let mut fields = BTreeMap::new();
+        for row in rows {
+            let (name, value) = row?;
+            fields.insert(name, parse_field_value(value));
+        }
+        Ok(fields)
+    }
+
+    /// Get custom field values for multiple issues efficiently.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_fields_for_issues(
+        &self,
+        issue_ids: &[String],
+    ) -> Result<HashMap<String, BTreeMap<String, Value>>> {
+        const SQLITE_VAR_LIMIT: usize = 900;
+
+        let mut map: HashMap<String, BTreeMap<String, Value>> = HashMap::new();
+        for chunk in issue_ids.chunks(SQLITE_VAR_LIMIT) {
+            let placeholders: Vec<&str> = chunk.iter().map(|_| "?").collect();
+            let sql = format!(
+                "SELECT issue_id, name, value FROM custom_fields WHERE issue_id IN ({})",
+                placeholders.join(",")
+            );
+
+            let params: Vec<&dyn rusqlite::ToSql> =
+                chunk.iter().map(|s| s as &dyn rusqlite::ToSql).collect();
+
+            let mut stmt = self.conn.prepare(&sql)?;
+            let rows = stmt.query_map(params.as_slice(), |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                ))
+            })?;
+
+            for row in rows {
+                let (issue_id, name, value) = row?;
+                map.entry(issue_id)
+                    .or_default()
+                    .insert(name, parse_field_value(value));
+            }
+        }
+
+        Ok(map)
+    }
+
+    /// Get custom field values for all issues as a map of issue_id -> fields.
+    ///
+    /// Used for export and sync operations that need complete field state.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_all_fields(&self) -> Result<HashMap<String, BTreeMap<String, Value>>> {
+        let mut stmt = self
+            .conn
+            .prepare_cached("SELECT issue_id, name, value FROM custom_fields")?;
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })?;
+
+        let mut map: HashMap<String, BTreeMap<String, Value>> = HashMap::new();
+        for row in rows {
+            let (issue_id, name, value) = row?;
+            map.entry(issue_id)
+                .or_default()
+                .insert(name, parse_field_value(value));
+        }
+        Ok(map)
+    }
+
+    /// Get all unique labels with their issue counts.
+    ///
+    /// Returns a vector of (label, count) pairs sorted alphabetically by label.
+    /// Excludes labels on tombstoned (deleted) issues.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_unique_labels_with_counts(&self) -> Result<Vec<(String, i64)>> {
+        let mut stmt = self.conn.prepare_cached(
+            r"SELECT l.label, COUNT(*) as count
+              FROM labels l
+              JOIN issues i ON l.issue_id = i.id
+              WHERE i.status != 'tombstone'
+              GROUP BY l.label
+              ORDER BY l.label",
+        )?;
+        let results = stmt
+            .query_map([], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(results)
+    }
+
+    /// Rename a label across all issues.
+    ///
+    /// Returns the number of issues affected.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    pub fn rename_label(&mut self, old_name: &str, new_name: &str, actor: &str) -> Result<usize> {
+        self.mutate("rename_label", actor, |tx, ctx| {
+            // Get all issue IDs that have the old label
+            let mut stmt = tx.prepare("SELECT issue_id FROM labels WHERE label = ?")?;
+            let issue_ids: Vec<String> = stmt
+                .query_map([old_name], |row| row.get(0))?
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            drop(stmt);
+
+            // Check if any issues already have the new label (would cause duplicates)
+            let mut check_stmt =
+                tx.prepare("SELECT issue_id FROM labels WHERE label = ? AND issue_id IN (SELECT issue_id FROM labels WHERE label = ?)")?;
+            let conflicts: Vec<String> = check_stmt
+                .query_map(rusqlite::params![new_name, old_name], |row| row.get(0))?
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            drop(check_stmt);
+
+            // For issues that already have the new label, just remove the old one
+            for conflict_id in &conflicts {
+                tx.execute(
+                    "DELETE FROM labels WHERE issue_id = ? AND label = ?",
+                    rusqlite::params![conflict_id, old_name],
+                )?;
+                ctx.mark_dirty(conflict_id);
+            }
+
+            // Rename the label for issues that don't have conflicts
+            let renamed = tx.execute(
+                "UPDATE labels SET label = ? WHERE label = ?",
+                rusqlite::params![new_name, old_name],
+            )?;
+
+            // Mark all affected issues as dirty and record events
+            let now = Utc::now().to_rfc3339();
+            for issue_id in &issue_ids {
+                let comment = format!("Renamed label {old_name} to {new_name}");
+                ctx.record_field_change(
+                    EventType::LabelRemoved,
+                    issue_id,
+                    Some(old_name.to_string()),
+                    None,
+                    Some(comment.clone()),
+                );
+                if !conflicts.contains(issue_id) {
+                    ctx.record_field_change(
+                        EventType::LabelAdded,
+                        issue_id,
+                        None,
+                        Some(new_name.to_string()),
+                        Some(comment),
+                    );
+                }
+                ctx.mark_dirty(issue_id);
+
+                // Update timestamp
+                tx.execute(
+                    "UPDATE issues SET updated_at = ? WHERE id = ?",
+                    rusqlite::params![now, issue_id],
+                )?;
+            }
+
+            Ok(renamed + conflicts.len())
+        })
+    }
+
+    /// Get comments for an issue.
     ///
     /// # Errors
     ///
@@ -2232,18 +3196,28 @@ impl SqliteStorage {
     /// Returns an error if the database update fails.
     pub fn add_comment(&mut self, issue_id: &str, author: &str, text: &str) -> Result<Comment> {
         self.mutate("add_comment", author, |tx, ctx| {
-            let comment_id = insert_comment_row(tx, issue_id, author, text)?;
+            Self::add_comment_impl(tx, ctx, issue_id, author, text)
+        })
+    }
 
-            tx.execute(
-                "UPDATE issues SET updated_at = ? WHERE id = ?",
-                rusqlite::params![Utc::now().to_rfc3339(), issue_id],
-            )?;
+    fn add_comment_impl(
+        tx: &Transaction,
+        ctx: &mut MutationContext,
+        issue_id: &str,
+        author: &str,
+        text: &str,
+    ) -> Result<Comment> {
+        let comment_id = insert_comment_row(tx, issue_id, author, text)?;
+
+        tx.execute(
+            "UPDATE issues SET updated_at = ? WHERE id = ?",
+            rusqlite::params![Utc::now().to_rfc3339(), issue_id],
+        )?;
 
-            ctx.record_event(EventType::Commented, issue_id, Some(text.to_string()));
-            ctx.mark_dirty(issue_id);
+        ctx.record_event(EventType::Commented, issue_id, Some(text.to_string()));
+        ctx.mark_dirty(issue_id);
 
-            fetch_comment(tx, comment_id)
-        })
+        fetch_comment(tx, comment_id)
     }
 
     /// Get dependencies with metadata.
@@ -2594,7 +3568,7 @@ impl SqliteStorage {
                            due_at, defer_until, external_ref, source_system, source_repo,
                            deleted_at, deleted_by, delete_reason, original_type, compaction_level,
                            compacted_at, compacted_at_commit, original_size, sender, ephemeral,
-                           pinned, is_template
+                           pinned, is_template, close_outcome
                     FROM issues
                     WHERE (ephemeral = 0 OR ephemeral IS NULL)
                       AND id NOT LIKE '%-wisp-%'
@@ -2602,7 +3576,7 @@ impl SqliteStorage {
 
         let mut stmt = self.conn.prepare_cached(sql)?;
         let issues = stmt
-            .query_map([], |row| self.issue_from_row(row))?
+            .query_map([], Self::issue_from_row)?
             .collect::<std::result::Result<Vec<_>, _>>()?;
 
         Ok(issues)
@@ -2933,9 +3907,10 @@ impl SqliteStorage {
         include_events: bool,
         event_limit: usize,
     ) -> Result<Option<IssueDetails>> {
-        let Some(issue) = self.get_issue(id)? else {
+        let Some(mut issue) = self.get_issue(id)? else {
             return Ok(None);
         };
+        issue.fields = self.get_fields(id)?;
 
         let labels = self.get_labels(id)?;
         let dependencies = self.get_dependencies_with_metadata(id)?;
@@ -2971,8 +3946,7 @@ impl SqliteStorage {
         s.filter(|v| !v.is_empty())
     }
 
-    #[allow(clippy::unused_self)]
-    fn issue_from_row(&self, row: &rusqlite::Row) -> rusqlite::Result<Issue> {
+    fn issue_from_row(row: &rusqlite::Row) -> rusqlite::Result<Issue> {
         Ok(Issue {
             id: row.get(0)?,
             content_hash: row.get::<_, Option<String>>(1)?,
@@ -2995,6 +3969,9 @@ impl SqliteStorage {
                 .as_deref()
                 .map(parse_datetime),
             close_reason: Self::empty_to_none(row.get::<_, Option<String>>(17)?),
+            close_outcome: row
+                .get::<_, Option<String>>(36)?
+                .and_then(|value| value.parse().ok()),
             closed_by_session: Self::empty_to_none(row.get::<_, Option<String>>(18)?),
             due_at: row
                 .get::<_, Option<String>>(19)?
@@ -3028,6 +4005,7 @@ impl SqliteStorage {
             labels: vec![],       // Loaded separately if needed
             dependencies: vec![], // Loaded separately if needed
             comments: vec![],     // Loaded separately if needed
+            fields: BTreeMap::new(),
         })
     }
 
@@ -3055,6 +4033,52 @@ impl SqliteStorage {
     }
 }
 
+/// A full-text search match.
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    pub issue: Issue,
+    /// BM25 relevance score (higher is better).
+    pub score: f64,
+    /// Best-matching excerpt, with matches wrapped in
+    /// [`SNIPPET_MATCH_START`]/[`SNIPPET_MATCH_END`].
+    pub snippet: Option<String>,
+}
+
+/// Marker inserted before each matched term in search snippets.
+pub const SNIPPET_MATCH_START: &str = "**";
+/// Marker inserted after each matched term in search snippets.
+pub const SNIPPET_MATCH_END: &str = "**";
+
+/// `--sort` prefix selecting a custom field, e.g. `field:severity`.
+pub const FIELD_SORT_PREFIX: &str = "field:";
+
+/// Columns read by `claim_from_row`, in order.
+const CLAIM_COLUMNS: &str = "issue_id, claimed_by, claimed_by_session, claimed_at, expires_at";
+
+fn claim_from_row(row: &rusqlite::Row) -> rusqlite::Result<Claim> {
+    Ok(Claim {
+        issue_id: row.get(0)?,
+        claimed_by: row.get(1)?,
+        claimed_by_session: row.get(2)?,
+        claimed_at: parse_datetime(&row.get::<_, String>(3)?),
+        expires_at: parse_datetime(&row.get::<_, String>(4)?),
+    })
+}
+
+/// Columns read by `issue_from_row`, in order.
+const ISSUE_COLUMNS: &str =
+    "id, content_hash, title, description, design, acceptance_criteria, notes,
+     status, priority, issue_type, assignee, owner, estimated_minutes,
+     created_at, created_by, updated_at, closed_at, close_reason, closed_by_session,
+     due_at, defer_until, external_ref, source_system, source_repo,
+     deleted_at, deleted_by, delete_reason, original_type,
+     compaction_level, compacted_at, compacted_at_commit, original_size,
+     sender, ephemeral, pinned, is_template, close_outcome";
+
+/// Number of columns in [`ISSUE_COLUMNS`]; extra columns selected after them
+/// start at this index.
+const ISSUE_COLUMN_COUNT: usize = 37;
+
 /// Filter options for listing issues.
 #[derive(Debug, Clone, Default)]
 #[allow(clippy::struct_excessive_bools)]
@@ -3069,7 +4093,7 @@ pub struct ListFilters {
     pub include_templates: bool,
     pub title_contains: Option<String>,
     pub limit: Option<usize>,
-    /// Sort field (priority, `created_at`, `updated_at`, title)
+    /// Sort field (priority, `created_at`, `updated_at`, title, `field:<name>`)
     pub sort: Option<String>,
     /// Reverse sort order
     pub reverse: bool,
@@ -3081,6 +4105,24 @@ pub struct ListFilters {
     pub updated_before: Option<DateTime<Utc>>,
     /// Filter by `updated_at` >= timestamp
     pub updated_after: Option<DateTime<Utc>>,
+    /// Parsed `--where` expression, AND-ed with the other filters
+    pub where_expr: Option<FilterExpr>,
+    /// Filter by custom field values (all must match)
+    pub fields: Vec<(String, Value)>,
+}
+
+impl ListFilters {
+    /// Set the `--where` expression.
+    ///
+    /// An expression that tests `status` decides on its own which statuses
+    /// match, so the default closed/deferred exclusions are lifted.
+    pub fn set_where(&mut self, expr: FilterExpr) {
+        if expr.references(FilterField::Status) {
+            self.include_closed = true;
+            self.include_deferred = true;
+        }
+        self.where_expr = Some(expr);
+    }
 }
 
 /// Fields to update on an issue.
@@ -3102,6 +4144,7 @@ pub struct IssueUpdate {
     pub external_ref: Option<Option<String>>,
     pub closed_at: Option<Option<DateTime<Utc>>>,
     pub close_reason: Option<Option<String>>,
+    pub close_outcome: Option<Option<CloseOutcome>>,
     pub closed_by_session: Option<Option<String>>,
     pub deleted_at: Option<Option<DateTime<Utc>>>,
     pub deleted_by: Option<Option<String>>,
@@ -3112,6 +4155,25 @@ pub struct IssueUpdate {
 }
 
 impl IssueUpdate {
+    /// The update `br close` applies: status, close time, reason, outcome and
+    /// closing session.
+    #[must_use]
+    pub fn close(
+        reason: &str,
+        outcome: Option<CloseOutcome>,
+        session: Option<String>,
+        closed_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            status: Some(Status::Closed),
+            closed_at: Some(Some(closed_at)),
+            close_reason: Some(Some(reason.to_string())),
+            close_outcome: outcome.map(Some),
+            closed_by_session: session.map(Some),
+            ..Self::default()
+        }
+    }
+
     #[must_use]
     pub const fn is_empty(&self) -> bool {
         self.title.is_none()
@@ -3130,6 +4192,7 @@ impl IssueUpdate {
             && self.external_ref.is_none()
             && self.closed_at.is_none()
             && self.close_reason.is_none()
+            && self.close_outcome.is_none()
             && self.closed_by_session.is_none()
             && self.deleted_at.is_none()
             && self.deleted_by.is_none()
@@ -3137,6 +4200,56 @@ impl IssueUpdate {
     }
 }
 
+/// One step of [`SqliteStorage::apply_batch`], with every ID already resolved.
+#[derive(Debug, Clone)]
+pub enum BatchOp {
+    /// Insert a fully built issue, labels and dependencies included.
+    Create(Box<Issue>),
+    /// Apply `update`, then set or clear custom `fields` as `set_fields` does.
+    Update {
+        id: String,
+        update: IssueUpdate,
+        fields: BTreeMap<String, Option<Value>>,
+    },
+    /// Close as `br close` does: skipped when already closed, refused while
+    /// the issue is blocked unless `force` is set, and closing moot
+    /// `conditional-blocks` dependents when the outcome is success.
+    Close {
+        id: String,
+        reason: String,
+        outcome: Option<CloseOutcome>,
+        force: bool,
+    },
+    AddDependency {
+        issue_id: String,
+        depends_on_id: String,
+        dep_type: String,
+    },
+    AddLabel {
+        issue_id: String,
+        label: String,
+    },
+    AddComment {
+        issue_id: String,
+        text: String,
+    },
+}
+
+impl BatchOp {
+    /// Short name used in error messages.
+    #[must_use]
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            Self::Create(_) => "create",
+            Self::Update { .. } => "update",
+            Self::Close { .. } => "close",
+            Self::AddDependency { .. } => "dep add",
+            Self::AddLabel { .. } => "label add",
+            Self::AddComment { .. } => "comment",
+        }
+    }
+}
+
 /// Filter options for ready issues.
 #[derive(Debug, Clone, Default)]
 pub struct ReadyFilters {
@@ -3148,6 +4261,10 @@ pub struct ReadyFilters {
     pub priorities: Option<Vec<Priority>>,
     pub include_deferred: bool,
     pub limit: Option<usize>,
+    /// Parsed `--where` expression, AND-ed with the readiness conditions
+    pub where_expr: Option<FilterExpr>,
+    /// Only issues this holder could claim (unassigned, theirs, or lapsed)
+    pub claimable_by: Option<String>,
 }
 
 /// Sort policy for ready issues.
@@ -3162,6 +4279,19 @@ pub enum ReadySortPolicy {
     Oldest,
 }
 
+impl ReadySortPolicy {
+    /// Compare two issues the way `get_ready_issues` orders them, for merging
+    /// ready lists from several databases.
+    #[must_use]
+    pub fn compare(self, a: &Issue, b: &Issue) -> std::cmp::Ordering {
+        match self {
+            Self::Hybrid => (a.priority.0 > 1, a.created_at).cmp(&(b.priority.0 > 1, b.created_at)),
+            Self::Priority => (a.priority, a.created_at).cmp(&(b.priority, b.created_at)),
+            Self::Oldest => a.created_at.cmp(&b.created_at),
+        }
+    }
+}
+
 fn parse_status(s: Option<&str>) -> Status {
     s.map_or_else(Status::default, |val| {
         val.parse()
@@ -3173,6 +4303,37 @@ fn parse_issue_type(s: Option<&str>) -> IssueType {
     s.and_then(|s| s.parse().ok()).unwrap_or_default()
 }
 
+/// Decode a stored custom field value, keeping non-JSON text as a string.
+fn parse_field_value(text: String) -> Value {
+    serde_json::from_str(&text).unwrap_or(Value::String(text))
+}
+
+/// SQL expression yielding an issue's value of a custom field for ORDER BY.
+///
+/// Enum fields sort by their position in the declared values list.
+fn field_sort_expr(
+    schema: &FieldSchema,
+    name: &str,
+    params: &mut Vec<Box<dyn rusqlite::ToSql>>,
+) -> String {
+    let mut value = String::from("json_extract(custom_fields.value, '$')");
+    if let Some(def) = schema.get(name)
+        && def.kind == FieldKind::Enum
+    {
+        let mut case = format!("CASE {value}");
+        for (rank, allowed) in def.values.iter().enumerate() {
+            let _ = write!(case, " WHEN ? THEN {rank}");
+            params.push(Box::new(allowed.clone()));
+        }
+        case.push_str(" END");
+        value = case;
+    }
+    params.push(Box::new(name.to_string()));
+    format!(
+        "(SELECT {value} FROM custom_fields WHERE custom_fields.issue_id = issues.id AND custom_fields.name = ?)"
+    )
+}
+
 fn parse_external_dependency(dep_id: &str) -> Option<(String, String)> {
     let mut parts = dep_id.splitn(3, ':');
     let prefix = parts.next()?;
@@ -3187,6 +4348,23 @@ fn parse_external_dependency(dep_id: &str) -> Option<(String, String)> {
     Some((project, capability))
 }
 
+/// A missing dependency target is routed when its prefix differs from the
+/// dependent's; a missing target with the same prefix is a local orphan.
+fn is_routed_dependency(issue_id: &str, depends_on_id: &str) -> bool {
+    use crate::util::id::split_prefix_remainder;
+
+    if depends_on_id.starts_with("external:") {
+        return false;
+    }
+    match (
+        split_prefix_remainder(issue_id),
+        split_prefix_remainder(depends_on_id),
+    ) {
+        (Some((local, _)), Some((target, _))) => !local.eq_ignore_ascii_case(target),
+        _ => false,
+    }
+}
+
 fn query_external_project_capabilities(
     db_path: &Path,
     capabilities: &HashSet<String>,
@@ -3249,6 +4427,124 @@ fn parse_datetime(s: &str) -> DateTime<Utc> {
     Utc::now()
 }
 
+/// Columns of `issues_fts` that may be used as `field:` scopes in search queries.
+const FTS_FIELDS: &[(&str, &str)] = &[
+    ("id", "id"),
+    ("title", "title"),
+    ("description", "description"),
+    ("desc", "description"),
+    ("design", "design"),
+    ("acceptance_criteria", "acceptance_criteria"),
+    ("acceptance", "acceptance_criteria"),
+    ("notes", "notes"),
+    ("comments", "comments"),
+    ("comment", "comments"),
+];
+
+/// Translate a user search query into an FTS5 `MATCH` expression.
+///
+/// Supported syntax:
+/// - bare terms match word prefixes (`auth` finds "authentication")
+/// - `"quoted phrases"` match exact word sequences; append `*` for a prefix
+/// - `term*` is an explicit prefix term
+/// - `AND`, `OR`, `NOT` (uppercase) and parentheses combine terms;
+///   adjacent terms are implicitly ANDed
+/// - `field:term` scopes a term, phrase or group to one column
+///   (`title`, `description`/`desc`, `design`, `acceptance_criteria`/`acceptance`,
+///   `notes`, `comments`, `id`)
+///
+/// Terms containing punctuation (`bd-a1b2`, `v2.0`, `C++`) are quoted so they
+/// match as phrases instead of being parsed as FTS5 operators. Returns `None`
+/// if the query contains nothing searchable.
+#[must_use]
+pub fn build_fts_query(query: &str) -> Option<String> {
+    let mut parts: Vec<String> = Vec::new();
+    let mut chars = query.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c == '(' || c == ')' {
+            chars.next();
+            parts.push(c.to_string());
+        } else if c == '"' {
+            chars.next();
+            let mut phrase = String::new();
+            for ch in chars.by_ref() {
+                if ch == '"' {
+                    break;
+                }
+                phrase.push(ch);
+            }
+            let prefix = chars.next_if_eq(&'*').is_some();
+            if !phrase.trim().is_empty() {
+                parts.push(fts_phrase(&phrase, prefix));
+            }
+        } else {
+            let mut word = String::new();
+            while let Some(ch) =
+                chars.next_if(|ch| !ch.is_whitespace() && !matches!(ch, '(' | ')' | '"'))
+            {
+                word.push(ch);
+            }
+            push_fts_word(&mut parts, &word);
+        }
+    }
+
+    // Drop dangling column filters (e.g. a trailing "title:")
+    while parts.last().is_some_and(|part| part.ends_with(" :")) {
+        parts.pop();
+    }
+
+    let has_terms = parts.iter().any(|part| {
+        !matches!(part.as_str(), "(" | ")" | "AND" | "OR" | "NOT") && !part.ends_with(" :")
+    });
+    has_terms.then(|| parts.join(" "))
+}
+
+fn push_fts_word(parts: &mut Vec<String>, word: &str) {
+    if matches!(word, "AND" | "OR" | "NOT") {
+        parts.push(word.to_string());
+        return;
+    }
+
+    let mut word = word;
+    if let Some((field, rest)) = word.split_once(':') {
+        let column = FTS_FIELDS
+            .iter()
+            .find(|(alias, _)| alias.eq_ignore_ascii_case(field))
+            .map(|(_, column)| *column);
+        if let Some(column) = column {
+            parts.push(format!("{column} :"));
+            word = rest;
+        }
+    }
+
+    let (word, explicit_prefix) = word
+        .strip_suffix('*')
+        .map_or((word, false), |stripped| (stripped, true));
+    if word.is_empty() {
+        return;
+    }
+
+    if word.chars().all(|ch| ch.is_alphanumeric() || ch == '_') {
+        // Plain words are matched as prefixes to keep bd's substring feel.
+        parts.push(format!("{word}*"));
+    } else {
+        let prefix = explicit_prefix || word.chars().last().is_some_and(char::is_alphanumeric);
+        parts.push(fts_phrase(word, prefix));
+    }
+}
+
+fn fts_phrase(text: &str, prefix: bool) -> String {
+    let escaped = text.replace('"', "\"\"");
+    if prefix {
+        format!("\"{escaped}\" *")
+    } else {
+        format!("\"{escaped}\"")
+    }
+}
+
 /// Escape special LIKE pattern characters (%, _, \) for literal matching.
 ///
 /// Use with `LIKE ? ESCAPE '\\'` in SQL queries.
@@ -3258,27 +4554,136 @@ fn escape_like_pattern(s: &str) -> String {
         .replace('_', "\\_")
 }
 
-// ============================================================================
-// EXPORT/SYNC METHODS
-// ============================================================================
-
-impl SqliteStorage {
-    /// Get issue with all relations populated for export.
-    ///
-    /// Includes labels, dependencies, and comments.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the database query fails.
-    pub fn get_issue_for_export(&self, id: &str) -> Result<Option<Issue>> {
-        let Some(mut issue) = self.get_issue(id)? else {
-            return Ok(None);
-        };
-
+/// Append a compiled `--where` expression to `sql`, binding values to `params`.
+///
+/// Every predicate is forced to a two-valued result, so an unset column never
+/// leaks out as SQL `NULL`: `assignee != bob` and `not (assignee = bob)` both
+/// match unassigned issues.
+fn push_filter_expr(
+    expr: &FilterExpr,
+    sql: &mut String,
+    params: &mut Vec<Box<dyn rusqlite::ToSql>>,
+) {
+    match expr {
+        FilterExpr::And(left, right) | FilterExpr::Or(left, right) => {
+            let joiner = if matches!(expr, FilterExpr::And(..)) {
+                " AND "
+            } else {
+                " OR "
+            };
+            sql.push('(');
+            push_filter_expr(left, sql, params);
+            sql.push_str(joiner);
+            push_filter_expr(right, sql, params);
+            sql.push(')');
+        }
+        FilterExpr::Not(inner) => {
+            sql.push_str("NOT (");
+            push_filter_expr(inner, sql, params);
+            sql.push(')');
+        }
+        FilterExpr::IsNull(FilterField::Label) => {
+            sql.push_str("NOT EXISTS (SELECT 1 FROM labels WHERE labels.issue_id = issues.id)");
+        }
+        FilterExpr::IsNull(field) => {
+            let _ = write!(sql, "{} IS NULL", field.column());
+        }
+        FilterExpr::Compare {
+            field: FilterField::Label,
+            op,
+            value,
+        } => {
+            // The parser only admits equality and substring operators for labels.
+            let substring = matches!(op, CompareOp::Contains | CompareOp::NotContains);
+            let negated = matches!(op, CompareOp::Ne | CompareOp::NotContains);
+            let _ = write!(
+                sql,
+                "{}EXISTS (SELECT 1 FROM labels WHERE labels.issue_id = issues.id AND labels.label {})",
+                if negated { "NOT " } else { "" },
+                if substring {
+                    "LIKE ? ESCAPE '\\'"
+                } else {
+                    "= ?"
+                }
+            );
+            params.push(filter_value_param(value, substring));
+        }
+        FilterExpr::Compare { field, op, value } => {
+            let column = field.column();
+            match op {
+                CompareOp::Contains => {
+                    let _ = write!(sql, "COALESCE({column} LIKE ? ESCAPE '\\', 0)");
+                }
+                CompareOp::NotContains => {
+                    let _ = write!(sql, "COALESCE({column} NOT LIKE ? ESCAPE '\\', 1)");
+                }
+                _ => {
+                    let (lhs, rhs) = if matches!(value, FilterValue::Timestamp(_)) {
+                        (format!("datetime({column})"), "datetime(?)")
+                    } else {
+                        (column.to_string(), "?")
+                    };
+                    let sql_op = match op {
+                        CompareOp::Ne => "<>",
+                        other => other.as_str(),
+                    };
+                    let unset = i32::from(*op == CompareOp::Ne);
+                    let _ = write!(sql, "COALESCE({lhs} {sql_op} {rhs}, {unset})");
+                }
+            }
+            params.push(filter_value_param(value, op.is_substring()));
+        }
+        FilterExpr::In { field, values } => {
+            let placeholders = vec!["?"; values.len()].join(",");
+            if *field == FilterField::Label {
+                let _ = write!(
+                    sql,
+                    "EXISTS (SELECT 1 FROM labels WHERE labels.issue_id = issues.id AND labels.label IN ({placeholders}))"
+                );
+            } else {
+                let _ = write!(sql, "COALESCE({} IN ({placeholders}), 0)", field.column());
+            }
+            for value in values {
+                params.push(filter_value_param(value, false));
+            }
+        }
+    }
+}
+
+/// Bind a filter value; substring matches become an escaped `LIKE` pattern.
+fn filter_value_param(value: &FilterValue, substring: bool) -> Box<dyn rusqlite::ToSql> {
+    match value {
+        FilterValue::Text(text) if substring => {
+            Box::new(format!("%{}%", escape_like_pattern(text)))
+        }
+        FilterValue::Text(text) => Box::new(text.clone()),
+        FilterValue::Integer(n) => Box::new(*n),
+        FilterValue::Timestamp(ts) => Box::new(ts.to_rfc3339()),
+    }
+}
+
+// ============================================================================
+// EXPORT/SYNC METHODS
+// ============================================================================
+
+impl SqliteStorage {
+    /// Get issue with all relations populated for export.
+    ///
+    /// Includes labels, dependencies, and comments.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_issue_for_export(&self, id: &str) -> Result<Option<Issue>> {
+        let Some(mut issue) = self.get_issue(id)? else {
+            return Ok(None);
+        };
+
         // Populate relations
         issue.labels = self.get_labels(id)?;
         issue.dependencies = self.get_dependencies_full(id)?;
         issue.comments = self.get_comments(id)?;
+        issue.fields = self.get_fields(id)?;
 
         Ok(Some(issue))
     }
@@ -3315,167 +4720,745 @@ impl SqliteStorage {
             .filter_map(std::result::Result::ok)
             .collect();
 
-        Ok(deps)
+        Ok(deps)
+    }
+
+    /// Clear dirty flags for the given issue IDs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub fn clear_dirty_flags(&mut self, ids: &[String]) -> Result<usize> {
+        if ids.is_empty() {
+            return Ok(0);
+        }
+
+        let placeholders = vec!["?"; ids.len()].join(", ");
+        let sql = format!("DELETE FROM dirty_issues WHERE issue_id IN ({placeholders})");
+
+        let params: Vec<&dyn rusqlite::ToSql> =
+            ids.iter().map(|s| s as &dyn rusqlite::ToSql).collect();
+        let deleted = self.conn.execute(&sql, params.as_slice())?;
+
+        Ok(deleted)
+    }
+
+    /// Clear all dirty flags.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub fn clear_all_dirty_flags(&mut self) -> Result<usize> {
+        let deleted = self.conn.execute("DELETE FROM dirty_issues", [])?;
+        Ok(deleted)
+    }
+
+    /// Get the count of issues (for safety guard).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn count_exportable_issues(&self) -> Result<usize> {
+        let count: i64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM issues WHERE ephemeral = 0 AND id NOT LIKE '%-wisp-%'",
+            [],
+            |row| row.get(0),
+        )?;
+        // count is always non-negative from COUNT(*), safe to cast
+        #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+        Ok(count as usize)
+    }
+
+    /// Check if a dependency exists between two issues.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn dependency_exists_between(&self, issue_id: &str, depends_on_id: &str) -> Result<bool> {
+        let count: i64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM dependencies WHERE issue_id = ? AND depends_on_id = ?",
+            rusqlite::params![issue_id, depends_on_id],
+            |row| row.get(0),
+        )?;
+        Ok(count > 0)
+    }
+
+    /// Check if adding a dependency would create a cycle.
+    ///
+    /// If `blocking_only` is true, only considers blocking dependency types
+    /// ('blocks', 'parent-child', 'conditional-blocks') for cycle detection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn would_create_cycle(
+        &self,
+        issue_id: &str,
+        depends_on_id: &str,
+        blocking_only: bool,
+    ) -> Result<bool> {
+        Self::check_cycle(&self.conn, issue_id, depends_on_id, blocking_only)
+    }
+
+    /// Detect all cycles in the dependency graph.
+    ///
+    /// Returns a list of cycles, where each cycle is a vector of issue IDs.
+    /// Uses an iterative DFS to avoid stack overflow on deep graphs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn detect_all_cycles(&self) -> Result<Vec<Vec<String>>> {
+        use std::collections::{HashMap, HashSet};
+
+        // Get all dependencies
+        let mut graph: HashMap<String, Vec<String>> = HashMap::new();
+        let mut stmt = self
+            .conn
+            .prepare_cached("SELECT issue_id, depends_on_id FROM dependencies")?;
+
+        let edges = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?;
+
+        for edge in edges {
+            let (from, to) = edge?;
+            graph.entry(from).or_default().push(to);
+        }
+
+        let mut cycles = Vec::new();
+        let mut visited = HashSet::new();
+        let mut rec_stack = HashSet::new();
+        let mut path = Vec::new();
+
+        // Stack stores (node_id, neighbor_index)
+        let mut stack: Vec<(String, usize)> = Vec::new();
+
+        // Sort keys for deterministic output
+        let mut keys: Vec<_> = graph.keys().cloned().collect();
+        keys.sort();
+
+        for node in keys {
+            if visited.contains(&node) {
+                continue;
+            }
+
+            stack.push((node.clone(), 0));
+            visited.insert(node.clone());
+            rec_stack.insert(node.clone());
+            path.push(node.clone());
+
+            while let Some((u, idx)) = stack.last_mut() {
+                let neighbors = graph.get(u);
+
+                if let Some(neighbors) = neighbors {
+                    if *idx < neighbors.len() {
+                        let v = &neighbors[*idx];
+                        *idx += 1;
+
+                        if rec_stack.contains(v) {
+                            // Found a cycle: reconstruct it from the current path
+                            if let Some(start_pos) = path.iter().position(|x| x == v) {
+                                let mut cycle = path[start_pos..].to_vec();
+                                cycle.push(v.clone()); // Close the loop
+                                cycles.push(cycle);
+                            }
+                        } else if !visited.contains(v) {
+                            visited.insert(v.clone());
+                            rec_stack.insert(v.clone());
+                            path.push(v.clone());
+                            stack.push((v.clone(), 0));
+                        }
+                        continue;
+                    }
+                }
+
+                // Finished processing all neighbors of u
+                rec_stack.remove(u);
+                path.pop();
+                stack.pop();
+            }
+        }
+
+        Ok(cycles)
+    }
+
+    // ===== Messaging =====
+
+    /// Thread a message belongs to: the `thread_id` of its `replies-to`
+    /// link, or the message's own ID if it starts a thread.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn message_thread_id(&self, id: &str) -> Result<String> {
+        let thread: Option<String> = self
+            .conn
+            .query_row(
+                "SELECT thread_id FROM dependencies
+                 WHERE issue_id = ? AND type = 'replies-to' AND COALESCE(thread_id, '') != ''
+                 LIMIT 1",
+                [id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(thread.unwrap_or_else(|| id.to_string()))
+    }
+
+    /// IDs of all messages in a thread (including its root), oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn thread_message_ids(&self, thread_id: &str) -> Result<Vec<String>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id FROM issues
+             WHERE id = ?1
+                OR id IN (SELECT issue_id FROM dependencies
+                          WHERE type = 'replies-to' AND thread_id = ?1)
+             ORDER BY created_at ASC, id ASC",
+        )?;
+        let ids = stmt
+            .query_map([thread_id], |row| row.get(0))?
+            .collect::<std::result::Result<Vec<String>, _>>()?;
+        Ok(ids)
+    }
+
+    /// Record that `reader` has read the given messages.
+    ///
+    /// Read receipts are local to this database and never exported.
+    /// Returns how many messages were newly marked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database write fails.
+    pub fn mark_messages_read(&mut self, ids: &[String], reader: &str) -> Result<usize> {
+        let tx = self.conn.transaction()?;
+        let mut marked = 0;
+        for id in ids {
+            marked += tx.execute(
+                "INSERT OR IGNORE INTO message_reads (message_id, reader, read_at) VALUES (?, ?, ?)",
+                rusqlite::params![id, reader, Utc::now().to_rfc3339()],
+            )?;
+        }
+        tx.commit()?;
+        Ok(marked)
+    }
+
+    /// IDs of messages `reader` has already read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn read_message_ids(&self, reader: &str) -> Result<HashSet<String>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT message_id FROM message_reads WHERE reader = ?")?;
+        let ids = stmt
+            .query_map([reader], |row| row.get(0))?
+            .collect::<std::result::Result<HashSet<String>, _>>()?;
+        Ok(ids)
+    }
+
+    // ===== Claims =====
+
+    /// The claim lease on an issue, expired or not.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_claim(&self, issue_id: &str) -> Result<Option<Claim>> {
+        let claim = self
+            .conn
+            .query_row(
+                &format!("SELECT {CLAIM_COLUMNS} FROM claims WHERE issue_id = ?"),
+                [issue_id],
+                claim_from_row,
+            )
+            .optional()?;
+        Ok(claim)
+    }
+
+    /// All claim leases, soonest to expire first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn get_claims(&self) -> Result<Vec<Claim>> {
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT {CLAIM_COLUMNS} FROM claims ORDER BY expires_at ASC, issue_id ASC"
+        ))?;
+        let claims = stmt
+            .query_map([], claim_from_row)?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(claims)
+    }
+
+    /// Claim an issue for `holder`: assign it, set it in progress and take a
+    /// lease that lasts `lease` unless renewed.
+    ///
+    /// Claiming again as the same holder renews the lease.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue doesn't exist or is done (closed or
+    /// deleted), another holder has a live claim on it, it is assigned to
+    /// someone else without a lapsed claim, or the workflow does not allow
+    /// moving it to `in_progress`.
+    pub fn claim_issue(
+        &mut self,
+        id: &str,
+        holder: &str,
+        session: Option<&str>,
+        lease: chrono::Duration,
+    ) -> Result<Claim> {
+        let workflow = self.workflow.clone();
+        self.mutate("claim_issue", holder, |tx, ctx| {
+            Self::claim_impl(tx, ctx, &workflow, id, holder, session, lease)
+        })
+    }
+
+    /// Pick the top ready issue `holder` can take and claim it in the same
+    /// write transaction, so concurrent callers never get the same issue.
+    ///
+    /// Issues in `skip` (e.g. blocked by another project) are passed over.
+    /// Returns `None` when nothing is available.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query or the claim fails.
+    pub fn claim_next_ready(
+        &mut self,
+        filters: &ReadyFilters,
+        sort: ReadySortPolicy,
+        holder: &str,
+        session: Option<&str>,
+        lease: chrono::Duration,
+        skip: &HashSet<String>,
+    ) -> Result<Option<Claim>> {
+        let filters = ReadyFilters {
+            claimable_by: Some(holder.to_string()),
+            ..filters.clone()
+        };
+        let (sql, params) = self.ready_query("id", &filters, sort);
+        let workflow = self.workflow.clone();
+
+        self.mutate("claim_next_ready", holder, |tx, ctx| {
+            let params_refs: Vec<&dyn rusqlite::ToSql> = params.iter().map(AsRef::as_ref).collect();
+            let mut stmt = tx.prepare(&sql)?;
+            let candidates: Vec<String> = stmt
+                .query_map(params_refs.as_slice(), |row| row.get(0))?
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+
+            match candidates.iter().find(|id| !skip.contains(*id)) {
+                Some(id) => {
+                    Self::claim_impl(tx, ctx, &workflow, id, holder, session, lease).map(Some)
+                }
+                None => Ok(None),
+            }
+        })
+    }
+
+    /// Extend `holder`'s claim on an issue to `lease` from now.
+    ///
+    /// A lapsed claim can still be renewed as long as nobody else took the
+    /// issue in the meantime.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the issue has no claim or it belongs to someone else.
+    pub fn heartbeat_claim(
+        &mut self,
+        id: &str,
+        holder: &str,
+        lease: chrono::Duration,
+    ) -> Result<Claim> {
+        let tx = self
+            .conn
+            .transaction_with_behavior(rusqlite::TransactionBehavior::Immediate)?;
+        let mut claim = tx
+            .query_row(
+                &format!("SELECT {CLAIM_COLUMNS} FROM claims WHERE issue_id = ?"),
+                [id],
+                claim_from_row,
+            )
+            .optional()?
+            .ok_or_else(|| BeadsError::validation("id", format!("{id} is not claimed")))?;
+        if claim.claimed_by != holder {
+            return Err(BeadsError::validation(
+                "id",
+                format!("{id} is claimed by {}", claim.claimed_by),
+            ));
+        }
+
+        claim.expires_at = Utc::now() + lease;
+        tx.execute(
+            "UPDATE claims SET expires_at = ? WHERE issue_id = ?",
+            rusqlite::params![claim.expires_at.to_rfc3339(), id],
+        )?;
+        tx.commit()?;
+        Ok(claim)
     }
 
-    /// Clear dirty flags for the given issue IDs.
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the database operation fails.
-    pub fn clear_dirty_flags(&mut self, ids: &[String]) -> Result<usize> {
-        if ids.is_empty() {
-            return Ok(0);
+    fn claim_impl(
+        tx: &Transaction,
+        ctx: &mut MutationContext,
+        workflow: &Workflow,
+        id: &str,
+        holder: &str,
+        session: Option<&str>,
+        lease: chrono::Duration,
+    ) -> Result<Claim> {
+        let mut issue = Self::get_issue_impl(tx, id)?
+            .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })?;
+        if workflow.category(&issue.status) == StatusCategory::Done {
+            return Err(BeadsError::validation(
+                "claim",
+                format!("{id} is {} and cannot be claimed", issue.status.as_str()),
+            ));
         }
+        let assignee = issue.assignee.clone().filter(|name| !name.is_empty());
+        let existing = tx
+            .query_row(
+                &format!("SELECT {CLAIM_COLUMNS} FROM claims WHERE issue_id = ?"),
+                [id],
+                claim_from_row,
+            )
+            .optional()?;
 
-        let placeholders = vec!["?"; ids.len()].join(", ");
-        let sql = format!("DELETE FROM dirty_issues WHERE issue_id IN ({placeholders})");
+        let now = Utc::now();
+        match &existing {
+            Some(claim) if claim.claimed_by != holder && !claim.is_expired(now) => {
+                return Err(BeadsError::validation(
+                    "claim",
+                    format!(
+                        "{id} is claimed by {} until {}",
+                        claim.claimed_by,
+                        claim.expires_at.to_rfc3339()
+                    ),
+                ));
+            }
+            // Without a claim to lapse, an assignment is never taken over
+            None => {
+                if let Some(current) = assignee.as_deref()
+                    && current != holder
+                {
+                    return Err(BeadsError::validation(
+                        "claim",
+                        format!("issue already assigned to {current}"),
+                    ));
+                }
+            }
+            Some(_) => {}
+        }
 
-        let params: Vec<&dyn rusqlite::ToSql> =
-            ids.iter().map(|s| s as &dyn rusqlite::ToSql).collect();
-        let deleted = self.conn.execute(&sql, params.as_slice())?;
+        let update = IssueUpdate {
+            status: (issue.status != Status::InProgress).then_some(Status::InProgress),
+            assignee: (assignee.as_deref() != Some(holder)).then(|| Some(holder.to_string())),
+            ..IssueUpdate::default()
+        };
+        if !update.is_empty() {
+            workflow.check_transition(&issue, &update)?;
+            Self::update_issue_impl(tx, ctx, &mut issue, &update)?;
+        }
 
-        Ok(deleted)
+        // Renewing a live claim keeps its start time
+        let claimed_at = existing
+            .filter(|claim| claim.claimed_by == holder && !claim.is_expired(now))
+            .map_or(now, |claim| claim.claimed_at);
+        let claim = Claim {
+            issue_id: id.to_string(),
+            claimed_by: holder.to_string(),
+            claimed_by_session: session.map(str::to_string),
+            claimed_at,
+            expires_at: now + lease,
+        };
+        tx.execute(
+            "INSERT OR REPLACE INTO claims
+                (issue_id, claimed_by, claimed_by_session, claimed_at, expires_at)
+             VALUES (?, ?, ?, ?, ?)",
+            rusqlite::params![
+                claim.issue_id,
+                claim.claimed_by,
+                claim.claimed_by_session,
+                claim.claimed_at.to_rfc3339(),
+                claim.expires_at.to_rfc3339()
+            ],
+        )?;
+        Ok(claim)
     }
 
-    /// Clear all dirty flags.
+    // ===== Batch =====
+
+    /// Run `ops` in order inside a single write transaction.
+    ///
+    /// Either every operation is applied or none is. Returns, per operation,
+    /// whether it changed anything (adding an existing label or dependency,
+    /// or closing a closed issue, does not).
     ///
     /// # Errors
     ///
-    /// Returns an error if the database operation fails.
-    pub fn clear_all_dirty_flags(&mut self) -> Result<usize> {
-        let deleted = self.conn.execute("DELETE FROM dirty_issues", [])?;
-        Ok(deleted)
+    /// Returns the first failing operation's error, naming its position; the
+    /// transaction is rolled back.
+    pub fn apply_batch(&mut self, ops: &[BatchOp], actor: &str) -> Result<Vec<bool>> {
+        let workflow = self.workflow.clone();
+        let done_statuses = workflow.custom_statuses_in(StatusCategory::Done);
+
+        self.mutate("batch", actor, |tx, ctx| {
+            let mut changed = Vec::with_capacity(ops.len());
+            for (index, op) in ops.iter().enumerate() {
+                let result = Self::apply_batch_op(tx, ctx, &workflow, &done_statuses, op, actor)
+                    .with_context(|| format!("batch operation {} ({})", index + 1, op.kind()));
+                changed.push(result?);
+            }
+            Ok(changed)
+        })
     }
 
-    /// Get the count of issues (for safety guard).
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the database query fails.
-    pub fn count_exportable_issues(&self) -> Result<usize> {
-        let count: i64 = self.conn.query_row(
-            "SELECT COUNT(*) FROM issues WHERE ephemeral = 0 AND id NOT LIKE '%-wisp-%'",
-            [],
-            |row| row.get(0),
-        )?;
-        // count is always non-negative from COUNT(*), safe to cast
-        #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
-        Ok(count as usize)
+    fn apply_batch_op(
+        tx: &Transaction,
+        ctx: &mut MutationContext,
+        workflow: &Workflow,
+        done_statuses: &[String],
+        op: &BatchOp,
+        actor: &str,
+    ) -> Result<bool> {
+        match op {
+            BatchOp::Create(issue) => {
+                Self::insert_issue_impl(tx, ctx, issue, actor)?;
+                Ok(true)
+            }
+            BatchOp::Update { id, update, fields } => {
+                let mut issue = Self::get_issue_impl(tx, id)?
+                    .ok_or_else(|| BeadsError::IssueNotFound { id: id.clone() })?;
+                if !update.is_empty() {
+                    workflow.check_transition(&issue, update)?;
+                    Self::update_issue_impl(tx, ctx, &mut issue, update)?;
+                }
+                let fields_changed = Self::set_fields_impl(tx, ctx, id, fields)?;
+                Ok(!update.is_empty() || fields_changed > 0)
+            }
+            BatchOp::Close {
+                id,
+                reason,
+                outcome,
+                force,
+            } => {
+                let mut issue = Self::get_issue_impl(tx, id)?
+                    .ok_or_else(|| BeadsError::IssueNotFound { id: id.clone() })?;
+                if issue.status.is_terminal() {
+                    return Ok(false);
+                }
+                if !force {
+                    // Earlier operations may have changed what blocks this one
+                    if ctx.invalidate_blocked_cache {
+                        Self::rebuild_blocked_cache_impl(tx, done_statuses)?;
+                        ctx.invalidate_blocked_cache = false;
+                    }
+                    let blocked: bool = tx.query_row(
+                        "SELECT EXISTS(SELECT 1 FROM blocked_issues_cache WHERE issue_id = ?)",
+                        [id],
+                        |row| row.get(0),
+                    )?;
+                    if blocked {
+                        return Err(BeadsError::validation(
+                            "id",
+                            format!("{id} is blocked by open dependencies"),
+                        ));
+                    }
+                }
+                let update = IssueUpdate::close(reason, *outcome, None, Utc::now());
+                workflow.check_transition(&issue, &update)?;
+                Self::update_issue_impl(tx, ctx, &mut issue, &update)?;
+                Ok(true)
+            }
+            BatchOp::AddDependency {
+                issue_id,
+                depends_on_id,
+                dep_type,
+            } => Self::add_dependency_impl(tx, ctx, issue_id, depends_on_id, dep_type, actor),
+            BatchOp::AddLabel { issue_id, label } => Self::add_label_impl(tx, ctx, issue_id, label),
+            BatchOp::AddComment { issue_id, text } => {
+                Self::add_comment_impl(tx, ctx, issue_id, actor, text)?;
+                Ok(true)
+            }
+        }
     }
 
-    /// Check if a dependency exists between two issues.
+    // ===== Compaction =====
+
+    /// Closed issues that were closed before `closed_before` and are below
+    /// compaction `level`, oldest first.
     ///
     /// # Errors
     ///
     /// Returns an error if the database query fails.
-    pub fn dependency_exists_between(&self, issue_id: &str, depends_on_id: &str) -> Result<bool> {
-        let count: i64 = self.conn.query_row(
-            "SELECT COUNT(*) FROM dependencies WHERE issue_id = ? AND depends_on_id = ?",
-            rusqlite::params![issue_id, depends_on_id],
-            |row| row.get(0),
+    pub fn compaction_candidates(
+        &self,
+        level: i32,
+        closed_before: DateTime<Utc>,
+    ) -> Result<Vec<Issue>> {
+        let mut stmt = self.conn.prepare(
+            r"SELECT id, content_hash, title, description, design, acceptance_criteria, notes,
+                     status, priority, issue_type, assignee, owner, estimated_minutes,
+                     created_at, created_by, updated_at, closed_at, close_reason, closed_by_session,
+                     due_at, defer_until, external_ref, source_system, source_repo,
+                     deleted_at, deleted_by, delete_reason, original_type, compaction_level,
+                     compacted_at, compacted_at_commit, original_size, sender, ephemeral,
+                     pinned, is_template, close_outcome
+              FROM issues
+              WHERE status = 'closed'
+                AND closed_at IS NOT NULL
+                AND closed_at <= ?1
+                AND COALESCE(compaction_level, 0) < ?2
+                AND COALESCE(pinned, 0) = 0
+              ORDER BY closed_at ASC, id ASC",
         )?;
-        Ok(count > 0)
+        let issues = stmt
+            .query_map(
+                rusqlite::params![closed_before.to_rfc3339(), level],
+                Self::issue_from_row,
+            )?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(issues)
     }
 
-    /// Check if adding a dependency would create a cycle.
+    /// Replace an issue's text with its compacted form.
     ///
-    /// If `blocking_only` is true, only considers blocking dependency types
-    /// ('blocks', 'parent-child', 'conditional-blocks') for cycle detection.
+    /// The text of `original` is archived locally under the new level so
+    /// [`restore_compacted`](Self::restore_compacted) can bring it back; the
+    /// archive is never exported. `compacted` supplies the new text and the
+    /// compaction fields.
     ///
     /// # Errors
     ///
-    /// Returns an error if the database query fails.
-    pub fn would_create_cycle(
-        &self,
-        issue_id: &str,
-        depends_on_id: &str,
-        blocking_only: bool,
-    ) -> Result<bool> {
-        Self::check_cycle(&self.conn, issue_id, depends_on_id, blocking_only)
+    /// Returns an error if the database write fails.
+    pub fn compact_issue(
+        &mut self,
+        original: &Issue,
+        compacted: &Issue,
+        actor: &str,
+    ) -> Result<()> {
+        let level = compacted.compaction_level.unwrap_or(0);
+        let text = |value: &Option<String>| value.clone().unwrap_or_default();
+
+        self.mutate("compact_issue", actor, |tx, ctx| {
+            tx.execute(
+                "INSERT OR REPLACE INTO compaction_archive
+                    (issue_id, level, description, design, acceptance_criteria, notes)
+                 VALUES (?, ?, ?, ?, ?, ?)",
+                rusqlite::params![
+                    original.id,
+                    level,
+                    text(&original.description),
+                    text(&original.design),
+                    text(&original.acceptance_criteria),
+                    text(&original.notes),
+                ],
+            )?;
+            tx.execute(
+                "UPDATE issues SET
+                    description = ?, design = ?, acceptance_criteria = ?, notes = ?,
+                    compaction_level = ?, compacted_at = ?, compacted_at_commit = ?,
+                    original_size = ?, content_hash = ?, updated_at = ?
+                 WHERE id = ?",
+                rusqlite::params![
+                    text(&compacted.description),
+                    text(&compacted.design),
+                    text(&compacted.acceptance_criteria),
+                    text(&compacted.notes),
+                    level,
+                    compacted.compacted_at.map(|dt| dt.to_rfc3339()),
+                    compacted.compacted_at_commit,
+                    compacted.original_size.unwrap_or(0),
+                    compacted.compute_content_hash(),
+                    Utc::now().to_rfc3339(),
+                    original.id,
+                ],
+            )?;
+
+            ctx.record_event(
+                EventType::Compacted,
+                &original.id,
+                Some(format!(
+                    "Compacted to level {level} (original size {} bytes)",
+                    compacted.original_size.unwrap_or(0)
+                )),
+            );
+            ctx.mark_dirty(&original.id);
+            Ok(())
+        })
     }
 
-    /// Detect all cycles in the dependency graph.
+    /// Undo all compaction of an issue from the local archive.
     ///
-    /// Returns a list of cycles, where each cycle is a vector of issue IDs.
-    /// Uses an iterative DFS to avoid stack overflow on deep graphs.
+    /// Returns `false` if the archive holds nothing for the issue (e.g. it was
+    /// compacted in another clone).
     ///
     /// # Errors
     ///
-    /// Returns an error if the database query fails.
-    pub fn detect_all_cycles(&self) -> Result<Vec<Vec<String>>> {
-        use std::collections::{HashMap, HashSet};
-
-        // Get all dependencies
-        let mut graph: HashMap<String, Vec<String>> = HashMap::new();
-        let mut stmt = self
-            .conn
-            .prepare_cached("SELECT issue_id, depends_on_id FROM dependencies")?;
-
-        let edges = stmt.query_map([], |row| {
-            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
-        })?;
-
-        for edge in edges {
-            let (from, to) = edge?;
-            graph.entry(from).or_default().push(to);
-        }
-
-        let mut cycles = Vec::new();
-        let mut visited = HashSet::new();
-        let mut rec_stack = HashSet::new();
-        let mut path = Vec::new();
-
-        // Stack stores (node_id, neighbor_index)
-        let mut stack: Vec<(String, usize)> = Vec::new();
-
-        // Sort keys for deterministic output
-        let mut keys: Vec<_> = graph.keys().cloned().collect();
-        keys.sort();
-
-        for node in keys {
-            if visited.contains(&node) {
-                continue;
-            }
-
-            stack.push((node.clone(), 0));
-            visited.insert(node.clone());
-            rec_stack.insert(node.clone());
-            path.push(node.clone());
+    /// Returns an error if the issue does not exist or the database write fails.
+    pub fn restore_compacted(&mut self, id: &str, actor: &str) -> Result<bool> {
+        let mut issue = self
+            .get_issue(id)?
+            .ok_or_else(|| BeadsError::IssueNotFound { id: id.to_string() })?;
 
-            while let Some((u, idx)) = stack.last_mut() {
-                let neighbors = graph.get(u);
+        // The lowest archived level holds the text from before any compaction
+        let archived = self.conn.query_row(
+            "SELECT description, design, acceptance_criteria, notes
+             FROM compaction_archive WHERE issue_id = ? ORDER BY level ASC LIMIT 1",
+            [id],
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, String>(3)?,
+                ))
+            },
+        );
+        let (description, design, acceptance_criteria, notes) = match archived {
+            Ok(text) => text,
+            Err(rusqlite::Error::QueryReturnedNoRows) => return Ok(false),
+            Err(e) => return Err(e.into()),
+        };
 
-                if let Some(neighbors) = neighbors {
-                    if *idx < neighbors.len() {
-                        let v = &neighbors[*idx];
-                        *idx += 1;
+        let non_empty = |value: String| if value.is_empty() { None } else { Some(value) };
+        issue.description = non_empty(description);
+        issue.design = non_empty(design);
+        issue.acceptance_criteria = non_empty(acceptance_criteria);
+        issue.notes = non_empty(notes);
+        let content_hash = issue.compute_content_hash();
 
-                        if rec_stack.contains(v) {
-                            // Found a cycle: reconstruct it from the current path
-                            if let Some(start_pos) = path.iter().position(|x| x == v) {
-                                let mut cycle = path[start_pos..].to_vec();
-                                cycle.push(v.clone()); // Close the loop
-                                cycles.push(cycle);
-                            }
-                        } else if !visited.contains(v) {
-                            visited.insert(v.clone());
-                            rec_stack.insert(v.clone());
-                            path.push(v.clone());
-                            stack.push((v.clone(), 0));
-                        }
-                        continue;
-                    }
-                }
+        self.mutate("restore_compacted", actor, |tx, ctx| {
+            tx.execute(
+                "UPDATE issues SET
+                    description = ?, design = ?, acceptance_criteria = ?, notes = ?,
+                    compaction_level = 0, compacted_at = NULL, compacted_at_commit = NULL,
+                    original_size = 0, content_hash = ?, updated_at = ?
+                 WHERE id = ?",
+                rusqlite::params![
+                    issue.description.as_deref().unwrap_or(""),
+                    issue.design.as_deref().unwrap_or(""),
+                    issue.acceptance_criteria.as_deref().unwrap_or(""),
+                    issue.notes.as_deref().unwrap_or(""),
+                    content_hash,
+                    Utc::now().to_rfc3339(),
+                    id,
+                ],
+            )?;
+            tx.execute("DELETE FROM compaction_archive WHERE issue_id = ?", [id])?;
 
-                // Finished processing all neighbors of u
-                rec_stack.remove(u);
-                path.pop();
-                stack.pop();
-            }
-        }
+            ctx.record_event(
+                EventType::Uncompacted,
+                id,
+                Some("Restored from compaction archive".to_string()),
+            );
+            ctx.mark_dirty(id);
+            Ok(())
+        })?;
 
-        Ok(cycles)
+        Ok(true)
     }
 
     // ===== Import Helper Methods =====
@@ -3493,10 +5476,10 @@ impl SqliteStorage {
                      due_at, defer_until, external_ref, source_system, source_repo,
                      deleted_at, deleted_by, delete_reason, original_type, compaction_level,
                      compacted_at, compacted_at_commit, original_size, sender, ephemeral,
-                     pinned, is_template
+                     pinned, is_template, close_outcome
                FROM issues WHERE external_ref = ?",
             [external_ref],
-            |row| self.issue_from_row(row),
+            Self::issue_from_row,
         );
         match result {
             Ok(issue) => Ok(Some(issue)),
@@ -3518,10 +5501,10 @@ impl SqliteStorage {
                      due_at, defer_until, external_ref, source_system, source_repo,
                      deleted_at, deleted_by, delete_reason, original_type, compaction_level,
                      compacted_at, compacted_at_commit, original_size, sender, ephemeral,
-                     pinned, is_template
+                     pinned, is_template, close_outcome
                FROM issues WHERE content_hash = ?",
             [content_hash],
-            |row| self.issue_from_row(row),
+            Self::issue_from_row,
         );
         match result {
             Ok(issue) => Ok(Some(issue)),
@@ -3576,9 +5559,9 @@ impl SqliteStorage {
                 due_at, defer_until, external_ref, source_system, source_repo,
                 deleted_at, deleted_by, delete_reason, original_type, compaction_level,
                 compacted_at, compacted_at_commit, original_size, sender, ephemeral,
-                pinned, is_template
+                pinned, is_template, close_outcome
             ) VALUES (
-                ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?
+                ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?
             )",
             rusqlite::params![
                 issue.id,
@@ -3617,6 +5600,7 @@ impl SqliteStorage {
                 issue.ephemeral,
                 issue.pinned,
                 issue.is_template,
+                issue.close_outcome.map(CloseOutcome::as_str),
             ],
         )?;
 
@@ -3644,6 +5628,29 @@ impl SqliteStorage {
         Ok(())
     }
 
+    /// Sync custom fields for an issue (remove existing, add new).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database operation fails.
+    pub fn sync_fields_for_import(
+        &mut self,
+        issue_id: &str,
+        fields: &BTreeMap<String, Value>,
+    ) -> Result<()> {
+        self.conn
+            .execute("DELETE FROM custom_fields WHERE issue_id = ?", [issue_id])?;
+
+        for (name, value) in fields {
+            self.conn.execute(
+                "INSERT INTO custom_fields (issue_id, name, value) VALUES (?, ?, ?)",
+                rusqlite::params![issue_id, name, value.to_string()],
+            )?;
+        }
+
+        Ok(())
+    }
+
     /// Sync dependencies for an issue (remove existing, add new).
     ///
     /// # Errors
@@ -3806,6 +5813,7 @@ mod tests {
             created_by: None,
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             due_at: None,
             external_ref: None,
@@ -3826,6 +5834,7 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -3835,6 +5844,36 @@ mod tests {
         assert!(storage.is_ok());
     }
 
+    #[test]
+    fn test_ready_sort_policy_compare_matches_query_order() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t = |day| Utc.with_ymd_and_hms(2025, 1, day, 0, 0, 0).unwrap();
+        for (id, priority, day) in [
+            ("bd-1", 3, 1),
+            ("bd-2", 1, 2),
+            ("bd-3", 0, 3),
+            ("bd-4", 2, 4),
+        ] {
+            let issue = make_issue(id, id, Status::Open, priority, None, t(day), None);
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+
+        for policy in [
+            ReadySortPolicy::Hybrid,
+            ReadySortPolicy::Priority,
+            ReadySortPolicy::Oldest,
+        ] {
+            let queried = storage
+                .get_ready_issues(&ReadyFilters::default(), policy)
+                .unwrap();
+            let mut merged = queried.clone();
+            merged.reverse();
+            merged.sort_by(|a, b| policy.compare(a, b));
+            let ids = |issues: &[Issue]| issues.iter().map(|i| i.id.clone()).collect::<Vec<_>>();
+            assert_eq!(ids(&merged), ids(&queried), "{policy:?}");
+        }
+    }
+
     #[test]
     fn test_create_issue() {
         let mut storage = SqliteStorage::open_memory().unwrap();
@@ -3857,6 +5896,7 @@ mod tests {
             created_by: None,
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             defer_until: None,
             due_at: None,
@@ -3878,6 +5918,7 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            fields: BTreeMap::new(),
         };
 
         storage.create_issue(&issue, "tester").unwrap();
@@ -3916,6 +5957,28 @@ mod tests {
         assert_eq!(dirty_count, 1);
     }
 
+    #[test]
+    fn test_create_issues_is_all_or_nothing() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let existing = make_issue("bd-2", "Existing", Status::Open, 2, None, t1, None);
+        storage.create_issue(&existing, "tester").unwrap();
+
+        let batch = vec![
+            make_issue("bd-1", "First", Status::Open, 2, None, t1, None),
+            make_issue("bd-2", "Collides", Status::Open, 2, None, t1, None),
+        ];
+        assert!(storage.create_issues(&batch, "tester").is_err());
+        assert!(storage.get_issue("bd-1").unwrap().is_none());
+        assert_eq!(
+            storage.get_issue("bd-2").unwrap().unwrap().title,
+            "Existing"
+        );
+
+        storage.create_issues(&batch[..1], "tester").unwrap();
+        assert!(storage.get_issue("bd-1").unwrap().is_some());
+    }
+
     #[test]
     fn test_transaction_rollback_on_error() {
         let mut storage = SqliteStorage::open_memory().unwrap();
@@ -3990,6 +6053,102 @@ mod tests {
         assert!(child_blockers.iter().any(|b| b == "bd-p1:parent-blocked"));
     }
 
+    #[test]
+    fn test_routed_dependency_resolved_at_runtime_not_cached() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 3, 3, 0, 0, 0).unwrap();
+        let task = make_issue("fe-1", "Task", Status::Open, 2, None, t1, None);
+        let orphaned = make_issue("fe-2", "Orphaned", Status::Open, 2, None, t1, None);
+        storage.create_issue(&task, "tester").unwrap();
+        storage.create_issue(&orphaned, "tester").unwrap();
+
+        // bd-abc lives in another workspace; fe-gone is a local orphan
+        storage
+            .add_dependency("fe-1", "bd-abc", "blocks", "tester")
+            .unwrap();
+        storage
+            .add_dependency("fe-2", "fe-gone", "blocks", "tester")
+            .unwrap();
+
+        let routed = storage.list_routed_dependency_ids().unwrap();
+        assert_eq!(routed, HashSet::from(["bd-abc".to_string()]));
+
+        let cached: Vec<String> = storage
+            .get_blocked_issues()
+            .unwrap()
+            .into_iter()
+            .map(|(issue, _)| issue.id)
+            .collect();
+        assert_eq!(cached, vec!["fe-2".to_string()]);
+
+        // Unknown routed targets block; satisfied ones do not
+        let blockers = storage.external_blockers(&HashMap::new()).unwrap();
+        assert_eq!(blockers["fe-1"], vec!["bd-abc:blocked".to_string()]);
+        let statuses = HashMap::from([("bd-abc".to_string(), true)]);
+        assert!(storage.external_blockers(&statuses).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_workflow_custom_statuses_drive_ready_and_transitions() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let mut layer = crate::config::ConfigLayer::default();
+        for (key, value) in [
+            ("statuses.review", "active"),
+            ("statuses.shipped", "done"),
+            ("workflow.transitions.review", "shipped"),
+        ] {
+            layer.startup.insert(key.to_string(), value.to_string());
+        }
+        storage.set_workflow(Workflow::from_layer(&layer).unwrap());
+
+        let t1 = Utc.with_ymd_and_hms(2025, 3, 4, 0, 0, 0).unwrap();
+        let blocker = make_issue("bd-w1", "Blocker", Status::Open, 2, None, t1, None);
+        let dependent = make_issue("bd-w2", "Dependent", Status::Open, 2, None, t1, None);
+        storage.create_issue(&blocker, "tester").unwrap();
+        storage.create_issue(&dependent, "tester").unwrap();
+        storage
+            .add_dependency("bd-w2", "bd-w1", "blocks", "tester")
+            .unwrap();
+
+        let to = |status: &str| IssueUpdate {
+            status: Some(Status::Custom(status.to_string())),
+            ..IssueUpdate::default()
+        };
+        let ready_ids = |storage: &SqliteStorage| {
+            storage
+                .get_ready_issues(&ReadyFilters::default(), ReadySortPolicy::Oldest)
+                .unwrap()
+                .into_iter()
+                .map(|issue| issue.id)
+                .collect::<Vec<_>>()
+        };
+
+        // An active custom status stays ready work
+        storage
+            .update_issue("bd-w1", &to("review"), "tester")
+            .unwrap();
+        assert_eq!(ready_ids(&storage), vec!["bd-w1".to_string()]);
+
+        let rejected = storage.update_issue(
+            "bd-w1",
+            &IssueUpdate {
+                status: Some(Status::Open),
+                ..IssueUpdate::default()
+            },
+            "tester",
+        );
+        assert!(matches!(
+            rejected,
+            Err(BeadsError::InvalidTransition { .. })
+        ));
+
+        // A done custom status releases dependents
+        storage
+            .update_issue("bd-w1", &to("shipped"), "tester")
+            .unwrap();
+        assert_eq!(ready_ids(&storage), vec!["bd-w2".to_string()]);
+    }
+
     #[test]
     fn test_update_issue_changes_fields() {
         let mut storage = SqliteStorage::open_memory().unwrap();
@@ -4033,6 +6192,313 @@ mod tests {
         assert!(is_tombstone);
     }
 
+    #[test]
+    fn test_undelete_restores_status_type_and_dependencies() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+
+        let mut issue = make_issue("bd-u1", "Restore me", Status::InProgress, 2, None, t1, None);
+        issue.issue_type = IssueType::Bug;
+        storage.create_issue(&issue, "tester").unwrap();
+        let blocker = make_issue("bd-u2", "Blocker", Status::Open, 2, None, t1, None);
+        storage.create_issue(&blocker, "tester").unwrap();
+        let dependent = make_issue("bd-u3", "Dependent", Status::Open, 2, None, t1, None);
+        storage.create_issue(&dependent, "tester").unwrap();
+        storage
+            .add_dependency("bd-u1", "bd-u2", "blocks", "tester")
+            .unwrap();
+        storage
+            .add_dependency("bd-u3", "bd-u1", "blocks", "tester")
+            .unwrap();
+
+        storage.remove_all_dependencies("bd-u1", "tester").unwrap();
+        storage
+            .delete_issue("bd-u1", "tester", "oops", None)
+            .unwrap();
+        let err = storage.undelete_issue("bd-u2", "tester").unwrap_err();
+        assert!(err.to_string().contains("not deleted"));
+
+        let restored = storage.undelete_issue("bd-u1", "tester").unwrap();
+        assert_eq!(restored, 2);
+        let issue = storage.get_issue("bd-u1").unwrap().unwrap();
+        assert_eq!(issue.status, Status::InProgress);
+        assert_eq!(issue.issue_type, IssueType::Bug);
+        assert!(issue.deleted_at.is_none());
+        assert!(issue.original_type.is_none());
+        assert_eq!(storage.get_dependencies("bd-u1").unwrap(), vec!["bd-u2"]);
+        assert_eq!(storage.get_dependencies("bd-u3").unwrap(), vec!["bd-u1"]);
+    }
+
+    #[test]
+    fn test_purge_tombstones_removes_only_tombstones() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+
+        let gone = make_issue("bd-p1", "Gone", Status::Open, 2, None, t1, None);
+        let live = make_issue("bd-p2", "Live", Status::Open, 2, None, t1, None);
+        storage.create_issue(&gone, "tester").unwrap();
+        storage.create_issue(&live, "tester").unwrap();
+        storage
+            .delete_issue("bd-p1", "tester", "cleanup", Some(t1))
+            .unwrap();
+        assert_eq!(storage.get_tombstones().unwrap().len(), 1);
+
+        let purged = storage
+            .purge_tombstones(&["bd-p1".to_string(), "bd-p2".to_string()], "tester")
+            .unwrap();
+        assert_eq!(purged, 1);
+        assert!(storage.get_issue("bd-p1").unwrap().is_none());
+        assert!(storage.get_issue("bd-p2").unwrap().is_some());
+        assert!(storage.get_tombstones().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_claims_exclude_from_ready_until_lease_expires() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+        let t2 = Utc.with_ymd_and_hms(2025, 6, 2, 0, 0, 0).unwrap();
+        let lease = chrono::Duration::minutes(30);
+
+        let first = make_issue("bd-c1", "First", Status::Open, 1, None, t1, None);
+        let second = make_issue("bd-c2", "Second", Status::Open, 2, None, t2, None);
+        storage.create_issue(&first, "tester").unwrap();
+        storage.create_issue(&second, "tester").unwrap();
+
+        let filters = ReadyFilters::default();
+        let skip = HashSet::new();
+        let claim = storage
+            .claim_next_ready(
+                &filters,
+                ReadySortPolicy::Priority,
+                "alice",
+                Some("s1"),
+                lease,
+                &skip,
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(claim.issue_id, "bd-c1");
+        assert_eq!(claim.claimed_by_session.as_deref(), Some("s1"));
+        let issue = storage.get_issue("bd-c1").unwrap().unwrap();
+        assert_eq!(issue.status, Status::InProgress);
+        assert_eq!(issue.assignee.as_deref(), Some("alice"));
+
+        // A live claim keeps the issue out of ready and away from others
+        let ready: Vec<String> = storage
+            .get_ready_issues(&filters, ReadySortPolicy::Priority)
+            .unwrap()
+            .into_iter()
+            .map(|issue| issue.id)
+            .collect();
+        assert!(!ready.contains(&"bd-c1".to_string()));
+        let err = storage
+            .claim_issue("bd-c1", "bob", None, lease)
+            .unwrap_err();
+        assert!(err.to_string().contains("claimed by alice"));
+        let next = storage
+            .claim_next_ready(
+                &filters,
+                ReadySortPolicy::Priority,
+                "bob",
+                None,
+                lease,
+                &skip,
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(next.issue_id, "bd-c2");
+
+        // Once the lease lapses the issue is up for grabs again
+        storage
+            .conn
+            .execute(
+                "UPDATE claims SET expires_at = ? WHERE issue_id = 'bd-c1'",
+                [t1.to_rfc3339()],
+            )
+            .unwrap();
+        let err = storage.heartbeat_claim("bd-c1", "bob", lease).unwrap_err();
+        assert!(err.to_string().contains("claimed by alice"));
+        let taken = storage.claim_issue("bd-c1", "bob", None, lease).unwrap();
+        assert_eq!(taken.claimed_by, "bob");
+        assert!(!taken.is_expired(Utc::now()));
+        let renewed = storage.heartbeat_claim("bd-c1", "bob", lease).unwrap();
+        assert!(renewed.expires_at >= taken.expires_at);
+        assert_eq!(storage.get_claims().unwrap().len(), 2);
+
+        let closed = IssueUpdate {
+            status: Some(Status::Closed),
+            ..IssueUpdate::default()
+        };
+        storage.update_issue("bd-c2", &closed, "bob").unwrap();
+        assert!(storage.get_claim("bd-c2").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_claim_updates_content_hash() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+        let issue = make_issue("bd-c1", "Claim me", Status::Open, 1, None, t1, None);
+        storage.create_issue(&issue, "tester").unwrap();
+        let before = storage.get_issue("bd-c1").unwrap().unwrap();
+
+        storage
+            .claim_issue("bd-c1", "alice", None, chrono::Duration::minutes(30))
+            .unwrap();
+        let after = storage.get_issue("bd-c1").unwrap().unwrap();
+        assert_ne!(after.content_hash, before.content_hash);
+        assert_eq!(
+            after.content_hash.as_deref(),
+            Some(after.compute_content_hash().as_str())
+        );
+    }
+
+    #[test]
+    fn test_claim_rejects_closed_and_deleted_issues() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+        let lease = chrono::Duration::minutes(30);
+        let closed = make_issue("bd-c1", "Done", Status::Open, 1, None, t1, None);
+        let deleted = make_issue("bd-c2", "Gone", Status::Open, 1, None, t1, None);
+        storage.create_issue(&closed, "tester").unwrap();
+        storage.create_issue(&deleted, "tester").unwrap();
+        let close = IssueUpdate {
+            status: Some(Status::Closed),
+            close_reason: Some(Some("done".to_string())),
+            ..IssueUpdate::default()
+        };
+        storage.update_issue("bd-c1", &close, "tester").unwrap();
+        storage
+            .delete_issue("bd-c2", "tester", "duplicate", None)
+            .unwrap();
+
+        for (id, status) in [("bd-c1", Status::Closed), ("bd-c2", Status::Tombstone)] {
+            let err = storage.claim_issue(id, "alice", None, lease).unwrap_err();
+            assert!(matches!(err, BeadsError::Validation { .. }), "{err}");
+            let issue = storage.get_issue(id).unwrap().unwrap();
+            assert_eq!(issue.status, status);
+            assert_eq!(issue.assignee, None);
+            assert!(storage.get_claim(id).unwrap().is_none());
+        }
+    }
+
+    #[test]
+    fn test_claim_follows_workflow_transitions() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let mut layer = crate::config::ConfigLayer::default();
+        layer.startup.insert(
+            "workflow.transitions.open".to_string(),
+            "closed".to_string(),
+        );
+        storage.set_workflow(Workflow::from_layer(&layer).unwrap());
+        let t1 = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+        let issue = make_issue("bd-c1", "Straight to done", Status::Open, 1, None, t1, None);
+        storage.create_issue(&issue, "tester").unwrap();
+
+        let err = storage
+            .claim_issue("bd-c1", "alice", None, chrono::Duration::minutes(30))
+            .unwrap_err();
+        assert!(matches!(err, BeadsError::InvalidTransition { .. }), "{err}");
+        assert!(storage.get_claim("bd-c1").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_apply_batch_is_all_or_nothing() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+        let epic = make_issue("bd-e1", "Epic", Status::Open, 1, None, t1, None);
+        let child = make_issue("bd-e1.1", "Child", Status::Open, 2, None, t1, None);
+        let close = |id: &str| BatchOp::Close {
+            id: id.to_string(),
+            reason: "done".to_string(),
+            outcome: None,
+            force: false,
+        };
+
+        // The child blocks the epic, so closing the epic fails and the
+        // creates before it are rolled back
+        let ops = vec![
+            BatchOp::Create(Box::new(epic)),
+            BatchOp::Create(Box::new(child)),
+            BatchOp::AddDependency {
+                issue_id: "bd-e1".to_string(),
+                depends_on_id: "bd-e1.1".to_string(),
+                dep_type: "blocks".to_string(),
+            },
+            close("bd-e1"),
+        ];
+        let err = storage.apply_batch(&ops, "tester").unwrap_err();
+        assert!(
+            err.to_string().contains("batch operation 4 (close)"),
+            "{err}"
+        );
+        assert!(storage.get_issue("bd-e1").unwrap().is_none());
+        assert_eq!(storage.get_dirty_issue_count().unwrap(), 0);
+
+        // Closing the blocker first inside the same batch unblocks the epic
+        let mut ops = ops;
+        ops.insert(3, close("bd-e1.1"));
+        ops.push(BatchOp::AddLabel {
+            issue_id: "bd-e1".to_string(),
+            label: "planned".to_string(),
+        });
+        ops.push(BatchOp::AddLabel {
+            issue_id: "bd-e1".to_string(),
+            label: "planned".to_string(),
+        });
+        let changed = storage.apply_batch(&ops, "tester").unwrap();
+        assert_eq!(changed, vec![true, true, true, true, true, true, false]);
+        let epic = storage.get_issue("bd-e1").unwrap().unwrap();
+        assert_eq!(epic.status, Status::Closed);
+        assert_eq!(storage.get_labels("bd-e1").unwrap(), vec!["planned"]);
+        assert_eq!(storage.get_dirty_issue_count().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_apply_batch_close_success_closes_moot_dependent() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+        let ops = vec![
+            BatchOp::Create(Box::new(make_issue(
+                "bd-try",
+                "Try",
+                Status::Open,
+                2,
+                None,
+                t1,
+                None,
+            ))),
+            BatchOp::Create(Box::new(make_issue(
+                "bd-retry",
+                "Retry",
+                Status::Open,
+                2,
+                None,
+                t1,
+                None,
+            ))),
+            BatchOp::AddDependency {
+                issue_id: "bd-retry".to_string(),
+                depends_on_id: "bd-try".to_string(),
+                dep_type: "conditional-blocks".to_string(),
+            },
+            BatchOp::Close {
+                id: "bd-try".to_string(),
+                reason: "shipped".to_string(),
+                outcome: Some(CloseOutcome::Success),
+                force: false,
+            },
+        ];
+        storage.apply_batch(&ops, "tester").unwrap();
+
+        let attempt = storage.get_issue("bd-try").unwrap().unwrap();
+        assert_eq!(attempt.close_outcome, Some(CloseOutcome::Success));
+        assert_eq!(attempt.close_reason.as_deref(), Some("shipped"));
+        let retry = storage.get_issue("bd-retry").unwrap().unwrap();
+        assert_eq!(retry.status, Status::Closed);
+        assert_eq!(retry.close_outcome, Some(CloseOutcome::Wontfix));
+        assert!(!storage.is_blocked("bd-retry").unwrap());
+    }
+
     #[test]
     fn test_get_blocked_issues_lists_blockers() {
         let mut storage = SqliteStorage::open_memory().unwrap();
@@ -4155,6 +6621,7 @@ mod tests {
             updated_at: t1,
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             defer_until: None,
             due_at: None,
@@ -4176,6 +6643,7 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            fields: BTreeMap::new(),
         };
         storage.create_issue(&issue, "tester").unwrap();
 
@@ -4224,6 +6692,7 @@ mod tests {
             updated_at: t1,
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             defer_until: None,
             due_at: None,
@@ -4245,6 +6714,7 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            fields: BTreeMap::new(),
         };
         storage.create_issue(&issue, "tester").unwrap();
 
@@ -4285,6 +6755,7 @@ mod tests {
             updated_at: t1,
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             defer_until: None,
             due_at: None,
@@ -4306,6 +6777,7 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            fields: BTreeMap::new(),
         };
         storage.create_issue(&issue, "tester").unwrap();
 
@@ -4700,6 +7172,156 @@ mod tests {
         assert_eq!(results[0].id, "bd-s1");
     }
 
+    #[test]
+    fn test_issue_column_count_matches_columns() {
+        assert_eq!(ISSUE_COLUMNS.split(',').count(), ISSUE_COLUMN_COUNT);
+    }
+
+    #[test]
+    fn test_build_fts_query_translation() {
+        assert_eq!(build_fts_query("auth").as_deref(), Some("auth*"));
+        assert_eq!(
+            build_fts_query("login OR \"session token\"").as_deref(),
+            Some("login* OR \"session token\"")
+        );
+        assert_eq!(
+            build_fts_query("title:cache NOT notes:flaky").as_deref(),
+            Some("title : cache* NOT notes : flaky*")
+        );
+        assert_eq!(build_fts_query("C++").as_deref(), Some("\"C++\""));
+        assert_eq!(build_fts_query("bd-a1").as_deref(), Some("\"bd-a1\" *"));
+        assert_eq!(build_fts_query("   ").as_deref(), None);
+        assert_eq!(build_fts_query("title:").as_deref(), None);
+    }
+
+    #[test]
+    fn test_search_ranks_and_covers_all_text_fields() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 9, 1, 0, 0, 0).unwrap();
+
+        let mut in_description = make_issue("bd-f1", "Unrelated", Status::Open, 2, None, t1, None);
+        in_description.description = Some("The parser crashes on empty input".to_string());
+        let in_title = make_issue("bd-f2", "Parser rewrite", Status::Open, 2, None, t1, None);
+        let mut in_notes = make_issue("bd-f3", "Other", Status::Open, 2, None, t1, None);
+        in_notes.notes = Some("blocked on the lexer".to_string());
+        let in_comment = make_issue("bd-f4", "Another", Status::Open, 2, None, t1, None);
+
+        for issue in [&in_description, &in_title, &in_notes, &in_comment] {
+            storage.create_issue(issue, "tester").unwrap();
+        }
+        storage
+            .add_comment("bd-f4", "tester", "lexer tokens are off by one")
+            .unwrap();
+
+        let filters = ListFilters::default();
+        let hits = storage.search_issues_ranked("parser", &filters).unwrap();
+        let ids: Vec<&str> = hits.iter().map(|hit| hit.issue.id.as_str()).collect();
+        assert_eq!(ids, vec!["bd-f2", "bd-f1"], "title match should rank first");
+        assert!(hits[1].snippet.as_deref().unwrap().contains("**parser**"));
+
+        let ids: Vec<String> = storage
+            .search_issues("lexer", &filters)
+            .unwrap()
+            .into_iter()
+            .map(|issue| issue.id)
+            .collect();
+        assert_eq!(ids.len(), 2);
+        assert!(ids.contains(&"bd-f3".to_string()));
+        assert!(ids.contains(&"bd-f4".to_string()));
+
+        let scoped = storage.search_issues("comments:lexer", &filters).unwrap();
+        assert_eq!(scoped.len(), 1);
+        assert_eq!(scoped[0].id, "bd-f4");
+    }
+
+    #[test]
+    fn test_search_index_tracks_updates_and_rebuild() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 9, 1, 0, 0, 0).unwrap();
+        let issue = make_issue("bd-u1", "Old wording", Status::Open, 2, None, t1, None);
+        storage.create_issue(&issue, "tester").unwrap();
+
+        let update = IssueUpdate {
+            title: Some("New phrasing".to_string()),
+            ..IssueUpdate::default()
+        };
+        storage.update_issue("bd-u1", &update, "tester").unwrap();
+
+        let filters = ListFilters::default();
+        assert!(
+            storage
+                .search_issues("wording", &filters)
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            storage.search_issues("phrasing", &filters).unwrap().len(),
+            1
+        );
+
+        storage.execute_test_sql("DELETE FROM issues_fts").unwrap();
+        assert!(
+            storage
+                .search_issues("phrasing", &filters)
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(storage.rebuild_search_index().unwrap(), 1);
+        assert_eq!(
+            storage.search_issues("phrasing", &filters).unwrap().len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_search_malformed_query_is_validation_error() {
+        let storage = SqliteStorage::open_memory().unwrap();
+        let err = storage
+            .search_issues("foo AND", &ListFilters::default())
+            .unwrap_err();
+        assert!(matches!(err, BeadsError::Validation { .. }));
+    }
+
+    #[test]
+    fn test_list_issues_where_expression() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let t1 = Utc.with_ymd_and_hms(2025, 9, 1, 0, 0, 0).unwrap();
+        let mine = make_issue("bd-w1", "Mine", Status::Open, 0, Some("alice"), t1, None);
+        let unassigned = make_issue("bd-w2", "Nobody", Status::Open, 1, None, t1, None);
+        let other = make_issue("bd-w3", "Bob's", Status::Open, 1, Some("bob"), t1, None);
+        let low = make_issue("bd-w4", "Low", Status::Open, 3, None, t1, None);
+        let closed = make_issue("bd-w5", "Done", Status::Closed, 0, None, t1, None);
+        for issue in [&mine, &unassigned, &other, &low, &closed] {
+            storage.create_issue(issue, "tester").unwrap();
+        }
+        storage.add_label("bd-w2", "wontfix", "tester").unwrap();
+
+        let run = |input: &str| -> Vec<String> {
+            let mut filters = ListFilters::default();
+            filters.set_where(FilterExpr::parse(input, "alice").unwrap());
+            let mut ids: Vec<String> = storage
+                .list_issues(&filters)
+                .unwrap()
+                .into_iter()
+                .map(|issue| issue.id)
+                .collect();
+            ids.sort();
+            ids
+        };
+
+        assert_eq!(
+            run("priority <= 1 and (assignee = me or assignee is null) and not label:wontfix"),
+            vec!["bd-w1"]
+        );
+        assert_eq!(
+            run("assignee != bob and status = open"),
+            vec!["bd-w1", "bd-w2", "bd-w4"]
+        );
+        assert_eq!(run("title ~ \"bob's\""), vec!["bd-w3"]);
+        assert_eq!(run("label in (wontfix, other)"), vec!["bd-w2"]);
+        assert_eq!(run("created < 2025-09-02 and priority > 2"), vec!["bd-w4"]);
+    }
+
     #[test]
     fn test_list_issues_filter_by_updated_date() {
         let mut storage = SqliteStorage::open_memory().unwrap();
@@ -4782,6 +7404,156 @@ mod tests {
         assert_eq!(issues[0].id, "bd-l1");
     }
 
+    fn close_with_outcome(storage: &mut SqliteStorage, id: &str, outcome: CloseOutcome) {
+        let update = IssueUpdate {
+            status: Some(Status::Closed),
+            closed_at: Some(Some(Utc::now())),
+            close_outcome: Some(Some(outcome)),
+            ..IssueUpdate::default()
+        };
+        storage.update_issue(id, &update, "tester").unwrap();
+    }
+
+    fn blocked_refs(storage: &SqliteStorage, id: &str) -> Vec<String> {
+        storage
+            .get_blocked_issues()
+            .unwrap()
+            .into_iter()
+            .find(|(issue, _)| issue.id == id)
+            .map(|(_, refs)| refs)
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_conditional_blocks_ready_only_after_failure() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let now = Utc::now();
+        for id in ["bd-try", "bd-retry"] {
+            let issue = make_issue(id, id, Status::Open, 2, None, now, None);
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+        storage
+            .add_dependency("bd-retry", "bd-try", "conditional-blocks", "tester")
+            .unwrap();
+
+        assert_eq!(
+            blocked_refs(&storage, "bd-retry"),
+            vec!["bd-try:if-failure"]
+        );
+
+        close_with_outcome(&mut storage, "bd-try", CloseOutcome::Failure);
+        assert!(!storage.is_blocked("bd-retry").unwrap());
+        let failed = storage.failed_conditional_blockers().unwrap();
+        assert_eq!(failed.get("bd-retry"), Some(&vec!["bd-try".to_string()]));
+
+        let stored = storage.get_issue("bd-try").unwrap().unwrap();
+        assert_eq!(stored.close_outcome, Some(CloseOutcome::Failure));
+
+        // Reopening clears the outcome and the dependent waits again
+        let reopen = IssueUpdate {
+            status: Some(Status::Open),
+            ..IssueUpdate::default()
+        };
+        storage.update_issue("bd-try", &reopen, "tester").unwrap();
+        let stored = storage.get_issue("bd-try").unwrap().unwrap();
+        assert!(stored.close_outcome.is_none());
+        assert_eq!(
+            blocked_refs(&storage, "bd-retry"),
+            vec!["bd-try:if-failure"]
+        );
+    }
+
+    #[test]
+    fn test_conditional_blocks_moot_after_success() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let now = Utc::now();
+        for id in ["bd-try", "bd-retry", "bd-next", "bd-fallback"] {
+            let issue = make_issue(id, id, Status::Open, 2, None, now, None);
+            storage.create_issue(&issue, "tester").unwrap();
+        }
+        storage
+            .add_dependency("bd-retry", "bd-try", "conditional-blocks", "tester")
+            .unwrap();
+        storage
+            .add_dependency("bd-next", "bd-try", "blocks", "tester")
+            .unwrap();
+
+        storage
+            .add_dependency("bd-fallback", "bd-retry", "conditional-blocks", "tester")
+            .unwrap();
+
+        close_with_outcome(&mut storage, "bd-try", CloseOutcome::Success);
+        assert!(!storage.is_blocked("bd-next").unwrap());
+        assert!(storage.failed_conditional_blockers().unwrap().is_empty());
+
+        // The dependent is closed as moot in the same write
+        let retry = storage.get_issue("bd-retry").unwrap().unwrap();
+        assert_eq!(retry.status, Status::Closed);
+        assert_eq!(retry.close_outcome, Some(CloseOutcome::Wontfix));
+        assert_eq!(
+            retry.close_reason.as_deref(),
+            Some("moot: bd-try closed with success")
+        );
+
+        // A wontfix closure does not cascade further
+        let fallback = storage.get_issue("bd-fallback").unwrap().unwrap();
+        assert_eq!(fallback.status, Status::Open);
+        assert_eq!(blocked_refs(&storage, "bd-fallback"), vec!["bd-retry:moot"]);
+    }
+
+    #[test]
+    fn test_compact_and_restore_round_trip() {
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let now = Utc::now();
+        let mut original = make_issue("bd-old", "Old work", Status::Closed, 2, None, now, None);
+        original.closed_at = Some(now - chrono::Duration::days(120));
+        original.description = Some("First sentence. A long tail of detail.".to_string());
+        original.notes = Some("Scratch notes".to_string());
+        storage.create_issue(&original, "tester").unwrap();
+
+        let candidates = storage
+            .compaction_candidates(1, now - chrono::Duration::days(90))
+            .unwrap();
+        assert_eq!(candidates.len(), 1);
+        assert!(
+            storage
+                .compaction_candidates(1, now - chrono::Duration::days(180))
+                .unwrap()
+                .is_empty()
+        );
+
+        let mut compacted = original.clone();
+        compacted.description = Some("First sentence.".to_string());
+        compacted.notes = None;
+        compacted.compaction_level = Some(1);
+        compacted.compacted_at = Some(now);
+        compacted.original_size = Some(51);
+        storage
+            .compact_issue(&original, &compacted, "tester")
+            .unwrap();
+
+        let stored = storage.get_issue("bd-old").unwrap().unwrap();
+        assert_eq!(stored.description.as_deref(), Some("First sentence."));
+        assert_eq!(stored.notes, None);
+        assert_eq!(stored.compaction_level, Some(1));
+        assert_eq!(stored.original_size, Some(51));
+        assert!(
+            storage
+                .compaction_candidates(1, now - chrono::Duration::days(90))
+                .unwrap()
+                .is_empty()
+        );
+
+        assert!(storage.restore_compacted("bd-old", "tester").unwrap());
+        let restored = storage.get_issue("bd-old").unwrap().unwrap();
+        assert_eq!(restored.description, original.description);
+        assert_eq!(restored.notes, original.notes);
+        assert_eq!(restored.compaction_level.unwrap_or(0), 0);
+        let events = storage.get_events("bd-old", 1).unwrap();
+        assert_eq!(events[0].event_type, EventType::Uncompacted);
+        assert!(!storage.restore_compacted("bd-old", "tester").unwrap());
+    }
+
     #[test]
     fn test_blocked_cache_handles_quotes_in_ids() {
         let mut storage = SqliteStorage::open_memory().unwrap();
@@ -0,0 +1,613 @@
+//! Point-in-time reconstruction for `--as-of` queries.
+//!
+//! Issue state at a past instant is rebuilt from the current database by
+//! undoing, newest first, every event recorded after that instant. The
+//! audit trail carries old values for status, priority, assignee, title and
+//! custom fields, and label and dependency edits record the label or the
+//! dependency row they touched, so they can be reversed. The text fields,
+//! type, dates and estimates are never logged. For issues touched after the
+//! instant those come from the nearest `.br_history` backup taken after it,
+//! and anything neither source can vouch for is reported per issue so the
+//! commands can mark it.
+//!
+//! The reconstructed issues are loaded into an in-memory database, so the
+//! regular list, ready and stats queries run against the snapshot unchanged.
+
+use crate::config::OpenStorageResult;
+use crate::error::Result;
+use crate::format::AsOfRecord;
+use crate::model::{Dependency, Event, EventType, Issue, IssueType, Priority, Status};
+use crate::storage::SqliteStorage;
+use crate::sync::history;
+use crate::sync::read_issues_from_jsonl;
+use crate::util::time::parse_flexible_timestamp;
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+/// Issue fields that no event records.
+const UNTRACKED_FIELDS: &[&str] = &[
+    "description",
+    "design",
+    "acceptance_criteria",
+    "notes",
+    "issue_type",
+    "owner",
+    "estimated_minutes",
+    "due_at",
+    "defer_until",
+    "external_ref",
+    "updated_at",
+];
+
+/// Text fields rewritten by compaction.
+const COMPACTED_FIELDS: &[&str] = &["description", "design", "acceptance_criteria", "notes"];
+
+/// Issue state reconstructed at a past instant.
+pub struct Snapshot {
+    /// The instant the snapshot describes.
+    pub as_of: DateTime<Utc>,
+    /// In-memory database holding the reconstructed issues.
+    pub storage: SqliteStorage,
+    /// History backup used for fields the event log does not cover.
+    pub backup: Option<PathBuf>,
+    unreconstructed: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl Snapshot {
+    /// Fields of an issue that could not be reconstructed and still show
+    /// their current value.
+    #[must_use]
+    pub fn unreconstructed(&self, id: &str) -> Vec<String> {
+        self.unreconstructed
+            .get(id)
+            .map(|fields| fields.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Tag output items with the snapshot instant and their unreconstructed
+    /// fields.
+    #[must_use]
+    pub fn records<T>(&self, items: Vec<T>, id: impl Fn(&T) -> &str) -> Vec<AsOfRecord<T>> {
+        items
+            .into_iter()
+            .map(|item| AsOfRecord {
+                as_of: self.as_of,
+                unreconstructed: self.unreconstructed(id(&item)),
+                item,
+            })
+            .collect()
+    }
+
+    /// Issues among `ids` with at least one unreconstructed field, paired
+    /// with those fields.
+    #[must_use]
+    pub fn partial<'a>(
+        &self,
+        ids: impl IntoIterator<Item = &'a str>,
+    ) -> Vec<(String, Vec<String>)> {
+        ids.into_iter()
+            .filter_map(|id| {
+                let fields = self.unreconstructed(id);
+                (!fields.is_empty()).then(|| (id.to_string(), fields))
+            })
+            .collect()
+    }
+}
+
+/// Reconstruct every exported issue as it was at `as_of`.
+///
+/// `jsonl_path` names the export whose backups serve as the fallback.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be read, a backup cannot be
+/// parsed, or the in-memory snapshot cannot be built.
+pub fn reconstruct(
+    storage: &SqliteStorage,
+    beads_dir: &Path,
+    jsonl_path: &Path,
+    as_of: DateTime<Utc>,
+) -> Result<Snapshot> {
+    let mut labels = storage.get_all_labels()?;
+    let mut dependencies = storage.get_all_dependency_records()?;
+    let mut comments = storage.get_all_comments()?;
+    let mut fields = storage.get_all_fields()?;
+
+    // Events come back newest first, which is the order they are undone in
+    let mut events: HashMap<String, Vec<Event>> = HashMap::new();
+    for event in storage.get_all_events(0)? {
+        if event.created_at > as_of {
+            events
+                .entry(event.issue_id.clone())
+                .or_default()
+                .push(event);
+        }
+    }
+
+    let backup = nearest_backup(beads_dir, jsonl_path, as_of)?;
+    let mut backup_issues: HashMap<String, Issue> = match &backup {
+        Some(path) => read_issues_from_jsonl(path)?
+            .into_iter()
+            .map(|issue| (issue.id.clone(), issue))
+            .collect(),
+        None => HashMap::new(),
+    };
+
+    let mut issues = Vec::new();
+    let mut unreconstructed = BTreeMap::new();
+    for mut issue in storage.get_all_issues_for_export()? {
+        let from_backup = backup_issues.remove(&issue.id);
+        if issue.created_at > as_of {
+            continue;
+        }
+        issue.labels = labels.remove(&issue.id).unwrap_or_default();
+        issue.dependencies = dependencies.remove(&issue.id).unwrap_or_default();
+        issue.comments = comments.remove(&issue.id).unwrap_or_default();
+        issue.fields = fields.remove(&issue.id).unwrap_or_default();
+
+        let issue_events = events.get(&issue.id).map_or(&[][..], Vec::as_slice);
+        let mut unknown = rewind(&mut issue, issue_events, as_of);
+        if issue.updated_at > as_of {
+            unknown.extend(UNTRACKED_FIELDS.iter().map(ToString::to_string));
+            issue.updated_at = as_of;
+            if let Some(old) = from_backup {
+                fill_from_backup(&mut issue, &old, &mut unknown);
+            }
+        }
+        prune_after(&mut issue, as_of);
+
+        if !unknown.is_empty() {
+            unreconstructed.insert(issue.id.clone(), unknown);
+        }
+        issues.push(issue);
+    }
+
+    // Issues purged from the database since the backup was taken
+    for mut issue in backup_issues.into_values() {
+        if issue.created_at <= as_of {
+            prune_after(&mut issue, as_of);
+            issues.push(issue);
+        }
+    }
+
+    let storage = load_snapshot(&issues)?;
+    Ok(Snapshot {
+        as_of,
+        storage,
+        backup,
+        unreconstructed,
+    })
+}
+
+/// Reconstruct the snapshot for an `--as-of` argument.
+///
+/// # Errors
+///
+/// Returns an error if the time cannot be parsed or reconstruction fails.
+pub fn reconstruct_for_arg(input: &str, storage_ctx: &OpenStorageResult) -> Result<Snapshot> {
+    let as_of = parse_flexible_timestamp(input, "as_of")?;
+    reconstruct(
+        &storage_ctx.storage,
+        &storage_ctx.paths.beads_dir,
+        &storage_ctx.paths.jsonl_path,
+        as_of,
+    )
+}
+
+/// The earliest backup taken at or after `as_of`.
+///
+/// Backups are written just before an export overwrites the file, so this
+/// is the last export that was current at `as_of`.
+fn nearest_backup(
+    beads_dir: &Path,
+    jsonl_path: &Path,
+    as_of: DateTime<Utc>,
+) -> Result<Option<PathBuf>> {
+    let stem = jsonl_path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("issues");
+    let backups = history::list_backups(&beads_dir.join(".br_history"), Some(&format!("{stem}.")))?;
+    Ok(backups
+        .into_iter()
+        .filter(|entry| entry.timestamp >= as_of)
+        .min_by_key(|entry| entry.timestamp)
+        .map(|entry| entry.path))
+}
+
+/// Undo `events` (newest first) on `issue`.
+///
+/// Returns the fields the events left undetermined.
+fn rewind(issue: &mut Issue, events: &[Event], as_of: DateTime<Utc>) -> BTreeSet<String> {
+    let mut unknown = BTreeSet::new();
+
+    for event in events {
+        match &event.event_type {
+            EventType::StatusChanged => {
+                match event.old_value.as_deref().map(str::parse::<Status>) {
+                    Some(Ok(status)) => {
+                        issue.status = status;
+                        unknown.remove("status");
+                    }
+                    _ => {
+                        unknown.insert("status".to_string());
+                    }
+                }
+            }
+            EventType::PriorityChanged => match event.old_value.as_deref().map(str::parse::<i32>) {
+                Some(Ok(priority)) => issue.priority = Priority(priority),
+                _ => {
+                    unknown.insert("priority".to_string());
+                }
+            },
+            EventType::AssigneeChanged => {
+                issue.assignee = event.old_value.clone().filter(|value| !value.is_empty());
+            }
+            EventType::TitleChanged => match &event.old_value {
+                Some(old) => issue.title.clone_from(old),
+                None => {
+                    unknown.insert("title".to_string());
+                }
+            },
+            EventType::FieldChanged => rewind_field(issue, event, &mut unknown),
+            EventType::LabelAdded => match &event.new_value {
+                Some(label) => issue.labels.retain(|l| l != label),
+                None => {
+                    unknown.insert("labels".to_string());
+                }
+            },
+            EventType::LabelRemoved => match &event.old_value {
+                Some(label) => {
+                    if !issue.labels.contains(label) {
+                        issue.labels.push(label.clone());
+                    }
+                }
+                None => {
+                    unknown.insert("labels".to_string());
+                }
+            },
+            EventType::DependencyAdded => match &event.new_value {
+                Some(target) => issue.dependencies.retain(|d| &d.depends_on_id != target),
+                None => {
+                    unknown.insert("dependencies".to_string());
+                }
+            },
+            EventType::DependencyRemoved => {
+                // Removals keep the removed rows, dependency type included
+                match event
+                    .old_value
+                    .as_deref()
+                    .map(serde_json::from_str::<Vec<Dependency>>)
+                {
+                    Some(Ok(removed)) => {
+                        for dep in removed {
+                            if dep.issue_id == issue.id
+                                && !issue
+                                    .dependencies
+                                    .iter()
+                                    .any(|d| d.depends_on_id == dep.depends_on_id)
+                            {
+                                issue.dependencies.push(dep);
+                            }
+                        }
+                    }
+                    _ => {
+                        unknown.insert("dependencies".to_string());
+                    }
+                }
+            }
+            EventType::Deleted => {
+                if let Some(original) = issue.original_type.take()
+                    && let Ok(issue_type) = original.parse::<IssueType>()
+                {
+                    issue.issue_type = issue_type;
+                }
+                issue.deleted_at = None;
+                issue.deleted_by = None;
+                issue.delete_reason = None;
+                match event.old_value.as_deref().map(str::parse::<Status>) {
+                    Some(Ok(status)) => {
+                        issue.status = status;
+                        unknown.remove("status");
+                    }
+                    // Older deletions did not log the status they replaced
+                    _ => {
+                        issue.status = if issue.closed_at.is_some_and(|at| at <= as_of) {
+                            Status::Closed
+                        } else {
+                            Status::Open
+                        };
+                        unknown.insert("status".to_string());
+                    }
+                }
+            }
+            EventType::Compacted => {
+                issue.compaction_level = None;
+                issue.compacted_at = None;
+                issue.compacted_at_commit = None;
+                issue.original_size = None;
+                for field in COMPACTED_FIELDS {
+                    unknown.insert((*field).to_string());
+                }
+            }
+            // `br undelete`
+            EventType::Restored => {
+                issue.original_type = Some(issue.issue_type.as_str().to_string());
+                issue.status = Status::Tombstone;
+                unknown.remove("status");
+                unknown.insert("deleted_at".to_string());
+            }
+            EventType::Uncompacted => {
+                for field in COMPACTED_FIELDS.iter().chain(&["compaction_level"]) {
+                    unknown.insert((*field).to_string());
+                }
+            }
+            EventType::Created
+            | EventType::Updated
+            | EventType::Commented
+            | EventType::Closed
+            | EventType::Reopened
+            | EventType::Custom(_) => {}
+        }
+    }
+
+    if issue.status == Status::Closed {
+        if issue.closed_at.map_or(!events.is_empty(), |at| at > as_of) {
+            // A later reopen or re-close overwrote the original close
+            unknown.insert("closed_at".to_string());
+            unknown.insert("close_reason".to_string());
+        }
+    } else if issue.status != Status::Tombstone {
+        issue.closed_at = None;
+        issue.close_reason = None;
+        issue.close_outcome = None;
+        issue.closed_by_session = None;
+    }
+
+    unknown
+}
+
+/// Undo a custom field change, logged as `{"<name>": <value>}` on each side.
+fn rewind_field(issue: &mut Issue, event: &Event, unknown: &mut BTreeSet<String>) {
+    let parse = |value: &Option<String>| {
+        value
+            .as_deref()
+            .map(serde_json::from_str::<serde_json::Map<String, serde_json::Value>>)
+    };
+    match (parse(&event.old_value), parse(&event.new_value)) {
+        (Some(Ok(old)), _) => issue.fields.extend(old),
+        (None, Some(Ok(new))) => {
+            for name in new.keys() {
+                issue.fields.remove(name);
+            }
+        }
+        _ => {
+            unknown.insert("fields".to_string());
+        }
+    }
+}
+
+/// Take the fields the event log could not settle from a backup copy.
+fn fill_from_backup(issue: &mut Issue, old: &Issue, unknown: &mut BTreeSet<String>) {
+    for field in std::mem::take(unknown) {
+        match field.as_str() {
+            "description" => issue.description.clone_from(&old.description),
+            "design" => issue.design.clone_from(&old.design),
+            "acceptance_criteria" => issue
+                .acceptance_criteria
+                .clone_from(&old.acceptance_criteria),
+            "notes" => issue.notes.clone_from(&old.notes),
+            "issue_type" => issue.issue_type.clone_from(&old.issue_type),
+            "owner" => issue.owner.clone_from(&old.owner),
+            "estimated_minutes" => issue.estimated_minutes = old.estimated_minutes,
+            "due_at" => issue.due_at = old.due_at,
+            "defer_until" => issue.defer_until = old.defer_until,
+            "external_ref" => issue.external_ref.clone_from(&old.external_ref),
+            "updated_at" => issue.updated_at = old.updated_at,
+            "status" => issue.status.clone_from(&old.status),
+            "priority" => issue.priority = old.priority,
+            "closed_at" => issue.closed_at = old.closed_at,
+            "close_reason" => issue.close_reason.clone_from(&old.close_reason),
+            "compaction_level" => issue.compaction_level = old.compaction_level,
+            "labels" => issue.labels.clone_from(&old.labels),
+            "dependencies" => issue.dependencies.clone_from(&old.dependencies),
+            "fields" => issue.fields.clone_from(&old.fields),
+            _ => {
+                unknown.insert(field);
+            }
+        }
+    }
+}
+
+/// Drop dependencies and comments created after `as_of`.
+fn prune_after(issue: &mut Issue, as_of: DateTime<Utc>) {
+    issue.dependencies.retain(|dep| dep.created_at <= as_of);
+    issue.comments.retain(|comment| comment.created_at <= as_of);
+}
+
+fn load_snapshot(issues: &[Issue]) -> Result<SqliteStorage> {
+    let mut storage = SqliteStorage::open_memory()?;
+    for issue in issues {
+        storage.upsert_issue_for_import(issue)?;
+    }
+    for issue in issues {
+        storage.sync_labels_for_import(&issue.id, &issue.labels)?;
+        storage.sync_dependencies_for_import(&issue.id, &issue.dependencies)?;
+        storage.sync_comments_for_import(&issue.id, &issue.comments)?;
+        storage.sync_fields_for_import(&issue.id, &issue.fields)?;
+    }
+    storage.rebuild_blocked_cache(true)?;
+    Ok(storage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::IssueUpdate;
+    use chrono::Duration;
+    use tempfile::TempDir;
+
+    fn create_issue(storage: &mut SqliteStorage, id: &str, created_at: DateTime<Utc>) {
+        let issue = Issue {
+            id: id.to_string(),
+            title: format!("Issue {id}"),
+            description: Some("original".to_string()),
+            status: Status::Open,
+            priority: Priority::MEDIUM,
+            created_at,
+            updated_at: created_at,
+            ..Issue::default()
+        };
+        storage.create_issue(&issue, "alice").unwrap();
+    }
+
+    #[test]
+    fn test_rewinds_tracked_fields_and_marks_the_rest() {
+        let temp = TempDir::new().unwrap();
+        let jsonl = temp.path().join("issues.jsonl");
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let created = Utc::now() - Duration::days(2);
+        create_issue(&mut storage, "bd-1", created);
+        storage.add_label("bd-1", "backend", "alice").unwrap();
+
+        let as_of = Utc::now();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        storage
+            .update_issue(
+                "bd-1",
+                &IssueUpdate {
+                    title: Some("Renamed".to_string()),
+                    status: Some(Status::InProgress),
+                    priority: Some(Priority::CRITICAL),
+                    description: Some(Some("rewritten".to_string())),
+                    ..IssueUpdate::default()
+                },
+                "bob",
+            )
+            .unwrap();
+        storage.add_label("bd-1", "urgent", "bob").unwrap();
+        storage.remove_label("bd-1", "backend", "bob").unwrap();
+        storage.add_comment("bd-1", "bob", "later").unwrap();
+
+        let snapshot = reconstruct(&storage, temp.path(), &jsonl, as_of).unwrap();
+        let issue = snapshot.storage.get_issue("bd-1").unwrap().unwrap();
+        assert_eq!(issue.title, "Issue bd-1");
+        assert_eq!(issue.status, Status::Open);
+        assert_eq!(issue.priority, Priority::MEDIUM);
+        assert_eq!(
+            snapshot.storage.get_labels("bd-1").unwrap(),
+            vec!["backend"]
+        );
+        assert!(snapshot.storage.get_comments("bd-1").unwrap().is_empty());
+
+        // No backup to fall back on: the description keeps its current value
+        let unknown = snapshot.unreconstructed("bd-1");
+        assert!(unknown.contains(&"description".to_string()));
+        assert!(!unknown.contains(&"status".to_string()));
+        assert_eq!(issue.description.as_deref(), Some("rewritten"));
+    }
+
+    #[test]
+    fn test_rewinds_labels_dependencies_and_fields_from_structured_events() {
+        let temp = TempDir::new().unwrap();
+        let jsonl = temp.path().join("issues.jsonl");
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        let created = Utc::now() - Duration::days(2);
+        create_issue(&mut storage, "bd-1", created);
+        create_issue(&mut storage, "bd-2", created);
+        create_issue(&mut storage, "bd-3", created);
+        storage.add_label("bd-1", "old-name", "alice").unwrap();
+        storage.add_label("bd-1", "keep", "alice").unwrap();
+        storage
+            .add_dependency("bd-1", "bd-2", "related", "alice")
+            .unwrap();
+        let size: BTreeMap<String, Option<serde_json::Value>> =
+            std::iter::once(("size".to_string(), Some(serde_json::json!(3)))).collect();
+        storage.set_fields("bd-1", &size, "alice").unwrap();
+
+        let as_of = Utc::now();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        storage.rename_label("old-name", "new-name", "bob").unwrap();
+        storage
+            .set_labels(
+                "bd-1",
+                &["new-name".to_string(), "added".to_string()],
+                "bob",
+            )
+            .unwrap();
+        storage.remove_dependency("bd-1", "bd-2", "bob").unwrap();
+        storage
+            .add_dependency("bd-1", "bd-3", "blocks", "bob")
+            .unwrap();
+        let size: BTreeMap<String, Option<serde_json::Value>> =
+            std::iter::once(("size".to_string(), Some(serde_json::json!(5)))).collect();
+        storage.set_fields("bd-1", &size, "bob").unwrap();
+        let team: BTreeMap<String, Option<serde_json::Value>> =
+            std::iter::once(("team".to_string(), Some(serde_json::json!("core")))).collect();
+        storage.set_fields("bd-1", &team, "bob").unwrap();
+
+        let snapshot = reconstruct(&storage, temp.path(), &jsonl, as_of).unwrap();
+        let mut labels = snapshot.storage.get_labels("bd-1").unwrap();
+        labels.sort();
+        assert_eq!(labels, vec!["keep", "old-name"]);
+        let deps = snapshot.storage.get_dependencies_full("bd-1").unwrap();
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].depends_on_id, "bd-2");
+        assert_eq!(deps[0].dep_type, crate::model::DependencyType::Related);
+        let issue = snapshot.storage.get_issue("bd-1").unwrap().unwrap();
+        let fields = snapshot.storage.get_all_fields().unwrap();
+        assert_eq!(
+            fields.get("bd-1").and_then(|fields| fields.get("size")),
+            Some(&serde_json::json!(3))
+        );
+        assert!(
+            fields
+                .get("bd-1")
+                .is_none_or(|fields| !fields.contains_key("team"))
+        );
+        let unknown = snapshot.unreconstructed(&issue.id);
+        for field in ["labels", "dependencies", "fields"] {
+            assert!(!unknown.contains(&field.to_string()), "{field} unknown");
+        }
+    }
+
+    #[test]
+    fn test_backup_fills_untracked_fields_and_skips_newer_issues() {
+        let temp = TempDir::new().unwrap();
+        let jsonl = temp.path().join("issues.jsonl");
+        let mut storage = SqliteStorage::open_memory().unwrap();
+        create_issue(&mut storage, "bd-1", Utc::now() - Duration::days(2));
+
+        let as_of = Utc::now();
+        let history_dir = temp.path().join(".br_history");
+        std::fs::create_dir_all(&history_dir).unwrap();
+        let backup = storage.get_issue("bd-1").unwrap().unwrap();
+        let stamp = (as_of + Duration::seconds(2)).format("%Y%m%d_%H%M%S");
+        std::fs::write(
+            history_dir.join(format!("issues.{stamp}.jsonl")),
+            format!("{}\n", serde_json::to_string(&backup).unwrap()),
+        )
+        .unwrap();
+
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        storage
+            .update_issue(
+                "bd-1",
+                &IssueUpdate {
+                    description: Some(Some("rewritten".to_string())),
+                    ..IssueUpdate::default()
+                },
+                "bob",
+            )
+            .unwrap();
+        create_issue(&mut storage, "bd-2", Utc::now());
+
+        let snapshot = reconstruct(&storage, temp.path(), &jsonl, as_of).unwrap();
+        assert!(snapshot.backup.is_some());
+        let issue = snapshot.storage.get_issue("bd-1").unwrap().unwrap();
+        assert_eq!(issue.description.as_deref(), Some("original"));
+        assert!(snapshot.unreconstructed("bd-1").is_empty());
+        assert!(snapshot.storage.get_issue("bd-2").unwrap().is_none());
+    }
+}
@@ -0,0 +1,349 @@
+//! Manual conflict resolution for 3-way merges.
+//!
+//! When `br sync --resolve` runs a merge with [`ConflictResolution::Manual`],
+//! every conflicting issue is written to `.beads/conflicts.jsonl` together with
+//! its base, local and remote versions. A resolution is recorded per issue by
+//! setting `take` (via `br sync resolve <id> --take ...`) or by writing a
+//! hand-merged issue into `resolved`. Re-running `br sync --resolve` feeds the
+//! recorded resolutions back into the merge report and applies the result.
+//!
+//! [`ConflictResolution::Manual`]: super::ConflictResolution::Manual
+
+use super::{ConflictType, FieldDecisionKind, MergeContext, MergeReport};
+use crate::error::{BeadsError, Result};
+use crate::model::Issue;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// File name of the pending conflicts file inside `.beads/`.
+pub const CONFLICTS_FILE_NAME: &str = "conflicts.jsonl";
+
+/// Which version of a conflicting issue to keep.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictSide {
+    /// The local (`SQLite`) version.
+    Local,
+    /// The remote (JSONL) version.
+    Remote,
+    /// The common ancestor from the base snapshot.
+    Base,
+}
+
+impl ConflictSide {
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Local => "local",
+            Self::Remote => "remote",
+            Self::Base => "base",
+        }
+    }
+}
+
+/// A conflicting issue as stored in `conflicts.jsonl`.
+///
+/// A missing `base`/`local`/`remote` means the issue does not exist on that
+/// side; choosing such a side deletes the issue.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConflictRecord {
+    pub id: String,
+    pub conflict_type: ConflictType,
+    /// Fields changed differently on both sides (field conflicts only).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<String>,
+    #[serde(default)]
+    pub base: Option<Issue>,
+    #[serde(default)]
+    pub local: Option<Issue>,
+    #[serde(default)]
+    pub remote: Option<Issue>,
+    /// Side chosen by `br sync resolve`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub take: Option<ConflictSide>,
+    /// Hand-merged issue; takes precedence over `take`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved: Option<Issue>,
+}
+
+impl ConflictRecord {
+    /// Build a record for a conflict reported by `three_way_merge`.
+    #[must_use]
+    pub fn from_merge(
+        id: &str,
+        conflict_type: ConflictType,
+        context: &MergeContext,
+        report: &MergeReport,
+    ) -> Self {
+        let fields = report
+            .field_decisions
+            .iter()
+            .filter(|d| d.issue_id == id && d.decision == FieldDecisionKind::Conflict)
+            .map(|d| d.field.clone())
+            .collect();
+        Self {
+            id: id.to_string(),
+            conflict_type,
+            fields,
+            base: context.base.get(id).cloned(),
+            local: context.left.get(id).cloned(),
+            remote: context.right.get(id).cloned(),
+            take: None,
+            resolved: None,
+        }
+    }
+
+    /// Whether a resolution has been recorded.
+    #[must_use]
+    pub const fn is_resolved(&self) -> bool {
+        self.take.is_some() || self.resolved.is_some()
+    }
+
+    /// The chosen outcome: `Some(Some(issue))` keeps an issue, `Some(None)`
+    /// deletes it, and `None` means the conflict is still unresolved.
+    #[must_use]
+    pub fn resolution(&self) -> Option<Option<&Issue>> {
+        if let Some(issue) = &self.resolved {
+            return Some(Some(issue));
+        }
+        self.take.map(|side| match side {
+            ConflictSide::Local => self.local.as_ref(),
+            ConflictSide::Remote => self.remote.as_ref(),
+            ConflictSide::Base => self.base.as_ref(),
+        })
+    }
+
+    /// Whether this record still describes the given merge inputs.
+    ///
+    /// A stale record (local or remote changed since it was written) must not be
+    /// applied, because its resolution was chosen against different content.
+    #[must_use]
+    pub fn matches(&self, context: &MergeContext) -> bool {
+        same_issue(self.local.as_ref(), context.left.get(&self.id))
+            && same_issue(self.remote.as_ref(), context.right.get(&self.id))
+    }
+}
+
+/// Compare issues ignoring `content_hash`, which is not serialized to JSONL.
+fn same_issue(a: Option<&Issue>, b: Option<&Issue>) -> bool {
+    match (a, b) {
+        (None, None) => true,
+        (Some(a), Some(b)) => {
+            let mut a = a.clone();
+            let mut b = b.clone();
+            a.content_hash = None;
+            b.content_hash = None;
+            a == b
+        }
+        _ => false,
+    }
+}
+
+/// Path of the pending conflicts file for a beads directory.
+#[must_use]
+pub fn conflicts_path(beads_dir: &Path) -> PathBuf {
+    beads_dir.join(CONFLICTS_FILE_NAME)
+}
+
+/// Load conflict records.
+///
+/// Returns an empty list if the file does not exist.
+///
+/// # Errors
+///
+/// Returns an error if the file exists but cannot be read or parsed.
+pub fn load_conflicts(path: &Path) -> Result<Vec<ConflictRecord>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let reader = BufReader::new(File::open(path)?);
+    let mut records = Vec::new();
+    for (line_num, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record: ConflictRecord = serde_json::from_str(&line).map_err(|e| {
+            BeadsError::Config(format!(
+                "Invalid JSON in {} at line {}: {}",
+                path.display(),
+                line_num + 1,
+                e
+            ))
+        })?;
+        records.push(record);
+    }
+    Ok(records)
+}
+
+/// Write conflict records, replacing the file. An empty list removes the file.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be written or removed.
+pub fn save_conflicts(path: &Path, records: &[ConflictRecord]) -> Result<()> {
+    if records.is_empty() {
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
+        return Ok(());
+    }
+
+    let temp_path = path.with_extension("jsonl.tmp");
+    {
+        let mut writer = BufWriter::new(File::create(&temp_path)?);
+        for record in records {
+            let json = serde_json::to_string(record).map_err(|e| {
+                BeadsError::Config(format!("Failed to serialize conflict {}: {}", record.id, e))
+            })?;
+            writeln!(writer, "{json}")?;
+        }
+        writer.flush()?;
+    }
+    fs::rename(&temp_path, path)?;
+    Ok(())
+}
+
+/// Apply recorded resolutions to a merge report.
+///
+/// Resolved conflicts are moved into `report.kept` / `report.deleted`. Returns
+/// the records that are still unresolved (fresh ones for new conflicts, existing
+/// ones when they still match the merge inputs); stale resolutions are dropped.
+#[must_use]
+pub fn apply_resolutions(
+    report: &mut MergeReport,
+    context: &MergeContext,
+    existing: &[ConflictRecord],
+) -> Vec<ConflictRecord> {
+    let mut pending = Vec::new();
+
+    for (id, conflict_type) in std::mem::take(&mut report.conflicts) {
+        let recorded = existing
+            .iter()
+            .find(|record| record.id == id && record.matches(context));
+
+        match recorded.and_then(ConflictRecord::resolution) {
+            Some(Some(issue)) => {
+                let mut issue = issue.clone();
+                issue.id.clone_from(&id);
+                issue.content_hash = Some(issue.compute_content_hash());
+                report
+                    .notes
+                    .push((id.clone(), "Conflict resolved manually".to_string()));
+                report.kept.push(issue);
+            }
+            Some(None) => {
+                report.notes.push((
+                    id.clone(),
+                    "Conflict resolved manually - deleted".to_string(),
+                ));
+                report.deleted.push(id);
+            }
+            None => {
+                report.conflicts.push((id.clone(), conflict_type));
+                pending.push(recorded.cloned().unwrap_or_else(|| {
+                    ConflictRecord::from_merge(&id, conflict_type, context, report)
+                }));
+            }
+        }
+    }
+
+    pending.sort_by(|a, b| a.id.cmp(&b.id));
+    pending
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sync::{ConflictResolution, three_way_merge};
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+
+    fn issue(id: &str, title: &str) -> Issue {
+        Issue {
+            id: id.to_string(),
+            title: title.to_string(),
+            ..Issue::default()
+        }
+    }
+
+    fn conflicting_context() -> MergeContext {
+        let base = issue("bd-1", "Base");
+        let local = issue("bd-1", "Local");
+        let remote = issue("bd-1", "Remote");
+        MergeContext::new(
+            HashMap::from([("bd-1".to_string(), base)]),
+            HashMap::from([("bd-1".to_string(), local)]),
+            HashMap::from([("bd-1".to_string(), remote)]),
+        )
+    }
+
+    #[test]
+    fn unresolved_conflict_is_recorded_with_all_sides() {
+        let context = conflicting_context();
+        let mut report = three_way_merge(&context, ConflictResolution::Manual, None);
+
+        let pending = apply_resolutions(&mut report, &context, &[]);
+
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].conflict_type, ConflictType::FieldConflict);
+        assert_eq!(pending[0].fields, vec!["title"]);
+        assert_eq!(pending[0].local.as_ref().unwrap().title, "Local");
+        assert_eq!(pending[0].remote.as_ref().unwrap().title, "Remote");
+        assert!(report.has_conflicts());
+    }
+
+    #[test]
+    fn recorded_side_is_applied() {
+        let context = conflicting_context();
+        let mut report = three_way_merge(&context, ConflictResolution::Manual, None);
+        let mut records = apply_resolutions(&mut report, &context, &[]);
+        records[0].take = Some(ConflictSide::Remote);
+
+        let mut report = three_way_merge(&context, ConflictResolution::Manual, None);
+        let pending = apply_resolutions(&mut report, &context, &records);
+
+        assert!(pending.is_empty());
+        assert!(!report.has_conflicts());
+        assert_eq!(report.kept.len(), 1);
+        assert_eq!(report.kept[0].title, "Remote");
+    }
+
+    #[test]
+    fn stale_resolution_is_not_applied() {
+        let context = conflicting_context();
+        let mut report = three_way_merge(&context, ConflictResolution::Manual, None);
+        let mut records = apply_resolutions(&mut report, &context, &[]);
+        records[0].take = Some(ConflictSide::Local);
+
+        let mut changed = conflicting_context();
+        changed.left.get_mut("bd-1").unwrap().title = "Local again".to_string();
+        let mut report = three_way_merge(&changed, ConflictResolution::Manual, None);
+        let pending = apply_resolutions(&mut report, &changed, &records);
+
+        assert_eq!(pending.len(), 1);
+        assert!(!pending[0].is_resolved());
+        assert!(report.kept.is_empty());
+    }
+
+    #[test]
+    fn conflicts_file_roundtrip_and_removal() {
+        let temp = TempDir::new().unwrap();
+        let path = conflicts_path(temp.path());
+        let context = conflicting_context();
+        let mut report = three_way_merge(&context, ConflictResolution::Manual, None);
+        let records = apply_resolutions(&mut report, &context, &[]);
+
+        save_conflicts(&path, &records).unwrap();
+        let loaded = load_conflicts(&path).unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert!(loaded[0].matches(&context));
+
+        save_conflicts(&path, &[]).unwrap();
+        assert!(!path.exists());
+        assert!(load_conflicts(&path).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,377 @@
+//! Issue-level semantic diff between two sets of issues.
+//!
+//! Each side of a diff is a JSONL file, a `.br_history` backup or the live
+//! database (as it would be exported). Issues are matched by ID and compared
+//! field by field through their JSONL representation, so anything that
+//! round-trips through `issues.jsonl` shows up and nothing else does. Labels,
+//! dependencies and comments are compared as sets rather than as lists.
+
+use super::{export_to_writer, read_issues_from_jsonl};
+use crate::error::{BeadsError, Result};
+use crate::model::{Comment, Dependency, Issue, Status};
+use crate::storage::SqliteStorage;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+/// Side name that selects the database instead of a file.
+pub const DATABASE_SOURCE: &str = "db";
+
+/// Keys compared as sets instead of scalar fields.
+const RELATION_KEYS: [&str; 3] = ["labels", "dependencies", "comments"];
+
+/// One side of a diff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffSource {
+    /// The database, exported the way `br sync --flush-only` would.
+    Database,
+    /// A JSONL file (including history backups).
+    Jsonl(PathBuf),
+}
+
+impl DiffSource {
+    /// Resolve a command-line side.
+    ///
+    /// `db` selects the database. Anything else is tried as a path, then
+    /// relative to `.beads/`, then as a backup name in `.beads/.br_history`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `spec` matches none of these.
+    pub fn resolve(spec: &str, beads_dir: &Path) -> Result<Self> {
+        if spec == DATABASE_SOURCE {
+            return Ok(Self::Database);
+        }
+        let candidates = [
+            PathBuf::from(spec),
+            beads_dir.join(spec),
+            beads_dir.join(".br_history").join(spec),
+        ];
+        candidates
+            .into_iter()
+            .find(|path| path.is_file())
+            .map(Self::Jsonl)
+            .ok_or_else(|| {
+                BeadsError::validation(
+                    "source",
+                    format!("'{spec}' is not a JSONL file, a history backup or `db`"),
+                )
+            })
+    }
+
+    /// Load the issues on this side.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be parsed or the export fails.
+    pub fn load(&self, storage: Option<&SqliteStorage>) -> Result<Vec<Issue>> {
+        match self {
+            Self::Jsonl(path) => read_issues_from_jsonl(path),
+            Self::Database => {
+                let storage = storage.ok_or_else(|| {
+                    BeadsError::Config("diffing against `db` needs a database".to_string())
+                })?;
+                let mut buffer = Vec::new();
+                export_to_writer(storage, &mut buffer)?;
+                serde_json::Deserializer::from_slice(&buffer)
+                    .into_iter::<Issue>()
+                    .map(|issue| issue.map_err(BeadsError::from))
+                    .collect()
+            }
+        }
+    }
+
+    /// Short name for headers.
+    #[must_use]
+    pub fn label(&self) -> String {
+        match self {
+            Self::Database => DATABASE_SOURCE.to_string(),
+            Self::Jsonl(path) => path.display().to_string(),
+        }
+    }
+}
+
+/// An issue that only appears (or disappears) as a whole.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct IssueRef {
+    pub id: String,
+    pub title: String,
+}
+
+impl From<&Issue> for IssueRef {
+    fn from(issue: &Issue) -> Self {
+        Self {
+            id: issue.id.clone(),
+            title: issue.title.clone(),
+        }
+    }
+}
+
+/// A scalar field whose value differs. Absent fields are `null`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub old: Value,
+    pub new: Value,
+}
+
+/// Members added to or removed from a set-valued relation.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SetChange {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub added: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub removed: Vec<String>,
+}
+
+impl SetChange {
+    fn between(old: &BTreeSet<String>, new: &BTreeSet<String>) -> Self {
+        Self {
+            added: new.difference(old).cloned().collect(),
+            removed: old.difference(new).cloned().collect(),
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Everything that differs for an issue present on both sides.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct IssueChange {
+    pub id: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<FieldChange>,
+    #[serde(skip_serializing_if = "SetChange::is_empty")]
+    pub labels: SetChange,
+    #[serde(skip_serializing_if = "SetChange::is_empty")]
+    pub dependencies: SetChange,
+    #[serde(skip_serializing_if = "SetChange::is_empty")]
+    pub comments: SetChange,
+}
+
+impl IssueChange {
+    fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+            && self.labels.is_empty()
+            && self.dependencies.is_empty()
+            && self.comments.is_empty()
+    }
+}
+
+/// Issue-level diff from `old` to `new`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct IssueDiff {
+    pub old: String,
+    pub new: String,
+    pub added: Vec<IssueRef>,
+    pub removed: Vec<IssueRef>,
+    /// Issues that became tombstones (or only exist on the new side as one).
+    pub tombstoned: Vec<IssueRef>,
+    pub changed: Vec<IssueChange>,
+    pub unchanged: usize,
+}
+
+impl IssueDiff {
+    /// Compare two sets of issues. Later duplicates of an ID win, as on import.
+    #[must_use]
+    pub fn between(old_label: &str, old: &[Issue], new_label: &str, new: &[Issue]) -> Self {
+        let old: BTreeMap<&str, &Issue> = old.iter().map(|i| (i.id.as_str(), i)).collect();
+        let new: BTreeMap<&str, &Issue> = new.iter().map(|i| (i.id.as_str(), i)).collect();
+        let mut diff = Self {
+            old: old_label.to_string(),
+            new: new_label.to_string(),
+            ..Self::default()
+        };
+
+        for (id, old_issue) in &old {
+            let Some(new_issue) = new.get(id) else {
+                diff.removed.push(IssueRef::from(*old_issue));
+                continue;
+            };
+            if new_issue.status == Status::Tombstone && old_issue.status != Status::Tombstone {
+                diff.tombstoned.push(IssueRef::from(*new_issue));
+                continue;
+            }
+            let change = compare_issue(old_issue, new_issue);
+            if change.is_empty() {
+                diff.unchanged += 1;
+            } else {
+                diff.changed.push(change);
+            }
+        }
+        for (id, new_issue) in &new {
+            if old.contains_key(id) {
+                continue;
+            }
+            if new_issue.status == Status::Tombstone {
+                diff.tombstoned.push(IssueRef::from(*new_issue));
+            } else {
+                diff.added.push(IssueRef::from(*new_issue));
+            }
+        }
+        diff
+    }
+
+    /// True when both sides hold the same issues.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.tombstoned.is_empty()
+            && self.changed.is_empty()
+    }
+}
+
+fn compare_issue(old: &Issue, new: &Issue) -> IssueChange {
+    let old_fields = scalar_fields(old);
+    let new_fields = scalar_fields(new);
+    let names: BTreeSet<&String> = old_fields.keys().chain(new_fields.keys()).collect();
+    let fields = names
+        .into_iter()
+        .filter_map(|name| {
+            let old_value = old_fields.get(name).cloned().unwrap_or(Value::Null);
+            let new_value = new_fields.get(name).cloned().unwrap_or(Value::Null);
+            (old_value != new_value).then(|| FieldChange {
+                field: name.clone(),
+                old: old_value,
+                new: new_value,
+            })
+        })
+        .collect();
+
+    IssueChange {
+        id: new.id.clone(),
+        title: new.title.clone(),
+        fields,
+        labels: SetChange::between(&label_set(old), &label_set(new)),
+        dependencies: SetChange::between(&dependency_set(old), &dependency_set(new)),
+        comments: SetChange::between(&comment_set(old), &comment_set(new)),
+    }
+}
+
+/// JSONL fields of an issue minus relations; custom fields become `fields.<name>`.
+fn scalar_fields(issue: &Issue) -> BTreeMap<String, Value> {
+    let Ok(Value::Object(object)) = serde_json::to_value(issue) else {
+        return BTreeMap::new();
+    };
+    let mut fields = BTreeMap::new();
+    for (key, value) in object {
+        if RELATION_KEYS.contains(&key.as_str()) {
+            continue;
+        }
+        if key == "fields"
+            && let Value::Object(custom) = value
+        {
+            for (name, value) in custom {
+                fields.insert(format!("fields.{name}"), value);
+            }
+            continue;
+        }
+        fields.insert(key, value);
+    }
+    fields
+}
+
+fn label_set(issue: &Issue) -> BTreeSet<String> {
+    issue.labels.iter().cloned().collect()
+}
+
+fn dependency_set(issue: &Issue) -> BTreeSet<String> {
+    issue.dependencies.iter().map(dependency_key).collect()
+}
+
+fn dependency_key(dep: &Dependency) -> String {
+    format!("{}:{}", dep.dep_type.as_str(), dep.depends_on_id)
+}
+
+fn comment_set(issue: &Issue) -> BTreeSet<String> {
+    issue.comments.iter().map(comment_key).collect()
+}
+
+/// Comment IDs are local to a database, so comments match on content.
+fn comment_key(comment: &Comment) -> String {
+    format!(
+        "{} ({}): {}",
+        comment.author,
+        comment.created_at.to_rfc3339(),
+        comment.body
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::DependencyType;
+    use chrono::Utc;
+
+    fn issue(id: &str, title: &str) -> Issue {
+        Issue {
+            id: id.to_string(),
+            title: title.to_string(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            ..Issue::default()
+        }
+    }
+
+    #[test]
+    fn test_diff_classifies_issues() {
+        let kept = issue("bd-1", "Kept");
+        let gone = issue("bd-2", "Gone");
+        let deleted = issue("bd-3", "Deleted");
+        let mut tombstone = deleted.clone();
+        tombstone.status = Status::Tombstone;
+        let fresh = issue("bd-4", "Fresh");
+
+        let diff = IssueDiff::between(
+            "a",
+            &[kept.clone(), gone, deleted],
+            "b",
+            &[kept, tombstone, fresh],
+        );
+
+        assert_eq!(diff.unchanged, 1);
+        assert_eq!(diff.removed[0].id, "bd-2");
+        assert_eq!(diff.tombstoned[0].id, "bd-3");
+        assert_eq!(diff.added[0].id, "bd-4");
+        assert!(diff.changed.is_empty());
+    }
+
+    #[test]
+    fn test_diff_reports_fields_and_relation_sets() {
+        let mut old = issue("bd-1", "Old title");
+        old.labels = vec!["a".to_string(), "b".to_string()];
+        old.fields
+            .insert("severity".to_string(), Value::String("low".to_string()));
+        let mut new = old.clone();
+        new.title = "New title".to_string();
+        new.status = Status::Closed;
+        new.labels = vec!["b".to_string(), "c".to_string()];
+        new.fields.remove("severity");
+        new.dependencies.push(Dependency {
+            issue_id: "bd-1".to_string(),
+            depends_on_id: "bd-9".to_string(),
+            dep_type: DependencyType::Blocks,
+            created_at: Utc::now(),
+            created_by: None,
+            metadata: None,
+            thread_id: None,
+        });
+
+        let diff = IssueDiff::between("a", &[old], "b", &[new]);
+        let change = &diff.changed[0];
+        let fields: Vec<&str> = change.fields.iter().map(|f| f.field.as_str()).collect();
+
+        assert_eq!(fields, vec!["fields.severity", "status", "title"]);
+        assert_eq!(change.fields[0].new, Value::Null);
+        assert_eq!(change.labels.added, vec!["c"]);
+        assert_eq!(change.labels.removed, vec!["a"]);
+        assert_eq!(change.dependencies.added, vec!["blocks:bd-9"]);
+        assert!(change.comments.is_empty());
+    }
+}
@@ -0,0 +1,338 @@
+//! Opt-in event log export (`events.jsonl`).
+//!
+//! The audit trail normally lives only in the local database, so a fresh
+//! clone or a rebuild from `issues.jsonl` starts with empty history. With
+//! `sync.events: true` in `.beads/config.yaml`, every export appends events
+//! that are not yet in `.beads/events.jsonl`, and every import loads events
+//! that are not yet in the database.
+//!
+//! Events are identified by a hash of their content rather than the local
+//! row ID, so the file is append-only, the same event is never written
+//! twice, and concurrent branches can be combined with a union merge
+//! (`events.jsonl merge=union` in `.gitattributes`).
+
+use crate::error::{BeadsError, Result};
+use crate::model::{Event, EventType};
+use crate::storage::SqliteStorage;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// File name of the event log inside `.beads/`.
+pub const EVENTS_FILE_NAME: &str = "events.jsonl";
+
+/// One line of `events.jsonl`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventRecord {
+    /// Stable content hash, see [`event_hash`].
+    pub hash: String,
+    pub issue_id: String,
+    pub event_type: EventType,
+    pub actor: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub old_value: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub new_value: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<&Event> for EventRecord {
+    fn from(event: &Event) -> Self {
+        Self {
+            hash: event_hash(event),
+            issue_id: event.issue_id.clone(),
+            event_type: event.event_type.clone(),
+            actor: event.actor.clone(),
+            old_value: event.old_value.clone(),
+            new_value: event.new_value.clone(),
+            comment: event.comment.clone(),
+            created_at: event.created_at,
+        }
+    }
+}
+
+impl EventRecord {
+    /// Convert back to an event. The local row ID is assigned on insert.
+    #[must_use]
+    pub fn to_event(&self) -> Event {
+        Event {
+            id: 0,
+            issue_id: self.issue_id.clone(),
+            event_type: self.event_type.clone(),
+            actor: self.actor.clone(),
+            old_value: self.old_value.clone(),
+            new_value: self.new_value.clone(),
+            comment: self.comment.clone(),
+            created_at: self.created_at,
+        }
+    }
+}
+
+/// Stable hash of an event's content.
+///
+/// The local row ID is excluded, so the same event hashes identically in
+/// every clone.
+#[must_use]
+pub fn event_hash(event: &Event) -> String {
+    let mut hasher = Sha256::new();
+    for field in [
+        event.issue_id.as_str(),
+        event.event_type.as_str(),
+        event.actor.as_str(),
+        event.old_value.as_deref().unwrap_or(""),
+        event.new_value.as_deref().unwrap_or(""),
+        event.comment.as_deref().unwrap_or(""),
+        &event.created_at.to_rfc3339(),
+    ] {
+        hasher.update(field.as_bytes());
+        hasher.update([0]);
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+/// Add `events.jsonl merge=union` to the `.gitattributes` in `dir` (the
+/// directory holding the log), keeping anything the file already has.
+///
+/// Returns `None` if the line was already there, otherwise whether the file
+/// existed before.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or written.
+pub fn ensure_union_merge_attribute(dir: &Path) -> Result<Option<bool>> {
+    let path = dir.join(".gitattributes");
+    let line = format!("{EVENTS_FILE_NAME} merge=union");
+    let existing = match std::fs::read_to_string(&path) {
+        Ok(existing) => Some(existing),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+        Err(err) => return Err(err.into()),
+    };
+    let current = existing.as_deref().unwrap_or_default();
+    if current.lines().any(|existing| existing.trim() == line) {
+        return Ok(None);
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+    if !current.is_empty() && !current.ends_with('\n') {
+        writeln!(file)?;
+    }
+    writeln!(file, "{line}")?;
+    Ok(Some(existing.is_some()))
+}
+
+/// Path of the event log that accompanies a JSONL export file.
+#[must_use]
+pub fn events_path_for(jsonl_path: &Path) -> PathBuf {
+    jsonl_path.with_file_name(EVENTS_FILE_NAME)
+}
+
+/// Load event records.
+///
+/// Returns an empty list if the file does not exist.
+///
+/// # Errors
+///
+/// Returns an error if the file exists but cannot be read or parsed.
+pub fn load_events(path: &Path) -> Result<Vec<EventRecord>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let reader = BufReader::new(File::open(path)?);
+    let mut records = Vec::new();
+    for (line_num, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record: EventRecord = serde_json::from_str(&line).map_err(|e| {
+            BeadsError::Config(format!(
+                "Invalid JSON in {} at line {}: {}",
+                path.display(),
+                line_num + 1,
+                e
+            ))
+        })?;
+        records.push(record);
+    }
+    Ok(records)
+}
+
+/// Append database events missing from the event log.
+///
+/// Returns the number of events appended.
+///
+/// # Errors
+///
+/// Returns an error if the log cannot be read or written, or the database
+/// query fails.
+pub fn export_events(storage: &SqliteStorage, path: &Path) -> Result<usize> {
+    let mut known: HashSet<String> = load_events(path)?
+        .iter()
+        .map(|record| event_hash(&record.to_event()))
+        .collect();
+
+    let pending: Vec<EventRecord> = storage
+        .get_exportable_events()?
+        .iter()
+        .map(EventRecord::from)
+        .filter(|record| known.insert(record.hash.clone()))
+        .collect();
+    if pending.is_empty() {
+        return Ok(0);
+    }
+
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    let mut writer = BufWriter::new(file);
+    for record in &pending {
+        let json = serde_json::to_string(record).map_err(|e| {
+            BeadsError::Config(format!(
+                "Failed to serialize event for {}: {}",
+                record.issue_id, e
+            ))
+        })?;
+        writeln!(writer, "{json}")?;
+    }
+    writer.flush()?;
+
+    tracing::debug!(path = %path.display(), appended = pending.len(), "Appended events");
+    Ok(pending.len())
+}
+
+/// Import events from the log that the database does not have yet.
+///
+/// Returns the number of events inserted.
+///
+/// # Errors
+///
+/// Returns an error if the log cannot be read or parsed, or the database
+/// write fails.
+pub fn import_events(storage: &mut SqliteStorage, path: &Path) -> Result<usize> {
+    let records = load_events(path)?;
+    if records.is_empty() {
+        return Ok(0);
+    }
+
+    let mut known: HashSet<String> = storage.get_all_events(0)?.iter().map(event_hash).collect();
+
+    let pending: Vec<Event> = records
+        .iter()
+        .map(EventRecord::to_event)
+        .filter(|event| known.insert(event_hash(event)))
+        .collect();
+    if pending.is_empty() {
+        return Ok(0);
+    }
+
+    let inserted = storage.import_events(&pending)?;
+    tracing::debug!(path = %path.display(), inserted, "Imported events");
+    Ok(inserted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{Issue, Priority, Status};
+    use tempfile::TempDir;
+
+    fn create_issue(storage: &mut SqliteStorage, id: &str) {
+        let now = Utc::now();
+        let issue = Issue {
+            id: id.to_string(),
+            title: format!("Issue {id}"),
+            status: Status::Open,
+            priority: Priority::MEDIUM,
+            created_at: now,
+            updated_at: now,
+            ..Issue::default()
+        };
+        storage.create_issue(&issue, "alice").unwrap();
+    }
+
+    #[test]
+    fn test_events_round_trip_without_duplicates() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(EVENTS_FILE_NAME);
+
+        let mut source = SqliteStorage::open_memory().unwrap();
+        create_issue(&mut source, "bd-1");
+        let exported = export_events(&source, &path).unwrap();
+        assert!(exported > 0);
+        // Re-exporting appends nothing
+        assert_eq!(export_events(&source, &path).unwrap(), 0);
+        assert_eq!(load_events(&path).unwrap().len(), exported);
+
+        // A fresh database (e.g. rebuilt from issues.jsonl) gets the history back
+        let mut clone = SqliteStorage::open_memory().unwrap();
+        create_issue(&mut clone, "bd-1");
+        let before = clone.get_events("bd-1", 0).unwrap().len();
+        assert_eq!(import_events(&mut clone, &path).unwrap(), exported);
+        assert_eq!(import_events(&mut clone, &path).unwrap(), 0);
+        assert_eq!(
+            clone.get_events("bd-1", 0).unwrap().len(),
+            before + exported
+        );
+
+        // Events already in the log are not written again from the clone
+        let known: HashSet<String> = load_events(&path)
+            .unwrap()
+            .into_iter()
+            .map(|record| record.hash)
+            .collect();
+        let appended = export_events(&clone, &path).unwrap();
+        assert_eq!(appended, before);
+        assert_eq!(load_events(&path).unwrap().len(), known.len() + before);
+    }
+
+    #[test]
+    fn test_event_hash_ignores_local_row_id() {
+        let event = Event {
+            id: 7,
+            issue_id: "bd-1".to_string(),
+            event_type: EventType::Closed,
+            actor: "alice".to_string(),
+            old_value: None,
+            new_value: None,
+            comment: Some("done".to_string()),
+            created_at: Utc::now(),
+        };
+        let mut other = event.clone();
+        other.id = 42;
+        assert_eq!(event_hash(&event), event_hash(&other));
+
+        other.actor = "bob".to_string();
+        assert_ne!(event_hash(&event), event_hash(&other));
+    }
+
+    #[test]
+    fn test_union_merge_attribute_appends_once() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(".gitattributes");
+
+        assert_eq!(
+            ensure_union_merge_attribute(temp.path()).unwrap(),
+            Some(false)
+        );
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "events.jsonl merge=union\n"
+        );
+
+        std::fs::write(&path, "issues.jsonl merge=beads").unwrap();
+        assert_eq!(
+            ensure_union_merge_attribute(temp.path()).unwrap(),
+            Some(true)
+        );
+        assert_eq!(ensure_union_merge_attribute(temp.path()).unwrap(), None);
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "issues.jsonl merge=beads\nevents.jsonl merge=union\n"
+        );
+    }
+}
@@ -0,0 +1,221 @@
+//! Git merge driver for `issues.jsonl`.
+//!
+//! Git calls `br merge-driver %O %A %B` with the common ancestor, our version
+//! and their version of the file. The three versions are merged issue by
+//! issue with [`three_way_merge`] and the result is written back to `%A`,
+//! sorted by ID. Issues that cannot be merged are written between ordinary
+//! conflict markers, so the file is still rejected on import (see
+//! [`scan_conflict_markers`]) until someone picks a side.
+//!
+//! The driver only reads and writes the three paths it is given. It never
+//! runs git and never opens the database.
+//!
+//! [`scan_conflict_markers`]: super::scan_conflict_markers
+
+use super::{
+    CONFLICT_END, CONFLICT_SEPARATOR, CONFLICT_START, ConflictResolution, MergeContext,
+    read_issues_from_jsonl, three_way_merge,
+};
+use crate::error::{BeadsError, Result};
+use crate::model::Issue;
+use crate::util::content_hash;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as FmtWrite;
+use std::fs::{self, File};
+use std::io::Write as IoWrite;
+use std::path::Path;
+
+/// Name of the driver in `.gitattributes` and git config.
+pub const MERGE_DRIVER_NAME: &str = "beads";
+
+/// Outcome of one driver run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DriverOutcome {
+    /// Issues written to the merged file (conflicts excluded).
+    pub merged: usize,
+    /// IDs written between conflict markers.
+    pub conflicts: Vec<String>,
+}
+
+/// Merge `base`, `ours` and `theirs`, writing the result to `ours`.
+///
+/// # Errors
+///
+/// Returns an error if any side cannot be parsed or the result cannot be
+/// written. `ours` is left untouched in that case.
+pub fn merge_files(base: &Path, ours: &Path, theirs: &Path) -> Result<DriverOutcome> {
+    let context = MergeContext::new(load_side(base)?, load_side(ours)?, load_side(theirs)?);
+    let (merged, outcome) = merge_contents(&context)?;
+    write_replacing(ours, &merged)?;
+    Ok(outcome)
+}
+
+/// Replace `path` with `contents` through a temp file in the same directory,
+/// so an interrupted driver never leaves a truncated `%A` for git to pick up.
+fn write_replacing(path: &Path, contents: &str) -> Result<()> {
+    let mut temp_name = path.file_name().unwrap_or_default().to_os_string();
+    temp_name.push(".br-merge.tmp");
+    let temp_path = path.with_file_name(temp_name);
+
+    let write = || -> Result<()> {
+        let mut file = File::create(&temp_path)?;
+        file.write_all(contents.as_bytes())?;
+        file.sync_all()?;
+        if let Ok(metadata) = fs::metadata(path) {
+            fs::set_permissions(&temp_path, metadata.permissions())?;
+        }
+        fs::rename(&temp_path, path)?;
+        Ok(())
+    };
+    write().inspect_err(|_| {
+        let _ = fs::remove_file(&temp_path);
+    })
+}
+
+/// Merge three parsed sides into JSONL text.
+///
+/// Conflicting issues keep their full local and external versions so nothing
+/// is lost; the merge never picks a winner for them.
+///
+/// # Errors
+///
+/// Returns an error if an issue cannot be serialized.
+pub fn merge_contents(context: &MergeContext) -> Result<(String, DriverOutcome)> {
+    let report = three_way_merge(context, ConflictResolution::Manual, None);
+
+    // Kept issues and conflict blocks, keyed by ID for a stable order
+    let mut blocks: BTreeMap<String, String> = BTreeMap::new();
+    for issue in &report.kept {
+        blocks.insert(issue.id.clone(), format!("{}\n", to_line(issue)?));
+    }
+    let mut outcome = DriverOutcome {
+        merged: blocks.len(),
+        conflicts: Vec::new(),
+    };
+    for (id, _) in &report.conflicts {
+        let mut block = String::new();
+        let _ = writeln!(block, "{CONFLICT_START} ours");
+        if let Some(issue) = context.left.get(id) {
+            let _ = writeln!(block, "{}", to_line(issue)?);
+        }
+        let _ = writeln!(block, "{CONFLICT_SEPARATOR}");
+        if let Some(issue) = context.right.get(id) {
+            let _ = writeln!(block, "{}", to_line(issue)?);
+        }
+        let _ = writeln!(block, "{CONFLICT_END} theirs");
+        blocks.insert(id.clone(), block);
+        outcome.conflicts.push(id.clone());
+    }
+    outcome.conflicts.sort();
+
+    Ok((blocks.into_values().collect(), outcome))
+}
+
+/// Read one side. An empty file (e.g. a missing ancestor) is an empty side.
+fn load_side(path: &Path) -> Result<HashMap<String, Issue>> {
+    let issues = read_issues_from_jsonl(path).map_err(|err| {
+        BeadsError::Config(format!(
+            "merge-driver: cannot read {}: {err}",
+            path.display()
+        ))
+    })?;
+    Ok(issues
+        .into_iter()
+        .map(|mut issue| {
+            // Convergent creations are matched on content, which JSONL omits
+            issue.content_hash = Some(content_hash(&issue));
+            (issue.id.clone(), issue)
+        })
+        .collect())
+}
+
+fn to_line(issue: &Issue) -> Result<String> {
+    serde_json::to_string(issue)
+        .map_err(|e| BeadsError::Config(format!("Failed to serialize issue {}: {}", issue.id, e)))
+}
+
+/// The `.gitattributes` line and git config that enable the driver.
+#[must_use]
+pub fn setup_snippet() -> (String, String) {
+    let attributes = format!("issues.jsonl merge={MERGE_DRIVER_NAME}");
+    let config = format!(
+        "[merge \"{MERGE_DRIVER_NAME}\"]\n\
+         \tname = beads issue-level JSONL merge\n\
+         \tdriver = br merge-driver %O %A %B"
+    );
+    (attributes, config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::Status;
+    use chrono::{Duration, Utc};
+
+    fn issue(id: &str, title: &str) -> Issue {
+        let now = Utc::now();
+        let mut issue = Issue {
+            id: id.to_string(),
+            title: title.to_string(),
+            created_at: now,
+            updated_at: now,
+            ..Issue::default()
+        };
+        issue.content_hash = Some(content_hash(&issue));
+        issue
+    }
+
+    fn side(issues: &[&Issue]) -> HashMap<String, Issue> {
+        issues
+            .iter()
+            .map(|issue| (issue.id.clone(), (*issue).clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_merge_contents_combines_independent_edits() {
+        let base = issue("bd-1", "Shared");
+        let mut ours = base.clone();
+        ours.status = Status::InProgress;
+        ours.updated_at = base.updated_at + Duration::minutes(1);
+        let mut theirs = base.clone();
+        theirs.priority = crate::model::Priority::CRITICAL;
+        theirs.updated_at = base.updated_at + Duration::minutes(2);
+        let added = issue("bd-0", "Theirs only");
+
+        let context = MergeContext::new(side(&[&base]), side(&[&ours]), side(&[&theirs, &added]));
+        let (text, outcome) = merge_contents(&context).expect("merge");
+
+        assert!(outcome.conflicts.is_empty());
+        assert_eq!(outcome.merged, 2);
+        let merged: Vec<Issue> = text
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("json line"))
+            .collect();
+        assert_eq!(merged[0].id, "bd-0");
+        assert_eq!(merged[1].status, Status::InProgress);
+        assert_eq!(merged[1].priority, crate::model::Priority::CRITICAL);
+    }
+
+    #[test]
+    fn test_merge_contents_marks_true_conflicts() {
+        let base = issue("bd-1", "Shared");
+        let mut ours = base.clone();
+        ours.title = "Ours".to_string();
+        ours.updated_at = base.updated_at + Duration::minutes(1);
+        let mut theirs = base.clone();
+        theirs.title = "Theirs".to_string();
+        theirs.updated_at = base.updated_at + Duration::minutes(2);
+
+        let context = MergeContext::new(side(&[&base]), side(&[&ours]), side(&[&theirs]));
+        let (text, outcome) = merge_contents(&context).expect("merge");
+
+        assert_eq!(outcome.conflicts, vec!["bd-1"]);
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], "<<<<<<< ours");
+        assert!(lines[1].contains("\"Ours\""));
+        assert_eq!(lines[2], "=======");
+        assert!(lines[3].contains("\"Theirs\""));
+        assert_eq!(lines[4], ">>>>>>> theirs");
+    }
+}
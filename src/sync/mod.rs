@@ -6,8 +6,14 @@
 //! - Dirty tracking for incremental exports
 //! - Collision detection during imports
 //! - Path validation and allowlist enforcement
+//! - Point-in-time reconstruction for `--as-of` queries
 
+pub mod as_of;
+pub mod conflicts;
+pub mod diff;
+pub mod events;
 pub mod history;
+pub mod merge_driver;
 pub mod path;
 
 pub use path::{
@@ -52,6 +58,8 @@ pub struct ExportConfig {
     pub show_progress: bool,
     /// Configuration for history backups.
     pub history: HistoryConfig,
+    /// Also append the audit log to `events.jsonl` next to the output file.
+    pub export_events: bool,
 }
 
 /// Export error handling policy.
@@ -105,6 +113,7 @@ pub enum ExportEntityType {
     Dependency,
     Label,
     Comment,
+    Field,
 }
 
 /// Export error record.
@@ -238,6 +247,8 @@ pub struct ExportResult {
     pub output_path: Option<String>,
     /// Per-issue content hashes (`issue_id`, `content_hash`) for incremental export tracking.
     pub issue_hashes: Vec<(String, String)>,
+    /// Event log that `finalize_export` appends to (None unless events export is enabled).
+    pub events_path: Option<PathBuf>,
 }
 
 /// Configuration for JSONL import.
@@ -262,6 +273,8 @@ pub struct ImportConfig {
     pub allow_external_jsonl: bool,
     /// Show progress indicators for long-running operations.
     pub show_progress: bool,
+    /// Also import `events.jsonl` next to the input file, if present.
+    pub import_events: bool,
 }
 
 impl Default for ImportConfig {
@@ -275,6 +288,7 @@ impl Default for ImportConfig {
             beads_dir: None,
             allow_external_jsonl: false,
             show_progress: false,
+            import_events: false,
         }
     }
 }
@@ -303,6 +317,8 @@ pub struct ImportResult {
     pub tombstone_skipped: usize,
     /// Conflict markers detected (if any).
     pub conflict_markers: Vec<ConflictMarker>,
+    /// Number of events imported from `events.jsonl`.
+    pub events_imported: usize,
 }
 
 // ============================================================================
@@ -1207,6 +1223,17 @@ pub fn export_to_jsonl_with_policy(
             None
         }
     };
+    let all_fields = match storage.get_all_fields() {
+        Ok(map) => Some(map),
+        Err(err) => {
+            ctx.handle_error(ExportError::new(
+                ExportEntityType::Field,
+                "all",
+                err.to_string(),
+            ))?;
+            None
+        }
+    };
 
     for issue in &mut issues {
         if let Some(deps) = all_deps.as_ref().and_then(|map| map.get(&issue.id)) {
@@ -1224,6 +1251,11 @@ pub fn export_to_jsonl_with_policy(
         } else {
             issue.comments.clear();
         }
+        if let Some(fields) = all_fields.as_ref().and_then(|map| map.get(&issue.id)) {
+            issue.fields = fields.clone();
+        } else {
+            issue.fields.clear();
+        }
     }
 
     // Write to temp file for atomic rename
@@ -1357,6 +1389,16 @@ pub fn export_to_jsonl_with_policy(
         )));
     }
 
+    let events_path = if config.export_events {
+        let path = events::events_path_for(output_path);
+        if let Some(ref beads_dir) = config.beads_dir {
+            validate_sync_path_with_external(&path, beads_dir, config.allow_external_jsonl)?;
+        }
+        Some(path)
+    } else {
+        None
+    };
+
     let result = ExportResult {
         exported_count: exported_ids.len(),
         exported_ids,
@@ -1364,6 +1406,7 @@ pub fn export_to_jsonl_with_policy(
         content_hash,
         output_path: Some(output_path.to_string_lossy().to_string()),
         issue_hashes,
+        events_path,
     };
 
     report.errors = ctx.errors;
@@ -1431,6 +1474,17 @@ pub fn export_to_writer_with_policy<W: Write>(
             None
         }
     };
+    let all_fields = match storage.get_all_fields() {
+        Ok(map) => Some(map),
+        Err(err) => {
+            ctx.handle_error(ExportError::new(
+                ExportEntityType::Field,
+                "all",
+                err.to_string(),
+            ))?;
+            None
+        }
+    };
 
     for issue in &mut issues {
         if let Some(deps) = all_deps.as_ref().and_then(|map| map.get(&issue.id)) {
@@ -1448,6 +1502,11 @@ pub fn export_to_writer_with_policy<W: Write>(
         } else {
             issue.comments.clear();
         }
+        if let Some(fields) = all_fields.as_ref().and_then(|map| map.get(&issue.id)) {
+            issue.fields = fields.clone();
+        } else {
+            issue.fields.clear();
+        }
     }
 
     let mut hasher = Sha256::new();
@@ -1501,6 +1560,7 @@ pub fn export_to_writer_with_policy<W: Write>(
         content_hash,
         output_path: None,
         issue_hashes,
+        events_path: None,
     };
 
     report.errors = ctx.errors;
@@ -1620,6 +1680,7 @@ pub fn auto_import_if_stale(
         beads_dir: Some(beads_dir.to_path_buf()),
         allow_external_jsonl: false,
         show_progress: false,
+        import_events: crate::config::sync_events_enabled(beads_dir),
         ..Default::default()
     };
 
@@ -1645,6 +1706,7 @@ pub fn auto_import_if_stale(
 /// - Records export hashes for each exported issue (for incremental export)
 /// - Updates `jsonl_content_hash` metadata with the export hash
 /// - Updates `last_export_time` metadata with the current timestamp
+/// - Appends new events to `events.jsonl` when events export is enabled
 ///
 /// # Errors
 ///
@@ -1674,6 +1736,13 @@ pub fn finalize_export(
     storage.set_metadata(METADATA_JSONL_CONTENT_HASH, &result.content_hash)?;
     storage.set_metadata(METADATA_LAST_EXPORT_TIME, &Utc::now().to_rfc3339())?;
 
+    if let Some(ref events_path) = result.events_path {
+        events::export_events(storage, events_path)?;
+        if let Some(dir) = events_path.parent() {
+            events::ensure_union_merge_attribute(dir)?;
+        }
+    }
+
     Ok(())
 }
 
@@ -1723,6 +1792,7 @@ pub fn auto_flush(storage: &mut SqliteStorage, beads_dir: &Path) -> Result<AutoF
     let export_config = ExportConfig {
         force: false,
         beads_dir: Some(beads_dir.to_path_buf()),
+        export_events: crate::config::sync_events_enabled(beads_dir),
         ..Default::default()
     };
 
@@ -2231,8 +2301,24 @@ pub fn import_from_jsonl(
         storage.set_export_hashes(&new_export_hashes)?;
     }
 
-    // Step 10: Refresh blocked cache
+    // Step 10: Refresh blocked cache and full-text search index
     storage.rebuild_blocked_cache(true)?;
+    storage.rebuild_search_index()?;
+
+    // Step 10.5: Load the opt-in event log (after issues, so events can attach)
+    if config.import_events {
+        let events_path = events::events_path_for(input_path);
+        if events_path.is_file() {
+            if let Some(ref beads_dir) = config.beads_dir {
+                validate_sync_path_with_external(
+                    &events_path,
+                    beads_dir,
+                    config.allow_external_jsonl,
+                )?;
+            }
+            result.events_imported = events::import_events(storage, &events_path)?;
+        }
+    }
 
     // Step 11: Update metadata
     storage.set_metadata(METADATA_LAST_IMPORT_TIME, &chrono::Utc::now().to_rfc3339())?;
@@ -2280,7 +2366,7 @@ fn process_import_action(
     Ok(())
 }
 
-/// Sync labels, dependencies, and comments for an imported issue.
+/// Sync labels, dependencies, comments, and custom fields for an imported issue.
 fn sync_issue_relations(storage: &mut SqliteStorage, issue: &Issue) -> Result<()> {
     // Sync labels
     storage.sync_labels_for_import(&issue.id, &issue.labels)?;
@@ -2291,6 +2377,9 @@ fn sync_issue_relations(storage: &mut SqliteStorage, issue: &Issue) -> Result<()
     // Sync comments
     storage.sync_comments_for_import(&issue.id, &issue.comments)?;
 
+    // Sync custom fields
+    storage.sync_fields_for_import(&issue.id, &issue.fields)?;
+
     Ok(())
 }
 
@@ -2324,12 +2413,62 @@ pub fn compute_jsonl_hash(path: &Path) -> Result<String> {
 // ============================================================================
 
 /// Types of conflicts that can occur during 3-way merge.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ConflictType {
     /// Issue was modified locally but deleted externally (or vice versa).
     DeleteVsModify,
     /// Issue was created in both local and external with different content.
     ConvergentCreation,
+    /// The same field was changed to different values on both sides.
+    FieldConflict,
+}
+
+/// How a single field was resolved when both sides modified an issue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldDecisionKind {
+    /// Only the local side changed the field.
+    TookLocal,
+    /// Only the external side changed the field.
+    TookExternal,
+    /// Both sides made the identical change.
+    Converged,
+    /// Both sides changed a set (labels, dependencies, comments) and the changes were combined.
+    Combined,
+    /// Both sides changed the field differently; the local value won per strategy.
+    ResolvedLocal,
+    /// Both sides changed the field differently; the external value won per strategy.
+    ResolvedExternal,
+    /// Both sides changed the field differently and no strategy could pick a winner.
+    Conflict,
+}
+
+impl FieldDecisionKind {
+    /// Stable string form (matches the JSON representation).
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::TookLocal => "took_local",
+            Self::TookExternal => "took_external",
+            Self::Converged => "converged",
+            Self::Combined => "combined",
+            Self::ResolvedLocal => "resolved_local",
+            Self::ResolvedExternal => "resolved_external",
+            Self::Conflict => "conflict",
+        }
+    }
+}
+
+/// Per-field outcome of merging an issue that was modified on both sides.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldDecision {
+    /// Issue the field belongs to.
+    pub issue_id: String,
+    /// Field name as it appears in JSONL (groups such as `status` cover related fields).
+    pub field: String,
+    /// How the field was resolved.
+    pub decision: FieldDecisionKind,
 }
 
 /// Result of merging a single issue across base, left (local), and right (external).
@@ -2394,6 +2533,8 @@ pub struct MergeReport {
     pub tombstone_protected: Vec<String>,
     /// Notes about merge decisions.
     pub notes: Vec<(String, String)>,
+    /// Per-field decisions for issues modified on both sides.
+    pub field_decisions: Vec<FieldDecision>,
 }
 
 impl MergeReport {
@@ -2430,7 +2571,8 @@ pub enum ConflictResolution {
 /// - New local issues are kept
 /// - New external issues are imported
 /// - Deletions are handled based on whether the other side modified
-/// - Both-modified uses `updated_at` as tiebreaker (or specified strategy)
+/// - Both-modified is merged field by field; only fields changed differently on
+///   both sides fall back to the strategy (see [`merge_issue_with_decisions`])
 ///
 /// # Arguments
 /// * `base` - The issue in the base (common ancestor) state, if it existed
@@ -2513,57 +2655,7 @@ pub fn merge_issue(
         }
 
         // Case 6: In all three (potentially modified in one or both)
-        (Some(b), Some(l), Some(r)) => {
-            let left_changed = l.content_hash != b.content_hash;
-            let right_changed = r.content_hash != b.content_hash;
-
-            match (left_changed, right_changed) {
-                // Neither changed OR only left changed - keep left
-                (false | true, false) => MergeResult::Keep(l.clone()),
-                // Only right changed - keep right
-                (false, true) => MergeResult::Keep(r.clone()),
-                // Both changed - use strategy
-                (true, true) => match strategy {
-                    ConflictResolution::PreferLocal => MergeResult::KeepWithNote(
-                        l.clone(),
-                        "Both modified - kept local".to_string(),
-                    ),
-                    ConflictResolution::PreferExternal => MergeResult::KeepWithNote(
-                        r.clone(),
-                        "Both modified - kept external".to_string(),
-                    ),
-                    ConflictResolution::PreferNewer => {
-                        if l.updated_at >= r.updated_at {
-                            MergeResult::KeepWithNote(
-                                l.clone(),
-                                "Both modified - kept local (newer)".to_string(),
-                            )
-                        } else {
-                            MergeResult::KeepWithNote(
-                                r.clone(),
-                                "Both modified - kept external (newer)".to_string(),
-                            )
-                        }
-                    }
-                    ConflictResolution::Manual => {
-                        // For manual, we still need to pick one, so use newer but mark as note
-                        if l.updated_at >= r.updated_at {
-                            MergeResult::KeepWithNote(
-                                l.clone(),
-                                "Both modified - kept local (newer), review recommended"
-                                    .to_string(),
-                            )
-                        } else {
-                            MergeResult::KeepWithNote(
-                                r.clone(),
-                                "Both modified - kept external (newer), review recommended"
-                                    .to_string(),
-                            )
-                        }
-                    }
-                },
-            }
-        }
+        (Some(b), Some(l), Some(r)) => merge_fields(b, l, r, strategy).0,
 
         // Case 7: In left and right but not base (convergent creation)
         (None, Some(l), Some(r)) => {
@@ -2603,10 +2695,461 @@ pub fn merge_issue(
     }
 }
 
+/// Merge a single issue and report per-field decisions.
+///
+/// Behaves exactly like [`merge_issue`]; when the issue exists in base, left and
+/// right and both sides changed it, the returned decisions describe how each
+/// modified field was resolved. Other cases return no decisions.
+#[must_use]
+pub fn merge_issue_with_decisions(
+    base: Option<&Issue>,
+    left: Option<&Issue>,
+    right: Option<&Issue>,
+    strategy: ConflictResolution,
+) -> (MergeResult, Vec<FieldDecision>) {
+    match (base, left, right) {
+        (Some(b), Some(l), Some(r)) => merge_fields(b, l, r, strategy),
+        _ => (merge_issue(base, left, right, strategy), Vec::new()),
+    }
+}
+
+/// A mergeable field, or a group of fields that must move together.
+struct MergeField {
+    name: &'static str,
+    same: fn(&Issue, &Issue) -> bool,
+    take: fn(&mut Issue, &Issue),
+}
+
+/// Scalar fields compared during a field-level merge.
+///
+/// `status` carries its closure and tombstone metadata so a merged issue can never
+/// end up closed without `closed_at` (or tombstoned without `deleted_at`).
+/// `created_at`/`updated_at` only track edits and are handled by `merge_fields`.
+const MERGE_FIELDS: &[MergeField] = &[
+    MergeField {
+        name: "title",
+        same: |a, b| a.title == b.title,
+        take: |m, s| m.title.clone_from(&s.title),
+    },
+    MergeField {
+        name: "description",
+        same: |a, b| a.description == b.description,
+        take: |m, s| m.description.clone_from(&s.description),
+    },
+    MergeField {
+        name: "design",
+        same: |a, b| a.design == b.design,
+        take: |m, s| m.design.clone_from(&s.design),
+    },
+    MergeField {
+        name: "acceptance_criteria",
+        same: |a, b| a.acceptance_criteria == b.acceptance_criteria,
+        take: |m, s| m.acceptance_criteria.clone_from(&s.acceptance_criteria),
+    },
+    MergeField {
+        name: "notes",
+        same: |a, b| a.notes == b.notes,
+        take: |m, s| m.notes.clone_from(&s.notes),
+    },
+    MergeField {
+        name: "status",
+        same: |a, b| {
+            a.status == b.status
+                && a.closed_at == b.closed_at
+                && a.close_reason == b.close_reason
+                && a.close_outcome == b.close_outcome
+                && a.closed_by_session == b.closed_by_session
+                && a.deleted_at == b.deleted_at
+                && a.deleted_by == b.deleted_by
+                && a.delete_reason == b.delete_reason
+                && a.original_type == b.original_type
+        },
+        take: |m, s| {
+            m.status.clone_from(&s.status);
+            m.closed_at = s.closed_at;
+            m.close_reason.clone_from(&s.close_reason);
+            m.close_outcome = s.close_outcome;
+            m.closed_by_session.clone_from(&s.closed_by_session);
+            m.deleted_at = s.deleted_at;
+            m.deleted_by.clone_from(&s.deleted_by);
+            m.delete_reason.clone_from(&s.delete_reason);
+            m.original_type.clone_from(&s.original_type);
+        },
+    },
+    MergeField {
+        name: "priority",
+        same: |a, b| a.priority == b.priority,
+        take: |m, s| m.priority = s.priority,
+    },
+    MergeField {
+        name: "issue_type",
+        same: |a, b| a.issue_type == b.issue_type,
+        take: |m, s| m.issue_type.clone_from(&s.issue_type),
+    },
+    MergeField {
+        name: "assignee",
+        same: |a, b| a.assignee == b.assignee,
+        take: |m, s| m.assignee.clone_from(&s.assignee),
+    },
+    MergeField {
+        name: "owner",
+        same: |a, b| a.owner == b.owner,
+        take: |m, s| m.owner.clone_from(&s.owner),
+    },
+    MergeField {
+        name: "estimated_minutes",
+        same: |a, b| a.estimated_minutes == b.estimated_minutes,
+        take: |m, s| m.estimated_minutes = s.estimated_minutes,
+    },
+    MergeField {
+        name: "created_by",
+        same: |a, b| a.created_by == b.created_by,
+        take: |m, s| m.created_by.clone_from(&s.created_by),
+    },
+    MergeField {
+        name: "due_at",
+        same: |a, b| a.due_at == b.due_at,
+        take: |m, s| m.due_at = s.due_at,
+    },
+    MergeField {
+        name: "defer_until",
+        same: |a, b| a.defer_until == b.defer_until,
+        take: |m, s| m.defer_until = s.defer_until,
+    },
+    MergeField {
+        name: "external_ref",
+        same: |a, b| a.external_ref == b.external_ref,
+        take: |m, s| m.external_ref.clone_from(&s.external_ref),
+    },
+    MergeField {
+        name: "source_system",
+        same: |a, b| a.source_system == b.source_system,
+        take: |m, s| m.source_system.clone_from(&s.source_system),
+    },
+    MergeField {
+        name: "source_repo",
+        same: |a, b| a.source_repo == b.source_repo,
+        take: |m, s| m.source_repo.clone_from(&s.source_repo),
+    },
+    MergeField {
+        name: "compaction",
+        same: |a, b| {
+            a.compaction_level == b.compaction_level
+                && a.compacted_at == b.compacted_at
+                && a.compacted_at_commit == b.compacted_at_commit
+                && a.original_size == b.original_size
+        },
+        take: |m, s| {
+            m.compaction_level = s.compaction_level;
+            m.compacted_at = s.compacted_at;
+            m.compacted_at_commit.clone_from(&s.compacted_at_commit);
+            m.original_size = s.original_size;
+        },
+    },
+    MergeField {
+        name: "sender",
+        same: |a, b| a.sender == b.sender,
+        take: |m, s| m.sender.clone_from(&s.sender),
+    },
+    MergeField {
+        name: "ephemeral",
+        same: |a, b| a.ephemeral == b.ephemeral,
+        take: |m, s| m.ephemeral = s.ephemeral,
+    },
+    MergeField {
+        name: "pinned",
+        same: |a, b| a.pinned == b.pinned,
+        take: |m, s| m.pinned = s.pinned,
+    },
+    MergeField {
+        name: "is_template",
+        same: |a, b| a.is_template == b.is_template,
+        take: |m, s| m.is_template = s.is_template,
+    },
+];
+
+/// Pick a winner for a field both sides changed to different values.
+fn resolve_overlap(strategy: ConflictResolution, left: &Issue, right: &Issue) -> FieldDecisionKind {
+    match strategy {
+        ConflictResolution::PreferLocal => FieldDecisionKind::ResolvedLocal,
+        ConflictResolution::PreferExternal => FieldDecisionKind::ResolvedExternal,
+        ConflictResolution::PreferNewer => {
+            if left.updated_at >= right.updated_at {
+                FieldDecisionKind::ResolvedLocal
+            } else {
+                FieldDecisionKind::ResolvedExternal
+            }
+        }
+        ConflictResolution::Manual => FieldDecisionKind::Conflict,
+    }
+}
+
+/// Three-way merge of a keyed collection (labels, dependencies, comments).
+///
+/// Each key is merged on its own: an addition, removal or change made on one
+/// side is taken, and identical changes converge. A key changed differently
+/// on both sides (a dependency retyped on one side and retyped otherwise or
+/// removed on the other) is an overlap, decided by `overlap` as for a scalar
+/// field. Returns `None` as the decision when neither side touched the
+/// collection.
+fn merge_keyed<T: Clone, K: Ord, V: PartialEq>(
+    base: &[T],
+    left: &[T],
+    right: &[T],
+    key: fn(&T) -> K,
+    value: fn(&T) -> V,
+    overlap: FieldDecisionKind,
+) -> (Vec<T>, Option<FieldDecisionKind>) {
+    let index = |items: &[T]| -> std::collections::BTreeMap<K, V> {
+        items.iter().map(|item| (key(item), value(item))).collect()
+    };
+    let base_map = index(base);
+    let left_map = index(left);
+    let right_map = index(right);
+
+    match (left_map != base_map, right_map != base_map) {
+        (false, false) => return (left.to_vec(), None),
+        (true, false) => return (left.to_vec(), Some(FieldDecisionKind::TookLocal)),
+        (false, true) => return (right.to_vec(), Some(FieldDecisionKind::TookExternal)),
+        (true, true) if left_map == right_map => {
+            return (left.to_vec(), Some(FieldDecisionKind::Converged));
+        }
+        (true, true) => {}
+    }
+
+    // Left items in left order, then right-only items in right order
+    let mut overlapped = false;
+    let mut merged = Vec::new();
+    for item in left {
+        let k = key(item);
+        let (b, l, r) = (base_map.get(&k), left_map.get(&k), right_map.get(&k));
+        let take_right = match (l != b, r != b) {
+            (_, false) => false,
+            (false, true) => true,
+            (true, true) if l == r => false,
+            (true, true) => {
+                overlapped = true;
+                overlap == FieldDecisionKind::ResolvedExternal
+            }
+        };
+        if !take_right {
+            merged.push(item.clone());
+        } else if let Some(right_item) = right.iter().find(|other| key(other) == k) {
+            merged.push(right_item.clone());
+        }
+    }
+    for item in right {
+        let k = key(item);
+        if left_map.contains_key(&k) {
+            continue;
+        }
+        let (b, r) = (base_map.get(&k), right_map.get(&k));
+        match b {
+            // Added on the right only
+            None => merged.push(item.clone()),
+            // Removed on the left; a right-side change makes it an overlap
+            Some(b) if Some(b) != r => {
+                overlapped = true;
+                if overlap == FieldDecisionKind::ResolvedExternal {
+                    merged.push(item.clone());
+                }
+            }
+            Some(_) => {}
+        }
+    }
+
+    let decision = if overlapped {
+        overlap
+    } else {
+        FieldDecisionKind::Combined
+    };
+    (merged, Some(decision))
+}
+
+/// Field-level merge of an issue present in base, left and right.
+///
+/// Each field is diffed against base: a change on one side is taken as-is,
+/// identical changes converge, and label/dependency/comment sets are unioned
+/// (honouring removals). Only a field changed differently on both sides is
+/// resolved by `strategy`; with `Manual` it becomes a `FieldConflict`.
+#[allow(clippy::too_many_lines)]
+fn merge_fields(
+    base: &Issue,
+    left: &Issue,
+    right: &Issue,
+    strategy: ConflictResolution,
+) -> (MergeResult, Vec<FieldDecision>) {
+    let mut merged = left.clone();
+    let mut decisions: Vec<(String, FieldDecisionKind)> = Vec::new();
+
+    for field in MERGE_FIELDS {
+        let left_changed = !(field.same)(base, left);
+        let right_changed = !(field.same)(base, right);
+        let decision = match (left_changed, right_changed) {
+            (false, false) => continue,
+            (true, false) => FieldDecisionKind::TookLocal,
+            (false, true) => {
+                (field.take)(&mut merged, right);
+                FieldDecisionKind::TookExternal
+            }
+            (true, true) if (field.same)(left, right) => FieldDecisionKind::Converged,
+            (true, true) => {
+                let decision = resolve_overlap(strategy, left, right);
+                if decision == FieldDecisionKind::ResolvedExternal {
+                    (field.take)(&mut merged, right);
+                }
+                decision
+            }
+        };
+        decisions.push((field.name.to_string(), decision));
+    }
+
+    // Custom fields merge one by one, like the built-in scalar fields
+    let names: std::collections::BTreeSet<&String> = base
+        .fields
+        .keys()
+        .chain(left.fields.keys())
+        .chain(right.fields.keys())
+        .collect();
+    for name in names {
+        let base_value = base.fields.get(name);
+        let left_value = left.fields.get(name);
+        let right_value = right.fields.get(name);
+        let take_right = |merged: &mut Issue| match right_value {
+            Some(value) => {
+                merged.fields.insert(name.clone(), value.clone());
+            }
+            None => {
+                merged.fields.remove(name);
+            }
+        };
+        let decision = match (left_value != base_value, right_value != base_value) {
+            (false, false) => continue,
+            (true, false) => FieldDecisionKind::TookLocal,
+            (false, true) => {
+                take_right(&mut merged);
+                FieldDecisionKind::TookExternal
+            }
+            (true, true) if left_value == right_value => FieldDecisionKind::Converged,
+            (true, true) => {
+                let decision = resolve_overlap(strategy, left, right);
+                if decision == FieldDecisionKind::ResolvedExternal {
+                    take_right(&mut merged);
+                }
+                decision
+            }
+        };
+        decisions.push((format!("fields.{name}"), decision));
+    }
+
+    let overlap = resolve_overlap(strategy, left, right);
+    let (labels, decision) = merge_keyed(
+        &base.labels,
+        &left.labels,
+        &right.labels,
+        String::clone,
+        |_| (),
+        overlap,
+    );
+    merged.labels = labels;
+    if let Some(decision) = decision {
+        decisions.push(("labels".to_string(), decision));
+    }
+
+    let (dependencies, decision) = merge_keyed(
+        &base.dependencies,
+        &left.dependencies,
+        &right.dependencies,
+        |dep| dep.depends_on_id.clone(),
+        |dep| (dep.dep_type.clone(), dep.metadata.clone()),
+        overlap,
+    );
+    merged.dependencies = dependencies;
+    if let Some(decision) = decision {
+        decisions.push(("dependencies".to_string(), decision));
+    }
+
+    let (comments, decision) = merge_keyed(
+        &base.comments,
+        &left.comments,
+        &right.comments,
+        |comment| {
+            (
+                comment.author.clone(),
+                comment.body.clone(),
+                comment.created_at,
+            )
+        },
+        |_| (),
+        overlap,
+    );
+    merged.comments = comments;
+    if let Some(decision) = decision {
+        decisions.push(("comments".to_string(), decision));
+    }
+
+    let left_changed = decisions
+        .iter()
+        .any(|(_, d)| *d != FieldDecisionKind::TookExternal);
+    let right_changed = decisions
+        .iter()
+        .any(|(_, d)| *d != FieldDecisionKind::TookLocal);
+
+    // One-sided (or no) changes: take that side verbatim.
+    if !right_changed {
+        return (MergeResult::Keep(left.clone()), Vec::new());
+    }
+    if !left_changed {
+        return (MergeResult::Keep(right.clone()), Vec::new());
+    }
+
+    let decisions: Vec<FieldDecision> = decisions
+        .into_iter()
+        .map(|(field, decision)| FieldDecision {
+            issue_id: left.id.clone(),
+            field,
+            decision,
+        })
+        .collect();
+
+    if decisions
+        .iter()
+        .any(|d| d.decision == FieldDecisionKind::Conflict)
+    {
+        return (
+            MergeResult::Conflict(ConflictType::FieldConflict),
+            decisions,
+        );
+    }
+
+    merged.labels.sort();
+    merged.labels.dedup();
+    merged.comments.sort_by_key(|comment| comment.created_at);
+    merged.updated_at = left.updated_at.max(right.updated_at);
+    merged.content_hash = Some(merged.compute_content_hash());
+
+    let resolved: Vec<String> = decisions
+        .iter()
+        .filter_map(|d| match d.decision {
+            FieldDecisionKind::ResolvedLocal => Some(format!("{} kept local", d.field)),
+            FieldDecisionKind::ResolvedExternal => Some(format!("{} kept external", d.field)),
+            _ => None,
+        })
+        .collect();
+    let mut note = format!("Both modified - merged {} field(s)", decisions.len());
+    if !resolved.is_empty() {
+        note.push_str("; ");
+        note.push_str(&resolved.join(", "));
+    }
+
+    (MergeResult::KeepWithNote(merged, note), decisions)
+}
+
 /// Perform a 3-way merge across all issues in the context.
 ///
 /// This iterates through all unique issue IDs across base, left, and right,
-/// and calls `merge_issue` for each to determine the appropriate action.
+/// and calls `merge_issue_with_decisions` for each to determine the appropriate
+/// action, collecting per-field decisions into the report.
 ///
 /// # Arguments
 /// * `context` - The merge context containing base, left, and right states
@@ -2640,7 +3183,8 @@ pub fn three_way_merge(
             }
         }
 
-        let result = merge_issue(base, left, right, strategy);
+        let (result, decisions) = merge_issue_with_decisions(base, left, right, strategy);
+        report.field_decisions.extend(decisions);
 
         match result {
             MergeResult::NoAction => {}
@@ -2736,9 +3280,10 @@ pub fn load_base_snapshot(jsonl_dir: &Path) -> Result<std::collections::HashMap<
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::model::{Issue, IssueType, Priority, Status};
+    use crate::model::{Dependency, DependencyType, Issue, IssueType, Priority, Status};
     use chrono::Utc;
     use indicatif::{ProgressBar, ProgressStyle};
+    use std::collections::BTreeMap;
     use std::io::{self, Write};
     use tempfile::TempDir;
 
@@ -2762,6 +3307,7 @@ mod tests {
             updated_at: Utc::now(),
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             due_at: None,
             defer_until: None,
@@ -2783,6 +3329,7 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -2807,6 +3354,7 @@ mod tests {
             updated_at,
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             due_at: None,
             defer_until: None,
@@ -2828,6 +3376,7 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -3978,6 +4527,7 @@ mod tests {
             updated_at,
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             due_at: None,
             defer_until: None,
@@ -3999,6 +4549,7 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -4446,6 +4997,222 @@ mod tests {
         assert!(report.notes[0].1.contains("Both modified"));
     }
 
+    #[test]
+    fn test_merge_disjoint_field_changes_combined() {
+        // Local edits priority, external edits description: both survive
+        let base = make_issue_with_hash("bd-020", "Same", fixed_time_merge(100), None);
+        let mut local = base.clone();
+        local.priority = Priority::CRITICAL;
+        local.updated_at = fixed_time_merge(200);
+        let mut external = base.clone();
+        external.description = Some("External description".to_string());
+        external.updated_at = fixed_time_merge(300);
+
+        let (result, decisions) = merge_issue_with_decisions(
+            Some(&base),
+            Some(&local),
+            Some(&external),
+            ConflictResolution::PreferLocal,
+        );
+
+        let MergeResult::KeepWithNote(merged, note) = result else {
+            panic!("expected KeepWithNote");
+        };
+        assert_eq!(merged.priority, Priority::CRITICAL);
+        assert_eq!(merged.description.as_deref(), Some("External description"));
+        assert_eq!(merged.updated_at, fixed_time_merge(300));
+        assert!(note.contains("Both modified"));
+        assert_eq!(decisions.len(), 2);
+        assert!(
+            decisions
+                .iter()
+                .any(|d| d.field == "priority" && d.decision == FieldDecisionKind::TookLocal)
+        );
+        assert!(
+            decisions
+                .iter()
+                .any(|d| d.field == "description" && d.decision == FieldDecisionKind::TookExternal)
+        );
+    }
+
+    #[test]
+    fn test_merge_labels_unioned_with_removals() {
+        let mut base = make_issue_with_hash("bd-021", "Same", fixed_time_merge(100), None);
+        base.labels = vec!["keep".to_string(), "drop".to_string()];
+        let mut local = base.clone();
+        local.labels = vec!["keep".to_string(), "drop".to_string(), "local".to_string()];
+        let mut external = base.clone();
+        external.labels = vec!["keep".to_string(), "external".to_string()];
+
+        let (result, decisions) = merge_issue_with_decisions(
+            Some(&base),
+            Some(&local),
+            Some(&external),
+            ConflictResolution::PreferNewer,
+        );
+
+        let MergeResult::KeepWithNote(merged, _) = result else {
+            panic!("expected KeepWithNote");
+        };
+        assert_eq!(merged.labels, vec!["external", "keep", "local"]);
+        assert_eq!(decisions.len(), 1);
+        assert_eq!(decisions[0].field, "labels");
+        assert_eq!(decisions[0].decision, FieldDecisionKind::Combined);
+    }
+
+    #[test]
+    fn test_merge_same_field_manual_is_conflict() {
+        let base = make_issue_with_hash("bd-022", "Base", fixed_time_merge(100), None);
+        let mut local = base.clone();
+        local.title = "Local".to_string();
+        local.priority = Priority::HIGH;
+        let mut external = base.clone();
+        external.title = "External".to_string();
+
+        let context = MergeContext::new(
+            std::iter::once(("bd-022".to_string(), base)).collect(),
+            std::iter::once(("bd-022".to_string(), local)).collect(),
+            std::iter::once(("bd-022".to_string(), external)).collect(),
+        );
+        let report = three_way_merge(&context, ConflictResolution::Manual, None);
+
+        assert!(report.kept.is_empty());
+        assert_eq!(
+            report.conflicts,
+            vec![("bd-022".to_string(), ConflictType::FieldConflict)]
+        );
+        assert!(
+            report
+                .field_decisions
+                .iter()
+                .any(|d| d.field == "title" && d.decision == FieldDecisionKind::Conflict)
+        );
+        assert!(
+            report
+                .field_decisions
+                .iter()
+                .any(|d| d.field == "priority" && d.decision == FieldDecisionKind::TookLocal)
+        );
+    }
+
+    #[test]
+    fn test_merge_identical_change_converges() {
+        let base = make_issue_with_hash("bd-023", "Base", fixed_time_merge(100), None);
+        let mut local = base.clone();
+        local.status = Status::InProgress;
+        local.notes = Some("local".to_string());
+        let mut external = base.clone();
+        external.status = Status::InProgress;
+
+        let (result, decisions) = merge_issue_with_decisions(
+            Some(&base),
+            Some(&local),
+            Some(&external),
+            ConflictResolution::Manual,
+        );
+
+        assert!(matches!(result, MergeResult::KeepWithNote(ref issue, _)
+            if issue.status == Status::InProgress && issue.notes.as_deref() == Some("local")));
+        assert!(
+            decisions
+                .iter()
+                .any(|d| d.field == "status" && d.decision == FieldDecisionKind::Converged)
+        );
+    }
+
+    fn merge_dep(issue_id: &str, depends_on_id: &str, dep_type: DependencyType) -> Dependency {
+        Dependency {
+            issue_id: issue_id.to_string(),
+            depends_on_id: depends_on_id.to_string(),
+            dep_type,
+            created_at: fixed_time_merge(100),
+            created_by: None,
+            metadata: None,
+            thread_id: None,
+        }
+    }
+
+    #[test]
+    fn test_merge_dependency_type_change_kept() {
+        let mut base = make_issue_with_hash("bd-024", "Base", fixed_time_merge(100), None);
+        base.dependencies = vec![merge_dep("bd-024", "bd-001", DependencyType::Blocks)];
+        let mut local = base.clone();
+        local.title = "Local".to_string();
+        let mut external = base.clone();
+        external.dependencies[0].dep_type = DependencyType::Related;
+
+        let (result, decisions) = merge_issue_with_decisions(
+            Some(&base),
+            Some(&local),
+            Some(&external),
+            ConflictResolution::Manual,
+        );
+
+        let MergeResult::KeepWithNote(merged, _) = result else {
+            panic!("expected KeepWithNote");
+        };
+        assert_eq!(merged.title, "Local");
+        assert_eq!(merged.dependencies.len(), 1);
+        assert_eq!(merged.dependencies[0].dep_type, DependencyType::Related);
+        assert!(
+            decisions
+                .iter()
+                .any(|d| d.field == "dependencies"
+                    && d.decision == FieldDecisionKind::TookExternal)
+        );
+    }
+
+    #[test]
+    fn test_merge_dependency_retyped_both_sides_is_conflict() {
+        let mut base = make_issue_with_hash("bd-025", "Base", fixed_time_merge(100), None);
+        base.dependencies = vec![merge_dep("bd-025", "bd-001", DependencyType::Blocks)];
+        let mut local = base.clone();
+        local.dependencies[0].dep_type = DependencyType::Related;
+        let mut external = base.clone();
+        external.dependencies[0].dep_type = DependencyType::ConditionalBlocks;
+        external
+            .dependencies
+            .push(merge_dep("bd-025", "bd-002", DependencyType::Blocks));
+
+        let (result, decisions) = merge_issue_with_decisions(
+            Some(&base),
+            Some(&local),
+            Some(&external),
+            ConflictResolution::Manual,
+        );
+        assert!(matches!(
+            result,
+            MergeResult::Conflict(ConflictType::FieldConflict)
+        ));
+        assert!(
+            decisions
+                .iter()
+                .any(|d| d.field == "dependencies" && d.decision == FieldDecisionKind::Conflict)
+        );
+
+        let (result, _) = merge_issue_with_decisions(
+            Some(&base),
+            Some(&local),
+            Some(&external),
+            ConflictResolution::PreferExternal,
+        );
+        let MergeResult::KeepWithNote(merged, _) = result else {
+            panic!("expected KeepWithNote");
+        };
+        let types: Vec<_> = merged
+            .dependencies
+            .iter()
+            .map(|dep| (dep.depends_on_id.as_str(), dep.dep_type.clone()))
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                ("bd-001", DependencyType::ConditionalBlocks),
+                ("bd-002", DependencyType::Blocks),
+            ]
+        );
+    }
+
     /// Create a progress bar if enabled.
     #[allow(dead_code)]
     fn progress_bar(show: bool, len: u64, message: &str) -> ProgressBar {
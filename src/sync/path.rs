@@ -17,7 +17,7 @@
 //! | `.beads/*.db` | `SQLite` database files |
 //! | `.beads/*.db-wal` | `SQLite` WAL files |
 //! | `.beads/*.db-shm` | `SQLite` shared memory files |
-//! | `.beads/*.jsonl` | `JSONL` export files |
+//! | `.beads/*.jsonl` | `JSONL` export files (incl. opt-in `events.jsonl`) |
 //! | `.beads/*.jsonl.tmp` | Temp files for atomic writes |
 //! | `.beads/.manifest.json` | Export manifest |
 //! | `.beads/metadata.json` | Workspace metadata |
@@ -673,6 +673,15 @@ mod tests {
         assert!(result.is_allowed(), "JSONL files should be allowed");
     }
 
+    #[test]
+    fn test_allowed_events_log() {
+        let (_temp, beads_dir) = setup_test_beads_dir();
+        let path = beads_dir.join(crate::sync::events::EVENTS_FILE_NAME);
+
+        let result = validate_sync_path(&path, &beads_dir);
+        assert!(result.is_allowed(), "events.jsonl should be allowed");
+    }
+
     #[test]
     fn test_allowed_db_file() {
         let (_temp, beads_dir) = setup_test_beads_dir();
@@ -0,0 +1,29 @@
+//! Read-only git queries shared by commands that record commits.
+
+use std::process::Command;
+
+/// Full SHA of HEAD, or `None` outside a repository or before the first
+/// commit.
+#[must_use]
+pub fn head_sha() -> Option<String> {
+    rev_parse(&["--verify", "--quiet", "HEAD"])
+}
+
+/// Abbreviated SHA of HEAD, as `git rev-parse --short` prints it.
+#[must_use]
+pub fn short_head_sha() -> Option<String> {
+    rev_parse(&["--short", "HEAD"])
+}
+
+fn rev_parse(args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .arg("rev-parse")
+        .args(args)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let sha = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!sha.is_empty()).then_some(sha)
+}
@@ -30,11 +30,11 @@ impl ContentHashable for Issue {
 ///
 /// Fields excluded:
 /// - id, `content_hash` (circular)
-/// - labels, dependencies, comments, events (separate entities)
+/// - labels, dependencies, comments, events, custom fields (separate entities)
 /// - timestamps (`created_at`, `updated_at`, `closed_at`, etc.)
 /// - tombstone fields (`deleted_at`, `deleted_by`, `delete_reason`)
 /// - `estimated_minutes`, `due_at`, `defer_until`
-/// - `close_reason`, `closed_by_session`
+/// - `close_reason`, `close_outcome`, `closed_by_session`
 /// - `deleted_at`, `deleted_by`, `delete_reason`
 #[must_use]
 pub fn content_hash(issue: &Issue) -> String {
@@ -134,6 +134,7 @@ impl HashFieldWriter {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::BTreeMap;
 
     fn make_test_issue() -> Issue {
         Issue {
@@ -155,6 +156,7 @@ mod tests {
             updated_at: chrono::Utc::now(),
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             due_at: None,
             defer_until: None,
@@ -176,6 +178,7 @@ mod tests {
             labels: vec![],
             dependencies: vec![],
             comments: vec![],
+            fields: BTreeMap::new(),
         }
     }
 
@@ -7,7 +7,9 @@
 //! - ID generation (base36 adaptive)
 //! - Last-touched tracking
 //! - Progress indicators (for long-running operations)
+//! - Git HEAD lookups
 
+pub mod git;
 mod hash;
 pub mod id;
 pub mod markdown_import;
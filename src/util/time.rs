@@ -150,6 +150,39 @@ pub fn parse_relative_time(s: &str) -> Option<DateTime<Utc>> {
     }
 }
 
+/// Parse a positive duration such as `30m`, `2h`, `1d` or `1w`.
+///
+/// A bare number is read as minutes.
+///
+/// # Errors
+///
+/// Returns an error if the amount is not a positive integer or the unit is
+/// not one of m, h, d, w.
+pub fn parse_duration(s: &str, field_name: &str) -> Result<Duration> {
+    let s = s.trim();
+    let invalid = || {
+        BeadsError::validation(
+            field_name,
+            format!("invalid duration '{s}' (expected e.g. 30m, 2h, 1d, 1w)"),
+        )
+    };
+    let (amount, unit) = match s.char_indices().last() {
+        Some((idx, unit)) if unit.is_ascii_alphabetic() => (&s[..idx], unit),
+        _ => (s, 'm'),
+    };
+    let amount: i64 = amount.parse().map_err(|_| invalid())?;
+    if amount <= 0 {
+        return Err(invalid());
+    }
+    match unit {
+        'm' => Ok(Duration::minutes(amount)),
+        'h' => Ok(Duration::hours(amount)),
+        'd' => Ok(Duration::days(amount)),
+        'w' => Ok(Duration::weeks(amount)),
+        _ => Err(invalid()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -198,4 +231,20 @@ mod tests {
         assert!(parse_relative_time("invalid").is_none());
         assert!(parse_relative_time("2025-01-15").is_none());
     }
+
+    #[test]
+    fn test_parse_duration_units() {
+        assert_eq!(
+            parse_duration("30m", "lease").unwrap(),
+            Duration::minutes(30)
+        );
+        assert_eq!(parse_duration("2h", "lease").unwrap(), Duration::hours(2));
+        assert_eq!(
+            parse_duration("45", "lease").unwrap(),
+            Duration::minutes(45)
+        );
+        assert!(parse_duration("0m", "lease").is_err());
+        assert!(parse_duration("3y", "lease").is_err());
+        assert!(parse_duration("soon", "lease").is_err());
+    }
 }
@@ -12,6 +12,7 @@
 //!
 //! See `SyncSafetyValidator` for runtime guards.
 
+use crate::config::fields::FieldSchema;
 use crate::error::{BeadsError, ValidationError};
 use crate::model::{Comment, Dependency, Issue, Priority};
 use std::path::Path;
@@ -99,6 +100,41 @@ impl IssueValidator {
             Err(errors)
         }
     }
+
+    /// Validate custom field values against the schema declared in config.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `Vec<ValidationError>` for undeclared fields and for values
+    /// that do not match their declared type or allowed values.
+    pub fn validate_fields(
+        issue: &Issue,
+        schema: &FieldSchema,
+    ) -> Result<(), Vec<ValidationError>> {
+        let errors: Vec<ValidationError> = issue
+            .fields
+            .iter()
+            .filter_map(|(name, value)| {
+                let field = format!("fields.{name}");
+                match schema.get(name) {
+                    None => Some(ValidationError::new(
+                        field,
+                        "is not declared under fields in config.yaml",
+                    )),
+                    Some(def) => def
+                        .check(value)
+                        .err()
+                        .map(|reason| ValidationError::new(field, reason)),
+                }
+            })
+            .collect();
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
 }
 
 /// Storage-facing dependency validation helpers.
@@ -423,6 +459,7 @@ mod tests {
     use super::*;
     use crate::model::{DependencyType, IssueType, Status};
     use chrono::{TimeZone, Utc};
+    use std::collections::BTreeMap;
 
     fn base_issue() -> Issue {
         Issue {
@@ -444,6 +481,7 @@ mod tests {
             updated_at: Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
             closed_at: None,
             close_reason: None,
+            close_outcome: None,
             closed_by_session: None,
             due_at: None,
             defer_until: None,
@@ -465,6 +503,7 @@ mod tests {
             labels: Vec::new(),
             dependencies: Vec::new(),
             comments: Vec::new(),
+            fields: BTreeMap::new(),
         }
     }
 
@@ -691,6 +730,31 @@ mod tests {
         assert!(errors.iter().any(|err| err.field == "external_ref"));
     }
 
+    #[test]
+    fn issue_validation_checks_custom_fields_against_schema() {
+        let mut layer = crate::config::ConfigLayer::default();
+        layer
+            .startup
+            .insert("fields.story_points".to_string(), "int".to_string());
+        let schema = FieldSchema::from_layer(&layer).unwrap();
+
+        let mut issue = base_issue();
+        issue
+            .fields
+            .insert("story_points".to_string(), serde_json::Value::from(3));
+        assert!(IssueValidator::validate_fields(&issue, &schema).is_ok());
+
+        issue
+            .fields
+            .insert("story_points".to_string(), serde_json::Value::from("lots"));
+        issue
+            .fields
+            .insert("mood".to_string(), serde_json::Value::from("happy"));
+        let errors = IssueValidator::validate_fields(&issue, &schema).unwrap_err();
+        let fields: Vec<_> = errors.iter().map(|err| err.field.as_str()).collect();
+        assert_eq!(fields, vec!["fields.mood", "fields.story_points"]);
+    }
+
     #[test]
     fn id_format_validation_accepts_classic_ids() {
         assert!(is_valid_id_format("bd-abc123"));
@@ -0,0 +1,583 @@
+//! Typed library access to a beads workspace.
+//!
+//! [`Workspace`] is the embedding API for Rust tooling that wants to drive
+//! `br` without shelling out. It opens the same storage the CLI uses, imports
+//! a newer `issues.jsonl` on open, and flushes dirty issues back to JSONL
+//! after every mutation, so a tool and the `br` binary can work on the same
+//! repository side by side.
+//!
+//! ```no_run
+//! use beads_rust::workspace::{NewIssue, Workspace};
+//!
+//! let mut ws = Workspace::discover(std::path::Path::new("."))?;
+//! let issue = ws.create(&NewIssue::new("Fix login redirect"))?;
+//! ws.add_label(&issue.id, "auth")?;
+//! ws.close(&issue.id, Some("fixed in #42"))?;
+//! # Ok::<(), beads_rust::BeadsError>(())
+//! ```
+
+use crate::cli::CreateArgs;
+use crate::cli::commands::close::{CloseArgs, ClosedIssue, close_issues};
+use crate::cli::commands::create::{CreateConfig, create_issue_impl};
+use crate::cli::commands::ready::{find_ready_issues, ready_records};
+use crate::config::{self, CliOverrides, ConfigLayer, ConfigPaths, OpenStorageResult};
+use crate::error::{BeadsError, Result};
+use crate::format::{IssueDetails, IssueWithCounts, ReadyIssue};
+use crate::model::{Comment, DependencyType, Issue, IssueType, Priority, Status};
+use crate::storage::{IssueUpdate, ListFilters, ReadyFilters, ReadySortPolicy, SqliteStorage};
+use crate::sync::{
+    AutoFlushResult, AutoImportResult, ImportConfig, ImportResult, auto_flush,
+    auto_import_if_stale, import_from_jsonl,
+};
+use crate::util::id::{IdResolver, ResolverConfig};
+use crate::validation::LabelValidator;
+use chrono::{DateTime, Utc};
+use std::path::Path;
+
+/// Number of events included by [`Workspace::show`], matching `br show`.
+const SHOW_EVENT_LIMIT: usize = 10;
+
+/// Fields for a new issue. Unset fields fall back to the project defaults.
+#[derive(Debug, Clone, Default)]
+pub struct NewIssue {
+    pub title: String,
+    pub description: Option<String>,
+    pub issue_type: Option<IssueType>,
+    pub priority: Option<Priority>,
+    pub status: Option<Status>,
+    pub assignee: Option<String>,
+    pub owner: Option<String>,
+    pub labels: Vec<String>,
+    /// Parent issue; the new issue gets a child ID under it.
+    pub parent: Option<String>,
+    /// Dependencies as `id` or `type:id`, the same syntax as `create --deps`.
+    pub deps: Vec<String>,
+    pub estimated_minutes: Option<i32>,
+    pub due_at: Option<DateTime<Utc>>,
+    pub defer_until: Option<DateTime<Utc>>,
+    pub external_ref: Option<String>,
+    pub ephemeral: bool,
+}
+
+impl NewIssue {
+    /// New issue with only a title.
+    #[must_use]
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Self::default()
+        }
+    }
+
+    fn to_create_args(&self) -> CreateArgs {
+        CreateArgs {
+            title: Some(self.title.clone()),
+            title_flag: None,
+            type_: self.issue_type.as_ref().map(|t| t.as_str().to_string()),
+            priority: self.priority.map(|p| p.0.to_string()),
+            description: self.description.clone(),
+            assignee: self.assignee.clone(),
+            owner: self.owner.clone(),
+            labels: self.labels.clone(),
+            parent: self.parent.clone(),
+            deps: self.deps.clone(),
+            estimate: self.estimated_minutes,
+            due: self.due_at.map(|at| at.to_rfc3339()),
+            defer: self.defer_until.map(|at| at.to_rfc3339()),
+            external_ref: self.external_ref.clone(),
+            fields: Vec::new(),
+            status: self.status.as_ref().map(|s| s.as_str().to_string()),
+            ephemeral: self.ephemeral,
+            dry_run: false,
+            silent: true,
+            file: None,
+            from_template: None,
+            vars: Vec::new(),
+            molecule: false,
+        }
+    }
+}
+
+/// An open beads workspace.
+#[derive(Debug)]
+pub struct Workspace {
+    inner: OpenStorageResult,
+    layer: ConfigLayer,
+    actor: String,
+    auto_flush: bool,
+    no_auto_import: bool,
+}
+
+impl Workspace {
+    /// Find the `.beads` directory at or above `start` and open it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BeadsError::NotInitialized`] if no workspace is found, or any
+    /// error from [`Workspace::open_with`].
+    pub fn discover(start: &Path) -> Result<Self> {
+        let beads_dir = config::discover_beads_dir(Some(start))?;
+        Self::open(&beads_dir)
+    }
+
+    /// Open the workspace in `beads_dir` with default settings.
+    ///
+    /// # Errors
+    ///
+    /// See [`Workspace::open_with`].
+    pub fn open(beads_dir: &Path) -> Result<Self> {
+        Self::open_with(beads_dir, &CliOverrides::default())
+    }
+
+    /// Open the workspace in `beads_dir`, applying the same overrides as the
+    /// global CLI flags (`--db`, `--actor`, `--no-db`, `--no-auto-flush`, ...).
+    ///
+    /// A JSONL file newer than the database is imported first, unless
+    /// `no_auto_import` is set, in which case opening fails instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if configuration loading, storage setup, or the
+    /// auto-import fails.
+    pub fn open_with(beads_dir: &Path, overrides: &CliOverrides) -> Result<Self> {
+        let mut inner = config::open_storage_with_cli(beads_dir, overrides)?;
+        let no_auto_import = overrides.no_auto_import.unwrap_or(false);
+        // Import before loading config, which reads the DB-stored settings
+        import_stale(&mut inner, no_auto_import)?;
+
+        let layer = config::load_config(beads_dir, Some(&inner.storage), overrides)?;
+        let actor = config::resolve_actor(&layer);
+
+        Ok(Self {
+            inner,
+            layer,
+            actor,
+            auto_flush: !overrides.no_auto_flush.unwrap_or(false),
+            no_auto_import,
+        })
+    }
+
+    /// Import the JSONL file if it changed since the last import or export.
+    ///
+    /// Long-lived callers should run this before each batch of work so edits
+    /// made by other processes (a `git pull`, another `br`) are picked up.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSONL is newer and auto-import is disabled, or
+    /// if the import fails.
+    pub fn import_if_stale(&mut self) -> Result<AutoImportResult> {
+        import_stale(&mut self.inner, self.no_auto_import)
+    }
+
+    /// Record mutations under `actor` instead of the configured one.
+    #[must_use]
+    pub fn with_actor(mut self, actor: impl Into<String>) -> Self {
+        self.actor = actor.into();
+        self
+    }
+
+    /// Enable or disable flushing to JSONL after each mutation.
+    ///
+    /// With auto-flush off, call [`Workspace::flush`] to export. In `no-db`
+    /// mode JSONL is the only store and is always written.
+    pub const fn set_auto_flush(&mut self, enabled: bool) {
+        self.auto_flush = enabled;
+    }
+
+    /// Actor recorded for mutations.
+    #[must_use]
+    pub fn actor(&self) -> &str {
+        &self.actor
+    }
+
+    /// Resolved `.beads` directory, database and JSONL paths.
+    #[must_use]
+    pub const fn paths(&self) -> &ConfigPaths {
+        &self.inner.paths
+    }
+
+    /// Underlying storage, for queries the facade does not cover.
+    #[must_use]
+    pub const fn storage(&self) -> &SqliteStorage {
+        &self.inner.storage
+    }
+
+    /// Resolve a full, prefix-less or partial ID to the full issue ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no issue matches or the input is ambiguous.
+    pub fn resolve_id(&self, input: &str) -> Result<String> {
+        let prefix = config::id_config_from_layer(&self.layer).prefix;
+        let resolver = IdResolver::new(ResolverConfig::with_prefix(prefix));
+        let storage = self.storage();
+        let resolution = resolver.resolve(
+            input,
+            |id| storage.id_exists(id).unwrap_or(false),
+            |hash| storage.find_ids_by_hash(hash).unwrap_or_default(),
+        )?;
+        Ok(resolution.id)
+    }
+
+    /// Issue with labels, dependencies, comments and recent events.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ID does not resolve or the query fails.
+    pub fn show(&self, id: &str) -> Result<IssueDetails> {
+        let id = self.resolve_id(id)?;
+        self.storage()
+            .get_issue_details(&id, true, true, SHOW_EVENT_LIMIT)?
+            .ok_or(BeadsError::IssueNotFound { id })
+    }
+
+    /// Issues matching `filters`, with labels and dependency counts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn list(&self, filters: &ListFilters) -> Result<Vec<IssueWithCounts>> {
+        let storage = self.storage();
+        let issues = storage.list_issues(filters)?;
+        let issue_ids: Vec<String> = issues.iter().map(|issue| issue.id.clone()).collect();
+        let mut labels_map = storage.get_labels_for_issues(&issue_ids)?;
+        let dependency_counts = storage.count_dependencies_for_issues(&issue_ids)?;
+        let dependent_counts = storage.count_dependents_for_issues(&issue_ids)?;
+
+        Ok(issues
+            .into_iter()
+            .map(|mut issue| {
+                if let Some(labels) = labels_map.remove(&issue.id) {
+                    issue.labels = labels;
+                }
+                IssueWithCounts {
+                    dependency_count: *dependency_counts.get(&issue.id).unwrap_or(&0),
+                    dependent_count: *dependent_counts.get(&issue.id).unwrap_or(&0),
+                    issue,
+                }
+            })
+            .collect())
+    }
+
+    /// Unblocked, undeferred open issues, as `br ready` reports them.
+    ///
+    /// Issues blocked by unresolved cross-project dependencies are excluded
+    /// before `filters.limit` is applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn ready(&self, filters: &ReadyFilters, sort: ReadySortPolicy) -> Result<Vec<ReadyIssue>> {
+        let storage = self.storage();
+        let beads_dir = &self.inner.paths.beads_dir;
+        let external_db_paths = config::external_project_db_paths(&self.layer, beads_dir);
+        let issues =
+            find_ready_issues(storage, &external_db_paths, beads_dir, filters, sort, None)?;
+        ready_records(storage, &issues)
+    }
+
+    /// Create an issue, applying the same defaults and validation as
+    /// `br create`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if validation fails, a parent or dependency does not
+    /// exist, or the write fails.
+    pub fn create(&mut self, new: &NewIssue) -> Result<Issue> {
+        let create_config = CreateConfig {
+            id_config: config::id_config_from_layer(&self.layer),
+            default_priority: config::default_priority_from_layer(&self.layer)?,
+            default_issue_type: config::default_issue_type_from_layer(&self.layer)?,
+            actor: self.actor.clone(),
+        };
+        let issue = create_issue_impl(
+            &mut self.inner.storage,
+            &new.to_create_args(),
+            &create_config,
+        )?;
+        self.after_mutation()?;
+        Ok(issue)
+    }
+
+    /// Apply `update` to an issue and return the updated issue.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ID does not resolve or the write fails.
+    pub fn update(&mut self, id: &str, update: &IssueUpdate) -> Result<Issue> {
+        let id = self.resolve_id(id)?;
+        let issue = self.inner.storage.update_issue(&id, update, &self.actor)?;
+        self.after_mutation()?;
+        Ok(issue)
+    }
+
+    /// Close an issue. The reason defaults to `done`, as with `br close`.
+    ///
+    /// # Errors
+    ///
+    /// See [`Workspace::close_with`].
+    pub fn close(&mut self, id: &str, reason: Option<&str>) -> Result<Issue> {
+        let args = CloseArgs {
+            reason: reason.map(str::to_string),
+            ..CloseArgs::default()
+        };
+        let id = self.resolve_id(id)?;
+        self.close_with(&id, &args)?;
+        self.storage()
+            .get_issue(&id)?
+            .ok_or(BeadsError::IssueNotFound { id })
+    }
+
+    /// Close an issue with the reason, outcome, session and `force` of
+    /// `args`, exactly as `br close` does; `args.ids` is ignored.
+    ///
+    /// Returns the closed issue followed by any `conditional-blocks`
+    /// dependents closed as moot because it succeeded.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error if the issue is already closed or, without
+    /// `force`, blocked by open dependencies, or an error if the write fails.
+    pub fn close_with(&mut self, id: &str, args: &CloseArgs) -> Result<Vec<ClosedIssue>> {
+        let id = self.resolve_id(id)?;
+        let result = close_issues(
+            &mut self.inner.storage,
+            std::slice::from_ref(&id),
+            args,
+            &self.actor,
+        )?;
+        if let Some(skipped) = result.skipped.first() {
+            return Err(BeadsError::validation(
+                "id",
+                format!("cannot close {}: {}", skipped.id, skipped.reason),
+            ));
+        }
+        self.after_mutation()?;
+        Ok(result.closed)
+    }
+
+    /// Make `issue_id` depend on `depends_on_id`.
+    ///
+    /// Returns `false` if the dependency already existed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either ID does not resolve, the dependency would
+    /// create a cycle, or the write fails.
+    pub fn add_dependency(
+        &mut self,
+        issue_id: &str,
+        depends_on_id: &str,
+        dep_type: &DependencyType,
+    ) -> Result<bool> {
+        let issue_id = self.resolve_id(issue_id)?;
+        let depends_on_id = self.resolve_id(depends_on_id)?;
+        if issue_id == depends_on_id {
+            return Err(BeadsError::SelfDependency { id: issue_id });
+        }
+        let added = self.inner.storage.add_dependency(
+            &issue_id,
+            &depends_on_id,
+            dep_type.as_str(),
+            &self.actor,
+        )?;
+        self.after_mutation()?;
+        Ok(added)
+    }
+
+    /// Remove the dependency of `issue_id` on `depends_on_id`.
+    ///
+    /// Returns `false` if there was no such dependency.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `issue_id` does not resolve or the write fails.
+    pub fn remove_dependency(&mut self, issue_id: &str, depends_on_id: &str) -> Result<bool> {
+        let issue_id = self.resolve_id(issue_id)?;
+        // The target may be external or already deleted
+        let depends_on_id = self
+            .resolve_id(depends_on_id)
+            .unwrap_or_else(|_| depends_on_id.to_string());
+        let removed =
+            self.inner
+                .storage
+                .remove_dependency(&issue_id, &depends_on_id, &self.actor)?;
+        self.after_mutation()?;
+        Ok(removed)
+    }
+
+    /// Add a label. Returns `false` if the issue already had it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the label is invalid, the ID does not resolve, or
+    /// the write fails.
+    pub fn add_label(&mut self, id: &str, label: &str) -> Result<bool> {
+        LabelValidator::validate(label).map_err(|e| BeadsError::validation("label", e.message))?;
+        let id = self.resolve_id(id)?;
+        let added = self.inner.storage.add_label(&id, label, &self.actor)?;
+        self.after_mutation()?;
+        Ok(added)
+    }
+
+    /// Remove a label. Returns `false` if the issue did not have it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ID does not resolve or the write fails.
+    pub fn remove_label(&mut self, id: &str, label: &str) -> Result<bool> {
+        let id = self.resolve_id(id)?;
+        let removed = self.inner.storage.remove_label(&id, label, &self.actor)?;
+        self.after_mutation()?;
+        Ok(removed)
+    }
+
+    /// Add a comment authored by the workspace actor.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text is empty, the ID does not resolve, or the
+    /// write fails.
+    pub fn add_comment(&mut self, id: &str, text: &str) -> Result<Comment> {
+        if text.trim().is_empty() {
+            return Err(BeadsError::validation(
+                "text",
+                "comment text cannot be empty",
+            ));
+        }
+        let id = self.resolve_id(id)?;
+        let comment = self.inner.storage.add_comment(&id, &self.actor, text)?;
+        self.after_mutation()?;
+        Ok(comment)
+    }
+
+    /// Export dirty issues to JSONL now, regardless of the auto-flush setting.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the export fails.
+    pub fn flush(&mut self) -> Result<AutoFlushResult> {
+        if self.inner.no_db {
+            self.inner.flush_no_db_if_dirty()?;
+            return Ok(AutoFlushResult::default());
+        }
+        auto_flush(&mut self.inner.storage, &self.inner.paths.beads_dir)
+    }
+
+    /// Import the JSONL file into the database, like `br sync --import-only`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSONL file is invalid, contains conflict
+    /// markers, or uses a different issue prefix.
+    pub fn import(&mut self) -> Result<ImportResult> {
+        let beads_dir = self.inner.paths.beads_dir.clone();
+        let import_config = ImportConfig {
+            beads_dir: Some(beads_dir.clone()),
+            allow_external_jsonl: false,
+            show_progress: false,
+            import_events: config::sync_events_enabled(&beads_dir),
+            ..ImportConfig::default()
+        };
+        let expected_prefix = self.inner.storage.get_config("issue_prefix")?;
+        import_from_jsonl(
+            &mut self.inner.storage,
+            &self.inner.paths.jsonl_path,
+            &import_config,
+            expected_prefix.as_deref(),
+        )
+    }
+
+    fn after_mutation(&mut self) -> Result<()> {
+        if self.inner.no_db {
+            return self.inner.flush_no_db_if_dirty();
+        }
+        if self.auto_flush {
+            auto_flush(&mut self.inner.storage, &self.inner.paths.beads_dir)?;
+        }
+        Ok(())
+    }
+}
+
+fn import_stale(inner: &mut OpenStorageResult, no_auto_import: bool) -> Result<AutoImportResult> {
+    // In no-db mode the JSONL was just loaded into memory
+    if inner.no_db {
+        return Ok(AutoImportResult::default());
+    }
+    let expected_prefix = inner.storage.get_config("issue_prefix")?;
+    auto_import_if_stale(
+        &mut inner.storage,
+        &inner.paths.beads_dir,
+        &inner.paths.jsonl_path,
+        expected_prefix.as_deref(),
+        false,
+        no_auto_import,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn init_workspace() -> (TempDir, Workspace) {
+        let temp = TempDir::new().unwrap();
+        let beads_dir = temp.path().join(".beads");
+        std::fs::create_dir(&beads_dir).unwrap();
+        let mut storage = SqliteStorage::open(&beads_dir.join("beads.db")).unwrap();
+        storage.set_config("issue_prefix", "ws").unwrap();
+        drop(storage);
+        let ws = Workspace::discover(temp.path())
+            .unwrap()
+            .with_actor("tester");
+        (temp, ws)
+    }
+
+    #[test]
+    fn test_mutations_flush_to_jsonl() {
+        let (_temp, mut ws) = init_workspace();
+        let blocker = ws.create(&NewIssue::new("Blocker")).unwrap();
+        let blocked = ws.create(&NewIssue::new("Blocked")).unwrap();
+        assert!(blocker.id.starts_with("ws-"));
+
+        ws.add_dependency(&blocked.id, &blocker.id, &DependencyType::Blocks)
+            .unwrap();
+        ws.add_label(&blocked.id, "backend").unwrap();
+        ws.add_comment(&blocked.id, "waiting on the blocker")
+            .unwrap();
+
+        let ready: Vec<String> = ws
+            .ready(&ReadyFilters::default(), ReadySortPolicy::default())
+            .unwrap()
+            .into_iter()
+            .map(|issue| issue.id)
+            .collect();
+        assert_eq!(ready, vec![blocker.id.clone()]);
+        assert!(ws.close(&blocked.id, None).is_err());
+
+        ws.close(&blocker.id, Some("shipped")).unwrap();
+        let details = ws.show(&blocked.id).unwrap();
+        assert_eq!(details.labels, vec!["backend".to_string()]);
+        assert_eq!(details.comments.len(), 1);
+        assert_eq!(details.comments[0].author, "tester");
+
+        let jsonl = crate::sync::read_issues_from_jsonl(&ws.paths().jsonl_path).unwrap();
+        assert_eq!(jsonl.len(), 2);
+        assert_eq!(ws.storage().get_dirty_issue_count().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_open_imports_newer_jsonl() {
+        let (temp, mut ws) = init_workspace();
+        let issue = ws.create(&NewIssue::new("Exported")).unwrap();
+        drop(ws);
+
+        // Another clone starts from the JSONL alone
+        std::fs::remove_file(temp.path().join(".beads/beads.db")).unwrap();
+        let ws = Workspace::discover(temp.path()).unwrap();
+        let listed = ws.list(&ListFilters::default()).unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].issue.id, issue.id);
+    }
+}
@@ -2,6 +2,7 @@
 
 use beads_rust::model::{Dependency, DependencyType, Issue, IssueType, Priority, Status};
 use chrono::{Duration, TimeZone, Utc};
+use std::collections::BTreeMap;
 
 /// Base time for test fixtures - set in the past to allow tests to manipulate
 /// `updated_at` without violating the `created_at` <= `updated_at` constraint.
@@ -32,6 +33,7 @@ pub fn issue(title: &str) -> Issue {
         created_by: None,
         closed_at: None,
         close_reason: None,
+        close_outcome: None,
         closed_by_session: None,
         due_at: None,
         defer_until: None,
@@ -52,6 +54,7 @@ pub fn issue(title: &str) -> Issue {
         is_template: false,
         dependencies: vec![],
         comments: vec![],
+        fields: BTreeMap::new(),
     }
 }
 
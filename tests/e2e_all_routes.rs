@@ -0,0 +1,108 @@
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+use std::fs;
+
+fn project_name(workspace: &BrWorkspace) -> String {
+    workspace
+        .root
+        .file_name()
+        .expect("workspace dir name")
+        .to_string_lossy()
+        .into_owned()
+}
+
+fn json_rows(stdout: &str) -> Vec<Value> {
+    let payload = extract_json_payload(stdout);
+    serde_json::from_str::<Value>(&payload)
+        .expect("json output")
+        .as_array()
+        .expect("array output")
+        .clone()
+}
+
+#[test]
+fn e2e_all_routes_merges_ready_list_and_blocked() {
+    let _log = common::test_log("e2e_all_routes_merges_ready_list_and_blocked");
+    let home = BrWorkspace::new();
+    let other = BrWorkspace::new();
+
+    let init = run_br(&home, ["init", "--prefix", "hm"], "init_home");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let init = run_br(&other, ["init", "--prefix", "ot"], "init_other");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let created = run_br(&home, ["create", "Home task", "-p", "2"], "create_home");
+    assert!(
+        created.status.success(),
+        "create failed: {}",
+        created.stderr
+    );
+    let created = run_br(
+        &other,
+        ["create", "Other urgent", "-p", "0"],
+        "create_other",
+    );
+    assert!(
+        created.status.success(),
+        "create failed: {}",
+        created.stderr
+    );
+    let blocker = run_br(&other, ["q", "Other blocker"], "create_blocker");
+    assert!(
+        blocker.status.success(),
+        "create failed: {}",
+        blocker.stderr
+    );
+    let blocker_id = blocker.stdout.trim().to_string();
+    let blocked = run_br(&other, ["q", "Other blocked"], "create_blocked");
+    let blocked_id = blocked.stdout.trim().to_string();
+    let dep = run_br(&other, ["dep", "add", &blocked_id, &blocker_id], "dep_add");
+    assert!(dep.status.success(), "dep add failed: {}", dep.stderr);
+
+    fs::write(
+        home.root.join(".beads/routes.jsonl"),
+        format!(
+            "{{\"prefix\":\"ot-\",\"path\":\"{}\"}}\n",
+            other.root.display()
+        ),
+    )
+    .expect("write routes");
+
+    let ready = run_br(
+        &home,
+        ["ready", "--all-routes", "--json"],
+        "ready_all_routes",
+    );
+    assert!(ready.status.success(), "ready failed: {}", ready.stderr);
+    let rows = json_rows(&ready.stdout);
+    // Hybrid sort puts the P0 from the other project first
+    assert_eq!(rows[0]["title"], "Other urgent");
+    assert_eq!(rows[0]["project"], project_name(&other).as_str());
+    assert!(
+        rows.iter().any(
+            |row| row["title"] == "Home task" && row["project"] == project_name(&home).as_str()
+        )
+    );
+    assert!(rows.iter().all(|row| row["title"] != "Other blocked"));
+
+    let list = run_br(&home, ["list", "--all-routes", "--json"], "list_all_routes");
+    assert!(list.status.success(), "list failed: {}", list.stderr);
+    assert_eq!(json_rows(&list.stdout).len(), 4);
+
+    let blocked = run_br(
+        &home,
+        ["blocked", "--all-routes", "--json"],
+        "blocked_all_routes",
+    );
+    assert!(
+        blocked.status.success(),
+        "blocked failed: {}",
+        blocked.stderr
+    );
+    let rows = json_rows(&blocked.stdout);
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0]["id"], blocked_id.as_str());
+    assert_eq!(rows[0]["project"], project_name(&other).as_str());
+}
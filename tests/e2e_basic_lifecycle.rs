@@ -4,6 +4,7 @@ use beads_rust::model::{Issue, IssueType, Priority, Status};
 use chrono::Utc;
 use common::cli::{BrWorkspace, extract_json_payload, run_br};
 use serde_json::Value;
+use std::collections::BTreeMap;
 use std::fs;
 use std::thread::sleep;
 use std::time::Duration;
@@ -42,6 +43,7 @@ fn make_issue(id: &str, title: &str, now: chrono::DateTime<Utc>) -> Issue {
         created_by: None,
         closed_at: None,
         close_reason: None,
+        close_outcome: None,
         closed_by_session: None,
         due_at: None,
         defer_until: None,
@@ -63,6 +65,7 @@ fn make_issue(id: &str, title: &str, now: chrono::DateTime<Utc>) -> Issue {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        fields: BTreeMap::new(),
     }
 }
 
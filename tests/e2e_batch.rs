@@ -0,0 +1,256 @@
+//! End-to-end tests for `br batch`.
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br, run_br_with_stdin};
+use serde_json::Value;
+use std::fs;
+
+fn json(workspace: &BrWorkspace, args: &[&str], label: &str) -> Value {
+    let mut full = args.to_vec();
+    full.push("--json");
+    let output = run_br(workspace, full, label);
+    assert!(output.status.success(), "{label} failed: {}", output.stderr);
+    serde_json::from_str(&extract_json_payload(&output.stdout)).expect("json")
+}
+
+#[test]
+fn e2e_batch_creates_plan_with_placeholders() {
+    let _log = common::test_log("e2e_batch_creates_plan_with_placeholders");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let ops = r#"
+{"op": "create", "ref": "epic", "title": "Search", "type": "epic", "priority": 1}
+{"op": "create", "ref": "index", "title": "Build index", "parent": "$epic", "labels": ["backend"]}
+{"op": "create", "ref": "ui", "title": "Search box", "parent": "$epic", "deps": ["blocks:$index"]}
+{"op": "label_add", "id": "$epic", "label": "q3"}
+{"op": "comment", "id": "$ui", "text": "Reuse the filter bar"}
+{"op": "update", "id": "$index", "status": "in_progress", "assignee": "alice"}
+"#;
+    let output = run_br_with_stdin(&workspace, ["batch", "-", "--json"], ops, "batch");
+    assert!(output.status.success(), "batch failed: {}", output.stderr);
+    let result: Value = serde_json::from_str(&extract_json_payload(&output.stdout)).expect("json");
+
+    let epic = result["ids"]["epic"].as_str().expect("epic id").to_string();
+    let index = result["ids"]["index"]
+        .as_str()
+        .expect("index id")
+        .to_string();
+    let ui = result["ids"]["ui"].as_str().expect("ui id").to_string();
+    assert_eq!(index, format!("{epic}.1"));
+    assert_eq!(ui, format!("{epic}.2"));
+    let results = result["results"].as_array().expect("results");
+    assert_eq!(results.len(), 6);
+    assert_eq!(results[3]["op"], "label_add");
+    assert_eq!(results[3]["id"], epic.as_str());
+    assert!(results.iter().all(|r| r["changed"] == true), "{result}");
+
+    let shown = json(&workspace, &["show", &index], "show_index");
+    let shown = if shown.is_array() { &shown[0] } else { &shown };
+    assert_eq!(shown["status"], "in_progress");
+    assert_eq!(shown["assignee"], "alice");
+
+    let ready = json(&workspace, &["ready"], "ready");
+    let ready_ids: Vec<&str> = ready
+        .as_array()
+        .expect("array")
+        .iter()
+        .filter_map(|issue| issue["id"].as_str())
+        .collect();
+    assert!(!ready_ids.contains(&ui.as_str()), "blocked child is ready");
+
+    let labels = json(&workspace, &["label", "list", &epic], "labels");
+    assert!(labels.to_string().contains("q3"), "{labels}");
+
+    // One flush wrote everything to the JSONL
+    let jsonl = fs::read_to_string(workspace.root.join(".beads/issues.jsonl")).expect("jsonl");
+    for id in [&epic, &index, &ui] {
+        assert!(jsonl.contains(id.as_str()), "{id} missing from JSONL");
+    }
+}
+
+#[test]
+fn e2e_batch_failure_writes_nothing() {
+    let _log = common::test_log("e2e_batch_failure_writes_nothing");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let ops_path = workspace.root.join("ops.jsonl");
+    fs::write(
+        &ops_path,
+        concat!(
+            r#"{"op": "create", "ref": "a", "title": "First"}"#,
+            "\n",
+            r#"{"op": "dep_add", "id": "$a", "depends_on": "$missing"}"#,
+            "\n",
+        ),
+    )
+    .expect("write ops");
+    let unknown_ref = run_br(
+        &workspace,
+        ["batch", ops_path.to_str().expect("path")],
+        "batch_unknown_ref",
+    );
+    assert!(
+        !unknown_ref.status.success(),
+        "unknown placeholder accepted"
+    );
+    assert!(
+        unknown_ref.stderr.contains("line 2"),
+        "{}",
+        unknown_ref.stderr
+    );
+
+    // Fails while applying: the second issue cannot be closed while blocked
+    fs::write(
+        &ops_path,
+        concat!(
+            r#"{"op": "create", "ref": "a", "title": "First"}"#,
+            "\n",
+            r#"{"op": "create", "ref": "b", "title": "Second", "deps": ["$a"]}"#,
+            "\n",
+            r#"{"op": "close", "id": "$b"}"#,
+            "\n",
+        ),
+    )
+    .expect("write ops");
+    let blocked = run_br(
+        &workspace,
+        ["batch", ops_path.to_str().expect("path")],
+        "batch_blocked_close",
+    );
+    assert!(!blocked.status.success(), "closed a blocked issue");
+
+    let listed = json(&workspace, &["list", "--all"], "list");
+    assert!(
+        listed.as_array().expect("array").is_empty(),
+        "batch left issues behind: {listed}"
+    );
+
+    let bad_json = run_br_with_stdin(
+        &workspace,
+        ["batch", "-"],
+        "{\"op\": \"create\", \"title\": \"Ok\"}\n{\"op\": \"explode\"}\n",
+        "batch_bad_op",
+    );
+    assert!(!bad_json.status.success(), "unknown op accepted");
+}
+
+#[test]
+fn e2e_batch_close_success_closes_conditional_dependent() {
+    let _log = common::test_log("e2e_batch_close_success_closes_conditional_dependent");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let ops = r#"
+{"op": "create", "ref": "try", "title": "Try the fast path"}
+{"op": "create", "ref": "fallback", "title": "Fall back", "deps": ["conditional-blocks:$try"]}
+{"op": "close", "id": "$try", "outcome": "success"}
+"#;
+    let output = run_br_with_stdin(&workspace, ["batch", "-", "--json"], ops, "batch");
+    assert!(output.status.success(), "batch failed: {}", output.stderr);
+    let result: Value = serde_json::from_str(&extract_json_payload(&output.stdout)).expect("json");
+
+    let attempt = json(
+        &workspace,
+        &["show", result["ids"]["try"].as_str().expect("try id")],
+        "show_try",
+    );
+    assert_eq!(attempt[0]["close_outcome"], "success");
+    let fallback = json(
+        &workspace,
+        &[
+            "show",
+            result["ids"]["fallback"].as_str().expect("fallback id"),
+        ],
+        "show_fallback",
+    );
+    assert_eq!(fallback[0]["status"], "closed");
+    assert_eq!(fallback[0]["close_outcome"], "wontfix");
+
+    let bad_outcome = run_br_with_stdin(
+        &workspace,
+        ["batch", "-"],
+        "{\"op\": \"close\", \"id\": \"$x\", \"outcome\": \"maybe\"}\n",
+        "batch_bad_outcome",
+    );
+    assert!(!bad_outcome.status.success(), "invalid outcome accepted");
+}
+
+#[test]
+fn e2e_batch_sets_fields_and_routed_dependencies() {
+    let _log = common::test_log("e2e_batch_sets_fields_and_routed_dependencies");
+    let workspace = BrWorkspace::new();
+    let other = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init", "--prefix", "hm"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let init = run_br(&other, ["init", "--prefix", "ot"], "init_other");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let blocker = run_br(&other, ["q", "Other blocker"], "create_blocker");
+    assert!(
+        blocker.status.success(),
+        "create failed: {}",
+        blocker.stderr
+    );
+    let blocker_id = blocker.stdout.trim().to_string();
+
+    let beads_dir = workspace.root.join(".beads");
+    let config_path = beads_dir.join("config.yaml");
+    let mut config = fs::read_to_string(&config_path).unwrap_or_default();
+    config.push_str("\nfields:\n  points: int\n  customer: string\n");
+    fs::write(&config_path, config).expect("write config");
+    fs::write(
+        beads_dir.join("routes.jsonl"),
+        format!(
+            "{{\"prefix\":\"ot-\",\"path\":\"{}\"}}\n",
+            other.root.display()
+        ),
+    )
+    .expect("write routes");
+
+    let ops = [
+        r#"{"op": "create", "ref": "a", "title": "Sized", "fields": ["points=3"]}"#.to_string(),
+        r#"{"op": "update", "id": "$a", "fields": ["customer=acme", "points=5"]}"#.to_string(),
+        format!(r#"{{"op": "dep_add", "id": "$a", "depends_on": "{blocker_id}"}}"#),
+    ]
+    .join("\n");
+    let output = run_br_with_stdin(&workspace, ["batch", "-", "--json"], &ops, "batch");
+    assert!(output.status.success(), "batch failed: {}", output.stderr);
+    let result: Value = serde_json::from_str(&extract_json_payload(&output.stdout)).expect("json");
+    let id = result["ids"]["a"].as_str().expect("a id").to_string();
+
+    let shown = json(&workspace, &["show", &id], "show");
+    assert_eq!(shown[0]["fields"]["points"], 5);
+    assert_eq!(shown[0]["fields"]["customer"], "acme");
+    let ready = json(&workspace, &["ready"], "ready");
+    assert!(
+        !ready.to_string().contains(&id),
+        "routed blocker did not block: {ready}"
+    );
+
+    let bad_field = run_br_with_stdin(
+        &workspace,
+        ["batch", "-"],
+        &format!("{{\"op\": \"update\", \"id\": \"{id}\", \"fields\": [\"points=many\"]}}\n"),
+        "batch_bad_field",
+    );
+    assert!(!bad_field.status.success(), "non-integer points accepted");
+    let missing_routed = run_br_with_stdin(
+        &workspace,
+        ["batch", "-"],
+        &format!("{{\"op\": \"dep_add\", \"id\": \"{id}\", \"depends_on\": \"ot-zzzz\"}}\n"),
+        "batch_missing_routed",
+    );
+    assert!(
+        !missing_routed.status.success(),
+        "dependency on a missing routed issue accepted"
+    );
+}
@@ -0,0 +1,203 @@
+//! End-to-end tests for claim leases: `br ready --claim`, `br heartbeat` and
+//! `br claims list`.
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+
+fn create(workspace: &BrWorkspace, args: &[&str]) -> String {
+    let mut full = vec!["create"];
+    full.extend_from_slice(args);
+    full.push("--silent");
+    let created = run_br(workspace, full, "create");
+    assert!(
+        created.status.success(),
+        "create failed: {}",
+        created.stderr
+    );
+    created.stdout.trim().to_string()
+}
+
+fn json(workspace: &BrWorkspace, args: &[&str], label: &str) -> Value {
+    let mut full = args.to_vec();
+    full.push("--json");
+    let output = run_br(workspace, full, label);
+    assert!(output.status.success(), "{label} failed: {}", output.stderr);
+    serde_json::from_str(&extract_json_payload(&output.stdout)).expect("json")
+}
+
+fn ready_ids(workspace: &BrWorkspace, label: &str) -> Vec<String> {
+    json(workspace, &["ready"], label)
+        .as_array()
+        .expect("array")
+        .iter()
+        .filter_map(|issue| issue["id"].as_str().map(str::to_string))
+        .collect()
+}
+
+#[test]
+fn e2e_ready_claim_takes_top_issue_and_hides_it() {
+    let _log = common::test_log("e2e_ready_claim_takes_top_issue_and_hides_it");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let urgent = create(&workspace, &["Urgent", "-p", "0"]);
+    let later = create(&workspace, &["Later", "-p", "3"]);
+
+    let first = json(
+        &workspace,
+        &[
+            "ready",
+            "--claim",
+            "--sort",
+            "priority",
+            "--session",
+            "s-1",
+            "--actor",
+            "alice",
+        ],
+        "ready_claim_alice",
+    );
+    assert_eq!(first["issue"]["id"], urgent.as_str());
+    assert_eq!(first["claim"]["claimed_by"], "alice");
+    assert_eq!(first["claim"]["claimed_by_session"], "s-1");
+
+    assert_eq!(
+        ready_ids(&workspace, "ready_after_claim"),
+        vec![later.clone()]
+    );
+
+    let second = json(
+        &workspace,
+        &["ready", "--claim", "--sort", "priority", "--actor", "bob"],
+        "ready_claim_bob",
+    );
+    assert_eq!(second["issue"]["id"], later.as_str());
+
+    let none = json(
+        &workspace,
+        &["ready", "--claim", "--actor", "carol"],
+        "ready_claim_empty",
+    );
+    assert!(none.is_null(), "{none}");
+
+    let stolen = run_br(
+        &workspace,
+        ["update", &urgent, "--claim", "--actor", "bob"],
+        "update_claim_conflict",
+    );
+    assert!(!stolen.status.success(), "bob took alice's live claim");
+    assert!(stolen.stderr.contains("alice"), "{}", stolen.stderr);
+
+    let shown = json(&workspace, &["show", &urgent], "show");
+    let shown = if shown.is_array() { &shown[0] } else { &shown };
+    assert_eq!(shown["status"], "in_progress");
+    assert_eq!(shown["assignee"], "alice");
+}
+
+#[test]
+fn e2e_heartbeat_extends_lease_and_claims_list_shows_it() {
+    let _log = common::test_log("e2e_heartbeat_extends_lease_and_claims_list_shows_it");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let id = create(&workspace, &["Long job"]);
+    let claim = run_br(
+        &workspace,
+        [
+            "update",
+            &id,
+            "--claim",
+            "--lease",
+            "10m",
+            "--session",
+            "s-9",
+            "--actor",
+            "alice",
+        ],
+        "claim",
+    );
+    assert!(claim.status.success(), "claim failed: {}", claim.stderr);
+
+    let listed = json(&workspace, &["claims", "list"], "claims_list");
+    assert_eq!(listed[0]["issue_id"], id.as_str());
+    assert_eq!(listed[0]["title"], "Long job");
+    assert_eq!(listed[0]["claimed_by_session"], "s-9");
+    assert_eq!(listed[0]["expired"], false);
+    let before = listed[0]["expires_at"]
+        .as_str()
+        .expect("expires_at")
+        .to_string();
+
+    let renewed = json(
+        &workspace,
+        &["heartbeat", &id, "--lease", "2h", "--actor", "alice"],
+        "heartbeat",
+    );
+    let after = renewed[0]["expires_at"].as_str().expect("expires_at");
+    assert!(after > before.as_str(), "{after} <= {before}");
+
+    let foreign = run_br(
+        &workspace,
+        ["heartbeat", &id, "--actor", "bob"],
+        "heartbeat_foreign",
+    );
+    assert!(!foreign.status.success(), "bob renewed alice's claim");
+
+    let bad_lease = run_br(
+        &workspace,
+        ["heartbeat", &id, "--lease", "soon", "--actor", "alice"],
+        "heartbeat_bad_lease",
+    );
+    assert!(!bad_lease.status.success(), "invalid lease accepted");
+
+    let close = run_br(&workspace, ["close", &id, "--actor", "alice"], "close");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+    assert!(
+        json(&workspace, &["claims", "list"], "claims_after_close")
+            .as_array()
+            .expect("array")
+            .is_empty()
+    );
+}
+
+#[test]
+fn e2e_update_claim_refuses_closed_issue() {
+    let _log = common::test_log("e2e_update_claim_refuses_closed_issue");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let id = create(&workspace, &["Finished"]);
+    let close = run_br(&workspace, ["close", &id, "--reason", "done"], "close");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+
+    let claim = run_br(
+        &workspace,
+        ["update", &id, "--claim", "--actor", "alice"],
+        "claim_closed",
+    );
+    assert!(
+        !claim.status.success(),
+        "claiming a closed issue reopened it"
+    );
+    assert!(claim.stderr.contains("closed"), "{}", claim.stderr);
+
+    let shown = json(&workspace, &["show", &id], "show");
+    let shown = if shown.is_array() { &shown[0] } else { &shown };
+    assert_eq!(shown["status"], "closed");
+    assert_eq!(shown["close_reason"], "done");
+    assert!(shown.get("assignee").is_none_or(Value::is_null), "{shown}");
+    assert!(
+        json(&workspace, &["claims", "list"], "claims_list")
+            .as_array()
+            .expect("array")
+            .is_empty()
+    );
+}
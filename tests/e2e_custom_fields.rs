@@ -0,0 +1,139 @@
+//! End-to-end tests for typed custom issue fields.
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+use std::fs;
+
+const FIELDS_CONFIG: &str = r"
+fields:
+  customer: string
+  points: int
+  severity:
+    type: enum
+    values: [low, medium, high]
+";
+
+fn json(stdout: &str) -> Value {
+    serde_json::from_str(&extract_json_payload(stdout)).expect("json output")
+}
+
+fn create(workspace: &BrWorkspace, title: &str, severity: &str) -> String {
+    let field = format!("severity={severity}");
+    let created = run_br(
+        workspace,
+        ["create", title, "--field", &field, "--silent"],
+        "create",
+    );
+    assert!(
+        created.status.success(),
+        "create failed: {}",
+        created.stderr
+    );
+    created.stdout.trim().to_string()
+}
+
+#[test]
+fn e2e_custom_fields_set_filter_sort_and_export() {
+    let _log = common::test_log("e2e_custom_fields_set_filter_sort_and_export");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let config_path = workspace.root.join(".beads/config.yaml");
+    let mut config = fs::read_to_string(&config_path).unwrap_or_default();
+    config.push_str(FIELDS_CONFIG);
+    fs::write(&config_path, config).expect("write config");
+
+    let high = create(&workspace, "Outage", "High");
+    let low = create(&workspace, "Typo", "low");
+
+    let update = run_br(
+        &workspace,
+        [
+            "update",
+            &low,
+            "--field",
+            "points=3",
+            "--field",
+            "customer=acme",
+        ],
+        "update",
+    );
+    assert!(update.status.success(), "update failed: {}", update.stderr);
+
+    let bad_type = run_br(
+        &workspace,
+        ["update", &low, "--field", "points=many"],
+        "bad",
+    );
+    assert!(!bad_type.status.success(), "non-integer points accepted");
+    let undeclared = run_br(
+        &workspace,
+        ["update", &low, "--field", "team=core"],
+        "undecl",
+    );
+    assert!(!undeclared.status.success(), "undeclared field accepted");
+
+    let show = run_br(&workspace, ["show", &low, "--json"], "show");
+    let shown = json(&show.stdout);
+    assert_eq!(shown[0]["fields"]["points"], 3);
+    assert_eq!(shown[0]["fields"]["customer"], "acme");
+    assert_eq!(shown[0]["fields"]["severity"], "low");
+
+    let filtered = run_br(
+        &workspace,
+        ["list", "--field", "severity=high", "--json"],
+        "filter",
+    );
+    assert!(
+        filtered.status.success(),
+        "list failed: {}",
+        filtered.stderr
+    );
+    let rows = json(&filtered.stdout);
+    let ids: Vec<&str> = rows
+        .as_array()
+        .expect("array output")
+        .iter()
+        .filter_map(|row| row["id"].as_str())
+        .collect();
+    assert_eq!(ids, vec![high.as_str()]);
+
+    let sorted = run_br(
+        &workspace,
+        [
+            "list",
+            "--sort",
+            "field:severity",
+            "--format",
+            "csv",
+            "--fields",
+            "id,fields.severity",
+        ],
+        "sort_csv",
+    );
+    assert!(sorted.status.success(), "list failed: {}", sorted.stderr);
+    let lines: Vec<&str> = sorted.stdout.lines().collect();
+    assert_eq!(lines[0], "id,fields.severity");
+    assert_eq!(lines[1], format!("{low},low"));
+    assert_eq!(lines[2], format!("{high},high"));
+
+    let cleared = run_br(
+        &workspace,
+        ["update", &low, "--field", "customer="],
+        "clear",
+    );
+    assert!(cleared.status.success(), "clear failed: {}", cleared.stderr);
+    let flush = run_br(&workspace, ["sync", "--flush-only"], "flush");
+    assert!(flush.status.success(), "flush failed: {}", flush.stderr);
+    let jsonl = fs::read_to_string(workspace.root.join(".beads/issues.jsonl")).expect("jsonl");
+    let exported: Value = jsonl
+        .lines()
+        .map(|line| serde_json::from_str::<Value>(line).expect("jsonl row"))
+        .find(|row| row["id"] == low.as_str())
+        .expect("exported issue");
+    assert_eq!(exported["fields"]["points"], 3);
+    assert!(exported["fields"].get("customer").is_none());
+}
@@ -0,0 +1,100 @@
+//! End-to-end tests for `br diff`.
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+use std::fs;
+
+fn create(workspace: &BrWorkspace, title: &str) -> String {
+    let created = run_br(workspace, ["create", title, "--silent"], "create");
+    assert!(
+        created.status.success(),
+        "create failed: {}",
+        created.stderr
+    );
+    created.stdout.trim().to_string()
+}
+
+fn ids(rows: &Value) -> Vec<&str> {
+    rows.as_array()
+        .expect("array")
+        .iter()
+        .filter_map(|row| row["id"].as_str())
+        .collect()
+}
+
+#[test]
+fn e2e_diff_snapshot_against_database() {
+    let _log = common::test_log("e2e_diff_snapshot_against_database");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let edited = create(&workspace, "Edited");
+    let deleted = create(&workspace, "Deleted");
+    let flush = run_br(&workspace, ["sync", "--flush-only"], "flush");
+    assert!(flush.status.success(), "flush failed: {}", flush.stderr);
+    let snapshot = workspace.root.join("before.jsonl");
+    fs::copy(workspace.root.join(".beads/issues.jsonl"), &snapshot).expect("snapshot");
+    let snapshot = snapshot.to_string_lossy().to_string();
+
+    let update = run_br(
+        &workspace,
+        [
+            "update",
+            &edited,
+            "--title",
+            "Edited again",
+            "--add-label",
+            "urgent",
+        ],
+        "update",
+    );
+    assert!(update.status.success(), "update failed: {}", update.stderr);
+    let delete = run_br(&workspace, ["delete", &deleted, "--force"], "delete");
+    assert!(delete.status.success(), "delete failed: {}", delete.stderr);
+    let added = create(&workspace, "Added");
+
+    let diff = run_br(&workspace, ["diff", &snapshot, "db", "--json"], "diff_json");
+    assert!(diff.status.success(), "diff failed: {}", diff.stderr);
+    let diff: Value = serde_json::from_str(&extract_json_payload(&diff.stdout)).expect("json");
+    assert_eq!(diff["new"], "db");
+    assert_eq!(ids(&diff["added"]), vec![added.as_str()]);
+    assert_eq!(ids(&diff["tombstoned"]), vec![deleted.as_str()]);
+    assert_eq!(ids(&diff["changed"]), vec![edited.as_str()]);
+    let change = &diff["changed"][0];
+    let title = change["fields"]
+        .as_array()
+        .expect("fields")
+        .iter()
+        .find(|field| field["field"] == "title")
+        .expect("title change");
+    assert_eq!(title["old"], "Edited");
+    assert_eq!(title["new"], "Edited again");
+    assert_eq!(change["labels"]["added"][0], "urgent");
+
+    let unified = run_br(&workspace, ["diff", &snapshot, "db"], "diff_text");
+    assert!(unified.status.success(), "diff failed: {}", unified.stderr);
+    assert!(unified.stdout.contains("+++ db"), "{}", unified.stdout);
+    assert!(
+        unified.stdout.contains(&format!("@@ {added} added @@")),
+        "{}",
+        unified.stdout
+    );
+    assert!(
+        unified
+            .stdout
+            .contains("-title: Edited\n+title: Edited again")
+    );
+    assert!(unified.stdout.contains("+label: urgent"));
+
+    let same = run_br(&workspace, ["diff", &snapshot, &snapshot, "--json"], "same");
+    let same: Value = serde_json::from_str(&extract_json_payload(&same.stdout)).expect("json");
+    assert_eq!(same["unchanged"], 2);
+    assert!(same["changed"].as_array().expect("changed").is_empty());
+
+    let missing = run_br(&workspace, ["diff", "nope.jsonl", "db"], "missing");
+    assert!(!missing.status.success(), "unknown side accepted");
+}
@@ -798,9 +798,9 @@ fn regression_full_cli_does_not_touch_git() {
     );
 
     // ========================================================================
-    // PHASE 13: Lint & Orphans
+    // PHASE 13: Lint, Orphans & Git-sync
     // ========================================================================
-    eprintln!("\n[PHASE 13] Testing lint and orphans...");
+    eprintln!("\n[PHASE 13] Testing lint, orphans and git-sync...");
 
     // lint
     let (git_snap, head, count, _) =
@@ -810,6 +810,16 @@ fn regression_full_cli_does_not_touch_git() {
     let (git_snap, head, count, _) =
         check_git_safety!(&workspace, git_snap, head, count, ["orphans"], "orphans");
 
+    // git-sync (reads git log, records progress in beads metadata only)
+    let (git_snap, head, count, _) = check_git_safety!(
+        &workspace,
+        git_snap,
+        head,
+        count,
+        ["git-sync", "--no-auto-flush"],
+        "git_sync"
+    );
+
     // ========================================================================
     // PHASE 14: Epic commands
     // ========================================================================
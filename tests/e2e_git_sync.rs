@@ -0,0 +1,243 @@
+//! End-to-end tests for `br git-sync` and trailer-aware `br orphans`.
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+use std::fs;
+use std::process::Command;
+
+fn git(workspace: &BrWorkspace, args: &[&str]) {
+    let output = Command::new("git")
+        .current_dir(&workspace.root)
+        .args(args)
+        .output()
+        .expect("run git");
+    assert!(
+        output.status.success(),
+        "git {args:?} failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+fn git_commit(workspace: &BrWorkspace, message: &str, label: &str) {
+    fs::write(workspace.root.join(format!("{label}.txt")), label).expect("write file");
+    git(workspace, &["add", "."]);
+    git(workspace, &["commit", "-q", "-m", message]);
+}
+
+fn head_sha(workspace: &BrWorkspace) -> String {
+    let output = Command::new("git")
+        .current_dir(&workspace.root)
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .expect("git rev-parse");
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+fn json(stdout: &str) -> Value {
+    serde_json::from_str(&extract_json_payload(stdout)).expect("json output")
+}
+
+#[test]
+fn e2e_git_sync_applies_trailers_incrementally() {
+    let _log = common::test_log("e2e_git_sync_applies_trailers_incrementally");
+    let workspace = BrWorkspace::new();
+    git(&workspace, &["init", "-q"]);
+    git(&workspace, &["config", "user.email", "test@example.com"]);
+    git(&workspace, &["config", "user.name", "Test User"]);
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let fixed = run_br(&workspace, ["q", "Login bug"], "create_fixed");
+    let fixed_id = fixed.stdout.trim().to_string();
+    let related = run_br(&workspace, ["q", "Auth epic"], "create_related");
+    let related_id = related.stdout.trim().to_string();
+
+    git_commit(
+        &workspace,
+        &format!(
+            "Fix login redirect\n\nCloses: {fixed_id}\nRefs: {related_id}\nBeads-Session: sess-7\n"
+        ),
+        "fix",
+    );
+    let fix_sha = head_sha(&workspace);
+
+    let orphans = run_br(&workspace, ["orphans", "--json"], "orphans");
+    assert!(
+        orphans.status.success(),
+        "orphans failed: {}",
+        orphans.stderr
+    );
+    let orphans = json(&orphans.stdout);
+    let intent_of = |id: &str| {
+        orphans
+            .as_array()
+            .expect("array")
+            .iter()
+            .find(|row| row["issue_id"] == id)
+            .map(|row| row["intent"].clone())
+    };
+    assert_eq!(intent_of(&fixed_id), Some(Value::from("closes")));
+    assert_eq!(intent_of(&related_id), Some(Value::from("refs")));
+
+    let dry = run_br(&workspace, ["git-sync", "--dry-run", "--json"], "dry_run");
+    assert!(dry.status.success(), "dry run failed: {}", dry.stderr);
+    assert_eq!(json(&dry.stdout)["actions"][0]["action"], "closed");
+    let show = run_br(&workspace, ["show", &fixed_id, "--json"], "show_after_dry");
+    assert_eq!(json(&show.stdout)[0]["status"], "open");
+
+    let sync = run_br(&workspace, ["git-sync", "--json"], "sync");
+    assert!(sync.status.success(), "git-sync failed: {}", sync.stderr);
+    let sync = json(&sync.stdout);
+    let actions: Vec<(String, String)> = sync["actions"]
+        .as_array()
+        .expect("actions")
+        .iter()
+        .map(|a| {
+            (
+                a["issue_id"].as_str().unwrap().to_string(),
+                a["action"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        actions,
+        vec![
+            (fixed_id.clone(), "closed".to_string()),
+            (related_id.clone(), "commented".to_string()),
+        ]
+    );
+
+    let show = run_br(&workspace, ["show", &fixed_id, "--json"], "show_closed");
+    let shown = json(&show.stdout);
+    assert_eq!(shown[0]["status"], "closed");
+    assert_eq!(shown[0]["closed_by_session"], "sess-7");
+    assert!(
+        shown[0]["comments"]
+            .as_array()
+            .expect("comments")
+            .iter()
+            .any(|c| c["text"].as_str().is_some_and(|t| t.contains(&fix_sha)))
+    );
+
+    // Nothing new since the last run
+    let again = run_br(&workspace, ["git-sync", "--json"], "sync_again");
+    let again = json(&again.stdout);
+    assert_eq!(again["since"], fix_sha.as_str());
+    assert_eq!(again["actions"].as_array().map(Vec::len), Some(0));
+
+    git_commit(
+        &workspace,
+        &format!("Follow-up cleanup\n\nRefs: {fixed_id}\n"),
+        "followup",
+    );
+    let next = run_br(&workspace, ["git-sync", "--json"], "sync_next");
+    let next = json(&next.stdout);
+    assert_eq!(next["commits_scanned"], 1);
+    assert_eq!(next["actions"][0]["issue_id"], fixed_id.as_str());
+    assert_eq!(next["actions"][0]["action"], "commented");
+
+    // A full rescan finds every trailer already recorded
+    let full = run_br(&workspace, ["git-sync", "--full", "--json"], "sync_full");
+    let full = json(&full.stdout);
+    assert!(
+        full["actions"]
+            .as_array()
+            .expect("actions")
+            .iter()
+            .all(|a| a["action"] == "already_recorded")
+    );
+}
+
+#[test]
+fn e2e_git_sync_close_respects_blockers_and_conditional_dependents() {
+    let _log = common::test_log("e2e_git_sync_close_respects_blockers_and_conditional_dependents");
+    let workspace = BrWorkspace::new();
+    git(&workspace, &["init", "-q"]);
+    git(&workspace, &["config", "user.email", "test@example.com"]);
+    git(&workspace, &["config", "user.name", "Test User"]);
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let create = |title: &str| {
+        let created = run_br(&workspace, ["q", title], "create");
+        created.stdout.trim().to_string()
+    };
+    let blocker = create("Schema migration");
+    let blocked = create("Use new schema");
+    let attempt = create("Try fast path");
+    let fallback = create("Slow path fallback");
+    for (issue, depends_on, dep_type) in [
+        (&blocked, &blocker, "blocks"),
+        (&fallback, &attempt, "conditional-blocks"),
+    ] {
+        let dep = run_br(
+            &workspace,
+            ["dep", "add", issue, depends_on, "--type", dep_type],
+            "dep_add",
+        );
+        assert!(dep.status.success(), "dep add failed: {}", dep.stderr);
+    }
+
+    git_commit(
+        &workspace,
+        &format!("Land work\n\nCloses: {blocked}\nFixes: {attempt}\n"),
+        "land",
+    );
+    let sync = run_br(&workspace, ["git-sync", "--json"], "sync");
+    assert!(sync.status.success(), "git-sync failed: {}", sync.stderr);
+    let sync = json(&sync.stdout);
+    let action_for = |id: &str| {
+        sync["actions"]
+            .as_array()
+            .expect("actions")
+            .iter()
+            .find(|a| a["issue_id"] == id)
+            .cloned()
+            .expect("action")
+    };
+    let skipped = action_for(&blocked);
+    assert_eq!(skipped["action"], "blocked");
+    assert!(
+        skipped["reason"]
+            .as_str()
+            .is_some_and(|r| r.contains(&blocker)),
+        "{skipped}"
+    );
+    assert_eq!(action_for(&attempt)["action"], "closed");
+
+    let status_of = |id: &str| {
+        let show = run_br(&workspace, ["show", id, "--json"], "show");
+        json(&show.stdout)[0].clone()
+    };
+    assert_eq!(status_of(&blocked)["status"], "open");
+    let attempt = status_of(&attempt);
+    assert_eq!(attempt["status"], "closed");
+    assert_eq!(attempt["close_outcome"], "success");
+    // The fallback only runs if the attempt fails, so it is closed as moot
+    let fallback = status_of(&fallback);
+    assert_eq!(fallback["status"], "closed");
+    assert_eq!(fallback["close_outcome"], "wontfix");
+
+    // The cursor stays before the blocked close, so a plain rerun retries it
+    assert!(sync["next_since"].is_null(), "{sync}");
+    let close = run_br(&workspace, ["close", &blocker], "close_blocker");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+    let retry = run_br(&workspace, ["git-sync", "--json"], "sync_retry");
+    assert!(retry.status.success(), "git-sync failed: {}", retry.stderr);
+    let retry = json(&retry.stdout);
+    let retried: Vec<(&str, &str)> = retry["actions"]
+        .as_array()
+        .expect("actions")
+        .iter()
+        .filter_map(|a| Some((a["issue_id"].as_str()?, a["action"].as_str()?)))
+        .collect();
+    assert!(retried.contains(&(blocked.as_str(), "closed")), "{retry}");
+    assert!(
+        retried.contains(&(attempt["id"].as_str().expect("id"), "already_recorded")),
+        "{retry}"
+    );
+    assert_eq!(retry["next_since"], head_sha(&workspace).as_str());
+    assert_eq!(status_of(&blocked)["status"], "closed");
+}
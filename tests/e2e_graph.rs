@@ -403,3 +403,87 @@ fn e2e_graph_chain_depth() {
     assert_eq!(node_b["depth"], 1, "B should be at depth 1");
     assert_eq!(node_c["depth"], 2, "C should be at depth 2");
 }
+
+#[test]
+fn e2e_graph_export_formats() {
+    let _log = common::test_log("e2e_graph_export_formats");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let epic = run_br(&workspace, ["create", "Epic", "-t", "epic"], "create_epic");
+    assert!(epic.status.success(), "create epic failed: {}", epic.stderr);
+    let epic_id = parse_created_id(&epic.stdout);
+
+    let child = run_br(
+        &workspace,
+        ["create", "Child task", "--parent", &epic_id],
+        "create_child",
+    );
+    assert!(
+        child.status.success(),
+        "create child failed: {}",
+        child.stderr
+    );
+    let child_id = parse_created_id(&child.stdout);
+
+    let leaf = run_br(&workspace, ["create", "Leaf task"], "create_leaf");
+    assert!(leaf.status.success(), "create leaf failed: {}", leaf.stderr);
+    let leaf_id = parse_created_id(&leaf.stdout);
+
+    let dep = run_br(&workspace, ["dep", "add", &leaf_id, &child_id], "dep_add");
+    assert!(dep.status.success(), "dep add failed: {}", dep.stderr);
+
+    let json = run_br(
+        &workspace,
+        ["graph", &epic_id, "--format", "json"],
+        "graph_format_json",
+    );
+    assert!(json.status.success(), "graph failed: {}", json.stderr);
+    let payload: Value = serde_json::from_str(&json.stdout).expect("graph json");
+    assert_eq!(payload["root"], epic_id.as_str());
+    assert_eq!(payload["nodes"].as_array().expect("nodes").len(), 3);
+    let edges = payload["edges"].as_array().expect("edges");
+    assert!(edges.iter().any(|e| e["from"] == child_id.as_str()
+        && e["to"] == epic_id.as_str()
+        && e["type"] == "parent-child"));
+    assert!(edges.iter().any(|e| e["from"] == leaf_id.as_str()
+        && e["to"] == child_id.as_str()
+        && e["type"] == "blocks"));
+    assert_eq!(payload["clusters"][0]["id"], epic_id.as_str());
+
+    let shallow = run_br(
+        &workspace,
+        ["graph", &epic_id, "--format", "json", "--max-depth", "1"],
+        "graph_max_depth",
+    );
+    assert!(shallow.status.success(), "graph failed: {}", shallow.stderr);
+    let payload: Value = serde_json::from_str(&shallow.stdout).expect("graph json");
+    assert_eq!(payload["nodes"].as_array().expect("nodes").len(), 2);
+
+    let dot = run_br(
+        &workspace,
+        ["graph", "--all", "--format", "dot"],
+        "graph_dot",
+    );
+    assert!(dot.status.success(), "graph failed: {}", dot.stderr);
+    assert!(dot.stdout.starts_with("digraph beads {"), "{}", dot.stdout);
+    assert!(dot.stdout.contains("label=\"blocks\""), "{}", dot.stdout);
+
+    let mermaid = run_br(
+        &workspace,
+        ["graph", "--all", "--format", "mermaid"],
+        "graph_mermaid",
+    );
+    assert!(mermaid.status.success(), "graph failed: {}", mermaid.stderr);
+    assert!(mermaid.stdout.starts_with("graph TD"), "{}", mermaid.stdout);
+
+    let graphml = run_br(
+        &workspace,
+        ["graph", "--all", "--format", "graphml"],
+        "graph_graphml",
+    );
+    assert!(graphml.status.success(), "graph failed: {}", graphml.stderr);
+    assert!(graphml.stdout.contains("<graphml"), "{}", graphml.stdout);
+}
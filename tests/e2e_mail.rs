@@ -0,0 +1,165 @@
+//! End-to-end tests for `br mail`: replies, acknowledgements and keeping
+//! messages out of the JSONL export.
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+use std::fs;
+
+fn json(workspace: &BrWorkspace, actor: &str, args: &[&str], label: &str) -> Value {
+    let mut full = args.to_vec();
+    full.extend_from_slice(&["--actor", actor, "--json"]);
+    let output = run_br(workspace, full, label);
+    assert!(output.status.success(), "{label} failed: {}", output.stderr);
+    serde_json::from_str(&extract_json_payload(&output.stdout)).expect("json")
+}
+
+fn inbox_ids(workspace: &BrWorkspace, actor: &str, all: bool, label: &str) -> Vec<String> {
+    let args: &[&str] = if all {
+        &["mail", "inbox", "--all"]
+    } else {
+        &["mail", "inbox"]
+    };
+    json(workspace, actor, args, label)
+        .as_array()
+        .expect("array")
+        .iter()
+        .filter_map(|message| message["id"].as_str().map(str::to_string))
+        .collect()
+}
+
+#[test]
+fn e2e_mail_reply_and_ack_round_trip() {
+    let _log = common::test_log("e2e_mail_reply_and_ack_round_trip");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let sent = json(
+        &workspace,
+        "alice",
+        &[
+            "mail",
+            "send",
+            "--to",
+            "bob",
+            "-s",
+            "Review parser",
+            "-b",
+            "Please look at the lexer changes.",
+        ],
+        "send",
+    );
+    let first_id = sent["id"].as_str().expect("id").to_string();
+    assert_eq!(sent["from"], "alice");
+    assert_eq!(sent["to"], "bob");
+    assert_eq!(
+        inbox_ids(&workspace, "bob", false, "bob_inbox"),
+        [first_id.clone()]
+    );
+
+    // Without --to or --subject the reply goes back to the sender
+    let reply = json(
+        &workspace,
+        "bob",
+        &["mail", "reply", &first_id, "-b", "Looks good."],
+        "reply",
+    );
+    let reply_id = reply["id"].as_str().expect("id").to_string();
+    assert_eq!(reply["to"], "alice");
+    assert_eq!(reply["subject"], "Re: Review parser");
+    assert_eq!(reply["reply_to"], first_id.as_str());
+    assert_eq!(reply["thread_id"], first_id.as_str());
+    assert_eq!(
+        inbox_ids(&workspace, "alice", false, "alice_inbox"),
+        [reply_id.clone()]
+    );
+
+    let acked = json(
+        &workspace,
+        "alice",
+        &["mail", "ack", &reply_id],
+        "alice_ack",
+    );
+    assert_eq!(
+        acked["acknowledged"],
+        serde_json::json!([reply_id.as_str()])
+    );
+    assert!(inbox_ids(&workspace, "alice", false, "alice_inbox_acked").is_empty());
+    let all = json(
+        &workspace,
+        "alice",
+        &["mail", "inbox", "--all"],
+        "alice_inbox_all",
+    );
+    assert_eq!(all[0]["id"], reply_id.as_str());
+    assert_eq!(all[0]["read"], true);
+    assert_eq!(all[0]["acknowledged"], true);
+
+    json(&workspace, "bob", &["mail", "ack", &first_id], "bob_ack");
+    assert!(inbox_ids(&workspace, "bob", false, "bob_inbox_acked").is_empty());
+    assert_eq!(
+        inbox_ids(&workspace, "bob", true, "bob_inbox_all"),
+        [first_id.clone()]
+    );
+
+    let thread = json(&workspace, "bob", &["mail", "thread", &reply_id], "thread");
+    assert_eq!(thread["thread_id"], first_id.as_str());
+    let messages = thread["messages"].as_array().expect("messages");
+    let ids: Vec<&str> = messages
+        .iter()
+        .map(|m| m["id"].as_str().expect("id"))
+        .collect();
+    assert_eq!(ids, [first_id.as_str(), reply_id.as_str()]);
+    assert!(messages.iter().all(|m| m["acknowledged"] == true));
+}
+
+#[test]
+fn e2e_mail_messages_stay_out_of_jsonl_export() {
+    let _log = common::test_log("e2e_mail_messages_stay_out_of_jsonl_export");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let task = run_br(&workspace, ["q", "Regular task"], "create_task");
+    assert!(task.status.success(), "create failed: {}", task.stderr);
+    let task_id = task.stdout.trim().to_string();
+
+    let sent = json(
+        &workspace,
+        "alice",
+        &["mail", "send", "--to", "bob", "-s", "Ping"],
+        "send",
+    );
+    let first_id = sent["id"].as_str().expect("id").to_string();
+    let reply = json(
+        &workspace,
+        "bob",
+        &["mail", "reply", &first_id, "-b", "Pong"],
+        "reply",
+    );
+    let reply_id = reply["id"].as_str().expect("id").to_string();
+    json(&workspace, "alice", &["mail", "ack", &reply_id], "ack");
+
+    let flush = run_br(&workspace, ["sync", "--flush-only"], "sync_flush");
+    assert!(flush.status.success(), "flush failed: {}", flush.stderr);
+
+    let jsonl = fs::read_to_string(workspace.root.join(".beads/issues.jsonl")).expect("jsonl");
+    let exported: Vec<Value> = jsonl
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).expect("jsonl line"))
+        .collect();
+    let ids: Vec<&str> = exported
+        .iter()
+        .map(|issue| issue["id"].as_str().expect("id"))
+        .collect();
+    assert_eq!(ids, [task_id.as_str()]);
+    assert!(
+        !jsonl.contains(&first_id) && !jsonl.contains(&reply_id),
+        "message leaked into export: {jsonl}"
+    );
+}
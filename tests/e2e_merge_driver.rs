@@ -0,0 +1,87 @@
+//! End-to-end tests for the `issues.jsonl` git merge driver.
+
+mod common;
+
+use common::cli::{BrWorkspace, run_br};
+use serde_json::Value;
+use std::fs;
+
+fn line(id: &str, title: &str, priority: u8, updated: &str) -> String {
+    format!(
+        r#"{{"id":"{id}","title":"{title}","status":"open","priority":{priority},"issue_type":"task","created_at":"2025-01-01T00:00:00Z","updated_at":"{updated}"}}"#
+    )
+}
+
+fn write_sides(workspace: &BrWorkspace, base: &[String], ours: &[String], theirs: &[String]) {
+    for (name, lines) in [
+        ("base.jsonl", base),
+        ("ours.jsonl", ours),
+        ("theirs.jsonl", theirs),
+    ] {
+        let mut text = lines.join("\n");
+        text.push('\n');
+        fs::write(workspace.root.join(name), text).expect("write side");
+    }
+}
+
+#[test]
+fn e2e_merge_driver_merges_and_flags_conflicts() {
+    let _log = common::test_log("e2e_merge_driver_merges_and_flags_conflicts");
+    let workspace = BrWorkspace::new();
+    let args = ["merge-driver", "base.jsonl", "ours.jsonl", "theirs.jsonl"];
+
+    // Independent edits: ours retitles bd-2, theirs reprioritizes it and adds bd-1
+    write_sides(
+        &workspace,
+        &[line("bd-2", "Shared", 2, "2025-01-01T00:00:00Z")],
+        &[line("bd-2", "Renamed", 2, "2025-01-02T00:00:00Z")],
+        &[
+            line("bd-2", "Shared", 0, "2025-01-03T00:00:00Z"),
+            line("bd-1", "New upstream", 2, "2025-01-03T00:00:00Z"),
+        ],
+    );
+    let clean = run_br(&workspace, args, "clean_merge");
+    assert!(clean.status.success(), "merge failed: {}", clean.stderr);
+    let merged = fs::read_to_string(workspace.root.join("ours.jsonl")).expect("read ours");
+    let rows: Vec<Value> = merged
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("json line"))
+        .collect();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0]["id"], "bd-1");
+    assert_eq!(rows[1]["title"], "Renamed");
+    assert_eq!(rows[1]["priority"], 0);
+    assert!(
+        fs::read_to_string(workspace.root.join("theirs.jsonl"))
+            .expect("read theirs")
+            .contains("\"Shared\""),
+        "driver modified %B"
+    );
+
+    // Both sides retitle the same issue differently
+    write_sides(
+        &workspace,
+        &[line("bd-2", "Shared", 2, "2025-01-01T00:00:00Z")],
+        &[line("bd-2", "Ours", 2, "2025-01-02T00:00:00Z")],
+        &[line("bd-2", "Theirs", 2, "2025-01-03T00:00:00Z")],
+    );
+    let conflict = run_br(&workspace, args, "conflict_merge");
+    assert_eq!(conflict.status.code(), Some(6), "{}", conflict.stderr);
+    assert!(conflict.stderr.contains("bd-2"), "{}", conflict.stderr);
+    let merged = fs::read_to_string(workspace.root.join("ours.jsonl")).expect("read ours");
+    assert!(merged.starts_with("<<<<<<< ours\n"), "{merged}");
+    assert!(merged.contains("\"Ours\"") && merged.contains("\"Theirs\""));
+    assert!(
+        !workspace.root.join("ours.jsonl.br-merge.tmp").exists(),
+        "temp file left behind"
+    );
+
+    let setup = run_br(&workspace, ["init", "--merge-driver"], "setup");
+    assert!(setup.status.success(), "setup failed: {}", setup.stderr);
+    assert!(setup.stdout.contains("issues.jsonl merge=beads"));
+    assert!(setup.stdout.contains("driver = br merge-driver %O %A %B"));
+    assert!(
+        !workspace.root.join(".beads/beads.db").exists(),
+        "--merge-driver initialized a workspace"
+    );
+}
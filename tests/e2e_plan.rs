@@ -0,0 +1,98 @@
+mod common;
+
+use common::cli::{BrWorkspace, run_br};
+use serde_json::Value;
+
+fn create(workspace: &BrWorkspace, args: &[&str], label: &str) -> String {
+    let mut full = vec!["create"];
+    full.extend_from_slice(args);
+    let out = run_br(workspace, full, label);
+    assert!(out.status.success(), "create failed: {}", out.stderr);
+    let line = out.stdout.lines().next().unwrap_or("");
+    let normalized = line.strip_prefix("✓ ").unwrap_or(line);
+    normalized
+        .strip_prefix("Created ")
+        .and_then(|rest| rest.split(':').next())
+        .unwrap_or("")
+        .trim()
+        .to_string()
+}
+
+#[test]
+fn e2e_plan_critical_path_and_schedule() {
+    let _log = common::test_log("e2e_plan_critical_path_and_schedule");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let epic = create(
+        &workspace,
+        &["Epic", "-t", "epic", "--estimate", "0"],
+        "epic",
+    );
+    let design = create(
+        &workspace,
+        &["Design", "--parent", &epic, "--estimate", "120"],
+        "design",
+    );
+    let build = create(
+        &workspace,
+        &["Build", "--parent", &epic, "--estimate", "240"],
+        "build",
+    );
+    let docs = create(
+        &workspace,
+        &["Docs", "--parent", &epic, "--estimate", "60"],
+        "docs",
+    );
+    let _unrelated = create(&workspace, &["Unrelated", "--estimate", "999"], "other");
+
+    let dep = run_br(&workspace, ["dep", "add", &build, &design], "dep_add");
+    assert!(dep.status.success(), "dep add failed: {}", dep.stderr);
+
+    let plan = run_br(
+        &workspace,
+        [
+            "plan",
+            &epic,
+            "--workers",
+            "2",
+            "--start",
+            "2026-01-05T09:00:00Z",
+            "--format",
+            "json",
+        ],
+        "plan_json",
+    );
+    assert!(plan.status.success(), "plan failed: {}", plan.stderr);
+    let json: Value = serde_json::from_str(&plan.stdout).expect("plan json");
+
+    assert_eq!(json["issues"].as_array().expect("issues").len(), 4);
+    assert_eq!(json["critical_path_minutes"], 360);
+    assert_eq!(json["schedule_minutes"], 360);
+    assert_eq!(json["projected_completion"], "2026-01-05T15:00:00Z");
+    let path: Vec<&str> = json["critical_path"]
+        .as_array()
+        .expect("critical path")
+        .iter()
+        .filter_map(Value::as_str)
+        .collect();
+    assert_eq!(path, vec![design.as_str(), build.as_str(), epic.as_str()]);
+    let docs_entry = json["issues"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|i| i["id"] == docs.as_str())
+        .expect("docs planned");
+    assert_eq!(docs_entry["slack_minutes"], 300);
+
+    let gantt = run_br(
+        &workspace,
+        ["plan", &epic, "--format", "mermaid"],
+        "plan_mermaid",
+    );
+    assert!(gantt.status.success(), "plan failed: {}", gantt.stderr);
+    assert!(gantt.stdout.starts_with("gantt"), "{}", gantt.stdout);
+    assert!(gantt.stdout.contains(":crit,"), "{}", gantt.stdout);
+}
@@ -0,0 +1,284 @@
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+use std::fs;
+
+fn json_value(stdout: &str) -> Value {
+    let payload = extract_json_payload(stdout);
+    serde_json::from_str::<Value>(&payload).expect("json output")
+}
+
+fn ids(value: &Value) -> Vec<String> {
+    value
+        .as_array()
+        .expect("array output")
+        .iter()
+        .filter_map(|row| row["id"].as_str().map(str::to_string))
+        .collect()
+}
+
+#[test]
+fn e2e_routed_dependency_blocks_until_closed_in_other_workspace() {
+    let _log = common::test_log("e2e_routed_dependency_blocks_until_closed_in_other_workspace");
+    let home = BrWorkspace::new();
+    let other = BrWorkspace::new();
+
+    let init = run_br(&home, ["init", "--prefix", "hm"], "init_home");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let init = run_br(&other, ["init", "--prefix", "ot"], "init_other");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let blocker = run_br(&other, ["q", "Other blocker"], "create_blocker");
+    assert!(
+        blocker.status.success(),
+        "create failed: {}",
+        blocker.stderr
+    );
+    let blocker_id = blocker.stdout.trim().to_string();
+    let upstream = run_br(&other, ["q", "Other upstream"], "create_upstream");
+    let upstream_id = upstream.stdout.trim().to_string();
+    let dep = run_br(
+        &other,
+        ["dep", "add", &blocker_id, &upstream_id],
+        "dep_add_other",
+    );
+    assert!(dep.status.success(), "dep add failed: {}", dep.stderr);
+
+    let task = run_br(&home, ["q", "Home task"], "create_task");
+    let task_id = task.stdout.trim().to_string();
+
+    fs::write(
+        home.root.join(".beads/routes.jsonl"),
+        format!(
+            "{{\"prefix\":\"ot-\",\"path\":\"{}\"}}\n",
+            other.root.display()
+        ),
+    )
+    .expect("write routes");
+
+    let missing = run_br(
+        &home,
+        ["dep", "add", &task_id, "ot-zzzz"],
+        "dep_add_missing",
+    );
+    assert!(
+        !missing.status.success(),
+        "dep add to a missing routed issue should fail"
+    );
+
+    let dep = run_br(
+        &home,
+        ["dep", "add", &task_id, &blocker_id],
+        "dep_add_routed",
+    );
+    assert!(dep.status.success(), "dep add failed: {}", dep.stderr);
+
+    let ready = run_br(&home, ["ready", "--json"], "ready_blocked");
+    assert!(ready.status.success(), "ready failed: {}", ready.stderr);
+    assert!(!ids(&json_value(&ready.stdout)).contains(&task_id));
+
+    let blocked = run_br(&home, ["blocked", "--json"], "blocked");
+    assert!(
+        blocked.status.success(),
+        "blocked failed: {}",
+        blocked.stderr
+    );
+    let blocked = json_value(&blocked.stdout);
+    let row = blocked
+        .as_array()
+        .expect("array output")
+        .iter()
+        .find(|row| row["id"] == task_id.as_str())
+        .expect("task listed as blocked");
+    assert!(
+        row["blocked_by"]
+            .as_array()
+            .expect("blocked_by")
+            .iter()
+            .any(|b| b.as_str().is_some_and(|b| b.starts_with(&blocker_id)))
+    );
+
+    let show = run_br(&home, ["show", &task_id, "--json"], "show");
+    assert!(show.status.success(), "show failed: {}", show.stderr);
+    let show = json_value(&show.stdout);
+    let dependency = &show[0]["dependencies"][0];
+    assert_eq!(dependency["id"], blocker_id.as_str());
+    assert_eq!(dependency["title"], "Other blocker");
+    assert_eq!(dependency["status"], "open");
+
+    let tree = run_br(&home, ["dep", "tree", &task_id, "--json"], "dep_tree");
+    assert!(tree.status.success(), "dep tree failed: {}", tree.stderr);
+    let tree_ids = ids(&json_value(&tree.stdout));
+    assert_eq!(
+        tree_ids,
+        vec![task_id.clone(), blocker_id.clone(), upstream_id.clone()]
+    );
+
+    let close = run_br(&other, ["close", &upstream_id], "close_upstream");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+    let close = run_br(&other, ["close", &blocker_id], "close_blocker");
+    assert!(close.status.success(), "close failed: {}", close.stderr);
+
+    let ready = run_br(&home, ["ready", "--json"], "ready_unblocked");
+    assert!(ready.status.success(), "ready failed: {}", ready.stderr);
+    assert!(ids(&json_value(&ready.stdout)).contains(&task_id));
+}
+
+#[test]
+fn e2e_routed_dependency_removed_after_target_deleted() {
+    let _log = common::test_log("e2e_routed_dependency_removed_after_target_deleted");
+    let home = BrWorkspace::new();
+    let other = BrWorkspace::new();
+
+    let init = run_br(&home, ["init", "--prefix", "hm"], "init_home");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let init = run_br(&other, ["init", "--prefix", "ot"], "init_other");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let blocker = run_br(&other, ["q", "Other blocker"], "create_blocker");
+    let blocker_id = blocker.stdout.trim().to_string();
+    let task = run_br(&home, ["q", "Home task"], "create_task");
+    let task_id = task.stdout.trim().to_string();
+
+    fs::write(
+        home.root.join(".beads/routes.jsonl"),
+        format!(
+            "{{\"prefix\":\"ot-\",\"path\":\"{}\"}}\n",
+            other.root.display()
+        ),
+    )
+    .expect("write routes");
+
+    let dep = run_br(
+        &home,
+        ["dep", "add", &task_id, &blocker_id],
+        "dep_add_routed",
+    );
+    assert!(dep.status.success(), "dep add failed: {}", dep.stderr);
+
+    // Gone from the other workspace entirely, as after a purge
+    let conn = rusqlite::Connection::open(other.root.join(".beads/beads.db")).expect("open db");
+    conn.execute("DELETE FROM issues WHERE id = ?", [&blocker_id])
+        .expect("delete blocker");
+    drop(conn);
+
+    let remove = run_br(
+        &home,
+        ["dep", "remove", &task_id, &blocker_id, "--json"],
+        "dep_remove_dangling",
+    );
+    assert!(
+        remove.status.success(),
+        "dep remove failed: {}",
+        remove.stderr
+    );
+    let remove = json_value(&remove.stdout);
+    assert_eq!(remove["status"], "ok");
+    assert_eq!(remove["depends_on_id"], blocker_id.as_str());
+
+    let show = run_br(&home, ["show", &task_id, "--json"], "show");
+    assert!(show.status.success(), "show failed: {}", show.stderr);
+    let show = json_value(&show.stdout);
+    assert!(
+        show[0]["dependencies"].as_array().is_none_or(Vec::is_empty),
+        "dependency should be gone: {show}"
+    );
+}
+
+#[test]
+fn e2e_routed_blocker_status_flips_drive_ready_and_tree() {
+    let _log = common::test_log("e2e_routed_blocker_status_flips_drive_ready_and_tree");
+    let home = BrWorkspace::new();
+    let other = BrWorkspace::new();
+
+    let init = run_br(&home, ["init", "--prefix", "hm"], "init_home");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let init = run_br(&other, ["init", "--prefix", "ot"], "init_other");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let blocker = run_br(&other, ["q", "Other blocker"], "create_blocker");
+    let blocker_id = blocker.stdout.trim().to_string();
+    let upstream = run_br(&other, ["q", "Other upstream"], "create_upstream");
+    let upstream_id = upstream.stdout.trim().to_string();
+    let dep = run_br(
+        &other,
+        ["dep", "add", &blocker_id, &upstream_id, "--type", "related"],
+        "dep_add_other",
+    );
+    assert!(dep.status.success(), "dep add failed: {}", dep.stderr);
+    let task = run_br(&home, ["q", "Home task"], "create_task");
+    let task_id = task.stdout.trim().to_string();
+
+    fs::write(
+        home.root.join(".beads/routes.jsonl"),
+        format!(
+            "{{\"prefix\":\"ot-\",\"path\":\"{}\"}}\n",
+            other.root.display()
+        ),
+    )
+    .expect("write routes");
+    let dep = run_br(
+        &home,
+        ["dep", "add", &task_id, &blocker_id],
+        "dep_add_routed",
+    );
+    assert!(dep.status.success(), "dep add failed: {}", dep.stderr);
+
+    let task_ready = |label: &str| {
+        let ready = run_br(&home, ["ready", "--json"], label);
+        assert!(ready.status.success(), "ready failed: {}", ready.stderr);
+        ids(&json_value(&ready.stdout)).contains(&task_id)
+    };
+    // (id, depth, status) of each node in the home task's dependency tree
+    let tree = |label: &str| -> Vec<(String, u64, String)> {
+        let tree = run_br(&home, ["dep", "tree", &task_id, "--json"], label);
+        assert!(tree.status.success(), "dep tree failed: {}", tree.stderr);
+        json_value(&tree.stdout)
+            .as_array()
+            .expect("array output")
+            .iter()
+            .map(|node| {
+                (
+                    node["id"].as_str().expect("id").to_string(),
+                    node["depth"].as_u64().expect("depth"),
+                    node["status"].as_str().expect("status").to_string(),
+                )
+            })
+            .collect()
+    };
+    let in_other = |args: &[&str], label: &str| {
+        let output = run_br(&other, args, label);
+        assert!(output.status.success(), "{label} failed: {}", output.stderr);
+    };
+
+    assert!(!task_ready("ready_open"), "open routed blocker ignored");
+    assert_eq!(
+        tree("tree_open"),
+        vec![
+            (task_id.clone(), 0, "open".to_string()),
+            (blocker_id.clone(), 1, "open".to_string()),
+            (upstream_id.clone(), 2, "open".to_string()),
+        ]
+    );
+
+    in_other(
+        &["update", &blocker_id, "--status", "in_progress"],
+        "start_blocker",
+    );
+    assert!(
+        !task_ready("ready_in_progress"),
+        "in-progress blocker ignored"
+    );
+    assert_eq!(tree("tree_in_progress")[1].2, "in_progress");
+
+    in_other(&["close", &blocker_id], "close_blocker");
+    assert!(task_ready("ready_closed"), "closed blocker still blocks");
+    assert_eq!(tree("tree_closed")[1].2, "closed");
+
+    in_other(&["reopen", &blocker_id], "reopen_blocker");
+    assert!(!task_ready("ready_reopened"), "reopened blocker ignored");
+    let reopened = tree("tree_reopened");
+    assert_eq!(reopened[1], (blocker_id.clone(), 1, "open".to_string()));
+    assert_eq!(reopened[2].0, upstream_id);
+}
@@ -0,0 +1,146 @@
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+use std::fs;
+
+fn json_value(stdout: &str) -> Value {
+    let payload = extract_json_payload(stdout);
+    serde_json::from_str::<Value>(&payload).expect("json output")
+}
+
+/// A workspace where the database and `issues.jsonl` retitled the same issue
+/// differently since the base snapshot. Returns the workspace and the ID.
+fn conflicted_workspace(label: &str) -> (BrWorkspace, String) {
+    let workspace = BrWorkspace::new();
+    let init = run_br(
+        &workspace,
+        ["init", "--prefix", "sr"],
+        &format!("{label}_init"),
+    );
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let create = run_br(&workspace, ["q", "Base title"], &format!("{label}_create"));
+    assert!(create.status.success(), "create failed: {}", create.stderr);
+    let id = create.stdout.trim().to_string();
+
+    let flush = run_br(
+        &workspace,
+        ["sync", "--flush-only"],
+        &format!("{label}_flush"),
+    );
+    assert!(flush.status.success(), "flush failed: {}", flush.stderr);
+    let beads_dir = workspace.root.join(".beads");
+    let jsonl_path = beads_dir.join("issues.jsonl");
+    let base_line = fs::read_to_string(&jsonl_path).expect("read jsonl");
+    fs::write(beads_dir.join("beads.base.jsonl"), &base_line).expect("write base");
+
+    let update = run_br(
+        &workspace,
+        ["--no-auto-flush", "update", &id, "--title", "Local title"],
+        &format!("{label}_update"),
+    );
+    assert!(update.status.success(), "update failed: {}", update.stderr);
+
+    let mut remote: Value = serde_json::from_str(base_line.trim()).expect("base json");
+    remote["title"] = Value::from("Remote title");
+    remote["updated_at"] = Value::from("2099-01-01T00:00:00Z");
+    fs::write(&jsonl_path, format!("{remote}\n")).expect("write remote");
+
+    let pending = run_br(
+        &workspace,
+        ["--no-auto-import", "sync", "--resolve", "--json"],
+        &format!("{label}_pending"),
+    );
+    assert!(
+        pending.status.success(),
+        "sync --resolve failed: {}",
+        pending.stderr
+    );
+    let pending = json_value(&pending.stdout);
+    assert_eq!(pending["status"], "conflicts_pending");
+    assert_eq!(pending["conflicts"][0]["id"], id.as_str());
+
+    (workspace, id)
+}
+
+#[test]
+fn e2e_sync_resolve_take_each_side() {
+    let _log = common::test_log("e2e_sync_resolve_take_each_side");
+
+    for (take, expected) in [
+        ("local", "Local title"),
+        ("remote", "Remote title"),
+        ("base", "Base title"),
+        ("ours", "Local title"),
+        ("theirs", "Remote title"),
+    ] {
+        let (workspace, id) = conflicted_workspace(take);
+
+        let resolve = run_br(
+            &workspace,
+            [
+                "--no-auto-import",
+                "sync",
+                "resolve",
+                &id,
+                "--take",
+                take,
+                "--json",
+            ],
+            &format!("{take}_resolve"),
+        );
+        assert!(
+            resolve.status.success(),
+            "resolve --take {take} failed: {}",
+            resolve.stderr
+        );
+        let resolve = json_value(&resolve.stdout);
+        assert_eq!(resolve["id"], id.as_str());
+        assert_eq!(resolve["unresolved"], 0);
+
+        let apply = run_br(
+            &workspace,
+            ["--no-auto-import", "sync", "--resolve", "--json"],
+            &format!("{take}_apply"),
+        );
+        assert!(apply.status.success(), "apply failed: {}", apply.stderr);
+        assert_eq!(json_value(&apply.stdout)["status"], "success");
+
+        let show = run_br(&workspace, ["show", &id, "--json"], &format!("{take}_show"));
+        assert!(show.status.success(), "show failed: {}", show.stderr);
+        assert_eq!(
+            json_value(&show.stdout)[0]["title"],
+            expected,
+            "--take {take}"
+        );
+    }
+}
+
+#[test]
+fn e2e_sync_resolve_rejects_unknown_side() {
+    let _log = common::test_log("e2e_sync_resolve_rejects_unknown_side");
+    let (workspace, id) = conflicted_workspace("invalid");
+
+    let resolve = run_br(
+        &workspace,
+        ["sync", "resolve", &id, "--take", "mine"],
+        "resolve_invalid",
+    );
+    assert!(!resolve.status.success(), "--take mine should be rejected");
+    for side in ["local", "remote", "base"] {
+        assert!(
+            resolve.stderr.contains(side),
+            "error should list {side}: {}",
+            resolve.stderr
+        );
+    }
+
+    let help = run_br(&workspace, ["sync", "resolve", "--help"], "resolve_help");
+    assert!(help.status.success(), "help failed: {}", help.stderr);
+    assert!(
+        help.stdout.contains("local") && help.stdout.contains("remote"),
+        "--help should list the sides: {}",
+        help.stdout
+    );
+}
@@ -0,0 +1,150 @@
+//! End-to-end tests for `br undelete`, `br tombstones list` and `br gc`.
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+use std::fmt::Write as _;
+use std::fs;
+
+fn create(workspace: &BrWorkspace, args: &[&str]) -> String {
+    let mut full = vec!["create"];
+    full.extend_from_slice(args);
+    full.push("--silent");
+    let created = run_br(workspace, full, "create");
+    assert!(
+        created.status.success(),
+        "create failed: {}",
+        created.stderr
+    );
+    created.stdout.trim().to_string()
+}
+
+fn json(workspace: &BrWorkspace, args: &[&str], label: &str) -> Value {
+    let mut full = args.to_vec();
+    full.push("--json");
+    let output = run_br(workspace, full, label);
+    assert!(output.status.success(), "{label} failed: {}", output.stderr);
+    serde_json::from_str(&extract_json_payload(&output.stdout)).expect("json")
+}
+
+#[test]
+fn e2e_undelete_restores_type_status_and_dependencies() {
+    let _log = common::test_log("e2e_undelete_restores_type_status_and_dependencies");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let bug = create(&workspace, &["Flaky bug", "--type", "bug"]);
+    let blocker = create(&workspace, &["Blocker"]);
+    let start = run_br(
+        &workspace,
+        ["update", &bug, "--status", "in_progress"],
+        "start",
+    );
+    assert!(start.status.success(), "update failed: {}", start.stderr);
+    let dep = run_br(&workspace, ["dep", "add", &bug, &blocker], "dep_add");
+    assert!(dep.status.success(), "dep add failed: {}", dep.stderr);
+
+    let delete = run_br(
+        &workspace,
+        ["delete", &bug, "--force", "--reason", "duplicate"],
+        "delete",
+    );
+    assert!(delete.status.success(), "delete failed: {}", delete.stderr);
+
+    let listed = json(&workspace, &["tombstones", "list"], "tombstones");
+    assert_eq!(listed[0]["id"], bug.as_str());
+    assert_eq!(listed[0]["delete_reason"], "duplicate");
+    assert_eq!(listed[0]["original_type"], "bug");
+    assert_eq!(listed[0]["age_days"], 0);
+
+    let undeleted = json(&workspace, &["undelete", &bug], "undelete");
+    assert_eq!(undeleted["undeleted"][0]["status"], "in_progress");
+    assert_eq!(undeleted["undeleted"][0]["issue_type"], "bug");
+    assert_eq!(undeleted["undeleted"][0]["dependencies_restored"], 1);
+
+    let shown = json(&workspace, &["show", &bug], "show");
+    let shown = if shown.is_array() { &shown[0] } else { &shown };
+    assert_eq!(shown["status"], "in_progress");
+    assert!(shown["deleted_at"].is_null());
+    let deps = json(&workspace, &["dep", "list", &bug], "dep_list");
+    assert!(deps.to_string().contains(&blocker), "{deps}");
+    assert!(
+        json(&workspace, &["tombstones", "list"], "tombstones_after")
+            .as_array()
+            .expect("array")
+            .is_empty()
+    );
+
+    let again = json(&workspace, &["undelete", &bug], "undelete_again");
+    assert!(again["undeleted"].as_array().expect("array").is_empty());
+    assert_eq!(again["skipped"][0]["id"], bug.as_str());
+}
+
+#[test]
+fn e2e_gc_purges_expired_tombstones_from_db_and_jsonl() {
+    let _log = common::test_log("e2e_gc_purges_expired_tombstones_from_db_and_jsonl");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+
+    let recent = create(&workspace, &["Recently deleted"]);
+    let delete = run_br(&workspace, ["delete", &recent, "--force"], "delete");
+    assert!(delete.status.success(), "delete failed: {}", delete.stderr);
+    let flush = run_br(&workspace, ["sync", "--flush-only"], "flush");
+    assert!(flush.status.success(), "flush failed: {}", flush.stderr);
+
+    // A tombstone from long ago, arriving through the JSONL
+    let prefix = recent.split('-').next().expect("prefix");
+    let old = format!("{prefix}-old1");
+    let jsonl_path = workspace.root.join(".beads/issues.jsonl");
+    let mut jsonl = fs::read_to_string(&jsonl_path).expect("read jsonl");
+    let _ = writeln!(
+        jsonl,
+        r#"{{"id":"{old}","title":"Ancient","status":"tombstone","priority":2,"issue_type":"task","created_at":"2020-01-01T00:00:00Z","updated_at":"2020-01-02T00:00:00Z","deleted_at":"2020-01-02T00:00:00Z","deleted_by":"someone","delete_reason":"stale","original_type":"task"}}"#
+    );
+    fs::write(&jsonl_path, jsonl).expect("write jsonl");
+    let import = run_br(&workspace, ["sync", "--import-only"], "import");
+    assert!(import.status.success(), "import failed: {}", import.stderr);
+
+    let missing = run_br(&workspace, ["gc"], "gc_no_retention");
+    assert!(
+        !missing.status.success(),
+        "gc ran without a retention period"
+    );
+
+    let preview = json(
+        &workspace,
+        &["gc", "--dry-run", "--older-than", "30"],
+        "gc_dry_run",
+    );
+    assert_eq!(preview["purged"][0]["id"], old.as_str());
+    assert_eq!(preview["purged"].as_array().expect("array").len(), 1);
+    assert_eq!(preview["purged_count"], 0);
+    assert!(
+        fs::read_to_string(&jsonl_path)
+            .expect("read")
+            .contains(&old)
+    );
+
+    let gc = json(&workspace, &["gc", "--older-than", "30"], "gc");
+    assert_eq!(gc["purged_count"], 1);
+    let jsonl = fs::read_to_string(&jsonl_path).expect("read jsonl");
+    assert!(!jsonl.contains(&old), "purged tombstone still in JSONL");
+    assert!(
+        jsonl.contains(&recent),
+        "recent tombstone dropped from JSONL"
+    );
+
+    let listed = json(&workspace, &["tombstones", "list"], "tombstones");
+    let ids: Vec<&str> = listed
+        .as_array()
+        .expect("array")
+        .iter()
+        .filter_map(|entry| entry["id"].as_str())
+        .collect();
+    assert_eq!(ids, vec![recent.as_str()]);
+}
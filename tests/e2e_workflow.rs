@@ -0,0 +1,114 @@
+//! End-to-end tests for custom statuses and workflow transitions.
+
+mod common;
+
+use common::cli::{BrWorkspace, extract_json_payload, run_br};
+use serde_json::Value;
+use std::fs;
+
+const WORKFLOW_CONFIG: &str = r#"
+statuses:
+  review: active
+  qa: waiting
+workflow:
+  transitions:
+    review: [qa, closed]
+  required:
+    "*":
+      closed: [close_reason]
+"#;
+
+fn json(stdout: &str) -> Value {
+    serde_json::from_str(&extract_json_payload(stdout)).expect("json output")
+}
+
+fn error_json(stderr: &str) -> Value {
+    let start = stderr.find('{').expect("json error on stderr");
+    serde_json::from_str(&stderr[start..]).expect("error json")
+}
+
+fn ready_ids(workspace: &BrWorkspace, label: &str) -> Vec<String> {
+    let ready = run_br(workspace, ["ready", "--json"], label);
+    assert!(ready.status.success(), "ready failed: {}", ready.stderr);
+    json(&ready.stdout)
+        .as_array()
+        .expect("array output")
+        .iter()
+        .filter_map(|row| row["id"].as_str().map(str::to_string))
+        .collect()
+}
+
+#[test]
+fn e2e_workflow_custom_statuses_and_transitions() {
+    let _log = common::test_log("e2e_workflow_custom_statuses_and_transitions");
+    let workspace = BrWorkspace::new();
+
+    let init = run_br(&workspace, ["init"], "init");
+    assert!(init.status.success(), "init failed: {}", init.stderr);
+    let config_path = workspace.root.join(".beads/config.yaml");
+    let mut config = fs::read_to_string(&config_path).unwrap_or_default();
+    config.push_str(WORKFLOW_CONFIG);
+    fs::write(&config_path, config).expect("write config");
+
+    let created = run_br(&workspace, ["q", "Review me"], "create");
+    assert!(
+        created.status.success(),
+        "create failed: {}",
+        created.stderr
+    );
+    let id = created.stdout.trim().to_string();
+
+    let undeclared = run_br(&workspace, ["update", &id, "-s", "limbo"], "undeclared");
+    assert!(!undeclared.status.success(), "undeclared status accepted");
+
+    let review = run_br(&workspace, ["update", &id, "-s", "review"], "to_review");
+    assert!(review.status.success(), "update failed: {}", review.stderr);
+    assert!(ready_ids(&workspace, "ready_review").contains(&id));
+
+    let rejected = run_br(
+        &workspace,
+        ["update", &id, "-s", "open", "--json"],
+        "to_open_rejected",
+    );
+    assert!(!rejected.status.success(), "disallowed transition accepted");
+    assert_eq!(rejected.status.code(), Some(4));
+    let error = error_json(&rejected.stderr);
+    assert_eq!(error["error"]["code"], "INVALID_TRANSITION");
+    assert_eq!(error["error"]["hint"], "Allowed from 'review': closed, qa");
+
+    let qa = run_br(&workspace, ["update", &id, "-s", "qa"], "to_qa");
+    assert!(qa.status.success(), "update failed: {}", qa.stderr);
+    assert!(!ready_ids(&workspace, "ready_qa").contains(&id));
+
+    let stats = run_br(&workspace, ["stats", "--json"], "stats");
+    assert!(stats.status.success(), "stats failed: {}", stats.stderr);
+    let stats = json(&stats.stdout);
+    let custom = stats["breakdowns"]
+        .as_array()
+        .expect("breakdowns")
+        .iter()
+        .find(|b| b["dimension"] == "custom_status")
+        .expect("custom status breakdown");
+    assert_eq!(custom["counts"][0]["key"], "qa (waiting)");
+
+    let missing = run_br(
+        &workspace,
+        ["update", &id, "-s", "closed", "--json"],
+        "close_without_reason",
+    );
+    assert!(!missing.status.success(), "required field not enforced");
+    let error = error_json(&missing.stderr);
+    assert_eq!(error["error"]["code"], "REQUIRED_FIELD");
+    assert_eq!(error["error"]["context"]["field"], "close_reason");
+
+    let closed = run_br(
+        &workspace,
+        ["update", &id, "-s", "closed", "--reason", "Passed QA"],
+        "close_with_reason",
+    );
+    assert!(closed.status.success(), "close failed: {}", closed.stderr);
+    let show = run_br(&workspace, ["show", &id, "--json"], "show");
+    let shown = json(&show.stdout);
+    assert_eq!(shown[0]["status"], "closed");
+    assert_eq!(shown[0]["close_reason"], "Passed QA");
+}
@@ -0,0 +1,123 @@
+//! In-process tests of the library entry points, [`beads_rust::run`] and
+//! [`beads_rust::Workspace`], without spawning the `br` binary.
+
+use beads_rust::cli::Cli;
+use beads_rust::cli::commands::close::CloseArgs;
+use beads_rust::model::{CloseOutcome, DependencyType, Status};
+use beads_rust::storage::{ReadyFilters, ReadySortPolicy, SqliteStorage};
+use beads_rust::workspace::{NewIssue, Workspace};
+use clap::Parser;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+fn init_workspace() -> (TempDir, PathBuf) {
+    let temp = TempDir::new().unwrap();
+    let beads_dir = temp.path().join(".beads");
+    std::fs::create_dir(&beads_dir).unwrap();
+    let mut storage = SqliteStorage::open(&beads_dir.join("beads.db")).unwrap();
+    storage.set_config("issue_prefix", "lib").unwrap();
+    (temp, beads_dir)
+}
+
+/// Run `br` in process against the workspace in `beads_dir`.
+fn br(beads_dir: &Path, args: &[&str]) -> beads_rust::Result<()> {
+    let db = beads_dir.join("beads.db");
+    let mut argv = vec![
+        "br",
+        "--db",
+        db.to_str().unwrap(),
+        "--actor",
+        "tester",
+        "--quiet",
+    ];
+    argv.extend_from_slice(args);
+    beads_rust::run(Cli::parse_from(argv))
+}
+
+fn status(ws: &Workspace, id: &str) -> Status {
+    ws.show(id).unwrap().issue.status
+}
+
+#[test]
+fn run_close_with_success_closes_moot_conditional_dependent() {
+    let (_temp, beads_dir) = init_workspace();
+    let mut ws = Workspace::open(&beads_dir).unwrap().with_actor("tester");
+    let attempt = ws.create(&NewIssue::new("Try the fast path")).unwrap();
+    let fallback = ws.create(&NewIssue::new("Fall back")).unwrap();
+    ws.add_dependency(
+        &fallback.id,
+        &attempt.id,
+        &DependencyType::ConditionalBlocks,
+    )
+    .unwrap();
+    drop(ws);
+
+    br(&beads_dir, &["close", &attempt.id, "--outcome", "success"]).unwrap();
+
+    let ws = Workspace::open(&beads_dir).unwrap();
+    let attempt = ws.show(&attempt.id).unwrap().issue;
+    assert_eq!(attempt.status, Status::Closed);
+    assert_eq!(attempt.close_outcome, Some(CloseOutcome::Success));
+    let fallback = ws.show(&fallback.id).unwrap().issue;
+    assert_eq!(fallback.status, Status::Closed);
+    assert_eq!(fallback.close_outcome, Some(CloseOutcome::Wontfix));
+
+    // Errors come back to the caller instead of exiting the process
+    assert!(br(&beads_dir, &["close", "lib-missing"]).is_err());
+}
+
+#[test]
+fn workspace_close_matches_br_close() {
+    let (_temp, beads_dir) = init_workspace();
+    let mut ws = Workspace::open(&beads_dir).unwrap().with_actor("tester");
+    let done = ws.create(&NewIssue::new("Already done")).unwrap();
+    let blocker = ws.create(&NewIssue::new("Blocker")).unwrap();
+    let attempt = ws.create(&NewIssue::new("Attempt")).unwrap();
+    let target = ws.create(&NewIssue::new("Target")).unwrap();
+    let retry = ws.create(&NewIssue::new("Retry")).unwrap();
+    for depends_on in [&done.id, &blocker.id] {
+        ws.add_dependency(&target.id, depends_on, &DependencyType::Blocks)
+            .unwrap();
+    }
+    ws.add_dependency(&retry.id, &attempt.id, &DependencyType::ConditionalBlocks)
+        .unwrap();
+    ws.close(&done.id, None).unwrap();
+
+    // Only the open blocker is reported
+    let err = ws.close(&target.id, None).unwrap_err().to_string();
+    assert!(err.contains(&blocker.id), "{err}");
+    assert!(!err.contains(&done.id), "{err}");
+    assert_eq!(status(&ws, &target.id), Status::Open);
+
+    let forced = CloseArgs {
+        force: true,
+        session: Some("s-1".to_string()),
+        ..CloseArgs::default()
+    };
+    let closed = ws.close_with(&target.id, &forced).unwrap();
+    assert_eq!(closed.len(), 1);
+    let target = ws.show(&target.id).unwrap().issue;
+    assert_eq!(target.closed_by_session.as_deref(), Some("s-1"));
+
+    let failed = CloseArgs {
+        outcome: Some(CloseOutcome::Failure),
+        ..CloseArgs::default()
+    };
+    ws.close_with(&attempt.id, &failed).unwrap();
+    let ready = ws
+        .ready(&ReadyFilters::default(), ReadySortPolicy::default())
+        .unwrap();
+    let retry_ready = ready
+        .iter()
+        .find(|issue| issue.id == retry.id)
+        .expect("retry is ready once the attempt failed");
+    assert_eq!(
+        retry_ready.condition.as_deref(),
+        Some(format!("runs because {} failed", attempt.id).as_str())
+    );
+
+    // The CLI sees the same state
+    br(&beads_dir, &["close", &blocker.id]).unwrap();
+    let ws = Workspace::open(&beads_dir).unwrap();
+    assert_eq!(status(&ws, &blocker.id), Status::Closed);
+}
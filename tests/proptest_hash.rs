@@ -8,7 +8,7 @@
 
 use chrono::Utc;
 use proptest::prelude::*;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use tracing::info;
 
 use beads_rust::model::{Issue, IssueType, Priority, Status};
@@ -43,6 +43,7 @@ fn make_issue(title: &str, description: Option<&str>) -> Issue {
         updated_at: Utc::now(),
         closed_at: None,
         close_reason: None,
+        close_outcome: None,
         closed_by_session: None,
         due_at: None,
         defer_until: None,
@@ -64,6 +65,7 @@ fn make_issue(title: &str, description: Option<&str>) -> Issue {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        fields: BTreeMap::new(),
     }
 }
 
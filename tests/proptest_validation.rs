@@ -12,6 +12,7 @@ use tracing::info;
 
 use beads_rust::model::{Issue, IssueType, Priority, Status};
 use beads_rust::validation::{IssueValidator, LabelValidator};
+use std::collections::BTreeMap;
 
 /// Initialize test logging for proptest
 fn init_test_logging() {
@@ -43,6 +44,7 @@ fn make_valid_issue(title: &str) -> Issue {
         updated_at: now,
         closed_at: None,
         close_reason: None,
+        close_outcome: None,
         closed_by_session: None,
         due_at: None,
         defer_until: None,
@@ -64,6 +66,7 @@ fn make_valid_issue(title: &str) -> Issue {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        fields: BTreeMap::new(),
     }
 }
 
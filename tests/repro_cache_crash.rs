@@ -1,6 +1,7 @@
 use beads_rust::model::{Issue, IssueType, Priority, Status};
 use beads_rust::storage::SqliteStorage;
 use chrono::Utc;
+use std::collections::BTreeMap;
 
 fn make_issue(id: &str, title: &str) -> Issue {
     Issue {
@@ -22,6 +23,7 @@ fn make_issue(id: &str, title: &str) -> Issue {
         created_by: None,
         closed_at: None,
         close_reason: None,
+        close_outcome: None,
         closed_by_session: None,
         due_at: None,
         defer_until: None,
@@ -43,6 +45,7 @@ fn make_issue(id: &str, title: &str) -> Issue {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        fields: BTreeMap::new(),
     }
 }
 
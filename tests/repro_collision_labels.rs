@@ -2,6 +2,7 @@ use beads_rust::model::{Issue, IssueType, Priority, Status};
 use beads_rust::storage::SqliteStorage;
 use beads_rust::sync::{ImportConfig, import_from_jsonl};
 use chrono::Utc;
+use std::collections::BTreeMap;
 use std::fs;
 use tempfile::TempDir;
 
@@ -26,6 +27,7 @@ fn make_issue(id: &str, title: &str) -> Issue {
         created_by: None,
         closed_at: None,
         close_reason: None,
+        close_outcome: None,
         closed_by_session: None,
         due_at: None,
         defer_until: None,
@@ -47,6 +49,7 @@ fn make_issue(id: &str, title: &str) -> Issue {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        fields: BTreeMap::new(),
     }
 }
 
@@ -11,7 +11,7 @@
 
 mod common;
 
-use beads_rust::model::{DependencyType, Status};
+use beads_rust::model::{CloseOutcome, DependencyType, Status};
 use beads_rust::storage::{IssueUpdate, ReadyFilters, ReadySortPolicy, SqliteStorage};
 use common::{fixtures, test_db};
 
@@ -228,12 +228,18 @@ fn mixed_dependency_types_all_block() {
         "target should be blocked by all three dependency types"
     );
 
-    // Close all blockers
-    for blocker_id in [&b1.id, &b2.id, &b3.id] {
+    // Close all blockers; a conditional blocker only releases its dependent
+    // when it fails
+    for blocker_id in [&b1.id, &b3.id] {
         storage
             .update_issue(blocker_id, &status_update(Status::Closed), "tester")
             .unwrap();
     }
+    let failed = IssueUpdate {
+        close_outcome: Some(Some(CloseOutcome::Failure)),
+        ..status_update(Status::Closed)
+    };
+    storage.update_issue(&b2.id, &failed, "tester").unwrap();
 
     assert!(
         !blocked_ids(&storage).contains(&target.id),
@@ -10,6 +10,7 @@ use beads_rust::model::{DependencyType, EventType, Issue, IssueType, Priority, S
 use beads_rust::storage::{IssueUpdate, SqliteStorage};
 use chrono::{Duration, Utc};
 use common::{fixtures, test_db, test_db_with_dir};
+use std::collections::BTreeMap;
 
 // ============================================================================
 // CREATE ISSUE TESTS
@@ -67,6 +68,7 @@ fn create_issue_all_fields_populated() {
         content_hash: None,
         closed_at: None,
         close_reason: None,
+        close_outcome: None,
         closed_by_session: None,
         source_system: None,
         source_repo: None,
@@ -79,6 +81,7 @@ fn create_issue_all_fields_populated() {
         compacted_at_commit: None,
         original_size: None,
         sender: None,
+        fields: BTreeMap::new(),
     };
 
     storage.create_issue(&issue, "tester").unwrap();
@@ -422,6 +425,7 @@ fn update_issue_clear_optional_fields() {
         created_by: None,
         closed_at: None,
         close_reason: None,
+        close_outcome: None,
         closed_by_session: None,
         due_at: None,
         defer_until: None,
@@ -443,6 +447,7 @@ fn update_issue_clear_optional_fields() {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        fields: BTreeMap::new(),
     };
 
     storage.create_issue(&issue, "tester").unwrap();
@@ -705,6 +710,7 @@ fn upsert_issue_stores_all_fields() {
         content_hash: Some("abc123".to_string()),
         closed_at: None,
         close_reason: None,
+        close_outcome: None,
         closed_by_session: None,
         source_system: Some("test".to_string()),
         source_repo: None,
@@ -717,6 +723,7 @@ fn upsert_issue_stores_all_fields() {
         compacted_at_commit: None,
         original_size: None,
         sender: None,
+        fields: BTreeMap::new(),
     };
 
     storage.upsert_issue_for_import(&issue).unwrap();
@@ -16,6 +16,7 @@ use beads_rust::util::id::{
     IdConfig, IdGenerator, compute_id_hash, generate_id_seed, is_valid_id_format, parse_id,
 };
 use beads_rust::util::{ContentHashable, content_hash, content_hash_from_parts};
+use std::collections::BTreeMap;
 
 // =============================================================================
 // ID GENERATION FIXTURES
@@ -568,6 +569,7 @@ fn content_hash_trait_implementation() {
         updated_at: Utc::now(),
         closed_at: None,
         close_reason: None,
+        close_outcome: None,
         closed_by_session: None,
         due_at: None,
         defer_until: None,
@@ -589,6 +591,7 @@ fn content_hash_trait_implementation() {
         labels: vec![],
         dependencies: vec![],
         comments: vec![],
+        fields: BTreeMap::new(),
     };
 
     let hash_trait = issue.content_hash();